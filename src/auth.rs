@@ -0,0 +1,152 @@
+use crate::error::ClientError;
+use crate::protocol::game::login::{self, OfflineIdentity};
+use crate::utils::encryption;
+use crate::utils::jwt::Jwt;
+use chrono::Utc;
+use minecraft_auth::bedrock;
+use openssl::ec::EcKey;
+use openssl::pkey::{PKey, Private};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs::{self, OpenOptions};
+use std::future::Future;
+use std::io::Write;
+use std::path::PathBuf;
+
+// Everything the login needs: the identity chain and the key the last chain entry was issued for
+pub struct AuthData {
+    pub chain: Vec<String>,
+    pub ec_key: EcKey<Private>
+}
+
+impl AuthData {
+    // Earliest `exp` of the chain, None if no entry has one
+    pub fn expires_at(&self) -> Option<i64> {
+        self.chain.iter()
+            .filter_map(|jwt| Jwt::parse(jwt).ok())
+            .filter_map(|jwt| jwt.get_claim("exp").and_then(Value::as_i64))
+            .min()
+    }
+}
+
+pub trait AuthProvider {
+    fn authenticate(&mut self) -> impl Future<Output = Result<AuthData, ClientError>>;
+}
+
+// Xbox Live device code login
+pub struct MicrosoftAuth {
+    client_version: String
+}
+
+impl MicrosoftAuth {
+    pub fn new(client_version: String) -> MicrosoftAuth {
+        MicrosoftAuth{ client_version }
+    }
+}
+
+impl AuthProvider for MicrosoftAuth {
+    async fn authenticate(&mut self) -> Result<AuthData, ClientError> {
+        let mut bedrock = bedrock::new(self.client_version.clone(), false);
+        if !bedrock.auth().await {
+            return Err(ClientError::Auth("Xbox Live authentication failed".to_string(), None));
+        }
+        let ec_key = bedrock.get_ec_key().ok_or(ClientError::Auth("Authentication did not provide an EC key".to_string(), None))?;
+        Ok(AuthData{ chain: bedrock.get_chain_data(), ec_key })
+    }
+}
+
+// Self-signed identity for servers running with online-mode=false
+pub struct OfflineAuth {
+    identity: OfflineIdentity
+}
+
+impl OfflineAuth {
+    pub fn new(identity: OfflineIdentity) -> OfflineAuth {
+        OfflineAuth{ identity }
+    }
+
+    pub fn auth_data(&self) -> Result<AuthData, ClientError> {
+        let ec_key = encryption::generate_ec_key().map_err(|e| ClientError::crypto("EC key can not be generated", e))?;
+        let pkey = PKey::from_ec_key(ec_key.clone()).map_err(|e| ClientError::crypto("EC key can not be converted to PKey", e))?;
+        let chain = login::offline_identity_chain(&self.identity, &pkey)?;
+        Ok(AuthData{ chain, ec_key })
+    }
+}
+
+impl AuthProvider for OfflineAuth {
+    async fn authenticate(&mut self) -> Result<AuthData, ClientError> {
+        self.auth_data()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedAuthFile {
+    chain: Vec<String>,
+    ec_key_pem: String
+}
+
+// Keeps the chain and key of another provider on disk and only asks it again when the chain is about to expire
+pub struct CachedAuth<A: AuthProvider> {
+    path: PathBuf,
+    inner: A,
+    refresh_before: i64 // seconds before `exp`
+}
+
+impl<A: AuthProvider> CachedAuth<A> {
+    pub fn new(path: impl Into<PathBuf>, inner: A) -> CachedAuth<A> {
+        CachedAuth{ path: path.into(), inner, refresh_before: 3600 }
+    }
+
+    pub fn refresh_before(mut self, seconds: i64) -> CachedAuth<A> {
+        self.refresh_before = seconds;
+        self
+    }
+
+    fn load(&self) -> Option<AuthData> {
+        let contents = fs::read_to_string(&self.path).ok()?;
+        let file: CachedAuthFile = serde_json::from_str(&contents).ok()?;
+        let ec_key = EcKey::private_key_from_pem(file.ec_key_pem.as_bytes()).ok()?;
+        Some(AuthData{ chain: file.chain, ec_key })
+    }
+
+    fn save(&self, auth_data: &AuthData) -> Result<(), ClientError> {
+        let ec_key_pem = auth_data.ec_key.private_key_to_pem().map_err(|e| ClientError::crypto("EC key can not be encoded", e))?;
+        let file = CachedAuthFile{ chain: auth_data.chain.clone(), ec_key_pem: String::from_utf8_lossy(&ec_key_pem).to_string() };
+        let contents = serde_json::to_string(&file).map_err(|e| ClientError::auth("Auth cache can not be encoded", e))?;
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // the file holds a private key: it is only readable by the owner from the start and replaced in one step,
+        // so nobody can read it in between and a crash does not leave half a file
+        let mut temp_name = self.path.file_name().unwrap_or_default().to_os_string();
+        temp_name.push(".tmp");
+        let temp_path = self.path.with_file_name(temp_name);
+        let _ = fs::remove_file(&temp_path); // left over from a crash, possibly with other permissions
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut temp_file = options.open(&temp_path)?;
+        temp_file.write_all(contents.as_bytes())?;
+        temp_file.sync_all()?;
+        fs::rename(&temp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+impl<A: AuthProvider> AuthProvider for CachedAuth<A> {
+    async fn authenticate(&mut self) -> Result<AuthData, ClientError> {
+        if let Some(auth_data) = self.load() {
+            if auth_data.expires_at().is_some_and(|exp| exp - self.refresh_before > Utc::now().timestamp()) {
+                return Ok(auth_data);
+            }
+        }
+        let auth_data = self.inner.authenticate().await?;
+        self.save(&auth_data)?;
+        Ok(auth_data)
+    }
+}
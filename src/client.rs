@@ -1,40 +1,64 @@
+use crate::auth::{AuthData, AuthProvider, MicrosoftAuth, OfflineAuth};
+use crate::client_data_options::ClientDataOptions;
+use crate::connection_state::{ConnectionState, StateChange, StateMachine};
+use crate::error::ClientError;
+use crate::entity_manager::EntityManager;
+use crate::handler::{ClientHandler, DebugHandler};
+use crate::movement::{MovementController, TICK_INTERVAL};
+use crate::online_players::OnlinePlayers;
 use crate::protocol::acknowledge::Acknowledge;
 use crate::protocol::conn_req::ConnReq;
 use crate::protocol::conn_req_accepted::ConnReqAccepted;
 use crate::protocol::connected_ping::ConnectedPing;
 use crate::protocol::connected_pong::ConnectedPong;
-use crate::protocol::frame_set::{Datagram, Frame, FrameNumberCache, RELIABLE, RELIABLE_ORDERED, UNRELIABLE};
+use crate::protocol::frame_set::{Datagram, Frame, RELIABLE, UNRELIABLE};
+use crate::protocol::game::bedrock_packet::BedrockPacket;
 use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::packet_registry::{Packet, PacketRegistry};
+use crate::protocol::game::login::{self, OfflineIdentity};
 use crate::protocol::game::play_status::LoginStatus;
+use crate::protocol::game::protocol_version;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+use crate::protocol::game::types::login_identity::LoginIdentity;
+use crate::protocol::game::types::vector::Vec3;
 use crate::protocol::game::*;
-use crate::protocol::game_packet::GamePacket;
+use crate::protocol::game_packet::{Compression, CompressionAlgorithm, GamePacket};
 use crate::protocol::new_incoming_conn::NewIncomingConn;
 use crate::protocol::open_conn_reply1::OpenConnReply1;
 use crate::protocol::open_conn_reply2::OpenConnReply2;
 use crate::protocol::open_conn_req1::OpenConnReq1;
 use crate::protocol::open_conn_req2::OpenConnReq2;
 use crate::protocol::packet_ids::{PacketType, MAGIC};
+use crate::protocol::unconnected_ping::UnconnectedPing;
+use crate::protocol::unconnected_pong::UnconnectedPong;
 use crate::protocol::*;
+use crate::session::{ClientHandle, PacketBatch, SendOptions, Session};
 use crate::utils::address::InternetAddress;
+use crate::utils::command_tree::CommandTree;
 use crate::utils::chunk::block::{BlockMapBuilder, BlockType, PropertyValues};
-use crate::utils::color_format::COLOR_WHITE;
+use crate::utils::chunk::block_palette::BlockPalette;
+use crate::utils::chunk::world::World;
 use crate::utils::encryption::Encryption;
-use crate::utils::{address, color_format, encryption};
+use crate::utils::jwt::Jwt;
+use crate::utils::pack_cache::{self, DownloadedPack, PackCache, PackDownload};
+use crate::utils::{address, encryption, log_target};
 use crate::*;
 use binary_utils::binary::Stream;
 use chrono::Utc;
-use minecraft_auth::bedrock;
+use log::{debug, error, trace, warn};
 use mojang_nbt::tag::compound_tag::CompoundTag;
 use mojang_nbt::tag::tag::Tag;
 use openssl::base64::decode_block;
 use openssl::ec::EcKey;
 use openssl::pkey::{PKey, Private};
 use rand::Rng;
-use serde_json::Value;
 use std::collections::{BTreeMap, HashMap};
-use std::io::Result;
+use std::io::ErrorKind;
 use std::net::UdpSocket;
-//use crate::handle_incoming_data;
+use std::sync::mpsc::Receiver;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
 
 
 // conn_req update
@@ -44,90 +68,305 @@ use std::net::UdpSocket;
 // fragment packet receiving - sending etc.
 // gönderdiğimiz paketleri buna kaydetme: FrameCache { //sequencenumber => framecache eğer nack gelirse birdaha göndeririz
 
+// unconnected pings sent (one per socket read timeout) before version detection gives up
+const UNCONNECTED_PING_ATTEMPTS: u32 = 10;
+
 pub struct Client {
-    socket: UdpSocket,
+    socket: Arc<UdpSocket>,
     target_address: String,
     target_port: u16,
     client_guid: i64,
     client_version: String,
+    protocol: u32,
+    detect_protocol: bool,
     chain: Vec<String>,
+    identity: LoginIdentity,
+    client_data_options: ClientDataOptions,
+    pack_cache: Option<PackCache>,
+    missing_packs: HashMap<Uuid, String>, // uuid => version of the packs still to download
+    pack_downloads: HashMap<String, PackDownload>, // pack id => download
+    pack_keys: HashMap<Uuid, String>, // content keys of the encrypted packs
+    block_palette: Option<BlockPalette>, // vanilla blocks, the custom blocks of StartGame are added to it
     ec_key: EcKey<Private>,
-    game: GamePacket,
-    frame_number_cache: FrameNumberCache,
+    session: Arc<Mutex<Session>>,
     last_received_packets: HashMap<i32, Frame>, // reliable_frame_index: Frame
     last_received_fragment_packets: HashMap<u16, HashMap<u32, Vec<u8>>>, // split_id: index => buffer
     last_received_sequence_number: i32,
     last_handled_reliable_frame_index: i32,
-    debug: bool,
-    compression_enabled: bool,
-    encryption_enabled: bool
+    state: StateMachine,
+    next_tick: Option<Instant>, // set with the first tick in game
+    handler: Box<dyn ClientHandler>,
+    packet_registry: PacketRegistry
+}
+
+pub async fn create(target_address: String, target_port: u16, client_version: String) -> Result<Client, ClientError> {
+    let (auth_version, _, _) = resolve_version(&client_version)?;
+    create_with_auth(target_address, target_port, client_version, &mut MicrosoftAuth::new(auth_version)).await
 }
 
-pub async fn create(target_address: String, target_port: u16, client_version: String, debug: bool) -> Option<Client> {
-    //block::vanilla_block_map(false, &vec![]);
-    let mut bedrock = bedrock::new(client_version.clone(), false);
-    if !bedrock.auth().await { return None; }
+pub async fn create_with_auth(target_address: String, target_port: u16, client_version: String, auth: &mut impl AuthProvider) -> Result<Client, ClientError> {
+    let auth_data = auth.authenticate().await?;
+    new_client(target_address, target_port, client_version, auth_data)
+}
+
+// Login without Xbox Live for servers running with online-mode=false, the identity chain is self-signed
+pub fn create_offline(target_address: String, target_port: u16, client_version: String, identity: OfflineIdentity) -> Result<Client, ClientError> {
+    new_client(target_address, target_port, client_version, OfflineAuth::new(identity).auth_data()?)
+}
+
+// "auto" takes the version from the server's unconnected pong in `connect`, until then the latest one is assumed
+fn resolve_version(client_version: &str) -> Result<(String, u32, bool), ClientError> {
+    if client_version == protocol_version::AUTO {
+        return Ok((protocol_version::game_version(protocol_version::LATEST).unwrap_or_default().to_string(), protocol_version::LATEST, true));
+    }
+    let protocol = protocol_version::from_game_version(client_version).ok_or(ClientError::UnsupportedVersion(format!("Game version {} is not supported", client_version)))?;
+    Ok((client_version.to_string(), protocol, false))
+}
+
+fn new_client(target_address: String, target_port: u16, client_version: String, auth_data: AuthData) -> Result<Client, ClientError> {
+    let (client_version, protocol, detect_protocol) = resolve_version(&client_version)?;
     let mut rng = rand::thread_rng();
-    Option::from(Client{
-        socket: UdpSocket::bind("0.0.0.0:0").expect("Socket Bind Error"),
+    let socket = Arc::new(UdpSocket::bind("0.0.0.0:0")?);
+    let identity = login::identity_from_chain(&auth_data.chain)?;
+    let session = Session::new(socket.clone(), GamePacket::new(None, None), frame_set::start_number_cache(), protocol, identity.clone());
+    let mut packet_registry = PacketRegistry::new();
+    packet_registry.set_protocol(protocol);
+    Ok(Client{
+        socket,
         target_address,
         target_port,
         client_guid: rng.gen_range(10000..100000),
         client_version,
-        chain: bedrock.get_chain_data(),
-        ec_key: bedrock.get_ec_key()?,
-        game: GamePacket::new(None, false),
-        frame_number_cache: frame_set::start_number_cache(),
+        protocol,
+        detect_protocol,
+        chain: auth_data.chain,
+        identity,
+        client_data_options: ClientDataOptions::new(),
+        pack_cache: None,
+        missing_packs: HashMap::new(),
+        pack_downloads: HashMap::new(),
+        pack_keys: HashMap::new(),
+        block_palette: None,
+        ec_key: auth_data.ec_key,
+        session: Arc::new(Mutex::new(session)),
         last_received_packets: HashMap::new(),
         last_received_fragment_packets: HashMap::new(),
         last_received_sequence_number: -1,
         last_handled_reliable_frame_index: -1,
-        debug,
-        compression_enabled: false,
-        encryption_enabled: false
+        state: StateMachine::new(),
+        next_tick: None,
+        handler: Box::new(DebugHandler{}),
+        packet_registry
     })
 }
 
 impl Client {
-    pub fn connect(&mut self) -> Result<()> {
-        if self.debug {
-            println!("Local socket bound to: {}", self.socket.local_addr()?);
-        }
+    pub fn connect(&mut self) -> Result<(), ClientError> {
+        debug!(target: log_target::RAKNET, "Local socket bound to: {}", self.socket.local_addr()?);
         let address = format!("{}:{}", self.target_address, self.target_port);
         self.socket.connect(address)?;
+        // wake up regularly so that state timeouts are noticed even if the server goes silent
+        self.socket.set_read_timeout(Option::from(Duration::from_millis(500)))?;
+
+        let result = self.detect_protocol_version().and_then(|_| self.read_raknet_socket());
+        if let Err(e) = &result {
+            error!(target: log_target::RAKNET, "{}", e);
+            if !self.state.is(ConnectionState::Disconnected) {
+                let _ = self.set_state(ConnectionState::Disconnected); // always allowed from the other states
+            }
+        }
+        result
+    }
+
+    pub fn get_state(&self) -> ConnectionState {
+        self.state.get()
+    }
+
+    pub fn get_protocol(&self) -> u32 {
+        self.protocol
+    }
+
+    pub fn get_commands(&self) -> Option<CommandTree> {
+        self.session().commands.clone()
+    }
+
+    pub fn movement<R>(&self, f: impl FnOnce(&mut MovementController) -> R) -> R {
+        f(&mut self.session().movement)
+    }
+
+    pub fn world<R>(&self, f: impl FnOnce(&World) -> R) -> R {
+        f(&self.session().world)
+    }
+
+    pub fn entities<R>(&self, f: impl FnOnce(&EntityManager) -> R) -> R {
+        f(&self.session().entities)
+    }
+
+    pub fn players<R>(&self, f: impl FnOnce(&OnlinePlayers) -> R) -> R {
+        f(&self.session().players)
+    }
+
+    // Names the runtime IDs of the chunks, without it the physics of the local player stay off.
+    // Must be the palette of the game version the server runs.
+    pub fn set_block_palette(&mut self, palette: BlockPalette) {
+        self.block_palette = Option::from(palette);
+    }
+
+    pub fn get_identity(&self) -> &LoginIdentity {
+        &self.identity
+    }
+
+    pub fn subscribe_state(&mut self) -> Receiver<StateChange> {
+        self.state.subscribe()
+    }
+
+    pub fn set_handler(&mut self, handler: impl ClientHandler + 'static) {
+        self.handler = Box::new(handler);
+    }
+
+    // Skin, device and settings sent with the Login packet, has to be set before `connect`
+    pub fn set_client_data_options(&mut self, options: ClientDataOptions) {
+        self.client_data_options = options;
+    }
+
+    // Packs found here are not downloaded again, downloaded packs are added
+    pub fn set_pack_cache(&mut self, pack_cache: PackCache) {
+        self.pack_cache = Option::from(pack_cache);
+    }
+
+    pub fn handle(&self) -> ClientHandle {
+        ClientHandle::new(self.session.clone())
+    }
+
+    // Requests the next chunk or, once all arrived, verifies and stores the pack
+    fn continue_pack_download(&mut self, download: PackDownload) -> Result<(), ClientError> {
+        if let Some(chunk_index) = download.next_chunk() {
+            let chunk_request = resource_pack_chunk_request::new(download.pack_id.clone(), chunk_index);
+            self.send_packet(&chunk_request, SendOptions::default())?;
+            self.pack_downloads.insert(download.pack_id.clone(), download);
+            return Ok(());
+        }
 
-        self.read_raknet_socket();
+        let pack_id = download.pack_id.clone();
+        let data = download.finish()?;
+        let (uuid, version) = pack_cache::split_pack_id(&pack_id);
+        let uuid = Uuid::parse_str(uuid).map_err(|e| ClientError::decode(format!("Resource pack id {} is not a UUID", pack_id), e))?;
+        let version = self.missing_packs.remove(&uuid).or(version.map(|version| version.to_string())).unwrap_or_default();
+        if let Some(cache) = &self.pack_cache {
+            cache.store(&uuid, &version, &data)?;
+        }
+        let content_key = self.pack_keys.get(&uuid).cloned().unwrap_or_default();
+        self.handler.on_resource_pack(&DownloadedPack{ uuid, version, content_key, data });
 
+        if self.missing_packs.is_empty() && self.pack_downloads.is_empty() {
+            // RESOURCE PACK CLIENT RESPONSE PACKET {HAVE_ALL_PACKS}
+            let rp_client_response = resource_pack_client_response::new(resource_pack_client_response::HAVE_ALL_PACKS, vec![]);
+            self.send_packet(&rp_client_response, SendOptions::default())?;
+        }
         Ok(())
     }
 
-    fn read_raknet_socket(&mut self) {
-        let req1: Vec<u8> = OpenConnReq1::new(MAGIC, RAKNET_PROTOCOL_VERSION, 1492).encode();
-        self.socket.send(&req1).expect("Packet could not be sent");
+    pub fn send_packet<P: BedrockPacket>(&self, packet: &P, options: SendOptions) -> Result<(), ClientError> {
+        self.session().send_packet(packet, options)
+    }
+
+    pub fn send_batch(&self, batch: &PacketBatch, options: SendOptions) -> Result<(), ClientError> {
+        self.session().send_batch(batch, options)
+    }
+
+    pub fn chat(&self, message: &str) -> Result<(), ClientError> {
+        self.session().chat(message)
+    }
+
+    pub fn whisper(&self, target: &str, message: &str) -> Result<(), ClientError> {
+        self.session().whisper(target, message)
+    }
+
+    // Empty batch encoded with the protocol of this client
+    pub fn new_batch(&self) -> PacketBatch {
+        PacketBatch::with_protocol(self.protocol)
+    }
+
+    fn session(&self) -> MutexGuard<'_, Session> {
+        self.session.lock().unwrap()
+    }
+
+    fn set_state(&mut self, next: ConnectionState) -> Result<(), ClientError> {
+        let from = self.state.get();
+        self.state.transition(next)?;
+        self.session().state = next;
+        self.handler.on_state_change(&StateChange{ from, to: next });
+        Ok(())
+    }
+
+    fn set_protocol(&mut self, protocol: u32, game_version: String) {
+        debug!(target: log_target::GAME, "Using protocol {} ({})", protocol, game_version);
+        self.protocol = protocol;
+        self.client_version = game_version;
+        self.session().protocol = protocol;
+        self.packet_registry.set_protocol(protocol);
+    }
+
+    // The server id in the unconnected pong contains the protocol and the game version of the server
+    fn detect_protocol_version(&mut self) -> Result<(), ClientError> {
+        if !self.detect_protocol {
+            return Ok(());
+        }
 
         let mut buffer = vec![0; 2048];
+        for _ in 0..UNCONNECTED_PING_ATTEMPTS {
+            let ping = UnconnectedPing::create(Utc::now().timestamp_millis(), self.client_guid).encode();
+            self.session().send_unconnected(&ping)?;
+
+            match self.socket.recv_from(&mut buffer) {
+                Ok((amt, _src)) => {
+                    if amt == 0 || !matches!(PacketType::from_byte(buffer[0]), PacketType::UnconnectedPong) { continue; }
+
+                    let unconnected_pong = UnconnectedPong::decode(Vec::from(&buffer[..amt])).map_err(|e| ClientError::decode("Unconnected pong could not be decoded", e))?;
+                    unconnected_pong.debug();
+
+                    let protocol = unconnected_pong.get_protocol().ok_or(ClientError::Protocol(format!("Server id has no protocol version: {}", unconnected_pong.server_id), None))?;
+                    let game_version = protocol_version::game_version(protocol).ok_or(ClientError::UnsupportedVersion(format!("Server protocol {} is not supported", protocol)))?;
+                    self.set_protocol(protocol, unconnected_pong.get_game_version().unwrap_or(game_version.to_string()));
+                    return Ok(());
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock || e.kind() == ErrorKind::TimedOut => continue,
+                Err(e) => return Err(e.into())
+            }
+        }
+        Err(ClientError::Protocol("Server did not answer the unconnected ping, protocol version could not be detected".to_string(), None))
+    }
 
-        let mut should_stop = false;
+    fn read_raknet_socket(&mut self) -> Result<(), ClientError> {
+        let req1: Vec<u8> = OpenConnReq1::new(MAGIC, RAKNET_PROTOCOL_VERSION, 1492).encode();
+        self.socket.send(&req1)?;
 
-        loop {
-            if should_stop { break; }
+        let mut buffer = vec![0; 2048];
+        // the socket read timeout is one tick, so ticks are on time even when the server sends nothing
+        self.socket.set_read_timeout(Option::from(TICK_INTERVAL))?;
 
+        while !self.state.is(ConnectionState::Disconnected) {
+            // checked on every iteration, pings and ACKs of the server must not keep a stuck state alive
+            if self.state.is_timed_out() {
+                return Err(ClientError::Protocol(format!("Timed out in state {}", self.state.get()), None));
+            }
+            self.run_ticks()?;
             match self.socket.recv_from(&mut buffer) {
                 Ok((amt, _src)) => {
                     let mut stream = Stream::new(Vec::from(&buffer[..amt]), 0);
 
-                    let packet_id = stream.get_byte();
+                    let packet_id = stream.try_get_byte()?;
                     let packet_type = PacketType::from_byte(packet_id);
 
-                    should_stop = self.raknet_packet_handler(packet_type, &mut stream);
+                    self.raknet_packet_handler(packet_type, &mut stream)?;
 
                     if !frame_set::is_datagram(packet_id) { continue; }
 
-                    let datagram = Datagram::from_binary(stream.get_buffer());
+                    let datagram = Datagram::from_binary(stream.get_buffer()).map_err(|e| ClientError::decode("Datagram could not be decoded", e))?;
 
                     // SENDING ACK
                     let ack = Acknowledge::create(PacketType::ACK, 1, true, Option::from(datagram.sequence_number.clone()), None, None);
-                    self.socket.send(&ack.encode()).expect("ACK Send Error");
+                    self.session().send_unconnected(&ack.encode())?;
 
                     let seq = datagram.sequence_number;
 
@@ -137,10 +376,10 @@ impl Client {
                         } else {
                             // UNRELIABLE PACKET HANDLER
                             let mut stream = Stream::new(frame.body, 0);
-                            let packet_id = stream.get_byte();
+                            let packet_id = stream.try_get_byte()?;
                             let packet_type = PacketType::from_byte(packet_id);
 
-                            should_stop = self.raknet_packet_handler(packet_type, &mut stream);
+                            self.raknet_packet_handler(packet_type, &mut stream)?;
                         }
                     }
 
@@ -148,7 +387,7 @@ impl Client {
                     if (self.last_received_sequence_number + 1) != seq {
                         for seq_num in (self.last_received_sequence_number+1)..seq {
                             let nack = Acknowledge::create(PacketType::NACK, 1, true, Option::from(seq_num), None, None);
-                            self.socket.send(&nack.encode()).expect("NACK Send Error");
+                            self.session().send_unconnected(&nack.encode())?;
                         }
                     }
                     if seq > self.last_received_sequence_number {
@@ -201,341 +440,68 @@ impl Client {
 
                                 // PACKET HANDLER
                                 let mut stream = Stream::new(real_body, 0);
-                                let packet_id = stream.get_byte();
+                                let packet_id = stream.try_get_byte()?;
                                 let packet_type = PacketType::from_byte(packet_id);
 
                                 match packet_type {
                                     PacketType::NACK => {
-                                        let nack = Acknowledge::decode(stream.get_buffer());
-                                        if self.debug { nack.debug(true); }
+                                        let nack = Acknowledge::decode(stream.get_buffer()).map_err(|e| ClientError::decode("NACK could not be decoded", e))?;
+                                        nack.debug(true);
                                     }
                                     PacketType::ConnectedPing => {
-                                        let connected_ping = ConnectedPing::decode(stream.get_buffer());
-                                        if self.debug { connected_ping.debug(); }
+                                        let connected_ping = ConnectedPing::decode(stream.get_buffer()).map_err(|e| ClientError::decode("Connected ping could not be decoded", e))?;
+                                        connected_ping.debug();
 
                                         let connected_pong = ConnectedPong::create(connected_ping.ping_time, Utc::now().timestamp()).encode();
-                                        let frame = Datagram::create_frame(connected_pong, UNRELIABLE, &self.frame_number_cache, None);
-                                        let datagram = Datagram::create(vec![frame], &self.frame_number_cache).to_binary();
-                                        self.frame_number_cache.sequence_number += 1;
-                                        self.socket.send(&datagram).expect("ConnectedPong Packet could not be sent");
+                                        self.session().send_raw(connected_pong, SendOptions::new(UNRELIABLE, 0))?;
                                     },
                                     PacketType::ConnectedPong => {
-                                        let connected_pong = ConnectedPong::decode(stream.get_buffer());
-                                        if self.debug { connected_pong.debug(); }
-                                        /*let connected_ping = connected_ping::create(Utc::now().timestamp()).encode();
-                                        let frame = Datagram::create_frame(connected_ping, UNRELIABLE, &self.frame_number_cache, None);
-                                        let datagram = Datagram::create(vec![frame], &self.frame_number_cache).to_binary();
-                                        self.frame_number_cache.sequence_number += 1;
-                                        self.socket.send(&datagram).expect("ConnectedPing Packet could not be sent");*/
+                                        let connected_pong = ConnectedPong::decode(stream.get_buffer()).map_err(|e| ClientError::decode("Connected pong could not be decoded", e))?;
+                                        connected_pong.debug();
                                     },
                                     PacketType::ConnReqAccepted => {
-                                        self.raknet_packet_handler(PacketType::ConnReqAccepted, &mut stream);
+                                        self.raknet_packet_handler(PacketType::ConnReqAccepted, &mut stream)?;
                                     },
                                     PacketType::Game => {
-                                        if self.encryption_enabled {
-                                            stream = Stream::new(self.game.decrypt(&stream.get_remaining().unwrap()), 0);
+                                        if !self.state.get().accepts_raknet(PacketType::Game) {
+                                            debug!(target: log_target::RAKNET, "Game packet rejected in state {}", self.state.get());
+                                            self.last_handled_reliable_frame_index = reliable_frame_index;
+                                            self.last_received_packets.remove(&reliable_frame_index);
+                                            continue;
                                         }
 
-                                        if self.compression_enabled {
-                                            let compression_type = stream.get_byte();
-
-                                            println!("Compression Type: {}", if compression_type == 0 { format!("{}ZLIB{}", color_format::COLOR_AQUA, COLOR_WHITE) } else if compression_type == 1 { format!("{}SNAPPY{}", color_format::COLOR_AQUA, COLOR_WHITE) } else { format!("{}NONE{}", color_format::COLOR_AQUA, COLOR_WHITE) });
-                                            if compression_type == 0 {
-                                                stream = Stream::new(GamePacket::decompress(&stream.get_remaining().unwrap()), 0);
+                                        {
+                                            let mut session = self.session();
+                                            if session.game.encryption.is_some() {
+                                                let payload = stream.get_remaining().map_err(|e| ClientError::decode("Game packet payload could not be read", e))?;
+                                                stream = Stream::new(session.game.decrypt(&payload)?, 0);
+                                            }
+                                            if session.game.compression.is_some() {
+                                                let payload = stream.get_remaining().map_err(|e| ClientError::decode("Game packet payload could not be read", e))?;
+                                                stream = Stream::new(GamePacket::decompress(&payload)?, 0);
                                             }
                                         }
 
                                         while !stream.feof() {
-                                            let length = stream.get_unsigned_var_int();
+                                            let length = stream.try_get_unsigned_var_int()?;
 
-                                            let packet = stream.get(length).unwrap();
+                                            let packet = stream.try_get(length).map_err(|e| ClientError::decode("Batched packet could not be read", e))?;
                                             let mut packet_stream = Stream::new(packet, 0);
 
-                                            let packet_id = packet_stream.get_unsigned_var_int();
+                                            let packet_id = packet_stream.try_get_unsigned_var_int()?;
                                             let packet_type = BedrockPacketType::from_byte(packet_id as u16);
 
-                                            println!("--- {}{}{} ---", color_format::COLOR_GOLD, BedrockPacketType::get_packet_name(packet_id as u16), COLOR_WHITE);
-                                            match packet_type {
-                                                BedrockPacketType::NetworkSettings => {
-                                                    let network_settings = network_settings::decode(packet_stream.get_remaining().unwrap());
-                                                    println!("Compression Threshold: {}", if network_settings.compression_threshold == 1 { "COMPRESS_EVERYTHING" } else { "COMPRESS_NOTHING" });
-                                                    println!("Compression Algorithm: {}", if network_settings.compression_algorithm == 0 { "ZLIB" } else if network_settings.compression_algorithm == 1 { "SNAPPY" } else { "NONE" });
-                                                    println!("Enable Client Throttling: {}", network_settings.enable_client_throttling);
-                                                    println!("Client Throttle Threshold: {}", network_settings.client_throttle_threshold);
-                                                    println!("Client Throttle Scalar: {}", network_settings.client_throttle_scalar);
-
-                                                    self.game = GamePacket::new(None, true);
-                                                    self.compression_enabled = true;
-
-                                                    // LOGIN PACKET
-                                                    let pkey = PKey::from_ec_key(self.ec_key.clone()).expect("PKey Error");
-                                                    let login_data_detail = login::convert_login_chain(&mut self.chain, pkey, self.target_address.clone(), self.target_port, self.client_guid, self.client_version.clone());
-                                                    let login = login::new(BEDROCK_PROTOCOL_VERSION, login_data_detail[0].clone(), login_data_detail[1].clone()).encode();
-
-                                                    let datagrams = Datagram::split_packet(login, &mut self.frame_number_cache);
-
-                                                    for datagram in datagrams {
-                                                        self.socket.send(&datagram.to_binary()).expect("Login Packet Fragment could not be sent");
-                                                    }
-                                                },
-                                                BedrockPacketType::ServerToClientHandshake => {
-                                                    let s_to_c_handshake = server_to_client_handshake::decode(packet_stream.get_remaining().unwrap());
-                                                    let jwt = String::from_utf8(s_to_c_handshake.jwt).unwrap();
-                                                    println!("JWT: {}", jwt);
-                                                    let jwt_split: Vec<&str> = jwt.split('.').collect();
-
-                                                    let jwt_header = Encryption::b64_url_decode(jwt_split[0]).unwrap();
-                                                    let jwt_header_value: Value = serde_json::from_str(jwt_header.as_str()).expect("JWT Header can not decoded.");
-
-                                                    let jwt_payload = Encryption::b64_url_decode(jwt_split[1]).unwrap();
-                                                    let jwt_payload_value: Value = serde_json::from_str(jwt_payload.as_str()).expect("JWT Payload can not decoded.");
-
-                                                    let x5u = jwt_header_value.get("x5u").and_then(Value::as_str).unwrap().to_string();
-                                                    let server_private = encryption::parse_der_public_key(decode_block(x5u.as_str()).unwrap().as_slice());
-                                                    let salt = decode_block(jwt_payload_value.get("salt").and_then(Value::as_str).unwrap()).unwrap();
-
-                                                    let local_pkey = PKey::from_ec_key(self.ec_key.clone()).expect("Local PKey Error");
-                                                    let shared_secret = encryption::generate_shared_secret(local_pkey, server_private);
-                                                    let encryption_key = encryption::generate_key(&shared_secret, salt);
-                                                    let encryption = Encryption::fake_gcm(encryption_key).expect("Encryption Fake GCM Error");
-
-                                                    self.game = GamePacket::new(Option::from(encryption), self.compression_enabled);
-                                                    self.encryption_enabled = true;
-
-                                                    // CLIENT TO SERVER HANDSHAKE PACKET
-                                                    let c_to_s_handshake = client_to_server_handshake::new().encode();
-
-                                                    let game_packet = self.game.encode(&c_to_s_handshake);
-
-                                                    let datagrams = Datagram::split_packet(game_packet, &mut self.frame_number_cache);
-
-                                                    for datagram in datagrams {
-                                                        self.socket.send(&datagram.to_binary()).expect("ClientToServerHandshake Packet Fragment could not be sent");
-                                                    }
-                                                },
-                                                BedrockPacketType::ResourcePacksInfo => {
-                                                    let resource_packs_info = resource_packs_info::decode(packet_stream.get_remaining().unwrap());
-                                                    let mut rp_uuids = Vec::new();
-                                                    println!("Must Accept: {}", resource_packs_info.must_accept);
-                                                    println!("Has Addons: {}", resource_packs_info.has_addons);
-                                                    println!("Has Scripts: {}", resource_packs_info.has_scripts);
-                                                    println!("World Template ID: {}", resource_packs_info.world_template_id);
-                                                    println!("World Template Version: {}", resource_packs_info.world_template_version);
-                                                    let resource_pack_count = resource_packs_info.resource_packs.len();
-                                                    println!("Resource Pack Count: {}", resource_pack_count);
-                                                    for (i, resource_pack) in resource_packs_info.resource_packs.iter().enumerate() {
-                                                        rp_uuids.push(resource_pack.uuid.clone());
-                                                        println!("- Resource Pack {} -", i + 1);
-                                                        println!(" - UUID: {}", resource_pack.uuid);
-                                                        println!(" - Version: {}", resource_pack.version);
-                                                        println!(" - Size Bytes: {}", resource_pack.size_bytes);
-                                                        println!(" - Encryption Key: {}", resource_pack.encryption_key);
-                                                        println!(" - Sub Pack Name: {}", resource_pack.sub_pack_name);
-                                                        println!(" - Content ID: {}", resource_pack.content_id);
-                                                        println!(" - Has Scripts: {}", resource_pack.has_scripts);
-                                                        println!(" - Is Addon Pack: {}", resource_pack.is_addon_pack);
-                                                        println!(" - Is RTX Capable: {}", resource_pack.is_rtx_capable);
-                                                        println!(" - CDN URL: {}", resource_pack.cdn_url);
-                                                        println!("-------------------");
-                                                    }
-
-                                                    // RESOURCE PACK CLIENT RESPONSE PACKET {COMPLETED}
-                                                    let rp_client_response = resource_pack_client_response::new(resource_pack_client_response::COMPLETED, rp_uuids).encode();
-
-                                                    let game_packet = self.game.encode(&rp_client_response);
-
-                                                    let datagrams = Datagram::split_packet(game_packet, &mut self.frame_number_cache);
-
-                                                    for datagram in datagrams {
-                                                        self.socket.send(&datagram.to_binary()).expect("ResourcePackClientResponse Packet Fragment could not be sent");
-                                                    }
-
-                                                    // CLIENT CACHE STATUS PACKET
-                                                    let client_cache_status = client_cache_status::new(false).encode();
-
-                                                    let game_packet = self.game.encode(&client_cache_status);
-
-                                                    let datagrams = Datagram::split_packet(game_packet, &mut self.frame_number_cache);
-
-                                                    for datagram in datagrams {
-                                                        self.socket.send(&datagram.to_binary()).expect("ClientCacheStatus Packet Fragment could not be sent");
-                                                    }
-                                                },
-                                                BedrockPacketType::PlayStatus => {
-                                                    let play_status = play_status::decode(packet_stream.get_remaining().unwrap());
-                                                    let status = LoginStatus::try_from(play_status.status).unwrap();
-                                                    if play_status.status == 3 { // Player Spawn
-                                                        // SET LOCAL PLAYER AS INITIALIZED PACKET
-                                                        let set_local_player_as_init = set_local_player_as_initialized::new(0).encode();
-
-                                                        let game_packet = self.game.encode(&set_local_player_as_init);
-
-                                                        let datagrams = Datagram::split_packet(game_packet, &mut self.frame_number_cache);
-
-                                                        for datagram in datagrams {
-                                                            self.socket.send(&datagram.to_binary()).expect("SetLocalPlayerAsInitialized Packet Fragment could not be sent");
-                                                        }
-                                                    }
-                                                    match status {
-                                                        LoginStatus::LoginSuccess => println!("Status: {}Login Success{}", color_format::COLOR_GREEN, COLOR_WHITE),
-                                                        LoginStatus::LoginFailedClient => println!("Status: {}Login Failed Client{}", color_format::COLOR_RED, COLOR_WHITE),
-                                                        LoginStatus::LoginFailedServer => println!("Status: {}Login Failed Server{}", color_format::COLOR_RED, COLOR_WHITE),
-                                                        LoginStatus::PlayerSpawn => println!("Status: {}Player Spawn{}", color_format::COLOR_GREEN, COLOR_WHITE),
-                                                        LoginStatus::LoginFailedInvalidTenant => println!("Status: {}Login Failed Invalid Tenant{}", color_format::COLOR_RED, COLOR_WHITE),
-                                                        LoginStatus::LoginFailedVanillaEdu => println!("Status: {}Login Failed Vanilla Edu{}", color_format::COLOR_RED, COLOR_WHITE),
-                                                        LoginStatus::LoginFailedEduVanilla => println!("Status: {}Login Failed Edu Vanilla{}", color_format::COLOR_RED, COLOR_WHITE),
-                                                        LoginStatus::LoginFailedServerFull => println!("Status: {}Login Failed Server Full{}", color_format::COLOR_RED, COLOR_WHITE),
-                                                        LoginStatus::LoginFailedEditorVanilla => println!("Status: {}Login Failed Editor Vanilla{}", color_format::COLOR_RED, COLOR_WHITE),
-                                                        LoginStatus::LoginFailedVanillaEditor => println!("Status: {}Login Failed Vanilla Editor{}", color_format::COLOR_RED, COLOR_WHITE),
-                                                    }
-                                                },
-                                                BedrockPacketType::StartGame => {
-                                                    let start_game = start_game::decode(packet_stream.get_remaining().unwrap());
-
-                                                    println!("actor_unique_id: {}", start_game.actor_unique_id);
-                                                    println!("actor_runtime_id: {}", start_game.actor_runtime_id);
-                                                    println!("server_software_version: {}", start_game.server_software_version);
-                                                    println!("player_game_mode: {}", start_game.player_game_mode);
-                                                    println!("player_position: {:?}", start_game.player_position);
-                                                    println!("yaw: {}", start_game.yaw);
-                                                    println!("pitch: {}", start_game.pitch);
-                                                    println!("level_settings: {:?}", start_game.level_settings);
-                                                    println!("level_id: {}", start_game.level_id);
-                                                    println!("world_name: {}", start_game.world_name);
-                                                    println!("premium_world_template_id: {}", start_game.premium_world_template_id);
-                                                    println!("is_trial: {}", start_game.is_trial);
-                                                    println!("player_movement_settings: {:?}", start_game.player_movement_settings);
-                                                    println!("current_tick: {}", start_game.current_tick);
-                                                    println!("enchantment_seed: {}", start_game.enchantment_seed);
-
-                                                    //block::vanilla_block_map();
-
-                                                    let mut builder = BlockMapBuilder::new();
-
-                                                    let block_palette = start_game.block_palette;
-                                                    for block in &block_palette {
-
-                                                        println!("Block Name: {}", block.get_name().clone());
-                                                        let mut block_type: BlockType = BlockType::new(block.get_name());
-                                                        let mut block_properties: BTreeMap<String, PropertyValues> = BTreeMap::new();
-
-                                                        let root = block.get_states().get_root();
-                                                        let bct = root.as_any().downcast_ref::<CompoundTag>().unwrap();
-                                                        /*for (key, value) in bct.get_value().downcast_ref::<HashMap<String, Box<dyn Tag>>>().unwrap() {
-                                                            println!(" - {} - {}", key, value.get_type());
-                                                        }
-                                                        if start_game.block_network_ids_are_hashes {} else {}*/
-
-                                                        let properties = bct.get_list_tag("properties".to_string());
-                                                        if let Some(data) = properties {
-                                                            for value in data.get_value().downcast_ref::<Vec<Box<dyn Tag>>>().unwrap() {
-                                                                let c_tag = value.as_any().downcast_ref::<CompoundTag>().unwrap();
-
-                                                                let property_name = c_tag.get_string("name").unwrap();
-                                                                println!(" - Property name: {}", property_name);
-                                                                let list_enum = c_tag.get_list_tag("enum".to_string()).unwrap();
-
-                                                                let mut strings = Vec::new();
-                                                                let mut ints = Vec::new();
-                                                                let mut bools = Vec::new();
-                                                                for value in list_enum.get_value().downcast_ref::<Vec<Box<dyn Tag>>>().unwrap() {
-                                                                    let inner_value = value.get_value();
-                                                                    if let Some(v) = inner_value.downcast_ref::<String>() {
-                                                                        println!(" - Enum (String): {}", v);
-                                                                        strings.push(v.to_string());
-                                                                    } else if let Some(v) = inner_value.downcast_ref::<u32>() {
-                                                                        println!(" - Enum (u32): {}", v);
-                                                                        ints.push(v.clone());
-                                                                    } else if let Some(v) = inner_value.downcast_ref::<bool>() {
-                                                                        println!(" - Enum (bool): {}", v);
-                                                                        bools.push(v.clone());
-                                                                    } else {
-                                                                        println!("enum: value - Unknown type");
-                                                                    }
-                                                                }
-                                                                let enums = PropertyValues{ strings, bools, ints };
-
-                                                                block_properties.insert(property_name, enums);
-                                                                block_type.properties = block_properties.clone();
-
-                                                                builder.insert_block(block_type.clone());
-                                                            }
-                                                        }
-
-                                                    }
-
-                                                    let _block_map = builder.build();
-
-                                                    println!("multiplayer_correlation_id: {}", start_game.multiplayer_correlation_id);
-                                                    println!("enable_new_inventory_system: {}", start_game.enable_new_inventory_system);
-                                                    println!("server_software_version: {}", start_game.server_software_version);
-                                                    //println!("player_actor_properties: {}", start_game.player_actor_properties);
-                                                    println!("block_palette_checksum: {:?}", start_game.block_palette_checksum);
-                                                    println!("world_template_id: {:?}", start_game.world_template_id);
-                                                    println!("enable_client_side_chunk_generation: {}", start_game.enable_client_side_chunk_generation);
-                                                    println!("block_network_ids_are_hashes: {}", start_game.block_network_ids_are_hashes);
-                                                    println!("network_permissions: {:?}", start_game.network_permissions);
-                                                    /*let item_table = start_game.item_table;
-                                                    for item in &item_table {
-                                                        println!("-----\nstring_id: {}", item.get_string_id());
-                                                        println!("numeric_id: {}", item.get_numeric_id());
-                                                        println!("component_based: {}", item.is_component_based());
-                                                    }*/
-
-                                                },
-                                                BedrockPacketType::AvailableCommands => {
-                                                    // REQUEST CHUNK RADIUS PACKET
-                                                    let req_chunk_radius = request_chunk_radius::new(40, 40).encode();
-
-                                                    let game_packet = self.game.encode(&req_chunk_radius);
-
-                                                    let datagrams = Datagram::split_packet(game_packet, &mut self.frame_number_cache);
-
-                                                    for datagram in datagrams {
-                                                        self.socket.send(&datagram.to_binary()).expect("RequestChunkRadius Packet Fragment could not be sent");
-                                                    }
-                                                },
-                                                BedrockPacketType::Text => {
-                                                    let text = text::decode(packet_stream.get_remaining().unwrap());
-                                                    if let Some(source_name) = text.source_name {
-                                                        println!("Source Name: {}", source_name);
-                                                    }
-                                                    println!("Message: {}", text.message);
-                                                    if let Some(parameters) = text.parameters {
-                                                        println!("Parameters: {}", parameters.join(" "));
-                                                        //handle_incoming_data(parameters.join(" ").into_bytes());
-                                                    } else {
-                                                        //handle_incoming_data(text.message.into_bytes());
-                                                    }
-
-                                                },
-                                                BedrockPacketType::LevelChunk => {
-                                                    let level_chunk = level_chunk::decode(packet_stream.get_remaining().unwrap());
-                                                    println!("Chunk X: {}", level_chunk.chunk_x);
-                                                    println!("Chunk Z: {}", level_chunk.chunk_z);
-                                                    println!("Dimension ID: {}", level_chunk.dimension_id);
-                                                    println!("Sub Chunk Count: {}", level_chunk.sub_chunk_count);
-                                                    println!("Client Sub Chunk Requests Enabled: {}", level_chunk.client_sub_chunk_requests_enabled);
-                                                    println!("Used Blob Hashes: {:?}", level_chunk.used_blob_hashes);
-                                                    println!("Extra Payload: {:?}", level_chunk.extra_payload.len());
-                                                    //ChunkResolve::new(level_chunk);
-                                                }
-                                                BedrockPacketType::Disconnect => {
-                                                    let disconnect = disconnect::decode(packet_stream.get_remaining().unwrap());
-                                                    println!("Reason: {}", disconnect.reason);
-                                                    if !disconnect.skip_message {
-                                                        println!("Message: {}", disconnect.message.unwrap());
-                                                        println!("Filtered Message: {}", disconnect.filtered_message.unwrap());
-                                                    }
-                                                    should_stop = true;
-                                                }
-                                                _ => {}
+                                            trace!(target: log_target::GAME, "--- {} ({} bytes) ---", BedrockPacketType::get_packet_name(packet_id as u16), length);
+                                            if !self.state.get().accepts(&packet_type) {
+                                                debug!(target: log_target::GAME, "{} rejected in state {}", BedrockPacketType::get_packet_name(packet_id as u16), self.state.get());
+                                                continue;
                                             }
+                                            let packet = self.packet_registry.decode(packet_id as u16, &mut packet_stream)?;
+                                            self.handle_game_packet(packet)?;
                                         }
                                     },
                                     PacketType::DisconnectionNotification => {
-                                        println!("{}Disconnect Notification Packet Received{}", color_format::COLOR_RED, COLOR_WHITE);
-                                        should_stop = true;
+                                        return Err(ClientError::Protocol("Disconnection notification received from server".to_string(), None));
                                     }
                                     _ => {}
                                 }
@@ -546,77 +512,390 @@ impl Client {
                     }
 
                 }
-                Err(e) => {
-                    eprintln!("Error receiving data: {}", e);
+                Err(e) if e.kind() == ErrorKind::WouldBlock || e.kind() == ErrorKind::TimedOut => {}
+                Err(e) => return Err(e.into())
+            }
+        }
+        Ok(())
+    }
+
+    // Sends a PlayerAuthInput for every tick that is due
+    fn run_ticks(&mut self) -> Result<(), ClientError> {
+        if !self.state.is(ConnectionState::InGame) {
+            return Ok(());
+        }
+        let now = Instant::now();
+        let mut next_tick = self.next_tick.unwrap_or(now);
+        // after a long stall the missed ticks are skipped instead of sent in a burst
+        if now.duration_since(next_tick) > TICK_INTERVAL * 20 {
+            next_tick = now;
+        }
+        while next_tick <= now {
+            let input = self.session().tick()?;
+            self.handler.on_tick(&input);
+            next_tick += TICK_INTERVAL;
+        }
+        self.next_tick = Option::from(next_tick);
+        Ok(())
+    }
+
+    // Updates the client state and calls the handler as if the server had sent the packet, e.g. to replay recorded packets
+    pub fn handle_game_packet(&mut self, packet: Packet) -> Result<(), ClientError> {
+        if !matches!(packet, Packet::Unknown{ .. }) {
+            self.handler.on_packet(&packet);
+        }
+        match packet {
+            Packet::NetworkSettings(network_settings) => {
+                self.handler.on_network_settings(&network_settings);
+
+                let algorithm = CompressionAlgorithm::from_network_id(network_settings.compression_algorithm)
+                    .ok_or(ClientError::Protocol(format!("Unknown compression algorithm {}", network_settings.compression_algorithm), None))?;
+                self.session().game = GamePacket::new(None, Option::from(Compression::new(algorithm, network_settings.compression_threshold)));
+
+                // LOGIN PACKET
+                let pkey = PKey::from_ec_key(self.ec_key.clone()).map_err(|e| ClientError::crypto("EC key can not be converted to PKey", e))?;
+                let login_data_detail = login::convert_login_chain(&mut self.chain, pkey, self.target_address.clone(), self.target_port, self.client_guid, self.client_version.clone(), &self.client_data_options)?;
+                let login = login::new(self.protocol, login_data_detail[0].clone(), login_data_detail[1].clone());
+
+                self.send_packet(&login, SendOptions::default())?;
+                self.set_state(ConnectionState::Login)?;
+            },
+            Packet::ServerToClientHandshake(s_to_c_handshake) => {
+                let jwt = String::from_utf8(s_to_c_handshake.jwt).map_err(|e| ClientError::handshake("JWT is not valid UTF-8", e))?;
+                trace!(target: log_target::AUTH, "JWT: {}", jwt);
+                let jwt = Jwt::parse(&jwt).map_err(|e| ClientError::handshake("Server handshake JWT can not be parsed", e))?;
+                // the server signs the handshake with the key it sends in x5u, the encryption must not start with a forged one
+                let server_public = jwt.verify_x5u().map_err(|e| ClientError::handshake("Server handshake JWT verification failed", e))?;
+                let salt_base64 = jwt.get_str_claim("salt").ok_or(ClientError::Handshake("JWT Payload has no salt".to_string(), None))?;
+                let salt = decode_block(salt_base64).map_err(|e| ClientError::handshake("salt is not valid base64", e))?;
+
+                let local_pkey = PKey::from_ec_key(self.ec_key.clone()).map_err(|e| ClientError::crypto("EC key can not be converted to PKey", e))?;
+                let shared_secret = encryption::generate_shared_secret(local_pkey, server_public).map_err(|e| ClientError::crypto("Shared secret can not be derived", e))?;
+                let encryption_key = encryption::generate_key(&shared_secret, salt).map_err(|e| ClientError::crypto("Encryption key can not be generated", e))?;
+                let encryption = Encryption::fake_gcm(encryption_key).map_err(|e| ClientError::Crypto("Encryption Fake GCM Error".to_string(), Option::from(e)))?;
+
+                {
+                    let mut session = self.session();
+                    session.game = GamePacket::new(Option::from(encryption), session.game.compression);
+                }
+
+                // CLIENT TO SERVER HANDSHAKE PACKET
+                let c_to_s_handshake = client_to_server_handshake::new();
+                self.send_packet(&c_to_s_handshake, SendOptions::default())?;
+                self.set_state(ConnectionState::Encrypted)?;
+            },
+            Packet::ResourcePacksInfo(resource_packs_info) => {
+                if self.state.is(ConnectionState::Encrypted) {
+                    self.set_state(ConnectionState::ResourcePacks)?;
+                }
+                self.handler.on_resource_packs_info(&resource_packs_info);
+
+                self.pack_keys = resource_packs_info.resource_packs.iter().chain(resource_packs_info.behaviour_packs.iter())
+                    .filter(|pack| !pack.encryption_key.is_empty())
+                    .map(|pack| (pack.uuid, pack.encryption_key.clone()))
+                    .collect();
+                self.missing_packs = resource_packs_info.resource_packs.iter().chain(resource_packs_info.behaviour_packs.iter())
+                    .filter(|pack| !self.pack_cache.as_ref().is_some_and(|cache| cache.contains(&pack.uuid, &pack.version)))
+                    .map(|pack| (pack.uuid, pack.version.clone()))
+                    .collect();
+
+                if self.missing_packs.is_empty() {
+                    // RESOURCE PACK CLIENT RESPONSE PACKET {HAVE_ALL_PACKS}
+                    let rp_client_response = resource_pack_client_response::new(resource_pack_client_response::HAVE_ALL_PACKS, vec![]);
+                    self.send_packet(&rp_client_response, SendOptions::default())?;
+                } else {
+                    debug!(target: log_target::GAME, "Downloading {} resource packs", self.missing_packs.len());
+                    let pack_ids = self.missing_packs.iter().map(|(uuid, version)| pack_cache::pack_id(uuid, version)).collect();
+
+                    // RESOURCE PACK CLIENT RESPONSE PACKET {SEND_PACKS}
+                    let rp_client_response = resource_pack_client_response::new(resource_pack_client_response::SEND_PACKS, pack_ids);
+                    self.send_packet(&rp_client_response, SendOptions::default())?;
+                }
+
+                // CLIENT CACHE STATUS PACKET
+                let client_cache_status = client_cache_status::new(false);
+                self.send_packet(&client_cache_status, SendOptions::default())?;
+            },
+            Packet::ResourcePackDataInfo(data_info) => {
+                let download = PackDownload::new(&data_info)?;
+                trace!(target: log_target::GAME, "Resource pack {}: {} bytes in {} chunks", data_info.pack_id, data_info.compressed_pack_size, data_info.chunk_count);
+                self.continue_pack_download(download)?;
+            },
+            Packet::ResourcePackChunkData(chunk_data) => {
+                let mut download = self.pack_downloads.remove(&chunk_data.pack_id).ok_or(ClientError::Protocol(format!("Chunk of resource pack {} that was not announced", chunk_data.pack_id), None))?;
+                download.add_chunk(&chunk_data)?;
+                // big packs take longer than the state timeout, it only runs out when chunks stop arriving
+                self.state.refresh();
+                self.continue_pack_download(download)?;
+            },
+            Packet::ResourcePackStack(resource_pack_stack) => {
+                self.handler.on_resource_pack_stack(&resource_pack_stack);
+                if !self.missing_packs.is_empty() || !self.pack_downloads.is_empty() {
+                    return Err(ClientError::Protocol(format!("Resource pack stack received while {} packs are still downloading", self.missing_packs.len().max(self.pack_downloads.len())), None));
+                }
+
+                // RESOURCE PACK CLIENT RESPONSE PACKET {COMPLETED}
+                let rp_client_response = resource_pack_client_response::new(resource_pack_client_response::COMPLETED, vec![]);
+                self.send_packet(&rp_client_response, SendOptions::default())?;
+            },
+            Packet::PlayStatus(play_status) => {
+                self.handler.on_play_status(&play_status);
+
+                let status = LoginStatus::try_from(play_status.status).map_err(|e| ClientError::Protocol(format!("{} ({})", e, play_status.status), None))?;
+                if play_status.status == 3 { // Player Spawn
+                    // SET LOCAL PLAYER AS INITIALIZED PACKET
+                    let actor_runtime_id = self.session().actor_runtime_id;
+                    let set_local_player_as_init = set_local_player_as_initialized::new(actor_runtime_id);
+                    self.send_packet(&set_local_player_as_init, SendOptions::default())?;
+                }
+                match status {
+                    LoginStatus::LoginSuccess => {
+                        if self.state.is(ConnectionState::Login) || self.state.is(ConnectionState::Encrypted) {
+                            self.set_state(ConnectionState::ResourcePacks)?;
+                        }
+                    },
+                    // sent again after a dimension change
+                    LoginStatus::PlayerSpawn => if !self.state.is(ConnectionState::InGame) {
+                        self.set_state(ConnectionState::InGame)?;
+                    },
+                    _ => return Err(ClientError::Handshake(format!("Login failed with play status {}", play_status.status), None))
+                }
+            },
+            Packet::StartGame(start_game) => {
+                self.set_state(ConnectionState::Spawning)?;
+                self.handler.on_start_game(&start_game);
+
+                let position = Vec3::new(start_game.player_position[0], start_game.player_position[1], start_game.player_position[2]);
+                self.session().movement = MovementController::new(position, start_game.yaw, start_game.pitch, start_game.current_tick as u64);
+
+                let mut builder = BlockMapBuilder::new();
+
+                for block in &start_game.block_palette {
+
+                    trace!(target: log_target::CHUNK, "Block Name: {}", block.get_name());
+                    let mut block_type: BlockType = BlockType::new(block.get_name());
+                    let mut block_properties: BTreeMap<String, PropertyValues> = BTreeMap::new();
+
+                    let root = block.get_states().get_root();
+                    let bct = root.as_any().downcast_ref::<CompoundTag>().ok_or(ClientError::Decode(format!("{} block states are not a compound tag", block.get_name()), None))?;
+                    let properties = bct.get_list_tag("properties".to_string());
+                    if let Some(data) = properties {
+                        for value in data.get_value().downcast_ref::<Vec<Box<dyn Tag>>>().ok_or(ClientError::Decode(format!("{} properties is not a list", block.get_name()), None))? {
+                            let c_tag = value.as_any().downcast_ref::<CompoundTag>().ok_or(ClientError::Decode(format!("{} property is not a compound tag", block.get_name()), None))?;
+
+                            let property_name = c_tag.get_string("name").ok_or(ClientError::Decode(format!("{} property has no name", block.get_name()), None))?;
+                            trace!(target: log_target::CHUNK, " - Property name: {}", property_name);
+                            let list_enum = c_tag.get_list_tag("enum".to_string()).ok_or(ClientError::Decode(format!("{} property {} has no enum", block.get_name(), property_name), None))?;
+
+                            let mut strings = Vec::new();
+                            let mut ints = Vec::new();
+                            let mut bools = Vec::new();
+                            for value in list_enum.get_value().downcast_ref::<Vec<Box<dyn Tag>>>().ok_or(ClientError::Decode(format!("{} property {} enum is not a list", block.get_name(), property_name), None))? {
+                                let inner_value = value.get_value();
+                                if let Some(v) = inner_value.downcast_ref::<String>() {
+                                    strings.push(v.to_string());
+                                } else if let Some(v) = inner_value.downcast_ref::<u32>() {
+                                    ints.push(v.clone());
+                                } else if let Some(v) = inner_value.downcast_ref::<bool>() {
+                                    bools.push(v.clone());
+                                } else {
+                                    warn!(target: log_target::CHUNK, "{} property {} has an enum value of unknown type", block.get_name(), property_name);
+                                }
+                            }
+                            let enums = PropertyValues{ strings, bools, ints };
+
+                            block_properties.insert(property_name, enums);
+                            block_type.properties = block_properties.clone();
+                        }
+                    }
+                    builder.insert_block(block_type);
+                }
+
+                let block_map = builder.build();
+
+                let mut session = self.session();
+                session.actor_runtime_id = start_game.actor_runtime_id;
+                session.world = World::new(start_game.level_settings.spawn_settings.get_dimension_id());
+                session.entities.clear();
+                session.players.clear();
+                match start_game.get_player_actor_properties() {
+                    Ok(definitions) if !definitions.properties.is_empty() => session.entities.set_property_definitions(definitions),
+                    Ok(_) => {},
+                    Err(e) => warn!(target: log_target::GAME, "{}", e)
                 }
+                if start_game.block_network_ids_are_hashes {
+                    warn!(target: log_target::CHUNK, "Block network IDs are hashes, blocks can not be named and physics stay off");
+                } else if let Some(palette) = &self.block_palette {
+                    let custom_blocks: Vec<(String, usize)> = block_map.blocks_types.keys().map(|block| (block.name.clone(), block.variant_count())).collect();
+                    session.world.set_palette(Option::from(palette.with_custom_blocks(&custom_blocks)));
+                }
+            },
+            Packet::AvailableCommands(available_commands) => {
+                // without a tree commands are sent unchecked, that is no reason to leave the server
+                match CommandTree::from_packet(&available_commands) {
+                    Ok(commands) => {
+                        self.handler.on_available_commands(&commands);
+                        self.session().commands = Option::from(commands);
+                    },
+                    Err(e) => warn!(target: log_target::GAME, "Available commands are ignored: {}", e)
+                }
+
+                // REQUEST CHUNK RADIUS PACKET
+                let req_chunk_radius = request_chunk_radius::new(40, 40);
+                self.send_packet(&req_chunk_radius, SendOptions::default())?;
+            },
+            Packet::CommandOutput(command_output) => {
+                self.handler.on_command_output(&command_output);
+                self.session().complete_command(&command_output);
+            },
+            Packet::UpdateSoftEnum(update_soft_enum) => {
+                if let Some(commands) = self.session().commands.as_mut() {
+                    commands.update_soft_enum(&update_soft_enum);
+                }
+            },
+            Packet::Text(text) => {
+                self.handler.on_text(&text);
+            },
+            Packet::LevelChunk(level_chunk) => {
+                self.handler.on_level_chunk(&level_chunk);
+                // a broken chunk only turns off the physics around it
+                if let Err(e) = self.session().world.add_level_chunk(&level_chunk) {
+                    warn!(target: log_target::CHUNK, "{}", e);
+                }
+            }
+            Packet::UpdateAttributes(update_attributes) => {
+                self.handler.on_update_attributes(&update_attributes);
+                let mut session = self.session();
+                if update_attributes.actor_runtime_id == session.actor_runtime_id {
+                    for attribute in &update_attributes.attributes {
+                        session.movement.set_attribute(attribute);
+                    }
+                }
+            }
+            // the handler gets a copy, it may lock the session through a ClientHandle
+            Packet::AddActor(add_actor) => {
+                let entity = self.session().entities.add_actor(&add_actor).clone();
+                self.handler.on_entity_added(&entity);
+            }
+            Packet::AddPlayer(add_player) => {
+                let entity = self.session().entities.add_player(&add_player).clone();
+                self.handler.on_entity_added(&entity);
+            }
+            Packet::AddItemActor(add_item_actor) => {
+                let entity = self.session().entities.add_item_actor(&add_item_actor).clone();
+                self.handler.on_entity_added(&entity);
+            }
+            Packet::RemoveActor(remove_actor) => {
+                let entity = self.session().entities.remove(remove_actor.actor_unique_id);
+                if let Some(entity) = entity {
+                    self.handler.on_entity_removed(&entity);
+                }
+            }
+            Packet::MoveActorAbsolute(move_actor) => {
+                self.session().entities.move_absolute(&move_actor);
+            }
+            Packet::MoveActorDelta(move_actor) => {
+                self.session().entities.move_delta(&move_actor);
             }
+            Packet::MovePlayer(move_player) => {
+                let mut session = self.session();
+                // corrections and teleports of the local player
+                if move_player.actor_runtime_id == session.actor_runtime_id {
+                    session.movement.set_position(move_player.position);
+                } else {
+                    session.entities.move_player(&move_player);
+                }
+            }
+            Packet::SetActorMotion(set_actor_motion) => {
+                self.session().entities.set_motion(&set_actor_motion);
+            }
+            Packet::SetActorData(set_actor_data) => {
+                let mut session = self.session();
+                if set_actor_data.actor_runtime_id == session.actor_runtime_id {
+                    session.entities.set_player_data(&set_actor_data);
+                } else {
+                    session.entities.set_data(&set_actor_data);
+                }
+            }
+            Packet::SetActorLink(set_actor_link) => {
+                self.session().entities.set_link(&set_actor_link);
+            }
+            Packet::PlayerList(player_list) => {
+                let changed = self.session().players.apply(&player_list);
+                for player in &changed {
+                    if player_list.list_type == player_list::TYPE_ADD {
+                        self.handler.on_player_join(player);
+                    } else {
+                        self.handler.on_player_leave(player);
+                    }
+                }
+            }
+            Packet::PlayerSkin(player_skin) => {
+                self.session().players.update_skin(&player_skin);
+            }
+            Packet::Disconnect(disconnect) => {
+                self.handler.on_disconnect(&disconnect);
+                return Err(ClientError::Protocol(format!("Disconnected by server (reason {}): {}", disconnect.reason, disconnect.message.unwrap_or_default()), None));
+            }
+            Packet::Unknown{ id, payload } => {
+                self.handler.on_raw_packet(id, &payload);
+            }
+            _ => {}
         }
+        Ok(())
     }
 
-    fn raknet_packet_handler(&mut self, packet_type: PacketType, stream: &mut Stream) -> bool {
-        let mut should_stop = false;
+    fn raknet_packet_handler(&mut self, packet_type: PacketType, stream: &mut Stream) -> Result<(), ClientError> {
+        if !self.state.get().accepts_raknet(packet_type) {
+            debug!(target: log_target::RAKNET, "RakNet packet 0x{:02x} rejected in state {}", packet_type.get_byte(), self.state.get());
+            return Ok(());
+        }
 
         match packet_type {
             PacketType::OpenConnReply1 => {
-                let open_conn_reply1 = OpenConnReply1::decode(stream.get_buffer());
-                if self.debug { open_conn_reply1.debug(); }
+                let open_conn_reply1 = OpenConnReply1::decode(stream.get_buffer()).map_err(|e| ClientError::decode("Open connection reply 1 could not be decoded", e))?;
+                open_conn_reply1.debug();
 
                 let req2 = OpenConnReq2::new(MAGIC, address::new(4, self.target_address.to_string(), self.target_port), open_conn_reply1.cookie, false, open_conn_reply1.mtu, self.client_guid).encode();
-                self.socket.send(&req2).expect("Open Connection Request 2 Packet could not be sent");
+                self.session().send_unconnected(&req2)?;
             },
             PacketType::OpenConnReply2 => {
-                let open_conn_reply2 = OpenConnReply2::decode(stream.get_buffer());
-                if self.debug { open_conn_reply2.debug(); }
+                let open_conn_reply2 = OpenConnReply2::decode(stream.get_buffer()).map_err(|e| ClientError::decode("Open connection reply 2 could not be decoded", e))?;
+                open_conn_reply2.debug();
 
                 let body = ConnReq::new(self.client_guid, Utc::now().timestamp(), false).encode();
-
-                let frame = Datagram::create_frame(body, RELIABLE, &self.frame_number_cache, None);
-                let datagram = Datagram::create(vec![frame], &self.frame_number_cache).to_binary();
-                self.frame_number_cache.sequence_number += 1;
-                self.frame_number_cache.reliable_frame_index += 1;
-
-                self.socket.send(&datagram).expect("Connection Request Packet could not be sent");
+                self.session().send_raw(body, SendOptions::new(RELIABLE, 0))?;
+                self.set_state(ConnectionState::Connecting)?;
             },
             PacketType::ConnReqAccepted => {
 
-                let conn_req_accepted = ConnReqAccepted::decode(stream.get_buffer());
-                if self.debug { conn_req_accepted.debug(); }
+                let conn_req_accepted = ConnReqAccepted::decode(stream.get_buffer()).map_err(|e| ClientError::decode("Connection request accepted could not be decoded", e))?;
+                conn_req_accepted.debug();
+                self.set_state(ConnectionState::Connected)?;
 
                 // New Incoming Connection
                 let addresses: [InternetAddress; 20] = core::array::from_fn(|_| address::new(4, "0.0.0.0".to_string(), 0));
                 let new_incoming_conn = NewIncomingConn::new(address::new(4, self.target_address.to_string(), self.target_port), addresses, Utc::now().timestamp(), Utc::now().timestamp() + 1).encode();
-                let frame = Datagram::create_frame(new_incoming_conn, RELIABLE_ORDERED, &self.frame_number_cache, None);
-                self.frame_number_cache.reliable_frame_index += 1;
-                self.frame_number_cache.ordered_frame_index += 1;
+                self.session().send_raw(new_incoming_conn, SendOptions::default())?;
 
                 // Connected Ping
                 let connected_ping = ConnectedPing::create(Utc::now().timestamp()).encode();
-                let frame_two = Datagram::create_frame(connected_ping, UNRELIABLE, &self.frame_number_cache, None);
+                self.session().send_raw(connected_ping, SendOptions::new(UNRELIABLE, 0))?;
 
                 // Request Network Settings Packet
-                let request_network_settings = req_network_settings::new(BEDROCK_PROTOCOL_VERSION).encode();
-                let frame_three = Datagram::create_frame(request_network_settings, RELIABLE_ORDERED, &self.frame_number_cache, None);
-
-                let datagram = Datagram::create(vec![frame, frame_two, frame_three], &self.frame_number_cache).to_binary();
-                self.frame_number_cache.sequence_number += 1;
-                self.frame_number_cache.reliable_frame_index += 1;
-                self.frame_number_cache.ordered_frame_index += 1;
-
-                self.socket.send(&datagram).expect("NewIncomingConnection & RequestNetworkSettings Packet could not be sent");
-                //should_stop = true;
+                let request_network_settings = req_network_settings::new(self.protocol);
+                self.send_packet(&request_network_settings, SendOptions::default())?;
+                self.set_state(ConnectionState::NetworkSettings)?;
             },
             PacketType::IncompatibleProtocol => {
-                let incompatible_protocol = incompatible_protocol::decode(stream.get_buffer());
-                println!("{}Incompatible Protocol Version, Server Protocol Version: {}{}", color_format::COLOR_RED, incompatible_protocol.server_protocol, COLOR_WHITE);
-                should_stop = true;
+                let incompatible_protocol = incompatible_protocol::decode(stream.get_buffer()).map_err(|e| ClientError::decode("Incompatible protocol could not be decoded", e))?;
+                return Err(ClientError::UnsupportedVersion(format!("Incompatible RakNet protocol, server protocol version: {}", incompatible_protocol.server_protocol)));
             },
             PacketType::DisconnectionNotification => {
-                println!("{}Disconnection Notification Packet Received From Server.{}", color_format::COLOR_RED, COLOR_WHITE);
-                should_stop = true;
+                return Err(ClientError::Protocol("Disconnection notification received from server".to_string(), None));
             },
-            _ => { /*vec![]*/ }
+            _ => {}
         };
-
-        should_stop
+        Ok(())
     }
 }
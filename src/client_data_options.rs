@@ -0,0 +1,321 @@
+use crate::error::ClientError;
+use openssl::base64::encode_block;
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+use uuid::Builder;
+
+// DeviceOS values of the client data
+pub const DEVICE_ANDROID: i32 = 1;
+pub const DEVICE_IOS: i32 = 2;
+pub const DEVICE_OSX: i32 = 3;
+pub const DEVICE_FIRE_OS: i32 = 4;
+pub const DEVICE_GEAR_VR: i32 = 5;
+pub const DEVICE_HOLOLENS: i32 = 6;
+pub const DEVICE_WINDOWS_10: i32 = 7;
+pub const DEVICE_WINDOWS_32: i32 = 8;
+pub const DEVICE_DEDICATED: i32 = 9;
+pub const DEVICE_TVOS: i32 = 10;
+pub const DEVICE_PLAYSTATION: i32 = 11;
+pub const DEVICE_NINTENDO: i32 = 12;
+pub const DEVICE_XBOX: i32 = 13;
+pub const DEVICE_WINDOWS_PHONE: i32 = 14;
+
+pub const INPUT_MOUSE: i32 = 1;
+pub const INPUT_TOUCH: i32 = 2;
+pub const INPUT_GAME_PAD: i32 = 3;
+pub const INPUT_MOTION_CONTROLLER: i32 = 4;
+
+pub const UI_PROFILE_CLASSIC: i32 = 0;
+pub const UI_PROFILE_POCKET: i32 = 1;
+
+pub const ARM_SIZE_WIDE: &str = "wide";
+pub const ARM_SIZE_SLIM: &str = "slim";
+
+// 64x64 default skin with the humanoid geometry, all base64
+const DEFAULT_SKIN_DATA: &str = "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWExP/GhcX/xYTE/8aFxf/GhcX/xYTE/8aFxf/FhMT/yQkJP8kJCT/JCQk/yQkJP8kJCT/JCQk/yQkJP8kJCT/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAGhcX/x4aGv8aFxf/Hhoa/x4aGv8aFxf/Hhoa/xoXF/8kJCT/JCQk/xoXF/8eGhr/Hhoa/xoXF/8kJCT/JCQk/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABYTE/8iICD/GhcX/x4aGv8iICD/GhcX/yIgIP8WExP/JCQk/yQkJP8aFxf/GhcX/xoXF/8aFxf/JCQk/yQkJP8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAaFxf/IiAg/xoXF/8eGhr/IiAg/xoXF/8iICD/GhcX/yQkJP8kJCT/Hhoa/x4aGv8eGhr/Hhoa/yQkJP8kJCT/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAGhcX/yIgIP8aFxf/Hhoa/yIgIP8aFxf/IiAg/xoXF/8aFxf/Hhoa//3s6P/97Oj//ezo//3s6P8eGhr/GhcX/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABYTE/8iICD/GhcX/x4aGv8iICD/GhcX/yIgIP8WExP/FhMT//3s6P/97Oj//ezo//3s6P/97Oj//ezo/xYTE/8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAaFxf/Hhoa/xoXF/8eGhr/Hhoa/xoXF/8eGhr/GhcX//re1//97Oj//ezo//3s6P/97Oj//ezo//3s6P/63tf/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFhMT/xoXF/8WExP/GhcX/xoXF/8WExP/GhcX/xYTE//63tf//ezo//3s6P/97Oj//ezo//3s6P/97Oj/+t7X/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABYTE/8aFxf/GhcX/xYTE/8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWExP/GhcX/xYTE/8aFxf/GhcX/xYTE/8aFxf/FhMT/xYTE/8aFxf/FhMT/xoXF/8aFxf/FhMT/xoXF/8WExP/FhMT/xoXF/8WExP/GhcX/xoXF/8WExP/GhcX/xYTE/8WExP/GhcX/xYTE/8aFxf/GhcX/xYTE/8aFxf/FhMT/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWExP/GhcX/xoXF/8WExP/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA//////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAeGhr/GhcX/x4aGv8eGhr/GhcX/x4aGv8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAaFxf/Hhoa/xoXF/8eGhr/IiAg/xoXF/8eGhr/GhcX/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD//////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAGhcX/x4aGv8aFxf/Hhoa/yIgIP8aFxf/Hhoa/xoXF/8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAGhcX/yIgIP8aFxf/IiAg/x4aGv8aFxf/IiAg/xoXF/8aFxf/CgoL/woKC///9fL//+zo/woKC/8KCgv/GhcX/xoXF/8iICD/GhcX/x4aGv8iICD/GhcX/yIgIP8aFxf/GhcX/yIgIP8aFxf/IiAg/x4aGv8aFxf/IiAg/xoXF/8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABoXF/8eGhr/AAAAAB4aGv8iICD/AAAAAB4aGv8aFxf/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABoXF/8iICD/GhcX/yIgIP8eGhr/GhcX/yIgIP8aFxf/GhcX//////8AAAD///Xy///s6P8AAAD//////xoXF/8aFxf/IiAg/xoXF/8eGhr/IiAg/xoXF/8iICD/GhcX/xoXF/8iICD/GhcX/yIgIP8eGhr/GhcX/yIgIP8aFxf/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAaFxf/AAAAAAAAAAAAAAAAIiAg/wAAAAAAAAAAGhcX/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAXFxf/JCQk/xcXF/8eGhr/Hhoa/xoXF/8eGhr/GhcX/xYTE///////GRkZ//ve1//73tf/GRkZ//////8WExP/GhcX/x4aGv8aFxf/Hhoa/x4aGv8XFxf/JCQk/xcXF/8XFxf/JCQk/xcXF/8kJCT/FxcX/xcXF/8kJCT/FxcX/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/wAAAP8XFxf/AAAA/xoXF/8WExP/GhcX//re1//63tf//ezo//3s6P/97Oj//ezo//3s6P/97Oj/+t7X//re1/8aFxf/FhMT/xoXF/8kJCT/FxcX/yQkJP8XFxf/FxcX/yQkJP8XFxf/JCQk/yQkJP8XFxf/JCQk/xcXF/8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/AAAA/wAAAP8AAAD//89u///Pbv//z27//89u/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR///n0f//59H//+fR///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/wAAAP8AAAD/AAAA///Pbv//z27//89u///Pbv8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP/n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP/n0f//59H//+fR///n0f//59H//+fR///n0f//59H/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP8AAAD/AAAA/wAAAP//z27//89u///Pbv//z27/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/59H//+fR///n0f//59H//+fR///n0f//59H//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/AAAA/wAAAP8AAAD//89u///Pbv//z27//89u/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR///n0f//59H//+fR///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/AAAA/wAAAP8AAAD/AAAA///1/v8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD//+fR///n0f//59H//+fR///n0f//uoz//7qM//+6jP//uoz//7qM//+6jP//59H//+fR///n0f//59H//+fR///n0f//uoz//7qM//+6jP//uoz//7qM//+6jP//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/wAAAP8AAAD/AAAA/wAAAP//9f7/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA///n0f//59H//+fR///n0f//59H//+fR///n0f//uoz//7qM///n0f//59H//+fR///n0f//59H//+fR///n0f//uoz//7qM///n0f//uoz//7qM///n0f//uoz//7qM///n0f//x5z//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP8AAAD/AAAA/wAAAP8AAAD///X+/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP//59H//+fR///n0f//59H//+fR///n0f//59H//7qM//+6jP//59H//+fR///n0f//59H//+fR///n0f//59H//7qM//+6jP//59H//7qM//+6jP//59H//7qM//+6jP//59H//8ec///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//8ec///n0f//59H/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/AAAA/wAAAP8AAAD///X+/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD//+fR///n0f//59H//+fR//+6jP//59H//+fR//+6jP//uoz//+fR///n0f//uoz//+fR///n0f//59H//+fR//+6jP//59H//+fR//+6jP//uoz//+fR///n0f//uoz//+fR///HnP//59H//+fR//+6jP//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///HnP//59H//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA///n0f//59H//+fR///n0f//uoz//7qM//+6jP//uoz//7qM//+6jP//uoz//7qM///n0f//59H//+fR///n0f//uoz//+fR///n0f//uoz//7qM///n0f//59H//7qM///n0f//59H//8ec///n0f//uoz//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///HnP8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP//59H//+fR///n0f//59H//+fR///n0f//uoz//+fR///n0f//uoz//+fR///n0f//59H//+fR///n0f//59H//7qM///n0f//59H//7qM//+6jP//59H//+fR//+6jP//59H//+fR///HnP//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//x5z/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//uoz//+fR//+6jP//uoz//+fR//+6jP//59H//+fR///n0f//59H//+fR//+6jP//59H//+fR//+6jP//uoz//+fR///n0f//uoz//+fR///n0f//59H//+fR///n0f//uoz//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//8ec/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//7qM//+6jP//uoz//7qM//+6jP//uoz//+fR///n0f//59H//+fR///n0f//uoz//+fR//+6jP//uoz//7qM//+6jP//59H//7qM///n0f//59H//+fR///n0f//59H//7qM///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//8ec///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP/n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR//+6jP//59H//7qM//+6jP//59H//7qM///n0f//59H//+fR///n0f//59H//+fR//+6jP//uoz//7qM//+6jP//uoz//7qM///n0f//59H//8ec///n0f//59H//+fR//+6jP//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/59H//+fR///n0f8AAAD/AAAA///n0f//59H/AAAA///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//uoz//7qM//+6jP//uoz//7qM//+6jP//59H//+fR///n0f//59H//+fR///n0f//59H//+fR//+6jP//uoz//+fR///n0f//59H//+fR///n0f//x5z//+fR///n0f//59H//7qM///n0f//59H//+fR///n0f//59H//+fR///HnP//59H//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR/wAAAP8AAAD//+fR///n0f8AAAD/AAAA///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//7qM///n0f//uoz//7qM///n0f//uoz//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//uoz//7qM///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR//+6jP//59H//+fR///n0f//59H//+fR///n0f//59H//8ec///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAQEBAQAAAQP/Pbv//z27//89u///Pbv//z27//89u///Pbv//z27//+fR///n0f//59H//+fR///Pbv//z27//89u///Pbv//59H//+fR///n0f//59H//+fR///n0f//uoz//7qM//+6jP//uoz//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//7qM//+6jP//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAQAAAQAAAAEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/AAAA/wAAAP8AAAD//89u///Pbv//z27//89u/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR//3s6P/97Oj//ezo//3s6P8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/wAAAP8AAAD/AAAA///Pbv//z27//89u///Pbv8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP/n0f//59H//+fR///n0f/97Oj//ezo//3s6P/97Oj/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP8AAAD/AAAA/wAAAP//z27//89u///Pbv//z27/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/59H//+fR///n0f//59H//ezo//3s6P/97Oj//ezo/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/AAAA/wAAAP8AAAD//89u///Pbv//z27//89u/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR//3s6P/97Oj//ezo//3s6P8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP//9f7/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD///X+/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///HnP//59H//+fR///n0f//59H//+fR///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA///1/v8AAAD/AAAA/wAAAP8AAAD/AAAA///1/v//9f7///X+///1/v//59H//+fR///n0f//59H//+fR//+6jP//59H//+fR///n0f//x5z//+fR///n0f//x5z//+fR///n0f//59H/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD///X+/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD//+fR///n0f//59H//+fR///n0f//uoz//+fR///n0f//59H//8ec///n0f//59H//8ec///n0f//59H//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD///X+///1/v//9f7///X+///n0f//59H//+fR///n0f//59H//+fR///n0f//uoz//+fR///n0f//59H//+fR///n0f//59H//8ec///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP//59H//+fR///n0f//59H//+fR///n0f//uoz//+fR///n0f//59H//+fR///n0f//59H//8ec///n0f//59H/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///1/v//9f7///X+///1/v//9f7///X+///1/v//9f7///X+///1/v//9f7///X+///1/v//9f7///X+///1/v8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP/n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/59H//+fR///n0f//59H/AAAA///n0f//59H/AAAA/wAAAP//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR//+6jP//59H//+fR///n0f//59H//8ec///HnP//59H//+fR///n0f//x5z//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR///n0f8AAAD/AAAA///n0f//59H/AAAA/wAAAP//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//x5z//+fR///n0f//59H//+fR///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP/n0f//59H//+fR///n0f//z27//89u///Pbv//z27//89u///Pbv//z27//89u///Pbv//z27//89u///Pbv//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA==";
+const DEFAULT_SKIN_GEOMETRY: &str = "ewogICAiZm9ybWF0X3ZlcnNpb24iIDogIjEuMTIuMCIsCiAgICJtaW5lY3JhZnQ6Z2VvbWV0cnkiIDogWwogICAgICB7CiAgICAgICAgICJib25lcyIgOiBbCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgIm5hbWUiIDogImJvZHkiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJ3YWlzdCIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIDAuMCwgMjQuMCwgMC4wIF0KICAgICAgICAgICAgfSwKICAgICAgICAgICAgewogICAgICAgICAgICAgICAibmFtZSIgOiAid2Fpc3QiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAwLjAsIDEyLjAsIDAuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTUuMCwgOC4wLCAzLjAgXSwKICAgICAgICAgICAgICAgICAgICAgInNpemUiIDogWyAxMCwgMTYsIDEgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMCwgMCBdCiAgICAgICAgICAgICAgICAgIH0KICAgICAgICAgICAgICAgXSwKICAgICAgICAgICAgICAgIm5hbWUiIDogImNhcGUiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJib2R5IiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgMC4wLCAyNC4wLCAzLjAgXSwKICAgICAgICAgICAgICAgInJvdGF0aW9uIiA6IFsgMC4wLCAxODAuMCwgMC4wIF0KICAgICAgICAgICAgfQogICAgICAgICBdLAogICAgICAgICAiZGVzY3JpcHRpb24iIDogewogICAgICAgICAgICAiaWRlbnRpZmllciIgOiAiZ2VvbWV0cnkuY2FwZSIsCiAgICAgICAgICAgICJ0ZXh0dXJlX2hlaWdodCIgOiAzMiwKICAgICAgICAgICAgInRleHR1cmVfd2lkdGgiIDogNjQKICAgICAgICAgfQogICAgICB9LAogICAgICB7CiAgICAgICAgICJib25lcyIgOiBbCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgIm5hbWUiIDogInJvb3QiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAwLjAsIDAuMCwgMC4wIF0KICAgICAgICAgICAgfSwKICAgICAgICAgICAgewogICAgICAgICAgICAgICAiY3ViZXMiIDogWwogICAgICAgICAgICAgICAgICB7CiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyAtNC4wLCAxMi4wLCAtMi4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgOCwgMTIsIDQgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMTYsIDE2IF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAiYm9keSIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogIndhaXN0IiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgMC4wLCAyNC4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJuYW1lIiA6ICJ3YWlzdCIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogInJvb3QiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAwLjAsIDEyLjAsIDAuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTQuMCwgMjQuMCwgLTQuMCBdLAogICAgICAgICAgICAgICAgICAgICAic2l6ZSIgOiBbIDgsIDgsIDggXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMCwgMCBdCiAgICAgICAgICAgICAgICAgIH0KICAgICAgICAgICAgICAgXSwKICAgICAgICAgICAgICAgIm5hbWUiIDogImhlYWQiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJib2R5IiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgMC4wLCAyNC4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJuYW1lIiA6ICJjYXBlIiwKICAgICAgICAgICAgICAgInBhcmVudCIgOiAiYm9keSIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIDAuMCwgMjQsIDMuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAiaW5mbGF0ZSIgOiAwLjUwLAogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTQuMCwgMjQuMCwgLTQuMCBdLAogICAgICAgICAgICAgICAgICAgICAic2l6ZSIgOiBbIDgsIDgsIDggXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMzIsIDAgXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJoYXQiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJoZWFkIiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgMC4wLCAyNC4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgIm9yaWdpbiIgOiBbIDQuMCwgMTIuMCwgLTIuMCBdLAogICAgICAgICAgICAgICAgICAgICAic2l6ZSIgOiBbIDQsIDEyLCA0IF0sCiAgICAgICAgICAgICAgICAgICAgICJ1diIgOiBbIDMyLCA0OCBdCiAgICAgICAgICAgICAgICAgIH0KICAgICAgICAgICAgICAgXSwKICAgICAgICAgICAgICAgIm5hbWUiIDogImxlZnRBcm0iLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJib2R5IiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgNS4wLCAyMi4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgImluZmxhdGUiIDogMC4yNTAsCiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyA0LjAsIDEyLjAsIC0yLjAgXSwKICAgICAgICAgICAgICAgICAgICAgInNpemUiIDogWyA0LCAxMiwgNCBdLAogICAgICAgICAgICAgICAgICAgICAidXYiIDogWyA0OCwgNDggXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJsZWZ0U2xlZXZlIiwKICAgICAgICAgICAgICAgInBhcmVudCIgOiAibGVmdEFybSIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIDUuMCwgMjIuMCwgMC4wIF0KICAgICAgICAgICAgfSwKICAgICAgICAgICAgewogICAgICAgICAgICAgICAibmFtZSIgOiAibGVmdEl0ZW0iLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJsZWZ0QXJtIiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgNi4wLCAxNS4wLCAxLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgIm9yaWdpbiIgOiBbIC04LjAsIDEyLjAsIC0yLjAgXSwKICAgICAgICAgICAgICAgICAgICAgInNpemUiIDogWyA0LCAxMiwgNCBdLAogICAgICAgICAgICAgICAgICAgICAidXYiIDogWyA0MCwgMTYgXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJyaWdodEFybSIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogImJvZHkiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAtNS4wLCAyMi4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgImluZmxhdGUiIDogMC4yNTAsCiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyAtOC4wLCAxMi4wLCAtMi4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgNCwgMTIsIDQgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgNDAsIDMyIF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAicmlnaHRTbGVldmUiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJyaWdodEFybSIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIC01LjAsIDIyLjAsIDAuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImxvY2F0b3JzIiA6IHsKICAgICAgICAgICAgICAgICAgImxlYWRfaG9sZCIgOiBbIC02LCAxNSwgMSBdCiAgICAgICAgICAgICAgIH0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJyaWdodEl0ZW0iLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJyaWdodEFybSIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIC02LCAxNSwgMSBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTAuMTAsIDAuMCwgLTIuMCBdLAogICAgICAgICAgICAgICAgICAgICAic2l6ZSIgOiBbIDQsIDEyLCA0IF0sCiAgICAgICAgICAgICAgICAgICAgICJ1diIgOiBbIDE2LCA0OCBdCiAgICAgICAgICAgICAgICAgIH0KICAgICAgICAgICAgICAgXSwKICAgICAgICAgICAgICAgIm5hbWUiIDogImxlZnRMZWciLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJyb290IiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgMS45MCwgMTIuMCwgMC4wIF0KICAgICAgICAgICAgfSwKICAgICAgICAgICAgewogICAgICAgICAgICAgICAiY3ViZXMiIDogWwogICAgICAgICAgICAgICAgICB7CiAgICAgICAgICAgICAgICAgICAgICJpbmZsYXRlIiA6IDAuMjUwLAogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTAuMTAsIDAuMCwgLTIuMCBdLAogICAgICAgICAgICAgICAgICAgICAic2l6ZSIgOiBbIDQsIDEyLCA0IF0sCiAgICAgICAgICAgICAgICAgICAgICJ1diIgOiBbIDAsIDQ4IF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAibGVmdFBhbnRzIiwKICAgICAgICAgICAgICAgInBhcmVudCIgOiAibGVmdExlZyIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIDEuOTAsIDEyLjAsIDAuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTMuOTAsIDAuMCwgLTIuMCBdLAogICAgICAgICAgICAgICAgICAgICAic2l6ZSIgOiBbIDQsIDEyLCA0IF0sCiAgICAgICAgICAgICAgICAgICAgICJ1diIgOiBbIDAsIDE2IF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAicmlnaHRMZWciLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJyb290IiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgLTEuOTAsIDEyLjAsIDAuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAiaW5mbGF0ZSIgOiAwLjI1MCwKICAgICAgICAgICAgICAgICAgICAgIm9yaWdpbiIgOiBbIC0zLjkwLCAwLjAsIC0yLjAgXSwKICAgICAgICAgICAgICAgICAgICAgInNpemUiIDogWyA0LCAxMiwgNCBdLAogICAgICAgICAgICAgICAgICAgICAidXYiIDogWyAwLCAzMiBdCiAgICAgICAgICAgICAgICAgIH0KICAgICAgICAgICAgICAgXSwKICAgICAgICAgICAgICAgIm5hbWUiIDogInJpZ2h0UGFudHMiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJyaWdodExlZyIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIC0xLjkwLCAxMi4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgImluZmxhdGUiIDogMC4yNTAsCiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyAtNC4wLCAxMi4wLCAtMi4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgOCwgMTIsIDQgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMTYsIDMyIF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAiamFja2V0IiwKICAgICAgICAgICAgICAgInBhcmVudCIgOiAiYm9keSIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIDAuMCwgMjQuMCwgMC4wIF0KICAgICAgICAgICAgfQogICAgICAgICBdLAogICAgICAgICAiZGVzY3JpcHRpb24iIDogewogICAgICAgICAgICAiaWRlbnRpZmllciIgOiAiZ2VvbWV0cnkuaHVtYW5vaWQuY3VzdG9tIiwKICAgICAgICAgICAgInRleHR1cmVfaGVpZ2h0IiA6IDY0LAogICAgICAgICAgICAidGV4dHVyZV93aWR0aCIgOiA2NCwKICAgICAgICAgICAgInZpc2libGVfYm91bmRzX2hlaWdodCIgOiAyLAogICAgICAgICAgICAidmlzaWJsZV9ib3VuZHNfb2Zmc2V0IiA6IFsgMCwgMSwgMCBdLAogICAgICAgICAgICAidmlzaWJsZV9ib3VuZHNfd2lkdGgiIDogMQogICAgICAgICB9CiAgICAgIH0sCiAgICAgIHsKICAgICAgICAgImJvbmVzIiA6IFsKICAgICAgICAgICAgewogICAgICAgICAgICAgICAibmFtZSIgOiAicm9vdCIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIDAuMCwgMC4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJuYW1lIiA6ICJ3YWlzdCIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogInJvb3QiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAwLjAsIDEyLjAsIDAuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTQuMCwgMTIuMCwgLTIuMCBdLAogICAgICAgICAgICAgICAgICAgICAic2l6ZSIgOiBbIDgsIDEyLCA0IF0sCiAgICAgICAgICAgICAgICAgICAgICJ1diIgOiBbIDE2LCAxNiBdCiAgICAgICAgICAgICAgICAgIH0KICAgICAgICAgICAgICAgXSwKICAgICAgICAgICAgICAgIm5hbWUiIDogImJvZHkiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJ3YWlzdCIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIDAuMCwgMjQuMCwgMC4wIF0KICAgICAgICAgICAgfSwKICAgICAgICAgICAgewogICAgICAgICAgICAgICAiY3ViZXMiIDogWwogICAgICAgICAgICAgICAgICB7CiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyAtNC4wLCAyNC4wLCAtNC4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgOCwgOCwgOCBdLAogICAgICAgICAgICAgICAgICAgICAidXYiIDogWyAwLCAwIF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAiaGVhZCIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogImJvZHkiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAwLjAsIDI0LjAsIDAuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAiaW5mbGF0ZSIgOiAwLjUwLAogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTQuMCwgMjQuMCwgLTQuMCBdLAogICAgICAgICAgICAgICAgICAgICAic2l6ZSIgOiBbIDgsIDgsIDggXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMzIsIDAgXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJoYXQiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJoZWFkIiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgMC4wLCAyNC4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgIm9yaWdpbiIgOiBbIC0zLjkwLCAwLjAsIC0yLjAgXSwKICAgICAgICAgICAgICAgICAgICAgInNpemUiIDogWyA0LCAxMiwgNCBdLAogICAgICAgICAgICAgICAgICAgICAidXYiIDogWyAwLCAxNiBdCiAgICAgICAgICAgICAgICAgIH0KICAgICAgICAgICAgICAgXSwKICAgICAgICAgICAgICAgIm5hbWUiIDogInJpZ2h0TGVnIiwKICAgICAgICAgICAgICAgInBhcmVudCIgOiAicm9vdCIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIC0xLjkwLCAxMi4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgImluZmxhdGUiIDogMC4yNTAsCiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyAtMy45MCwgMC4wLCAtMi4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgNCwgMTIsIDQgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMCwgMzIgXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJyaWdodFBhbnRzIiwKICAgICAgICAgICAgICAgInBhcmVudCIgOiAicmlnaHRMZWciLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAtMS45MCwgMTIuMCwgMC4wIF0KICAgICAgICAgICAgfSwKICAgICAgICAgICAgewogICAgICAgICAgICAgICAiY3ViZXMiIDogWwogICAgICAgICAgICAgICAgICB7CiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyAtMC4xMCwgMC4wLCAtMi4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgNCwgMTIsIDQgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMTYsIDQ4IF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAibGVmdExlZyIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogInJvb3QiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAxLjkwLCAxMi4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgImluZmxhdGUiIDogMC4yNTAsCiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyAtMC4xMCwgMC4wLCAtMi4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgNCwgMTIsIDQgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMCwgNDggXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJsZWZ0UGFudHMiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJsZWZ0TGVnIiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgMS45MCwgMTIuMCwgMC4wIF0KICAgICAgICAgICAgfSwKICAgICAgICAgICAgewogICAgICAgICAgICAgICAiY3ViZXMiIDogWwogICAgICAgICAgICAgICAgICB7CiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyA0LjAsIDExLjUwLCAtMi4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgMywgMTIsIDQgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMzIsIDQ4IF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAibGVmdEFybSIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogImJvZHkiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyA1LjAsIDIxLjUwLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgImluZmxhdGUiIDogMC4yNTAsCiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyA0LjAsIDExLjUwLCAtMi4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgMywgMTIsIDQgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgNDgsIDQ4IF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAibGVmdFNsZWV2ZSIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogImxlZnRBcm0iLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyA1LjAsIDIxLjUwLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJuYW1lIiA6ICJsZWZ0SXRlbSIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogImxlZnRBcm0iLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyA2LCAxNC41MCwgMSBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTcuMCwgMTEuNTAsIC0yLjAgXSwKICAgICAgICAgICAgICAgICAgICAgInNpemUiIDogWyAzLCAxMiwgNCBdLAogICAgICAgICAgICAgICAgICAgICAidXYiIDogWyA0MCwgMTYgXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJyaWdodEFybSIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogImJvZHkiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAtNS4wLCAyMS41MCwgMC4wIF0KICAgICAgICAgICAgfSwKICAgICAgICAgICAgewogICAgICAgICAgICAgICAiY3ViZXMiIDogWwogICAgICAgICAgICAgICAgICB7CiAgICAgICAgICAgICAgICAgICAgICJpbmZsYXRlIiA6IDAuMjUwLAogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTcuMCwgMTEuNTAsIC0yLjAgXSwKICAgICAgICAgICAgICAgICAgICAgInNpemUiIDogWyAzLCAxMiwgNCBdLAogICAgICAgICAgICAgICAgICAgICAidXYiIDogWyA0MCwgMzIgXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJyaWdodFNsZWV2ZSIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogInJpZ2h0QXJtIiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgLTUuMCwgMjEuNTAsIDAuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImxvY2F0b3JzIiA6IHsKICAgICAgICAgICAgICAgICAgImxlYWRfaG9sZCIgOiBbIC02LCAxNC41MCwgMSBdCiAgICAgICAgICAgICAgIH0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJyaWdodEl0ZW0iLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJyaWdodEFybSIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIC02LCAxNC41MCwgMSBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAiaW5mbGF0ZSIgOiAwLjI1MCwKICAgICAgICAgICAgICAgICAgICAgIm9yaWdpbiIgOiBbIC00LjAsIDEyLjAsIC0yLjAgXSwKICAgICAgICAgICAgICAgICAgICAgInNpemUiIDogWyA4LCAxMiwgNCBdLAogICAgICAgICAgICAgICAgICAgICAidXYiIDogWyAxNiwgMzIgXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJqYWNrZXQiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJib2R5IiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgMC4wLCAyNC4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJuYW1lIiA6ICJjYXBlIiwKICAgICAgICAgICAgICAgInBhcmVudCIgOiAiYm9keSIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIDAuMCwgMjQsIC0zLjAgXQogICAgICAgICAgICB9CiAgICAgICAgIF0sCiAgICAgICAgICJkZXNjcmlwdGlvbiIgOiB7CiAgICAgICAgICAgICJpZGVudGlmaWVyIiA6ICJnZW9tZXRyeS5odW1hbm9pZC5jdXN0b21TbGltIiwKICAgICAgICAgICAgInRleHR1cmVfaGVpZ2h0IiA6IDY0LAogICAgICAgICAgICAidGV4dHVyZV93aWR0aCIgOiA2NCwKICAgICAgICAgICAgInZpc2libGVfYm91bmRzX2hlaWdodCIgOiAyLAogICAgICAgICAgICAidmlzaWJsZV9ib3VuZHNfb2Zmc2V0IiA6IFsgMCwgMSwgMCBdLAogICAgICAgICAgICAidmlzaWJsZV9ib3VuZHNfd2lkdGgiIDogMQogICAgICAgICB9CiAgICAgIH0KICAgXQp9Cg==";
+const DEFAULT_SKIN_RESOURCE_PATCH: &str = "ewogICAiZ2VvbWV0cnkiIDogewogICAgICAiZGVmYXVsdCIgOiAiZ2VvbWV0cnkuaHVtYW5vaWQuY3VzdG9tIgogICB9Cn0K";
+
+// Decoded while compiling, a broken constant fails the build instead of the first `Skin::default()`
+const DEFAULT_SKIN: [u8; 64 * 64 * 4] = decode_base64(DEFAULT_SKIN_DATA);
+const DEFAULT_GEOMETRY: &str = utf8(&decode_base64::<12394>(DEFAULT_SKIN_GEOMETRY));
+const DEFAULT_RESOURCE_PATCH: &str = utf8(&decode_base64::<72>(DEFAULT_SKIN_RESOURCE_PATCH));
+
+// Standard base64 with padding into exactly N bytes
+const fn decode_base64<const N: usize>(input: &str) -> [u8; N] {
+    const fn sextet(c: u8) -> u32 {
+        match c {
+            b'A'..=b'Z' => (c - b'A') as u32,
+            b'a'..=b'z' => (c - b'a' + 26) as u32,
+            b'0'..=b'9' => (c - b'0' + 52) as u32,
+            b'+' => 62,
+            b'/' => 63,
+            _ => panic!("Invalid base64 character")
+        }
+    }
+    let input = input.as_bytes();
+    if !input.len().is_multiple_of(4) {
+        panic!("Base64 length is not a multiple of 4");
+    }
+    let mut output = [0u8; N];
+    let (mut i, mut o) = (0, 0);
+    while i < input.len() {
+        let padding = if input[i + 3] != b'=' { 0 } else if input[i + 2] != b'=' { 1 } else { 2 };
+        if padding > 0 && i + 4 != input.len() {
+            panic!("Base64 padding before the end");
+        }
+        let mut group = sextet(input[i]) << 18 | sextet(input[i + 1]) << 12;
+        if padding < 2 {
+            group |= sextet(input[i + 2]) << 6;
+        }
+        if padding < 1 {
+            group |= sextet(input[i + 3]);
+        }
+        let mut k = 0;
+        while k < 3 - padding {
+            if o == N {
+                panic!("Base64 decodes to more bytes than expected");
+            }
+            output[o] = (group >> (16 - 8 * k)) as u8;
+            o += 1;
+            k += 1;
+        }
+        i += 4;
+    }
+    if o != N {
+        panic!("Base64 decodes to fewer bytes than expected");
+    }
+    output
+}
+
+const fn utf8(bytes: &[u8]) -> &str {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(_) => panic!("Invalid UTF-8")
+    }
+}
+
+pub struct Skin {
+    pub id: String,
+    pub data: Vec<u8>, // RGBA
+    pub width: u32,
+    pub height: u32,
+    pub geometry: String, // JSON
+    pub resource_patch: String, // JSON, names the geometry to use
+    pub geometry_engine_version: String,
+    pub arm_size: String,
+    pub color: String
+}
+
+impl Skin {
+    // Skin PNG (64x64 or 128x128) with its geometry JSON
+    pub fn from_png(png_path: impl AsRef<Path>, geometry_path: impl AsRef<Path>) -> Result<Skin, ClientError> {
+        let png = fs::read(png_path)?;
+        let geometry = fs::read_to_string(geometry_path)?;
+        Skin::from_png_bytes(&png, geometry)
+    }
+
+    pub fn from_png_bytes(png: &[u8], geometry: String) -> Result<Skin, ClientError> {
+        let (data, width, height) = decode_png(png)?;
+        if !matches!((width, height), (64, 64) | (128, 128)) {
+            return Err(ClientError::Decode(format!("Skin must be 64x64 or 128x128, got {}x{}", width, height), None));
+        }
+        let identifier = geometry_identifier(&geometry)?;
+        let resource_patch = json!({ "geometry": { "default": identifier } }).to_string();
+
+        Ok(Skin{ id: format!("Custom{}", identifier), data, width, height, geometry, resource_patch, ..Skin::default() })
+    }
+
+    pub fn with_id(mut self, id: String) -> Skin {
+        self.id = id;
+        self
+    }
+
+    pub fn with_arm_size(mut self, arm_size: &str) -> Skin {
+        self.arm_size = arm_size.to_string();
+        self
+    }
+}
+
+impl Default for Skin {
+    fn default() -> Skin {
+        Skin{
+            id: "Customebc40067-bfdb-3ad0-af9d-65248592acf1".to_string(),
+            data: DEFAULT_SKIN.to_vec(),
+            width: 64,
+            height: 64,
+            geometry: DEFAULT_GEOMETRY.to_string(),
+            resource_patch: DEFAULT_RESOURCE_PATCH.to_string(),
+            geometry_engine_version: "0.0.0".to_string(),
+            arm_size: ARM_SIZE_WIDE.to_string(),
+            color: "#0".to_string()
+        }
+    }
+}
+
+pub struct Cape {
+    pub id: String,
+    pub data: Vec<u8>, // RGBA
+    pub width: u32,
+    pub height: u32,
+    pub on_classic_skin: bool
+}
+
+impl Cape {
+    pub fn from_png(id: String, png_path: impl AsRef<Path>) -> Result<Cape, ClientError> {
+        Cape::from_png_bytes(id, &fs::read(png_path)?)
+    }
+
+    // Capes are 64x32
+    pub fn from_png_bytes(id: String, png: &[u8]) -> Result<Cape, ClientError> {
+        let (data, width, height) = decode_png(png)?;
+        if (width, height) != (64, 32) {
+            return Err(ClientError::Decode(format!("Cape must be 64x32, got {}x{}", width, height), None));
+        }
+        Ok(Cape{ id, data, width, height, on_classic_skin: false })
+    }
+}
+
+// What the client tells the server about its device, skin and settings in the Login packet
+pub struct ClientDataOptions {
+    pub skin: Skin,
+    pub cape: Option<Cape>,
+    pub device_os: i32,
+    pub device_model: String,
+    pub device_id: String,
+    pub self_signed_id: String,
+    pub play_fab_id: String,
+    pub default_input_mode: i32,
+    pub current_input_mode: i32,
+    pub ui_profile: i32,
+    pub gui_scale: i32,
+    pub language_code: String,
+    pub memory_tier: i32,
+    pub max_view_distance: i32
+}
+
+impl ClientDataOptions {
+    // Random device ids, so that every client looks like its own device
+    pub fn new() -> ClientDataOptions {
+        ClientDataOptions{
+            device_id: Builder::from_random_bytes(rand::random()).into_uuid().to_string(),
+            self_signed_id: Builder::from_random_bytes(rand::random()).into_uuid().to_string(),
+            play_fab_id: hex::encode(rand::random::<[u8; 8]>()),
+            ..ClientDataOptions::default()
+        }
+    }
+
+    pub fn skin(mut self, skin: Skin) -> ClientDataOptions {
+        self.skin = skin;
+        self
+    }
+
+    pub fn cape(mut self, cape: Cape) -> ClientDataOptions {
+        self.cape = Option::from(cape);
+        self
+    }
+
+    pub fn device(mut self, device_os: i32, device_model: String) -> ClientDataOptions {
+        self.device_os = device_os;
+        self.device_model = device_model;
+        self
+    }
+
+    pub fn device_id(mut self, device_id: String) -> ClientDataOptions {
+        self.device_id = device_id;
+        self
+    }
+
+    pub fn input_mode(mut self, default_input_mode: i32, current_input_mode: i32) -> ClientDataOptions {
+        self.default_input_mode = default_input_mode;
+        self.current_input_mode = current_input_mode;
+        self
+    }
+
+    pub fn ui_profile(mut self, ui_profile: i32) -> ClientDataOptions {
+        self.ui_profile = ui_profile;
+        self
+    }
+
+    pub fn gui_scale(mut self, gui_scale: i32) -> ClientDataOptions {
+        self.gui_scale = gui_scale;
+        self
+    }
+
+    pub fn language(mut self, language_code: String) -> ClientDataOptions {
+        self.language_code = language_code;
+        self
+    }
+
+    pub fn memory_tier(mut self, memory_tier: i32) -> ClientDataOptions {
+        self.memory_tier = memory_tier;
+        self
+    }
+
+    pub fn max_view_distance(mut self, max_view_distance: i32) -> ClientDataOptions {
+        self.max_view_distance = max_view_distance;
+        self
+    }
+
+    pub fn get_skin_data(&self) -> String {
+        encode_block(&self.skin.data)
+    }
+
+    pub fn get_cape_data(&self) -> String {
+        self.cape.as_ref().map(|cape| encode_block(&cape.data)).unwrap_or_default()
+    }
+}
+
+// The values older versions of this client always sent
+impl Default for ClientDataOptions {
+    fn default() -> ClientDataOptions {
+        ClientDataOptions{
+            skin: Skin::default(),
+            cape: None,
+            device_os: DEVICE_ANDROID,
+            device_model: "System Product Name ASUS".to_string(),
+            device_id: "ebc40067-bfdb-3ad0-af9d-65248592acf1".to_string(),
+            self_signed_id: "651a4f81-aa0e-3c32-b30b-3d250293a340".to_string(),
+            play_fab_id: "a3561c5eacf46e1d".to_string(),
+            default_input_mode: INPUT_MOUSE,
+            current_input_mode: INPUT_MOUSE,
+            ui_profile: UI_PROFILE_CLASSIC,
+            gui_scale: -1,
+            language_code: "en_US".to_string(),
+            memory_tier: 5,
+            max_view_distance: 40
+        }
+    }
+}
+
+// RGBA pixels, width and height
+fn decode_png(png: &[u8]) -> Result<(Vec<u8>, u32, u32), ClientError> {
+    let mut decoder = png::Decoder::new(png);
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder.read_info().map_err(|e| ClientError::decode("PNG can not be decoded", e))?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer).map_err(|e| ClientError::decode("PNG can not be decoded", e))?;
+    buffer.truncate(info.buffer_size());
+
+    let pixels = match info.color_type {
+        png::ColorType::Rgba => buffer,
+        png::ColorType::Rgb => buffer.chunks(3).flat_map(|p| [p[0], p[1], p[2], 255]).collect(),
+        png::ColorType::GrayscaleAlpha => buffer.chunks(2).flat_map(|p| [p[0], p[0], p[0], p[1]]).collect(),
+        png::ColorType::Grayscale => buffer.iter().flat_map(|g| [*g, *g, *g, 255]).collect(),
+        png::ColorType::Indexed => return Err(ClientError::Decode("Indexed PNG was not expanded".to_string(), None))
+    };
+    Ok((pixels, info.width, info.height))
+}
+
+// Identifier of the first geometry, "minecraft:geometry" format or the older "geometry.name[:parent]" keys
+fn geometry_identifier(geometry: &str) -> Result<String, ClientError> {
+    let json: Value = serde_json::from_str(geometry).map_err(|e| ClientError::decode("Geometry is not JSON", e))?;
+    if let Some(identifier) = json.get("minecraft:geometry").and_then(|list| list.get(0)).and_then(|g| g.get("description")).and_then(|d| d.get("identifier")).and_then(Value::as_str) {
+        return Ok(identifier.to_string());
+    }
+    json.as_object()
+        .and_then(|object| object.keys().find(|key| key.starts_with("geometry.")))
+        .map(|key| key.split(':').next().unwrap_or(key).to_string())
+        .ok_or(ClientError::Decode("Geometry has no identifier".to_string(), None))
+}
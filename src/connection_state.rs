@@ -0,0 +1,175 @@
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::packet_ids::PacketType;
+use std::fmt;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConnectionState {
+    OfflineHandshake, // OpenConnectionRequest 1 & 2
+    Connecting,       // ConnectionRequest sent
+    Connected,        // ConnectionRequestAccepted received
+    NetworkSettings,  // RequestNetworkSettings sent
+    Login,            // Login sent
+    Encrypted,        // ClientToServerHandshake sent
+    ResourcePacks,
+    Spawning,         // StartGame received, waiting for PlayStatus::PlayerSpawn
+    InGame,
+    Disconnected
+}
+
+impl ConnectionState {
+    pub fn can_transition_to(self, next: ConnectionState) -> bool {
+        if next == ConnectionState::Disconnected {
+            return self != ConnectionState::Disconnected;
+        }
+        matches!(
+            (self, next),
+            (ConnectionState::OfflineHandshake, ConnectionState::Connecting)
+                | (ConnectionState::Connecting, ConnectionState::Connected)
+                | (ConnectionState::Connected, ConnectionState::NetworkSettings)
+                | (ConnectionState::NetworkSettings, ConnectionState::Login)
+                | (ConnectionState::Login, ConnectionState::Encrypted)
+                | (ConnectionState::Login, ConnectionState::ResourcePacks) // server with encryption disabled
+                | (ConnectionState::Encrypted, ConnectionState::ResourcePacks)
+                | (ConnectionState::ResourcePacks, ConnectionState::Spawning)
+                | (ConnectionState::Spawning, ConnectionState::InGame)
+        )
+    }
+
+    pub fn timeout(self) -> Option<Duration> {
+        match self {
+            ConnectionState::OfflineHandshake => Some(Duration::from_secs(5)),
+            ConnectionState::Connecting => Some(Duration::from_secs(5)),
+            ConnectionState::Connected => Some(Duration::from_secs(5)),
+            ConnectionState::NetworkSettings => Some(Duration::from_secs(10)),
+            ConnectionState::Login => Some(Duration::from_secs(10)),
+            ConnectionState::Encrypted => Some(Duration::from_secs(10)),
+            ConnectionState::ResourcePacks => Some(Duration::from_secs(60)),
+            ConnectionState::Spawning => Some(Duration::from_secs(30)),
+            ConnectionState::InGame | ConnectionState::Disconnected => None
+        }
+    }
+
+    pub fn accepts_raknet(self, packet_type: PacketType) -> bool {
+        match packet_type {
+            PacketType::OpenConnReply1 | PacketType::OpenConnReply2 | PacketType::IncompatibleProtocol => self == ConnectionState::OfflineHandshake,
+            PacketType::ConnReqAccepted => self == ConnectionState::Connecting,
+            PacketType::Game => self != ConnectionState::OfflineHandshake && self != ConnectionState::Connecting && self != ConnectionState::Disconnected,
+            _ => self != ConnectionState::Disconnected
+        }
+    }
+
+    pub fn accepts(self, packet_type: &BedrockPacketType) -> bool {
+        if let BedrockPacketType::Disconnect = packet_type {
+            return self != ConnectionState::Disconnected;
+        }
+        match self {
+            ConnectionState::OfflineHandshake | ConnectionState::Connecting | ConnectionState::Connected | ConnectionState::Disconnected => false,
+            ConnectionState::NetworkSettings => matches!(packet_type, BedrockPacketType::NetworkSettings | BedrockPacketType::PlayStatus),
+            ConnectionState::Login => matches!(packet_type, BedrockPacketType::ServerToClientHandshake | BedrockPacketType::PlayStatus),
+            ConnectionState::Encrypted => matches!(packet_type, BedrockPacketType::PlayStatus | BedrockPacketType::ResourcePacksInfo),
+            // only the pack exchange until StartGame, the world is not known before that
+            ConnectionState::ResourcePacks => matches!(
+                packet_type,
+                BedrockPacketType::PlayStatus
+                    | BedrockPacketType::ResourcePacksInfo
+                    | BedrockPacketType::ResourcePackStack
+                    | BedrockPacketType::ResourcePackDataInfo
+                    | BedrockPacketType::ResourcePackChunkData
+                    | BedrockPacketType::StartGame
+                    | BedrockPacketType::Text
+                    | BedrockPacketType::Transfer
+            ),
+            ConnectionState::Spawning | ConnectionState::InGame => !matches!(
+                packet_type,
+                BedrockPacketType::NetworkSettings
+                    | BedrockPacketType::ServerToClientHandshake
+                    | BedrockPacketType::ResourcePacksInfo
+                    | BedrockPacketType::ResourcePackStack
+                    | BedrockPacketType::StartGame
+            )
+        }
+    }
+}
+
+impl fmt::Display for ConnectionState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StateChange {
+    pub from: ConnectionState,
+    pub to: ConnectionState
+}
+
+#[derive(Debug)]
+pub struct InvalidTransition {
+    pub from: ConnectionState,
+    pub to: ConnectionState
+}
+
+impl fmt::Display for InvalidTransition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid connection state transition: {} -> {}", self.from, self.to)
+    }
+}
+
+impl std::error::Error for InvalidTransition {}
+
+pub struct StateMachine {
+    state: ConnectionState,
+    entered_at: Instant,
+    listeners: Vec<Sender<StateChange>>
+}
+
+impl StateMachine {
+    pub fn new() -> StateMachine {
+        StateMachine{ state: ConnectionState::OfflineHandshake, entered_at: Instant::now(), listeners: Vec::new() }
+    }
+
+    pub fn get(&self) -> ConnectionState {
+        self.state
+    }
+
+    pub fn is(&self, state: ConnectionState) -> bool {
+        self.state == state
+    }
+
+    pub fn transition(&mut self, next: ConnectionState) -> Result<(), InvalidTransition> {
+        if !self.state.can_transition_to(next) {
+            return Err(InvalidTransition{ from: self.state, to: next });
+        }
+        let change = StateChange{ from: self.state, to: next };
+        self.state = next;
+        self.entered_at = Instant::now();
+
+        // drop the listeners whose receiver is gone
+        self.listeners.retain(|listener| listener.send(change).is_ok());
+        Ok(())
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.entered_at.elapsed()
+    }
+
+    // Restarts the timeout of the current state, for progress that does not change the state
+    pub fn refresh(&mut self) {
+        self.entered_at = Instant::now();
+    }
+
+    pub fn is_timed_out(&self) -> bool {
+        match self.state.timeout() {
+            Some(timeout) => self.elapsed() > timeout,
+            None => false
+        }
+    }
+
+    pub fn subscribe(&mut self) -> Receiver<StateChange> {
+        let (sender, receiver) = channel();
+        self.listeners.push(sender);
+        receiver
+    }
+}
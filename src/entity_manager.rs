@@ -0,0 +1,314 @@
+use crate::physics::EYE_HEIGHT;
+use crate::protocol::game::add_actor::AddActor;
+use crate::protocol::game::add_item_actor::AddItemActor;
+use crate::protocol::game::add_player::AddPlayer;
+use crate::protocol::game::move_actor_absolute::{MoveActorAbsolute, FLAG_GROUND};
+use crate::protocol::game::move_actor_delta::MoveActorDelta;
+use crate::protocol::game::move_player::MovePlayer;
+use crate::protocol::game::set_actor_data::SetActorData;
+use crate::protocol::game::set_actor_link::SetActorLink;
+use crate::protocol::game::set_actor_motion::SetActorMotion;
+use crate::protocol::game::types::actor_link::{ActorLink, TYPE_REMOVE};
+use crate::protocol::game::types::actor_metadata::ActorMetadata;
+use crate::protocol::game::types::actor_properties::{ActorProperties, PropertyValue};
+use crate::protocol::game::types::item_stack::ItemStack;
+use crate::protocol::game::types::property_sync_data::PropertySyncData;
+use crate::protocol::game::types::vector::Vec3;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub const PLAYER_TYPE_ID: &str = "minecraft:player";
+pub const ITEM_TYPE_ID: &str = "minecraft:item";
+
+#[derive(Debug, Clone)]
+pub struct PlayerInfo {
+    pub uuid: Uuid,
+    pub username: String,
+    pub platform_chat_id: String,
+    pub device_id: String,
+    pub build_platform: i32,
+    pub game_mode: i32,
+    pub held_item: ItemStack
+}
+
+// An actor the server spawned around the local player
+#[derive(Debug, Clone)]
+pub struct Entity {
+    pub runtime_id: u64,
+    pub unique_id: i64,
+    pub type_id: String,
+    pub position: Vec3, // at the feet, also for players
+    pub pitch: f32,
+    pub yaw: f32,
+    pub head_yaw: f32,
+    pub velocity: Vec3,
+    pub on_ground: bool,
+    pub metadata: ActorMetadata,
+    pub properties: PropertySyncData,
+    pub links: Vec<ActorLink>, // riders of this entity
+    pub player: Option<PlayerInfo>, // set for AddPlayer
+    pub item: Option<ItemStack> // set for AddItemActor
+}
+
+impl Entity {
+    pub fn new(runtime_id: u64, unique_id: i64, type_id: String, position: Vec3) -> Entity {
+        Entity{
+            runtime_id,
+            unique_id,
+            type_id,
+            position,
+            pitch: 0.0,
+            yaw: 0.0,
+            head_yaw: 0.0,
+            velocity: Vec3::default(),
+            on_ground: false,
+            metadata: ActorMetadata::new(),
+            properties: PropertySyncData::default(),
+            links: vec![],
+            player: None,
+            item: None
+        }
+    }
+
+    pub fn is_player(&self) -> bool {
+        self.player.is_some()
+    }
+
+    pub fn get_username(&self) -> Option<&str> {
+        self.player.as_ref().map(|player| player.username.as_str())
+    }
+
+    pub fn distance_to(&self, position: Vec3) -> f32 {
+        (self.position - position).length()
+    }
+
+    // Move packets of players are at eye height
+    fn feet(&self, y: f32) -> f32 {
+        if self.is_player() { y - EYE_HEIGHT } else { y }
+    }
+}
+
+// Entities by runtime ID. Removal is by unique ID, links use unique IDs too.
+#[derive(Debug, Default)]
+pub struct EntityManager {
+    entities: HashMap<u64, Entity>,
+    runtime_ids: HashMap<i64, u64>, // unique ID => runtime ID
+    property_definitions: HashMap<String, ActorProperties>, // by actor type
+    player_metadata: ActorMetadata, // of the local player
+    player_properties: PropertySyncData
+}
+
+impl EntityManager {
+    pub fn new() -> EntityManager {
+        EntityManager::default()
+    }
+
+    // Replaces an entity with the same runtime ID
+    pub fn add(&mut self, entity: Entity) -> &Entity {
+        let runtime_id = entity.runtime_id;
+        if let Some(old) = self.entities.remove(&runtime_id) {
+            self.runtime_ids.remove(&old.unique_id);
+        }
+        self.runtime_ids.insert(entity.unique_id, runtime_id);
+        self.entities.entry(runtime_id).or_insert(entity)
+    }
+
+    pub fn add_actor(&mut self, add_actor: &AddActor) -> &Entity {
+        let mut entity = Entity::new(add_actor.actor_runtime_id, add_actor.actor_unique_id, add_actor.type_id.clone(), add_actor.position);
+        entity.velocity = add_actor.motion;
+        entity.pitch = add_actor.pitch;
+        entity.yaw = add_actor.yaw;
+        entity.head_yaw = add_actor.head_yaw;
+        entity.metadata = add_actor.metadata.clone();
+        entity.properties = add_actor.properties.clone();
+        entity.links = add_actor.links.clone();
+        self.add(entity)
+    }
+
+    pub fn add_player(&mut self, add_player: &AddPlayer) -> &Entity {
+        let mut entity = Entity::new(add_player.actor_runtime_id, add_player.get_actor_unique_id(), PLAYER_TYPE_ID.to_string(), add_player.position);
+        entity.velocity = add_player.motion;
+        entity.pitch = add_player.pitch;
+        entity.yaw = add_player.yaw;
+        entity.head_yaw = add_player.head_yaw;
+        entity.metadata = add_player.metadata.clone();
+        entity.properties = add_player.properties.clone();
+        entity.links = add_player.links.clone();
+        entity.player = Option::from(PlayerInfo{
+            uuid: add_player.uuid,
+            username: add_player.username.clone(),
+            platform_chat_id: add_player.platform_chat_id.clone(),
+            device_id: add_player.device_id.clone(),
+            build_platform: add_player.build_platform,
+            game_mode: add_player.game_mode,
+            held_item: add_player.held_item.clone()
+        });
+        self.add(entity)
+    }
+
+    pub fn add_item_actor(&mut self, add_item_actor: &AddItemActor) -> &Entity {
+        let mut entity = Entity::new(add_item_actor.actor_runtime_id, add_item_actor.actor_unique_id, ITEM_TYPE_ID.to_string(), add_item_actor.position);
+        entity.velocity = add_item_actor.motion;
+        entity.metadata = add_item_actor.metadata.clone();
+        entity.item = Option::from(add_item_actor.item.clone());
+        self.add(entity)
+    }
+
+    pub fn remove(&mut self, unique_id: i64) -> Option<Entity> {
+        let runtime_id = self.runtime_ids.remove(&unique_id)?;
+        self.entities.remove(&runtime_id)
+    }
+
+    // The move and update methods return false for entities that are not tracked (e.g. the local player)
+    pub fn move_absolute(&mut self, packet: &MoveActorAbsolute) -> bool {
+        match self.entities.get_mut(&packet.actor_runtime_id) {
+            Some(entity) => {
+                entity.position = Vec3::new(packet.position.x, entity.feet(packet.position.y), packet.position.z);
+                entity.pitch = packet.pitch;
+                entity.yaw = packet.yaw;
+                entity.head_yaw = packet.head_yaw;
+                entity.on_ground = packet.flags & FLAG_GROUND != 0;
+                true
+            },
+            None => false
+        }
+    }
+
+    pub fn move_delta(&mut self, packet: &MoveActorDelta) -> bool {
+        match self.entities.get_mut(&packet.actor_runtime_id) {
+            Some(entity) => {
+                entity.position.x = packet.x.unwrap_or(entity.position.x);
+                entity.position.y = packet.y.map(|y| entity.feet(y)).unwrap_or(entity.position.y);
+                entity.position.z = packet.z.unwrap_or(entity.position.z);
+                entity.pitch = packet.pitch.unwrap_or(entity.pitch);
+                entity.yaw = packet.yaw.unwrap_or(entity.yaw);
+                entity.head_yaw = packet.head_yaw.unwrap_or(entity.head_yaw);
+                entity.on_ground = packet.is_on_ground();
+                true
+            },
+            None => false
+        }
+    }
+
+    pub fn move_player(&mut self, packet: &MovePlayer) -> bool {
+        match self.entities.get_mut(&packet.actor_runtime_id) {
+            Some(entity) => {
+                entity.position = Vec3::new(packet.position.x, entity.feet(packet.position.y), packet.position.z);
+                entity.pitch = packet.pitch;
+                entity.yaw = packet.yaw;
+                entity.head_yaw = packet.head_yaw;
+                entity.on_ground = packet.on_ground;
+                true
+            },
+            None => false
+        }
+    }
+
+    pub fn set_motion(&mut self, packet: &SetActorMotion) -> bool {
+        match self.entities.get_mut(&packet.actor_runtime_id) {
+            Some(entity) => {
+                entity.velocity = packet.motion;
+                true
+            },
+            None => false
+        }
+    }
+
+    pub fn set_data(&mut self, packet: &SetActorData) -> bool {
+        match self.entities.get_mut(&packet.actor_runtime_id) {
+            Some(entity) => {
+                entity.metadata.merge(&packet.metadata);
+                entity.properties.merge(&packet.properties);
+                true
+            },
+            None => false
+        }
+    }
+
+    // The link is kept by the ridden entity (`from_unique_id`)
+    pub fn set_link(&mut self, packet: &SetActorLink) -> bool {
+        let link = &packet.link;
+        let entity = match self.runtime_ids.get(&link.from_unique_id).and_then(|runtime_id| self.entities.get_mut(runtime_id)) {
+            Some(entity) => entity,
+            None => return false
+        };
+        entity.links.retain(|old| old.to_unique_id != link.to_unique_id);
+        if link.link_type != TYPE_REMOVE {
+            entity.links.push(link.clone());
+        }
+        true
+    }
+
+    // SetActorData of the local player, which is not one of the entities
+    pub fn set_player_data(&mut self, packet: &SetActorData) {
+        self.player_metadata.merge(&packet.metadata);
+        self.player_properties.merge(&packet.properties);
+    }
+
+    pub fn get_player_metadata(&self) -> &ActorMetadata {
+        &self.player_metadata
+    }
+
+    pub fn get_player_property(&self, name: &str) -> Option<PropertyValue> {
+        self.property_definitions.get(PLAYER_TYPE_ID)?.get(&self.player_properties, name)
+    }
+
+    // Replaces the definitions of the same actor type
+    pub fn set_property_definitions(&mut self, definitions: ActorProperties) {
+        self.property_definitions.insert(definitions.actor_type.clone(), definitions);
+    }
+
+    pub fn get_property_definitions(&self, actor_type: &str) -> Option<&ActorProperties> {
+        self.property_definitions.get(actor_type)
+    }
+
+    pub fn get_property(&self, runtime_id: u64, name: &str) -> Option<PropertyValue> {
+        let entity = self.entities.get(&runtime_id)?;
+        self.property_definitions.get(&entity.type_id)?.get(&entity.properties, name)
+    }
+
+    pub fn get(&self, runtime_id: u64) -> Option<&Entity> {
+        self.entities.get(&runtime_id)
+    }
+
+    pub fn get_by_unique_id(&self, unique_id: i64) -> Option<&Entity> {
+        self.runtime_ids.get(&unique_id).and_then(|runtime_id| self.entities.get(runtime_id))
+    }
+
+    pub fn get_entities(&self) -> impl Iterator<Item = &Entity> {
+        self.entities.values()
+    }
+
+    pub fn get_players(&self) -> impl Iterator<Item = &Entity> {
+        self.entities.values().filter(|entity| entity.is_player())
+    }
+
+    // Usernames are case insensitive
+    pub fn get_player(&self, username: &str) -> Option<&Entity> {
+        self.get_players().find(|entity| entity.get_username().is_some_and(|name| name.eq_ignore_ascii_case(username)))
+    }
+
+    pub fn nearest(&self, position: Vec3) -> Option<&Entity> {
+        self.entities.values().min_by(|a, b| a.distance_to(position).total_cmp(&b.distance_to(position)))
+    }
+
+    // Sorted by distance, nearest first
+    pub fn in_radius(&self, position: Vec3, radius: f32) -> Vec<&Entity> {
+        let mut entities: Vec<&Entity> = self.entities.values().filter(|entity| entity.distance_to(position) <= radius).collect();
+        entities.sort_by(|a, b| a.distance_to(position).total_cmp(&b.distance_to(position)));
+        entities
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    // Everything, also the property definitions and the data of the local player
+    pub fn clear(&mut self) {
+        *self = EntityManager::default();
+    }
+}
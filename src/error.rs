@@ -0,0 +1,108 @@
+use crate::connection_state::InvalidTransition;
+use crate::utils::command_tree::CommandError;
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+pub type BoxError = Box<dyn Error + Send + Sync>;
+
+#[derive(Debug)]
+pub enum ClientError {
+    Io(io::Error),
+    Auth(String, Option<BoxError>),
+    Handshake(String, Option<BoxError>),
+    Crypto(String, Option<BoxError>),
+    Compression(String, Option<BoxError>),
+    Decompression(String, Option<BoxError>),
+    Decode(String, Option<BoxError>),
+    Protocol(String, Option<BoxError>),
+    UnsupportedVersion(String), // game or protocol version this client can not speak, a retry ends the same way
+    Command(CommandError) // rejected before it was sent
+}
+
+impl ClientError {
+    pub fn auth(message: impl Into<String>, source: impl Into<BoxError>) -> ClientError {
+        ClientError::Auth(message.into(), Option::from(source.into()))
+    }
+
+    pub fn handshake(message: impl Into<String>, source: impl Into<BoxError>) -> ClientError {
+        ClientError::Handshake(message.into(), Option::from(source.into()))
+    }
+
+    pub fn crypto(message: impl Into<String>, source: impl Into<BoxError>) -> ClientError {
+        ClientError::Crypto(message.into(), Option::from(source.into()))
+    }
+
+    pub fn compression(message: impl Into<String>, source: impl Into<BoxError>) -> ClientError {
+        ClientError::Compression(message.into(), Option::from(source.into()))
+    }
+
+    pub fn decompression(message: impl Into<String>, source: impl Into<BoxError>) -> ClientError {
+        ClientError::Decompression(message.into(), Option::from(source.into()))
+    }
+
+    pub fn decode(message: impl Into<String>, source: impl Into<BoxError>) -> ClientError {
+        ClientError::Decode(message.into(), Option::from(source.into()))
+    }
+
+    pub fn protocol(message: impl Into<String>, source: impl Into<BoxError>) -> ClientError {
+        ClientError::Protocol(message.into(), Option::from(source.into()))
+    }
+
+    // Errors that are worth a reconnect attempt (network problems, timeouts, server side disconnects), not an unsupported version
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ClientError::Io(_) | ClientError::Protocol(..))
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Io(e) => write!(f, "I/O error: {}", e),
+            ClientError::Auth(message, _) => write!(f, "Authentication error: {}", message),
+            ClientError::Handshake(message, _) => write!(f, "Handshake error: {}", message),
+            ClientError::Crypto(message, _) => write!(f, "Crypto error: {}", message),
+            ClientError::Compression(message, _) => write!(f, "Compression error: {}", message),
+            ClientError::Decompression(message, _) => write!(f, "Decompression error: {}", message),
+            ClientError::Decode(message, _) => write!(f, "Decode error: {}", message),
+            ClientError::Protocol(message, _) => write!(f, "Protocol error: {}", message),
+            ClientError::UnsupportedVersion(message) => write!(f, "Unsupported version: {}", message),
+            ClientError::Command(e) => write!(f, "Command error: {}", e)
+        }
+    }
+}
+
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ClientError::Io(e) => Some(e),
+            ClientError::Auth(_, source)
+            | ClientError::Handshake(_, source)
+            | ClientError::Crypto(_, source)
+            | ClientError::Compression(_, source)
+            | ClientError::Decompression(_, source)
+            | ClientError::Decode(_, source)
+            | ClientError::Protocol(_, source) => source.as_ref().map(|e| e.as_ref() as &(dyn Error + 'static)),
+            ClientError::Command(e) => Some(e),
+            ClientError::UnsupportedVersion(_) => None
+        }
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> ClientError {
+        ClientError::Io(e)
+    }
+}
+
+impl From<CommandError> for ClientError {
+    fn from(e: CommandError) -> ClientError {
+        ClientError::Command(e)
+    }
+}
+
+impl From<InvalidTransition> for ClientError {
+    fn from(e: InvalidTransition) -> ClientError {
+        ClientError::protocol(e.to_string(), e)
+    }
+}
@@ -0,0 +1,241 @@
+use crate::connection_state::StateChange;
+use crate::entity_manager::Entity;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::command_output::CommandOutput;
+use crate::protocol::game::disconnect::Disconnect;
+use crate::protocol::game::level_chunk::LevelChunk;
+use crate::protocol::game::network_settings::NetworkSettings;
+use crate::protocol::game::packet_registry::Packet;
+use crate::protocol::game::play_status::{LoginStatus, PlayStatus};
+use crate::protocol::game::player_list::PlayerListEntry;
+use crate::protocol::game::player_auth_input::PlayerAuthInput;
+use crate::protocol::game::resource_pack_stack::ResourcePackStack;
+use crate::protocol::game::resource_packs_info::ResourcePacksInfo;
+use crate::protocol::game::start_game::StartGame;
+use crate::protocol::game::text::Text;
+use crate::protocol::game::update_attributes::UpdateAttributes;
+use crate::utils::command_tree::CommandTree;
+use crate::utils::log_target;
+use crate::utils::pack_cache::DownloadedPack;
+use log::{debug, info, trace, warn};
+
+// Every method has an empty default, implement only the events you care about.
+pub trait ClientHandler: Send {
+    fn on_state_change(&mut self, _change: &StateChange) {}
+
+    fn on_network_settings(&mut self, _network_settings: &NetworkSettings) {}
+
+    fn on_resource_packs_info(&mut self, _resource_packs_info: &ResourcePacksInfo) {}
+
+    // A downloaded pack after its SHA-256 was checked, `files()` unpacks and decrypts it
+    fn on_resource_pack(&mut self, _pack: &DownloadedPack) {}
+
+    fn on_resource_pack_stack(&mut self, _resource_pack_stack: &ResourcePackStack) {}
+
+    fn on_play_status(&mut self, _play_status: &PlayStatus) {}
+
+    fn on_start_game(&mut self, _start_game: &StartGame) {}
+
+    fn on_text(&mut self, _text: &Text) {}
+
+    // Decoded AvailableCommands, `run_command` checks commands against this tree
+    fn on_available_commands(&mut self, _commands: &CommandTree) {}
+
+    // Every CommandOutput, also the ones that complete a `run_command`
+    fn on_command_output(&mut self, _command_output: &CommandOutput) {}
+
+    fn on_level_chunk(&mut self, _level_chunk: &LevelChunk) {}
+
+    // Attributes of every actor, the movement speeds of the local player are also taken by its physics
+    fn on_update_attributes(&mut self, _update_attributes: &UpdateAttributes) {}
+
+    // AddActor, AddPlayer and AddItemActor, after the entity was added to the entity manager
+    fn on_entity_added(&mut self, _entity: &Entity) {}
+
+    // RemoveActor, with the last known state of the entity
+    fn on_entity_removed(&mut self, _entity: &Entity) {}
+
+    // PlayerList additions, also the players that were online when the local player joined
+    fn on_player_join(&mut self, _player: &PlayerListEntry) {}
+
+    // PlayerList removals, with the last known entry of the player
+    fn on_player_leave(&mut self, _player: &PlayerListEntry) {}
+
+    // After every client tick (20 per second while in game) with the input that was sent
+    fn on_tick(&mut self, _input: &PlayerAuthInput) {}
+
+    fn on_disconnect(&mut self, _disconnect: &Disconnect) {}
+
+    // Every decoded packet before the client handles it, also the ones without a callback of their own (e.g. MovePlayer).
+    fn on_packet(&mut self, _packet: &Packet) {}
+
+    // Packets the client does not decode, the payload starts right after the packet id.
+    fn on_raw_packet(&mut self, _packet_id: u16, _payload: &[u8]) {}
+}
+
+// Used until the application sets its own handler, logs every event (packet dumps at trace level).
+pub struct DebugHandler {}
+
+impl ClientHandler for DebugHandler {
+    fn on_state_change(&mut self, change: &StateChange) {
+        info!(target: log_target::GAME, "State: {} -> {}", change.from, change.to);
+    }
+
+    fn on_network_settings(&mut self, network_settings: &NetworkSettings) {
+        trace!(target: log_target::GAME, "Compression Threshold: {}", network_settings.compression_threshold);
+        trace!(target: log_target::GAME, "Compression Algorithm: {}", if network_settings.compression_algorithm == 0 { "ZLIB" } else if network_settings.compression_algorithm == 1 { "SNAPPY" } else { "NONE" });
+        trace!(target: log_target::GAME, "Enable Client Throttling: {}", network_settings.enable_client_throttling);
+        trace!(target: log_target::GAME, "Client Throttle Threshold: {}", network_settings.client_throttle_threshold);
+        trace!(target: log_target::GAME, "Client Throttle Scalar: {}", network_settings.client_throttle_scalar);
+    }
+
+    fn on_resource_packs_info(&mut self, resource_packs_info: &ResourcePacksInfo) {
+        trace!(target: log_target::GAME, "Must Accept: {}", resource_packs_info.must_accept);
+        trace!(target: log_target::GAME, "Has Addons: {}", resource_packs_info.has_addons);
+        trace!(target: log_target::GAME, "Has Scripts: {}", resource_packs_info.has_scripts);
+        trace!(target: log_target::GAME, "World Template ID: {}", resource_packs_info.world_template_id);
+        trace!(target: log_target::GAME, "World Template Version: {}", resource_packs_info.world_template_version);
+        trace!(target: log_target::GAME, "Resource Pack Count: {}", resource_packs_info.resource_packs.len());
+        for (i, resource_pack) in resource_packs_info.resource_packs.iter().enumerate() {
+            trace!(target: log_target::GAME, "- Resource Pack {} -", i + 1);
+            trace!(target: log_target::GAME, " - UUID: {}", resource_pack.uuid);
+            trace!(target: log_target::GAME, " - Version: {}", resource_pack.version);
+            trace!(target: log_target::GAME, " - Size Bytes: {}", resource_pack.size_bytes);
+            trace!(target: log_target::GAME, " - Encrypted: {}", !resource_pack.encryption_key.is_empty());
+            trace!(target: log_target::GAME, " - Sub Pack Name: {}", resource_pack.sub_pack_name);
+            trace!(target: log_target::GAME, " - Content ID: {}", resource_pack.content_id);
+            trace!(target: log_target::GAME, " - Has Scripts: {}", resource_pack.has_scripts);
+            trace!(target: log_target::GAME, " - Is Addon Pack: {}", resource_pack.is_addon_pack);
+            trace!(target: log_target::GAME, " - Is RTX Capable: {}", resource_pack.is_rtx_capable);
+            trace!(target: log_target::GAME, " - CDN URL: {}", resource_pack.cdn_url);
+            trace!(target: log_target::GAME, "-------------------");
+        }
+    }
+
+    fn on_resource_pack(&mut self, pack: &DownloadedPack) {
+        info!(target: log_target::GAME, "Resource pack {} {} downloaded ({} bytes{})", pack.uuid, pack.version, pack.data.len(), if pack.content_key.is_empty() { "" } else { ", encrypted" });
+    }
+
+    fn on_resource_pack_stack(&mut self, resource_pack_stack: &ResourcePackStack) {
+        trace!(target: log_target::GAME, "Must Accept: {}", resource_pack_stack.must_accept);
+        trace!(target: log_target::GAME, "Base Game Version: {}", resource_pack_stack.base_game_version);
+        trace!(target: log_target::GAME, "Use Vanilla Editor Packs: {}", resource_pack_stack.use_vanilla_editor_packs);
+        for entry in resource_pack_stack.behaviour_packs.iter().chain(resource_pack_stack.resource_packs.iter()) {
+            trace!(target: log_target::GAME, " - {} {} {}", entry.pack_id, entry.version, entry.sub_pack_name);
+        }
+        for (experiment_name, enabled) in resource_pack_stack.experiments.get_experiments() {
+            trace!(target: log_target::GAME, " - Experiment {}: {}", experiment_name, enabled);
+        }
+    }
+
+    fn on_play_status(&mut self, play_status: &PlayStatus) {
+        match LoginStatus::try_from(play_status.status) {
+            Ok(LoginStatus::LoginSuccess) => info!(target: log_target::GAME, "Status: Login Success"),
+            Ok(LoginStatus::LoginFailedClient) => warn!(target: log_target::GAME, "Status: Login Failed Client"),
+            Ok(LoginStatus::LoginFailedServer) => warn!(target: log_target::GAME, "Status: Login Failed Server"),
+            Ok(LoginStatus::PlayerSpawn) => info!(target: log_target::GAME, "Status: Player Spawn"),
+            Ok(LoginStatus::LoginFailedInvalidTenant) => warn!(target: log_target::GAME, "Status: Login Failed Invalid Tenant"),
+            Ok(LoginStatus::LoginFailedVanillaEdu) => warn!(target: log_target::GAME, "Status: Login Failed Vanilla Edu"),
+            Ok(LoginStatus::LoginFailedEduVanilla) => warn!(target: log_target::GAME, "Status: Login Failed Edu Vanilla"),
+            Ok(LoginStatus::LoginFailedServerFull) => warn!(target: log_target::GAME, "Status: Login Failed Server Full"),
+            Ok(LoginStatus::LoginFailedEditorVanilla) => warn!(target: log_target::GAME, "Status: Login Failed Editor Vanilla"),
+            Ok(LoginStatus::LoginFailedVanillaEditor) => warn!(target: log_target::GAME, "Status: Login Failed Vanilla Editor"),
+            Err(e) => warn!(target: log_target::GAME, "Status: {} ({})", e, play_status.status)
+        }
+    }
+
+    fn on_start_game(&mut self, start_game: &StartGame) {
+        trace!(target: log_target::GAME, "actor_unique_id: {}", start_game.actor_unique_id);
+        trace!(target: log_target::GAME, "actor_runtime_id: {}", start_game.actor_runtime_id);
+        trace!(target: log_target::GAME, "server_software_version: {}", start_game.server_software_version);
+        trace!(target: log_target::GAME, "player_game_mode: {}", start_game.player_game_mode);
+        trace!(target: log_target::GAME, "player_position: {:?}", start_game.player_position);
+        trace!(target: log_target::GAME, "yaw: {}", start_game.yaw);
+        trace!(target: log_target::GAME, "pitch: {}", start_game.pitch);
+        trace!(target: log_target::GAME, "level_settings: {:?}", start_game.level_settings);
+        trace!(target: log_target::GAME, "level_id: {}", start_game.level_id);
+        trace!(target: log_target::GAME, "world_name: {}", start_game.world_name);
+        trace!(target: log_target::GAME, "premium_world_template_id: {}", start_game.premium_world_template_id);
+        trace!(target: log_target::GAME, "is_trial: {}", start_game.is_trial);
+        trace!(target: log_target::GAME, "player_movement_settings: {:?}", start_game.player_movement_settings);
+        trace!(target: log_target::GAME, "current_tick: {}", start_game.current_tick);
+        trace!(target: log_target::GAME, "enchantment_seed: {}", start_game.enchantment_seed);
+        trace!(target: log_target::GAME, "multiplayer_correlation_id: {}", start_game.multiplayer_correlation_id);
+        trace!(target: log_target::GAME, "enable_new_inventory_system: {}", start_game.enable_new_inventory_system);
+        trace!(target: log_target::GAME, "block_palette_checksum: {:?}", start_game.block_palette_checksum);
+        trace!(target: log_target::GAME, "world_template_id: {:?}", start_game.world_template_id);
+        trace!(target: log_target::GAME, "enable_client_side_chunk_generation: {}", start_game.enable_client_side_chunk_generation);
+        trace!(target: log_target::GAME, "block_network_ids_are_hashes: {}", start_game.block_network_ids_are_hashes);
+        trace!(target: log_target::GAME, "network_permissions: {:?}", start_game.network_permissions);
+    }
+
+    fn on_text(&mut self, text: &Text) {
+        if let Some(source_name) = text.message.get_source_name() {
+            info!(target: log_target::GAME, "Source Name: {}", source_name);
+        }
+        info!(target: log_target::GAME, "Message: {}", text.message.get_message());
+        if !text.message.get_parameters().is_empty() {
+            info!(target: log_target::GAME, "Parameters: {}", text.message.get_parameters().join(" "));
+        }
+    }
+
+    fn on_available_commands(&mut self, commands: &CommandTree) {
+        debug!(target: log_target::GAME, "Available commands: {}", commands.get_commands().count());
+        for command in commands.get_commands() {
+            trace!(target: log_target::GAME, "{}", commands.usage(command).join("\n"));
+        }
+    }
+
+    fn on_command_output(&mut self, command_output: &CommandOutput) {
+        debug!(target: log_target::GAME, "Command output for {} ({} succeeded)", command_output.origin_data.uuid, command_output.success_count);
+        for message in &command_output.messages {
+            debug!(target: log_target::GAME, " - {} {}", message.message, message.parameters.join(" "));
+        }
+    }
+
+    fn on_level_chunk(&mut self, level_chunk: &LevelChunk) {
+        trace!(target: log_target::GAME, "Chunk X: {}", level_chunk.chunk_x);
+        trace!(target: log_target::GAME, "Chunk Z: {}", level_chunk.chunk_z);
+        trace!(target: log_target::GAME, "Dimension ID: {}", level_chunk.dimension_id);
+        trace!(target: log_target::GAME, "Sub Chunk Count: {}", level_chunk.sub_chunk_count);
+        trace!(target: log_target::GAME, "Client Sub Chunk Requests Enabled: {}", level_chunk.client_sub_chunk_requests_enabled);
+        trace!(target: log_target::GAME, "Used Blob Hashes: {:?}", level_chunk.used_blob_hashes);
+        trace!(target: log_target::GAME, "Extra Payload: {:?}", level_chunk.extra_payload.len());
+    }
+
+    fn on_update_attributes(&mut self, update_attributes: &UpdateAttributes) {
+        for attribute in &update_attributes.attributes {
+            trace!(target: log_target::GAME, "Attribute of {}: {} = {} ({}..{})", update_attributes.actor_runtime_id, attribute.name, attribute.value, attribute.min, attribute.max);
+        }
+    }
+
+    fn on_entity_added(&mut self, entity: &Entity) {
+        debug!(target: log_target::GAME, "Entity added: {} {} ({}) at {:?}", entity.runtime_id, entity.type_id, entity.get_username().unwrap_or_default(), entity.position);
+    }
+
+    fn on_entity_removed(&mut self, entity: &Entity) {
+        debug!(target: log_target::GAME, "Entity removed: {} {}", entity.runtime_id, entity.type_id);
+    }
+
+    fn on_player_join(&mut self, player: &PlayerListEntry) {
+        debug!(target: log_target::GAME, "Player joined: {} (XUID {}, platform {})", player.username, player.xuid, player.build_platform);
+    }
+
+    fn on_player_leave(&mut self, player: &PlayerListEntry) {
+        debug!(target: log_target::GAME, "Player left: {}", player.username);
+    }
+
+    fn on_disconnect(&mut self, disconnect: &Disconnect) {
+        info!(target: log_target::GAME, "Reason: {}", disconnect.reason);
+        if let Some(message) = &disconnect.message {
+            info!(target: log_target::GAME, "Message: {}", message);
+        }
+        if let Some(filtered_message) = &disconnect.filtered_message {
+            info!(target: log_target::GAME, "Filtered Message: {}", filtered_message);
+        }
+    }
+
+    fn on_raw_packet(&mut self, packet_id: u16, payload: &[u8]) {
+        trace!(target: log_target::GAME, "{} ({} bytes)", BedrockPacketType::get_packet_name(packet_id), payload.len());
+    }
+}
@@ -1,17 +1,26 @@
 #![recursion_limit = "16384"]
+pub mod auth;
 pub mod client;
+pub mod client_data_options;
+pub mod connection_state;
+pub mod entity_manager;
+pub mod error;
+pub mod handler;
+pub mod movement;
+pub mod online_players;
+pub mod physics;
 pub mod protocol;
+pub mod session;
 pub mod utils;
 
 const RAKNET_PROTOCOL_VERSION: u8 = 11;
-const BEDROCK_PROTOCOL_VERSION: u32 = 766;
 
 /*
 use std::ffi::CStr;
 use std::os::raw::c_char;
 use tokio::runtime::Runtime;
 #[no_mangle]
-pub extern "C" fn connect_to_server(address: *const c_char, port: u16, version: *const c_char, debug_mode: bool) {
+pub extern "C" fn connect_to_server(address: *const c_char, port: u16, version: *const c_char) {
     let c_str_address = unsafe { CStr::from_ptr(address).to_str().unwrap().to_string() };
     let c_str_version = unsafe { CStr::from_ptr(version).to_str().unwrap().to_string() };
 
@@ -23,7 +32,6 @@ pub extern "C" fn connect_to_server(address: *const c_char, port: u16, version:
             c_str_address,
             port,
             c_str_version,
-            debug_mode,
         );
 
         client.await.unwrap().connect().expect("Connection Error!");
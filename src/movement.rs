@@ -0,0 +1,184 @@
+use crate::physics::{MoveInput, Physics, EYE_HEIGHT};
+use crate::protocol::game::player_auth_input::{self, PlayerAuthInput};
+use crate::protocol::game::player_auth_input::*;
+use crate::protocol::game::types::attribute::Attribute;
+use crate::protocol::game::types::vector::{Vec2, Vec3};
+use crate::utils::chunk::world::World;
+use std::time::Duration;
+
+pub const TICK_INTERVAL: Duration = Duration::from_millis(50); // 20 TPS
+
+// Blocks per tick, only used while the world around the player is not known
+pub const WALK_SPEED: f32 = 0.21585;
+pub const SPRINT_SPEED: f32 = 0.2806;
+pub const SNEAK_SPEED: f32 = 0.0648;
+
+// Input of the local player, turned into one PlayerAuthInput per tick
+pub struct MovementController {
+    position: Vec3,
+    yaw: f32,
+    pitch: f32,
+    move_vector: Vec2, // x: left (+) / right (-), y: forward (+) / backward (-)
+    sprinting: bool,
+    sneaking: bool,
+    jump: bool,
+    was_sprinting: bool,
+    was_sneaking: bool,
+    physics: Physics,
+    tick: u64
+}
+
+impl MovementController {
+    pub fn new(position: Vec3, yaw: f32, pitch: f32, tick: u64) -> MovementController {
+        MovementController{
+            position,
+            yaw,
+            pitch,
+            move_vector: Vec2::default(),
+            sprinting: false,
+            sneaking: false,
+            jump: false,
+            was_sprinting: false,
+            was_sneaking: false,
+            physics: Physics::new(),
+            tick
+        }
+    }
+
+    // Both values are clamped to -1..1, negative forward walks backwards and negative strafe to the right
+    pub fn walk(&mut self, forward: f32, strafe: f32) {
+        self.move_vector = Vec2::new(strafe.clamp(-1.0, 1.0), forward.clamp(-1.0, 1.0));
+    }
+
+    pub fn stop(&mut self) {
+        self.move_vector = Vec2::default();
+        self.sprinting = false;
+    }
+
+    // Only has an effect while walking forward
+    pub fn sprint(&mut self, sprinting: bool) {
+        self.sprinting = sprinting;
+    }
+
+    pub fn sneak(&mut self, sneaking: bool) {
+        self.sneaking = sneaking;
+    }
+
+    // Jumps with the next tick
+    pub fn jump(&mut self) {
+        self.jump = true;
+    }
+
+    // Yaw 0 faces south (+z), 90 west (-x), pitch -90 looks up
+    pub fn look(&mut self, yaw: f32, pitch: f32) {
+        self.yaw = yaw.rem_euclid(360.0);
+        self.pitch = pitch.clamp(-90.0, 90.0);
+    }
+
+    // For teleports and corrections of the server
+    pub fn set_position(&mut self, position: Vec3) {
+        self.position = position;
+        self.physics.velocity = Vec3::default();
+        self.physics.fall_distance = 0.0;
+    }
+
+    // Movement speeds of UpdateAttributes, false for the other attributes
+    pub fn set_attribute(&mut self, attribute: &Attribute) -> bool {
+        self.physics.set_attribute(attribute)
+    }
+
+    pub fn get_physics(&self) -> &Physics {
+        &self.physics
+    }
+
+    pub fn get_position(&self) -> Vec3 {
+        self.position
+    }
+
+    pub fn get_yaw(&self) -> f32 {
+        self.yaw
+    }
+
+    pub fn get_pitch(&self) -> f32 {
+        self.pitch
+    }
+
+    pub fn get_tick(&self) -> u64 {
+        self.tick
+    }
+
+    pub fn is_sprinting(&self) -> bool {
+        self.sprinting && self.move_vector.y > 0.0 && !self.sneaking
+    }
+
+    // Horizontal movement of one tick for the current input
+    pub fn velocity(&self) -> Vec3 {
+        let length = self.move_vector.length();
+        if length == 0.0 {
+            return Vec3::default();
+        }
+        let speed = if self.sneaking { SNEAK_SPEED } else if self.is_sprinting() { SPRINT_SPEED } else { WALK_SPEED };
+        let (forward, strafe) = (self.move_vector.y / length.max(1.0), self.move_vector.x / length.max(1.0));
+        let (sin, cos) = self.yaw.to_radians().sin_cos();
+        Vec3::new(-sin * forward + cos * strafe, 0.0, cos * forward + sin * strafe) * speed
+    }
+
+    // Moves the player without physics and returns the input for this tick
+    pub fn tick(&mut self) -> PlayerAuthInput {
+        let delta = self.velocity();
+        self.position = self.position + delta;
+        self.input(delta, false, false)
+    }
+
+    // Moves the player with gravity and collisions once the blocks around it are loaded, until then like `tick`
+    pub fn tick_in(&mut self, world: &World) -> PlayerAuthInput {
+        let feet = self.position - Vec3::new(0.0, EYE_HEIGHT, 0.0);
+        if !Physics::can_simulate(world, feet) {
+            self.physics.velocity = Vec3::default();
+            return self.tick();
+        }
+        let input = MoveInput{ move_vector: self.move_vector, yaw: self.yaw, jump: self.jump, sprinting: self.is_sprinting(), sneaking: self.sneaking };
+        let position = self.physics.step(world, feet, &input) + Vec3::new(0.0, EYE_HEIGHT, 0.0);
+        let delta = position - self.position;
+        self.position = position;
+        self.input(delta, self.physics.collided_horizontally, self.physics.collided_vertically)
+    }
+
+    fn input(&mut self, delta: Vec3, horizontal_collision: bool, vertical_collision: bool) -> PlayerAuthInput {
+        let mut input = 0u128;
+        let mut set = |flag: u32, value: bool| if value { input |= 1 << flag };
+        set(INPUT_HORIZONTAL_COLLISION, horizontal_collision);
+        set(INPUT_VERTICAL_COLLISION, vertical_collision);
+        set(INPUT_UP, self.move_vector.y > 0.0);
+        set(INPUT_DOWN, self.move_vector.y < 0.0);
+        set(INPUT_LEFT, self.move_vector.x > 0.0);
+        set(INPUT_RIGHT, self.move_vector.x < 0.0);
+
+        let sprinting = self.is_sprinting();
+        set(INPUT_SPRINTING, sprinting);
+        set(INPUT_SPRINT_DOWN, sprinting);
+        set(INPUT_START_SPRINTING, sprinting && !self.was_sprinting);
+        set(INPUT_STOP_SPRINTING, !sprinting && self.was_sprinting);
+
+        set(INPUT_SNEAKING, self.sneaking);
+        set(INPUT_SNEAK_DOWN, self.sneaking);
+        set(INPUT_SNEAK_CURRENT_RAW, self.sneaking);
+        set(INPUT_SNEAK_PRESSED_RAW, self.sneaking && !self.was_sneaking);
+        set(INPUT_START_SNEAKING, self.sneaking && !self.was_sneaking);
+        set(INPUT_STOP_SNEAKING, !self.sneaking && self.was_sneaking);
+
+        set(INPUT_JUMP_DOWN, self.jump);
+        set(INPUT_JUMPING, self.jump);
+        set(INPUT_WANT_UP, self.jump);
+        set(INPUT_START_JUMPING, self.jump);
+        set(INPUT_JUMP_PRESSED_RAW, self.jump);
+        set(INPUT_JUMP_CURRENT_RAW, self.jump);
+
+        self.was_sprinting = sprinting;
+        self.was_sneaking = self.sneaking;
+        self.jump = false;
+        self.tick += 1;
+
+        player_auth_input::new(self.pitch, self.yaw, self.position, self.move_vector, self.yaw, input, INPUT_MODE_MOUSE, PLAY_MODE_NORMAL, INTERACTION_MODEL_CROSSHAIR, self.tick, delta)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::error::ClientError;
+use crate::protocol::game::player_list::{PlayerList, PlayerListEntry, TYPE_ADD};
+use crate::protocol::game::player_skin::PlayerSkin;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use uuid::Uuid;
+
+// Everyone on the server as the player list of the pause screen shows it, also players that are out of view
+// (those are entities as well only while they are near the local player).
+#[derive(Debug, Default)]
+pub struct OnlinePlayers {
+    players: HashMap<Uuid, PlayerListEntry>
+}
+
+impl OnlinePlayers {
+    pub fn new() -> OnlinePlayers {
+        OnlinePlayers::default()
+    }
+
+    // Returns the players that joined or left with this packet
+    pub fn apply(&mut self, player_list: &PlayerList) -> Vec<PlayerListEntry> {
+        if player_list.list_type == TYPE_ADD {
+            for entry in &player_list.entries {
+                self.players.insert(entry.uuid, entry.clone());
+            }
+            player_list.entries.clone()
+        } else {
+            player_list.entries.iter().filter_map(|entry| self.players.remove(&entry.uuid)).collect()
+        }
+    }
+
+    // False for players that are not in the list
+    pub fn update_skin(&mut self, player_skin: &PlayerSkin) -> bool {
+        match self.players.get_mut(&player_skin.uuid) {
+            Some(player) => {
+                player.skin = player_skin.skin.clone();
+                player.skin_trusted = player_skin.skin_trusted;
+                true
+            },
+            None => false
+        }
+    }
+
+    pub fn get(&self, uuid: &Uuid) -> Option<&PlayerListEntry> {
+        self.players.get(uuid)
+    }
+
+    // Usernames are case insensitive
+    pub fn get_by_name(&self, username: &str) -> Option<&PlayerListEntry> {
+        self.players.values().find(|player| player.username.eq_ignore_ascii_case(username))
+    }
+
+    pub fn get_by_xuid(&self, xuid: &str) -> Option<&PlayerListEntry> {
+        self.players.values().find(|player| !player.xuid.is_empty() && player.xuid == xuid)
+    }
+
+    // Sorted by username
+    pub fn get_players(&self) -> Vec<&PlayerListEntry> {
+        let mut players: Vec<&PlayerListEntry> = self.players.values().collect();
+        players.sort_by_key(|player| player.username.to_lowercase());
+        players
+    }
+
+    pub fn len(&self) -> usize {
+        self.players.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.players.clear();
+    }
+
+    // Who is online without the skins, sorted by username
+    pub fn to_json(&self) -> Value {
+        Value::Array(self.get_players().into_iter().map(|player| json!({
+            "uuid": player.uuid.to_string(),
+            "username": player.username,
+            "xuid": player.xuid,
+            "platform_chat_id": player.platform_chat_id,
+            "build_platform": player.build_platform,
+            "actor_unique_id": player.actor_unique_id,
+            "skin_id": player.skin.skin_id
+        })).collect())
+    }
+
+    // Writes <username>.png (and <username>_cape.png) for every player with a skin image, returns the number of skins
+    pub fn export_skins(&self, directory: impl AsRef<Path>) -> Result<usize, ClientError> {
+        let directory = directory.as_ref();
+        fs::create_dir_all(directory)?;
+        let mut count = 0;
+        for player in self.players.values() {
+            if player.skin.skin_image.is_empty() {
+                continue;
+            }
+            // gamertags can not contain path separators, names of other servers might
+            let name: String = player.username.chars().map(|c| if c.is_alphanumeric() || c == ' ' || c == '_' || c == '-' { c } else { '_' }).collect();
+            let name = if name.is_empty() { player.uuid.to_string() } else { name };
+            player.skin.skin_image.save_png(directory.join(format!("{}.png", name)))?;
+            if player.skin.has_cape() {
+                player.skin.cape_image.save_png(directory.join(format!("{}_cape.png", name)))?;
+            }
+            count += 1;
+        }
+        Ok(count)
+    }
+}
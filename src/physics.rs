@@ -0,0 +1,438 @@
+use crate::protocol::game::types::attribute::{self, Attribute, OPERATION_MULTIPLY_TOTAL};
+use crate::protocol::game::types::vector::{Vec2, Vec3};
+use crate::utils::chunk::world::World;
+
+// Blocks per tick², velocities are multiplied with the drag after every tick
+pub const GRAVITY: f32 = 0.08;
+pub const AIR_DRAG: f32 = 0.98;
+pub const FLUID_GRAVITY: f32 = 0.02;
+pub const WATER_DRAG: f32 = 0.8;
+pub const LAVA_DRAG: f32 = 0.5;
+
+pub const JUMP_VELOCITY: f32 = 0.42;
+pub const SPRINT_JUMP_BOOST: f32 = 0.2;
+pub const SWIM_UP_VELOCITY: f32 = 0.04;
+pub const STEP_HEIGHT: f32 = 0.6;
+
+pub const PLAYER_WIDTH: f32 = 0.6;
+pub const PLAYER_HEIGHT: f32 = 1.8;
+pub const EYE_HEIGHT: f32 = 1.62; // positions in PlayerAuthInput are at eye height
+
+// Values of the movement attributes until the server sends others
+pub const DEFAULT_MOVEMENT_SPEED: f32 = 0.1;
+pub const DEFAULT_FLUID_MOVEMENT_SPEED: f32 = 0.02;
+pub const SPRINT_MULTIPLIER: f32 = 1.3;
+pub const SNEAK_MULTIPLIER: f32 = 0.3;
+pub const DEFAULT_SLIPPERINESS: f32 = 0.6;
+const INPUT_FACTOR: f32 = 0.98;
+
+pub const SAFE_FALL_DISTANCE: f32 = 3.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3
+}
+
+impl Aabb {
+    pub fn new(min: Vec3, max: Vec3) -> Aabb {
+        Aabb{ min, max }
+    }
+
+    // Box of a player whose feet are at the position
+    pub fn player(feet: Vec3) -> Aabb {
+        let half = PLAYER_WIDTH / 2.0;
+        Aabb::new(Vec3::new(feet.x - half, feet.y, feet.z - half), Vec3::new(feet.x + half, feet.y + PLAYER_HEIGHT, feet.z + half))
+    }
+
+    pub fn offset(&self, offset: Vec3) -> Aabb {
+        Aabb::new(self.min + offset, self.max + offset)
+    }
+
+    // Grows the box in the direction of the motion, covers everything the box passes on the way
+    pub fn expand(&self, motion: Vec3) -> Aabb {
+        Aabb::new(
+            Vec3::new(self.min.x + motion.x.min(0.0), self.min.y + motion.y.min(0.0), self.min.z + motion.z.min(0.0)),
+            Vec3::new(self.max.x + motion.x.max(0.0), self.max.y + motion.y.max(0.0), self.max.z + motion.z.max(0.0))
+        )
+    }
+
+    // Grows the box on every side, a negative amount shrinks it
+    pub fn inflate(&self, amount: f32) -> Aabb {
+        Aabb::new(self.min + Vec3::new(-amount, -amount, -amount), self.max + Vec3::new(amount, amount, amount))
+    }
+
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        self.min.x < other.max.x && self.max.x > other.min.x
+            && self.min.y < other.max.y && self.max.y > other.min.y
+            && self.min.z < other.max.z && self.max.z > other.min.z
+    }
+
+    // How far the other box can move along the axis before it hits this one
+    pub fn clip_x(&self, other: &Aabb, motion: f32) -> f32 {
+        if other.max.y <= self.min.y || other.min.y >= self.max.y || other.max.z <= self.min.z || other.min.z >= self.max.z {
+            return motion;
+        }
+        clip(self.min.x, self.max.x, other.min.x, other.max.x, motion)
+    }
+
+    pub fn clip_y(&self, other: &Aabb, motion: f32) -> f32 {
+        if other.max.x <= self.min.x || other.min.x >= self.max.x || other.max.z <= self.min.z || other.min.z >= self.max.z {
+            return motion;
+        }
+        clip(self.min.y, self.max.y, other.min.y, other.max.y, motion)
+    }
+
+    pub fn clip_z(&self, other: &Aabb, motion: f32) -> f32 {
+        if other.max.x <= self.min.x || other.min.x >= self.max.x || other.max.y <= self.min.y || other.min.y >= self.max.y {
+            return motion;
+        }
+        clip(self.min.z, self.max.z, other.min.z, other.max.z, motion)
+    }
+}
+
+fn clip(min: f32, max: f32, other_min: f32, other_max: f32, motion: f32) -> f32 {
+    if motion > 0.0 && other_max <= min {
+        motion.min(min - other_max)
+    } else if motion < 0.0 && other_min >= max {
+        motion.max(max - other_min)
+    } else {
+        motion
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fluid {
+    Water,
+    Lava
+}
+
+fn short_name(name: &str) -> &str {
+    name.strip_prefix("minecraft:").unwrap_or(name)
+}
+
+// Blocks without any collision
+fn is_passable(name: &str) -> bool {
+    const NAMES: [&str; 44] = [
+        "air", "structure_void", "water", "flowing_water", "lava", "flowing_lava", "bubble_column",
+        "short_grass", "tall_grass", "tallgrass", "fern", "large_fern", "double_plant", "deadbush", "seagrass", "kelp",
+        "dandelion", "poppy", "yellow_flower", "red_flower", "blue_orchid", "allium", "azure_bluet", "oxeye_daisy",
+        "lily_of_the_valley", "wither_rose", "pink_petals", "reeds", "wheat", "carrots", "potatoes", "beetroot",
+        "sweet_berry_bush", "nether_wart", "vine", "web", "fire", "soul_fire", "redstone_wire", "tripwire", "lever",
+        "snow_layer", "portal", "end_portal"
+    ];
+    const SUFFIXES: [&str; 15] = [
+        "_sapling", "torch", "rail", "_button", "_pressure_plate", "_sign", "_banner", "_tulip", "flower",
+        "_mushroom", "_fungus", "_roots", "_sprouts", "_vines", "_fan"
+    ];
+    NAMES.contains(&name) || name.starts_with("light_block") || name.ends_with("_coral")
+        || SUFFIXES.iter().any(|suffix| name.ends_with(suffix))
+}
+
+// Height of the blocks that are not full cubes. Block states are not known, so every block has the shape of its
+// default state: slabs are bottom slabs, trapdoors are closed and lie on the ground, doors and fence gates are closed.
+// A closed door only covers one side of its block, it is a full cube here so that nothing walks through it.
+fn block_height(name: &str) -> f32 {
+    match name {
+        "farmland" | "grass_path" | "dirt_path" => 15.0 / 16.0,
+        "soul_sand" | "mud" => 14.0 / 16.0,
+        "end_portal_frame" => 13.0 / 16.0,
+        "chest" | "trapped_chest" | "ender_chest" => 14.0 / 16.0,
+        "enchanting_table" => 12.0 / 16.0,
+        "bed" | "stonecutter_block" => 9.0 / 16.0,
+        "cake" => 8.0 / 16.0,
+        "daylight_detector" | "daylight_detector_inverted" => 6.0 / 16.0,
+        "border_block" => 1.5,
+        _ if name.ends_with("_slab") && !name.contains("double") => 0.5,
+        _ if name.ends_with("_carpet") => 1.0 / 16.0,
+        _ if name.ends_with("trapdoor") => 3.0 / 16.0,
+        // fences, fence gates and walls can not be jumped over
+        _ if name.ends_with("fence") || name.ends_with("fence_gate") || name.ends_with("_wall") => 1.5,
+        _ => 1.0
+    }
+}
+
+// Collision boxes of a block relative to its corner. Unknown blocks are full cubes.
+pub fn collision_boxes(name: &str) -> Vec<Aabb> {
+    let name = short_name(name);
+    if is_passable(name) {
+        return vec![];
+    }
+    vec![Aabb::new(Vec3::default(), Vec3::new(1.0, block_height(name), 1.0))]
+}
+
+pub fn fluid(name: &str) -> Option<Fluid> {
+    match short_name(name) {
+        "water" | "flowing_water" | "bubble_column" | "seagrass" | "kelp" => Option::from(Fluid::Water),
+        "lava" | "flowing_lava" => Option::from(Fluid::Lava),
+        _ => None
+    }
+}
+
+// Ground friction of the block the player stands on
+pub fn slipperiness(name: &str) -> f32 {
+    match short_name(name) {
+        "ice" | "packed_ice" | "frosted_ice" => 0.98,
+        "blue_ice" => 0.989,
+        "slime" => 0.8,
+        _ => DEFAULT_SLIPPERINESS
+    }
+}
+
+// Height of a standing jump, the top of the jump arc
+pub fn jump_height() -> f32 {
+    let (mut height, mut velocity) = (0.0, JUMP_VELOCITY);
+    while velocity > 0.0 {
+        height += velocity;
+        velocity = (velocity - GRAVITY) * AIR_DRAG;
+    }
+    height
+}
+
+// Input of one tick, see MovementController
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MoveInput {
+    pub move_vector: Vec2, // x: left (+) / right (-), y: forward (+) / backward (-)
+    pub yaw: f32,
+    pub jump: bool,
+    pub sprinting: bool,
+    pub sneaking: bool
+}
+
+// State of the simulated local player
+#[derive(Debug, Clone)]
+pub struct Physics {
+    pub velocity: Vec3,
+    pub on_ground: bool,
+    pub collided_horizontally: bool,
+    pub collided_vertically: bool,
+    pub in_water: bool,
+    pub in_lava: bool,
+    pub fall_distance: f32,
+    pub fall_damage: f32, // damage of the landing in the last tick, 0 if the player did not land
+    pub movement_speed: f32,
+    pub underwater_movement_speed: f32,
+    pub lava_movement_speed: f32
+}
+
+impl Default for Physics {
+    fn default() -> Physics {
+        Physics::new()
+    }
+}
+
+impl Physics {
+    pub fn new() -> Physics {
+        Physics{
+            velocity: Vec3::default(),
+            on_ground: false,
+            collided_horizontally: false,
+            collided_vertically: false,
+            in_water: false,
+            in_lava: false,
+            fall_distance: 0.0,
+            fall_damage: 0.0,
+            movement_speed: DEFAULT_MOVEMENT_SPEED,
+            underwater_movement_speed: DEFAULT_FLUID_MOVEMENT_SPEED,
+            lava_movement_speed: DEFAULT_FLUID_MOVEMENT_SPEED
+        }
+    }
+
+    // Takes the movement attributes of UpdateAttributes, false for the other attributes
+    pub fn set_attribute(&mut self, attribute: &Attribute) -> bool {
+        // sprinting is a modifier of the movement attribute, the simulation adds it itself
+        let sprint = attribute.modifiers.iter()
+            .filter(|modifier| modifier.operation == OPERATION_MULTIPLY_TOTAL && modifier.name.to_lowercase().contains("sprint"))
+            .fold(1.0, |multiplier, modifier| multiplier * (1.0 + modifier.amount));
+        match attribute.name.as_str() {
+            attribute::MOVEMENT => self.movement_speed = attribute.value / sprint,
+            attribute::UNDERWATER_MOVEMENT => self.underwater_movement_speed = attribute.value,
+            attribute::LAVA_MOVEMENT => self.lava_movement_speed = attribute.value,
+            _ => return false
+        }
+        true
+    }
+
+    // The simulation needs the blocks around the player, without them MovementController moves without physics
+    pub fn can_simulate(world: &World, feet: Vec3) -> bool {
+        world.get_palette().is_some() && world.is_loaded(feet.x.floor() as i32, feet.z.floor() as i32)
+    }
+
+    // Damage if the player landed now
+    pub fn estimate_fall_damage(&self) -> f32 {
+        if self.in_water {
+            return 0.0;
+        }
+        (self.fall_distance - SAFE_FALL_DISTANCE).ceil().max(0.0)
+    }
+
+    // Simulates one tick and returns the new feet position
+    pub fn step(&mut self, world: &World, feet: Vec3, input: &MoveInput) -> Vec3 {
+        self.update_fluids(world, feet);
+
+        // the top speeds on the ground are WALK_SPEED, SPRINT_SPEED and SNEAK_SPEED of the movement controller
+        let (mut forward, mut strafe) = (input.move_vector.y * INPUT_FACTOR, input.move_vector.x * INPUT_FACTOR);
+        if input.sneaking {
+            forward *= SNEAK_MULTIPLIER;
+            strafe *= SNEAK_MULTIPLIER;
+        }
+
+        if self.in_water || self.in_lava {
+            if input.jump {
+                self.velocity.y += SWIM_UP_VELOCITY;
+            }
+            let speed = if self.in_water { self.underwater_movement_speed } else { self.lava_movement_speed };
+            self.velocity = self.velocity + move_relative(forward, strafe, speed, input.yaw);
+            let position = self.move_with_collisions(world, feet);
+
+            self.velocity = self.velocity * if self.in_water { WATER_DRAG } else { LAVA_DRAG };
+            self.velocity.y -= FLUID_GRAVITY;
+            return position;
+        }
+
+        if input.jump && self.on_ground {
+            self.velocity.y = JUMP_VELOCITY;
+            if input.sprinting {
+                let (sin, cos) = input.yaw.to_radians().sin_cos();
+                self.velocity.x -= sin * SPRINT_JUMP_BOOST;
+                self.velocity.z += cos * SPRINT_JUMP_BOOST;
+            }
+        }
+
+        let friction = if self.on_ground { self.ground_slipperiness(world, feet) * 0.91 } else { 0.91 };
+        let acceleration = if self.on_ground {
+            let speed = if input.sprinting { self.movement_speed * SPRINT_MULTIPLIER } else { self.movement_speed };
+            // keeps the top speed on every ground the same as on normal blocks
+            speed * (0.16277136 / (friction * friction * friction))
+        } else if input.sprinting {
+            0.026
+        } else {
+            0.02
+        };
+        self.velocity = self.velocity + move_relative(forward, strafe, acceleration, input.yaw);
+        let position = self.move_with_collisions(world, feet);
+
+        self.velocity.y = (self.velocity.y - GRAVITY) * AIR_DRAG;
+        self.velocity.x *= friction;
+        self.velocity.z *= friction;
+        position
+    }
+
+    fn update_fluids(&mut self, world: &World, feet: Vec3) {
+        self.in_water = false;
+        self.in_lava = false;
+        let player = Aabb::player(feet).inflate(-0.001);
+        for_each_block(&player, |x, y, z| {
+            // waterlogged blocks have their water in the second layer
+            for layer in 0..2 {
+                match world.get_block_layer(x, y, z, layer).and_then(fluid) {
+                    Some(Fluid::Water) => self.in_water = true,
+                    Some(Fluid::Lava) => self.in_lava = true,
+                    None => {}
+                }
+            }
+        });
+        if self.in_water {
+            self.fall_distance = 0.0;
+        }
+    }
+
+    fn ground_slipperiness(&self, world: &World, feet: Vec3) -> f32 {
+        world.get_block(feet.x.floor() as i32, (feet.y - 0.5).floor() as i32, feet.z.floor() as i32).map(slipperiness).unwrap_or(DEFAULT_SLIPPERINESS)
+    }
+
+    fn move_with_collisions(&mut self, world: &World, feet: Vec3) -> Vec3 {
+        let motion = self.velocity;
+        let player = Aabb::player(feet);
+        let boxes = collision_boxes_in(world, &player.expand(motion).expand(Vec3::new(0.0, STEP_HEIGHT, 0.0)));
+
+        let (mut moved, mut offset) = move_box(&boxes, player, motion);
+        let collided_vertically = offset.y != motion.y;
+        let landed = collided_vertically && motion.y < 0.0;
+
+        // walks up blocks up to the step height (slabs, stairs) instead of stopping in front of them
+        if (self.on_ground || landed) && (offset.x != motion.x || offset.z != motion.z) {
+            let (stepped, step_offset) = move_box(&boxes, player, Vec3::new(motion.x, STEP_HEIGHT, motion.z));
+            // back down on top of the step
+            let down = boxes.iter().fold(-step_offset.y, |down, block| block.clip_y(&stepped, down));
+            let stepped = stepped.offset(Vec3::new(0.0, down, 0.0));
+            if step_offset.x * step_offset.x + step_offset.z * step_offset.z > offset.x * offset.x + offset.z * offset.z {
+                moved = stepped;
+                offset = Vec3::new(step_offset.x, step_offset.y + down, step_offset.z);
+            }
+        }
+
+        self.collided_horizontally = offset.x != motion.x || offset.z != motion.z;
+        self.collided_vertically = collided_vertically;
+        if offset.x != motion.x {
+            self.velocity.x = 0.0;
+        }
+        if offset.z != motion.z {
+            self.velocity.z = 0.0;
+        }
+        if collided_vertically {
+            self.velocity.y = 0.0;
+        }
+
+        self.fall_damage = 0.0;
+        self.on_ground = landed;
+        if self.on_ground {
+            self.fall_damage = self.estimate_fall_damage();
+            self.fall_distance = 0.0;
+        } else if offset.y < 0.0 && !self.in_water {
+            self.fall_distance -= offset.y;
+        }
+
+        Vec3::new(moved.min.x + PLAYER_WIDTH / 2.0, moved.min.y, moved.min.z + PLAYER_WIDTH / 2.0)
+    }
+}
+
+// Turns the input into a horizontal acceleration in the direction the player looks
+fn move_relative(forward: f32, strafe: f32, acceleration: f32, yaw: f32) -> Vec3 {
+    let length = (forward * forward + strafe * strafe).sqrt();
+    if length < 0.01 {
+        return Vec3::default();
+    }
+    let (forward, strafe) = (forward / length.max(1.0) * acceleration, strafe / length.max(1.0) * acceleration);
+    let (sin, cos) = yaw.to_radians().sin_cos();
+    Vec3::new(strafe * cos - forward * sin, 0.0, forward * cos + strafe * sin)
+}
+
+// Moves along y first, then x and z, every axis as far as the blocks allow. Returns the box and the actual motion.
+fn move_box(boxes: &[Aabb], player: Aabb, motion: Vec3) -> (Aabb, Vec3) {
+    let y = boxes.iter().fold(motion.y, |y, block| block.clip_y(&player, y));
+    let player = player.offset(Vec3::new(0.0, y, 0.0));
+    let x = boxes.iter().fold(motion.x, |x, block| block.clip_x(&player, x));
+    let player = player.offset(Vec3::new(x, 0.0, 0.0));
+    let z = boxes.iter().fold(motion.z, |z, block| block.clip_z(&player, z));
+    (player.offset(Vec3::new(0.0, 0.0, z)), Vec3::new(x, y, z))
+}
+
+fn for_each_block(area: &Aabb, mut f: impl FnMut(i32, i32, i32)) {
+    for x in area.min.x.floor() as i32..=area.max.x.floor() as i32 {
+        for y in area.min.y.floor() as i32..=area.max.y.floor() as i32 {
+            for z in area.min.z.floor() as i32..=area.max.z.floor() as i32 {
+                f(x, y, z);
+            }
+        }
+    }
+}
+
+// Boxes of the blocks in the area in world coordinates, blocks with an unknown runtime ID are solid
+fn collision_boxes_in(world: &World, area: &Aabb) -> Vec<Aabb> {
+    let mut boxes = Vec::new();
+    // one block lower for the blocks taller than a block (fences, walls)
+    let area = Aabb::new(area.min + Vec3::new(0.0, -1.0, 0.0), area.max);
+    for_each_block(&area, |x, y, z| {
+        let Some(runtime_id) = world.get_runtime_id(x, y, z) else {
+            return;
+        };
+        let block_boxes = match world.get_palette().and_then(|palette| palette.get_name(runtime_id)) {
+            Some(name) => collision_boxes(name),
+            None => vec![Aabb::new(Vec3::default(), Vec3::new(1.0, 1.0, 1.0))]
+        };
+        let corner = Vec3::new(x as f32, y as f32, z as f32);
+        boxes.extend(block_boxes.iter().map(|block| block.offset(corner)));
+    });
+    boxes
+}
@@ -1,7 +1,9 @@
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
 use crate::protocol::packet_ids::PacketType;
-use crate::utils::color_format;
-use crate::utils::color_format::COLOR_WHITE;
+use crate::utils::log_target;
+use log::trace;
 
 pub struct Acknowledge {
     pub packet_type: PacketType,
@@ -35,32 +37,32 @@ impl Acknowledge {
         stream.get_buffer()
     }
 
-    pub fn decode(bytes: Vec<u8>) -> Acknowledge {
+    pub fn decode(bytes: Vec<u8>) -> Result<Acknowledge, ClientError> {
         let mut stream = Stream::new(bytes, 0);
 
-        let packet_id = stream.get_byte();
+        let packet_id = stream.try_get_byte()?;
         let packet_type = PacketType::from_byte(packet_id);
-        let record_count = stream.get_short();
-        let single_sequence_number = stream.get_bool();
+        let record_count = stream.try_get_short()?;
+        let single_sequence_number = stream.try_get_bool()?;
         if single_sequence_number {
-            let sequence_number = stream.get_l_triad();
-            return Acknowledge{ packet_type, record_count, single_sequence_number, sequence_number: Option::from(sequence_number), start_sequence_number: None, end_sequence_number: None };
+            let sequence_number = stream.try_get_l_triad()?;
+            return Ok(Acknowledge{ packet_type, record_count, single_sequence_number, sequence_number: Option::from(sequence_number), start_sequence_number: None, end_sequence_number: None });
         }
-        let start_sequence_number = stream.get_l_triad();
-        let end_sequence_number = stream.get_l_triad();
-        Acknowledge{ packet_type, record_count, single_sequence_number, sequence_number: None, start_sequence_number: Option::from(start_sequence_number), end_sequence_number: Option::from(end_sequence_number) }
+        let start_sequence_number = stream.try_get_l_triad()?;
+        let end_sequence_number = stream.try_get_l_triad()?;
+        Ok(Acknowledge{ packet_type, record_count, single_sequence_number, sequence_number: None, start_sequence_number: Option::from(start_sequence_number), end_sequence_number: Option::from(end_sequence_number) })
     }
 
     pub fn debug(&self, is_nack: bool) {
         if is_nack {
-            println!("--- {}NACK{} ---", color_format::COLOR_RED, COLOR_WHITE);
+            trace!(target: log_target::RAKNET, "--- NACK ---");
         } else {
-            println!("--- {}ACK{} ---", color_format::COLOR_RED, COLOR_WHITE);
+            trace!(target: log_target::RAKNET, "--- ACK ---");
         }
-        println!("Record Count: Record Type {}", if self.record_count == 0 { "Range" } else { "Single" });
-        println!("Single Sequence Number: {}", self.single_sequence_number);
-        println!("Sequence Number: {:?}", self.sequence_number);
-        println!("Start Sequence Number: {:?}", self.start_sequence_number);
-        println!("End Sequence Number: {:?}", self.end_sequence_number);
+        trace!(target: log_target::RAKNET, "Record Count: Record Type {}", if self.record_count == 0 { "Range" } else { "Single" });
+        trace!(target: log_target::RAKNET, "Single Sequence Number: {}", self.single_sequence_number);
+        trace!(target: log_target::RAKNET, "Sequence Number: {:?}", self.sequence_number);
+        trace!(target: log_target::RAKNET, "Start Sequence Number: {:?}", self.start_sequence_number);
+        trace!(target: log_target::RAKNET, "End Sequence Number: {:?}", self.end_sequence_number);
     }
 }
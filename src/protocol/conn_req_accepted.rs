@@ -1,7 +1,9 @@
 use binary_utils::binary::Stream;
-use crate::utils::{address, color_format};
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+use crate::utils::{address, log_target};
 use crate::utils::address::InternetAddress;
-use crate::utils::color_format::COLOR_WHITE;
+use log::trace;
 
 pub struct ConnReqAccepted {
     pub client_address: InternetAddress,
@@ -12,38 +14,35 @@ pub struct ConnReqAccepted {
 }
 
 impl ConnReqAccepted {
-    pub fn decode(bytes: Vec<u8>) -> ConnReqAccepted {
+    pub fn decode(bytes: Vec<u8>) -> Result<ConnReqAccepted, ClientError> {
 
         let mut stream = Stream::new(bytes, 0);
 
-        let _ = stream.get_byte();
+        let _ = stream.try_get_byte()?;
 
-        let (client_address, offset) = address::get_address(stream.get_remaining().unwrap()).unwrap();
-        stream.set_offset(stream.get_offset() + offset);
-        let system_index = stream.get_short();
+        let client_address = address::read(&mut stream)?;
+        let system_index = stream.try_get_short()?;
 
         let mut system_addresses: [InternetAddress; 20] = core::array::from_fn(|_| address::new(4, "127.0.0.1".to_string(), 0));
 
-        for index in 0..20 {
-            let (system_address, offset) = address::get_address(stream.get_remaining().unwrap()).unwrap();
-            stream.set_offset(stream.get_offset() + offset);
-            system_addresses[index] = system_address;
+        for system_address in system_addresses.iter_mut() {
+            *system_address = address::read(&mut stream)?;
         }
 
-        let ping_time = stream.get_long();
-        let pong_time = stream.get_long();
+        let ping_time = stream.try_get_long()?;
+        let pong_time = stream.try_get_long()?;
 
-        ConnReqAccepted { client_address, system_index, system_addresses, ping_time, pong_time }
+        Ok(ConnReqAccepted { client_address, system_index, system_addresses, ping_time, pong_time })
     }
 
     pub fn debug(&self) {
-        println!("--- {}ConnectionRequestAccepted{} ---", color_format::COLOR_GOLD, COLOR_WHITE);
-        println!("Client Address: {}:{}", self.client_address.address, self.client_address.port);
-        println!("System Index: {}", self.system_index);
+        trace!(target: log_target::RAKNET, "--- ConnectionRequestAccepted ---");
+        trace!(target: log_target::RAKNET, "Client Address: {}:{}", self.client_address.address, self.client_address.port);
+        trace!(target: log_target::RAKNET, "System Index: {}", self.system_index);
         for index in 0..20 {
-            println!("System Address {}: {}:{}", index + 1, self.system_addresses[index].address, self.system_addresses[index].port);
+            trace!(target: log_target::RAKNET, "System Address {}: {}:{}", index + 1, self.system_addresses[index].address, self.system_addresses[index].port);
         }
-        println!("Ping Time: {}", self.ping_time);
-        println!("Pong Time: {}", self.ping_time);
+        trace!(target: log_target::RAKNET, "Ping Time: {}", self.ping_time);
+        trace!(target: log_target::RAKNET, "Pong Time: {}", self.ping_time);
     }
 }
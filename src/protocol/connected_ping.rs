@@ -1,7 +1,9 @@
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
 use crate::protocol::packet_ids::PacketType;
-use crate::utils::color_format;
-use crate::utils::color_format::COLOR_WHITE;
+use crate::utils::log_target;
+use log::trace;
 
 pub struct ConnectedPing {
     pub ping_time: i64
@@ -20,16 +22,16 @@ impl ConnectedPing {
         stream.get_buffer()
     }
 
-    pub fn decode(bytes: Vec<u8>) -> ConnectedPing {
+    pub fn decode(bytes: Vec<u8>) -> Result<ConnectedPing, ClientError> {
         let mut stream = Stream::new(bytes, 0);
 
-        let _ = stream.get_byte();
-        let ping_time = stream.get_long();
-        ConnectedPing{ ping_time }
+        let _ = stream.try_get_byte()?;
+        let ping_time = stream.try_get_long()?;
+        Ok(ConnectedPing{ ping_time })
     }
 
     pub fn debug(&self) {
-        println!("--- {}ConnectedPing{} ---", color_format::COLOR_GOLD, COLOR_WHITE);
-        println!("Ping Time: {:?}", self.ping_time);
+        trace!(target: log_target::RAKNET, "--- ConnectedPing ---");
+        trace!(target: log_target::RAKNET, "Ping Time: {:?}", self.ping_time);
     }
 }
@@ -1,7 +1,9 @@
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
 use crate::protocol::packet_ids::PacketType;
 use binary_utils::binary::Stream;
-use crate::utils::color_format;
-use crate::utils::color_format::COLOR_WHITE;
+use crate::utils::log_target;
+use log::trace;
 
 pub struct ConnectedPong {
     pub ping_time: i64,
@@ -22,18 +24,18 @@ impl ConnectedPong {
         stream.get_buffer()
     }
 
-    pub fn decode(bytes: Vec<u8>) -> ConnectedPong {
+    pub fn decode(bytes: Vec<u8>) -> Result<ConnectedPong, ClientError> {
         let mut stream = Stream::new(bytes, 0);
 
-        let _ = stream.get_byte();
-        let ping_time = stream.get_long();
-        let pong_time = stream.get_long();
-        ConnectedPong{ ping_time, pong_time }
+        let _ = stream.try_get_byte()?;
+        let ping_time = stream.try_get_long()?;
+        let pong_time = stream.try_get_long()?;
+        Ok(ConnectedPong{ ping_time, pong_time })
     }
 
     pub fn debug(&self) {
-        println!("--- {}ConnectedPong{} ---", color_format::COLOR_GOLD, COLOR_WHITE);
-        println!("Ping Time: {:?}", self.ping_time);
-        println!("Pong Time: {:?}", self.pong_time);
+        trace!(target: log_target::RAKNET, "--- ConnectedPong ---");
+        trace!(target: log_target::RAKNET, "Ping Time: {:?}", self.ping_time);
+        trace!(target: log_target::RAKNET, "Pong Time: {:?}", self.pong_time);
     }
 }
@@ -1,4 +1,6 @@
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
 
 pub const BITFLAG_VALID: u8 = 0x80;
 pub const BITFLAG_ACK: u8 = 0x40;
@@ -22,6 +24,9 @@ pub const RELIABILITY_FLAGS: u8 = 0b111 << RELIABILITY_SHIFT;
 
 pub const SPLIT_FLAG: u8 = 0b00010000;
 
+pub const MAX_ORDER_CHANNELS: usize = 32;
+pub const MAX_FRAME_BODY_SIZE: usize = 1300;
+
 pub struct Datagram {
     pub packet_id: u8,
     pub sequence_number: i32,
@@ -62,49 +67,34 @@ pub struct FrameNumberCache {
     pub sequence_number: i32,
     pub reliable_frame_index: i32,
     pub sequenced_frame_index: i32,
-    pub ordered_frame_index: i32,
-    pub order_channel: u8,
+    pub ordered_frame_index: [i32; MAX_ORDER_CHANNELS], // order_channel => ordered_frame_index
     pub compound_id: u16
 }
 
 impl Datagram {
-    pub fn create_frame(body: Vec<u8>, reliability: u8, frame_number_cache: &FrameNumberCache, fragment: Option<Fragment>) -> Frame {
-        match reliability {
-            UNRELIABLE => {
-                Frame{
-                    flags: reliability << 5,
-                    length_in_bits: (body.len() << 3) as u16,
-                    reliable_frame_index: None,
-                    sequenced_frame_index: None,
-                    order: None,
-                    fragment: None,
-                    body,
-                }
-            },
-            RELIABLE => {
-                Frame{
-                    flags: reliability << 5,
-                    length_in_bits: (body.len() << 3) as u16,
-                    reliable_frame_index: Option::from(frame_number_cache.reliable_frame_index),
-                    sequenced_frame_index: None,
-                    order: None,
-                    fragment: None,
-                    body,
-                }
-            },
-            RELIABLE_ORDERED => { // devam et buraya... fragment olarak gönderme sorunu var
-                //let total_length: usize = bodies.iter().map(|v| v.len()).sum();
-                Frame{
-                    flags: (reliability << 5) | if fragment.is_some() { SPLIT_FLAG } else { 0 }, // buraya split flagını ekle eğer fragment var ise
-                    length_in_bits: (body.len() << 3) as u16,
-                    reliable_frame_index: Option::from(frame_number_cache.reliable_frame_index),
-                    sequenced_frame_index: None,
-                    order: Option::from(Order { ordered_frame_index: frame_number_cache.ordered_frame_index, order_channel: frame_number_cache.order_channel }),
-                    fragment,
-                    body,
-                }
-            },
-            _ => { Frame{ flags: 0, length_in_bits: 0, reliable_frame_index: None, sequenced_frame_index: None, order: None, fragment: None, body: vec![] } }
+    pub fn create_frame(body: Vec<u8>, reliability: u8, order_channel: u8, frame_number_cache: &FrameNumberCache, fragment: Option<Fragment>) -> Frame {
+        let mut reliable_frame_index = None;
+        let mut sequenced_frame_index = None;
+        let mut order = None;
+
+        if is_reliable(reliability) {
+            reliable_frame_index = Option::from(frame_number_cache.reliable_frame_index);
+        }
+        if is_sequenced(reliability) {
+            sequenced_frame_index = Option::from(frame_number_cache.sequenced_frame_index);
+        }
+        if is_sequenced_or_ordered(reliability) {
+            order = Option::from(Order { ordered_frame_index: frame_number_cache.ordered_frame_index[order_channel as usize], order_channel });
+        }
+
+        Frame{
+            flags: (reliability << RELIABILITY_SHIFT) | if fragment.is_some() { SPLIT_FLAG } else { 0 },
+            length_in_bits: (body.len() << 3) as u16,
+            reliable_frame_index,
+            sequenced_frame_index,
+            order,
+            fragment,
+            body,
         }
     }
 
@@ -116,69 +106,85 @@ impl Datagram {
         }
     }
 
-    pub fn split_packet(body: Vec<u8>, frame_number_cache: &mut FrameNumberCache) -> Vec<Datagram> {
+    pub fn split_packet(body: Vec<u8>, reliability: u8, order_channel: u8, frame_number_cache: &mut FrameNumberCache) -> Vec<Datagram> {
+        let mut reliability = reliability;
         let mut datagrams: Vec<Datagram> = Vec::new();
-        if body.len() > 1300 {
-            let multiple = body.len() / 1300;
+        let mut fragments: Vec<(Vec<u8>, Option<Fragment>)> = Vec::new();
+        if body.len() > MAX_FRAME_BODY_SIZE {
+            // split packets have to be reliable, otherwise the receiver can never complete the compound
+            if reliability == UNRELIABLE {
+                reliability = RELIABLE;
+            } else if reliability == UNRELIABLE_SEQUENCED {
+                reliability = RELIABLE_SEQUENCED;
+            }
+
+            let multiple = body.len() / MAX_FRAME_BODY_SIZE;
             let compound_size = multiple + 1;
 
             for i in 0..=multiple {
-                let range = if i == multiple { body[(i*1300)..].to_vec() } else { body[(i*1300)..((i+1) * 1300)].to_vec() };
-                let frame = Datagram::create_frame(range, RELIABLE_ORDERED, frame_number_cache, Option::from(Fragment{
+                let range = if i == multiple { body[(i*MAX_FRAME_BODY_SIZE)..].to_vec() } else { body[(i*MAX_FRAME_BODY_SIZE)..((i+1) * MAX_FRAME_BODY_SIZE)].to_vec() };
+                fragments.push((range, Option::from(Fragment{
                     compound_size: compound_size as u32,
                     compound_id: frame_number_cache.compound_id,
                     index: i as u32,
-                }));
-                datagrams.push(Datagram::create(vec![frame], frame_number_cache));
-                frame_number_cache.sequence_number += 1;
-                frame_number_cache.reliable_frame_index += 1;
+                })));
             }
-            frame_number_cache.ordered_frame_index += 1;
             frame_number_cache.compound_id += 1;
         } else {
-            let frame = Datagram::create_frame(body, RELIABLE_ORDERED, frame_number_cache, None);
+            fragments.push((body, None));
+        }
+
+        for (range, fragment) in fragments {
+            let frame = Datagram::create_frame(range, reliability, order_channel, frame_number_cache, fragment);
             datagrams.push(Datagram::create(vec![frame], frame_number_cache));
             frame_number_cache.sequence_number += 1;
-            frame_number_cache.reliable_frame_index += 1;
-            frame_number_cache.ordered_frame_index += 1;
+            if is_reliable(reliability) {
+                frame_number_cache.reliable_frame_index += 1;
+            }
+            if is_sequenced(reliability) {
+                frame_number_cache.sequenced_frame_index += 1;
+            }
+        }
+        if is_ordered(reliability) {
+            frame_number_cache.ordered_frame_index[order_channel as usize] += 1;
         }
         datagrams
     }
     
-    pub fn from_binary(frame_packet: Vec<u8>) -> Datagram {// fragment handler kısmı yok - belki burda sunucunun sequenceini kontrol ederiz ona göre nack gönderirirz
+    pub fn from_binary(frame_packet: Vec<u8>) -> Result<Datagram, ClientError> {// fragment handler kısmı yok - belki burda sunucunun sequenceini kontrol ederiz ona göre nack gönderirirz
         let mut stream = Stream::new(frame_packet, 0);
-        let packet_id = stream.get_byte();
-        let sequence_number = stream.get_l_triad();
+        let packet_id = stream.try_get_byte()?;
+        let sequence_number = stream.try_get_l_triad()?;
         let mut frames: Vec<Frame> = Vec::new();
         while !stream.feof() {
-            let flags = stream.get_byte();
+            let flags = stream.try_get_byte()?;
             let reliability = (flags & RELIABILITY_FLAGS) >> RELIABILITY_SHIFT;
             let has_split = (flags & SPLIT_FLAG) != 0;
-            let length_in_bits = stream.get_short();
+            let length_in_bits = stream.try_get_short()?;
             let (mut reliable_frame_index, mut sequenced_frame_index, mut order, mut fragment) = (None, None, None, None);
 
             if is_reliable(reliability) {
-                reliable_frame_index = Option::from(stream.get_l_triad());
+                reliable_frame_index = Option::from(stream.try_get_l_triad()?);
             }
 
             if is_sequenced(reliability) {
-                sequenced_frame_index = Option::from(stream.get_l_triad());
+                sequenced_frame_index = Option::from(stream.try_get_l_triad()?);
             }
 
             if is_sequenced_or_ordered(reliability) {
-                order = Option::from(Order { ordered_frame_index: stream.get_l_triad(), order_channel: stream.get_byte() })
+                order = Option::from(Order { ordered_frame_index: stream.try_get_l_triad()?, order_channel: stream.try_get_byte()? })
             }
 
             if has_split {
-                fragment = Option::from(Fragment{ compound_size: stream.get_int(), compound_id: stream.get_short(), index: stream.get_int() })
+                fragment = Option::from(Fragment{ compound_size: stream.try_get_int()?, compound_id: stream.try_get_short()?, index: stream.try_get_int()? })
             }
 
-            let body = stream.get(((length_in_bits as f64) / 8.0).ceil() as u32).unwrap();
+            let body = stream.try_get(((length_in_bits as f64) / 8.0).ceil() as u32)?;
 
             frames.push(Frame{ flags, length_in_bits, reliable_frame_index, sequenced_frame_index, order, fragment, body });
         }
 
-        Datagram{ packet_id, sequence_number, frames }
+        Ok(Datagram{ packet_id, sequence_number, frames })
     }
 
     pub fn to_binary(&self) -> Vec<u8> {
@@ -217,7 +223,7 @@ impl Datagram {
 }
 
 pub fn start_number_cache() -> FrameNumberCache {
-    FrameNumberCache{ sequence_number: 0, reliable_frame_index: 0, sequenced_frame_index: 0, ordered_frame_index: 0, order_channel: 0, compound_id: 0 }
+    FrameNumberCache{ sequence_number: 0, reliable_frame_index: 0, sequenced_frame_index: 0, ordered_frame_index: [0; MAX_ORDER_CHANNELS], compound_id: 0 }
 }
 
 pub fn is_datagram(packet_id: u8) -> bool {
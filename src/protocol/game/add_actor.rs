@@ -0,0 +1,97 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::types::actor_link::ActorLink;
+use crate::protocol::game::types::actor_metadata::ActorMetadata;
+use crate::protocol::game::types::attribute::AttributeValue;
+use crate::protocol::game::types::property_sync_data::PropertySyncData;
+use crate::protocol::game::types::vector::Vec3;
+use crate::protocol::game::serializer::stream_helper::{get_string, put_string, StreamReader};
+
+// Spawns any entity that is not a player or a dropped item
+pub struct AddActor {
+    pub actor_unique_id: i64,
+    pub actor_runtime_id: u64,
+    pub type_id: String, // e.g. minecraft:zombie
+    pub position: Vec3,
+    pub motion: Vec3,
+    pub pitch: f32,
+    pub yaw: f32,
+    pub head_yaw: f32,
+    pub body_yaw: f32,
+    pub attributes: Vec<AttributeValue>,
+    pub metadata: ActorMetadata,
+    pub properties: PropertySyncData,
+    pub links: Vec<ActorLink>
+}
+
+pub fn new(actor_unique_id: i64, actor_runtime_id: u64, type_id: String, position: Vec3) -> AddActor {
+    AddActor{
+        actor_unique_id,
+        actor_runtime_id,
+        type_id,
+        position,
+        motion: Vec3::default(),
+        pitch: 0.0,
+        yaw: 0.0,
+        head_yaw: 0.0,
+        body_yaw: 0.0,
+        attributes: vec![],
+        metadata: ActorMetadata::new(),
+        properties: PropertySyncData::default(),
+        links: vec![]
+    }
+}
+
+impl BedrockPacket for AddActor {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::AddActor);
+
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_var_long(self.actor_unique_id);
+        stream.put_unsigned_var_long(self.actor_runtime_id);
+        put_string(stream, &self.type_id);
+        self.position.write(stream);
+        self.motion.write(stream);
+        stream.put_l_float(self.pitch);
+        stream.put_l_float(self.yaw);
+        stream.put_l_float(self.head_yaw);
+        stream.put_l_float(self.body_yaw);
+        stream.put_unsigned_var_int(self.attributes.len() as u32);
+        for attribute in &self.attributes {
+            attribute.write(stream);
+        }
+        self.metadata.write(stream);
+        self.properties.write(stream);
+        stream.put_unsigned_var_int(self.links.len() as u32);
+        for link in &self.links {
+            link.write(stream);
+        }
+    }
+
+    fn decode(stream: &mut Stream) -> Result<AddActor, ClientError> {
+        let actor_unique_id = stream.try_get_var_long()?;
+        let actor_runtime_id = stream.try_get_unsigned_var_long()?;
+        let type_id = get_string(stream)?;
+        let position = Vec3::read(stream)?;
+        let motion = Vec3::read(stream)?;
+        let pitch = stream.try_get_l_float()?;
+        let yaw = stream.try_get_l_float()?;
+        let head_yaw = stream.try_get_l_float()?;
+        let body_yaw = stream.try_get_l_float()?;
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut attributes = Vec::new();
+        for _ in 0..count {
+            attributes.push(AttributeValue::read(stream)?);
+        }
+        let metadata = ActorMetadata::read(stream)?;
+        let properties = PropertySyncData::read(stream)?;
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut links = Vec::new();
+        for _ in 0..count {
+            links.push(ActorLink::read(stream)?);
+        }
+
+        Ok(AddActor{ actor_unique_id, actor_runtime_id, type_id, position, motion, pitch, yaw, head_yaw, body_yaw, attributes, metadata, properties, links })
+    }
+}
@@ -0,0 +1,49 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::types::actor_metadata::ActorMetadata;
+use crate::protocol::game::types::item_stack::ItemStack;
+use crate::protocol::game::types::vector::Vec3;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+
+// Spawns a dropped item
+pub struct AddItemActor {
+    pub actor_unique_id: i64,
+    pub actor_runtime_id: u64,
+    pub item: ItemStack,
+    pub position: Vec3,
+    pub motion: Vec3,
+    pub metadata: ActorMetadata,
+    pub is_from_fishing: bool
+}
+
+pub fn new(actor_unique_id: i64, actor_runtime_id: u64, item: ItemStack, position: Vec3) -> AddItemActor {
+    AddItemActor{ actor_unique_id, actor_runtime_id, item, position, motion: Vec3::default(), metadata: ActorMetadata::new(), is_from_fishing: false }
+}
+
+impl BedrockPacket for AddItemActor {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::AddItemActor);
+
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_var_long(self.actor_unique_id);
+        stream.put_unsigned_var_long(self.actor_runtime_id);
+        self.item.write(stream);
+        self.position.write(stream);
+        self.motion.write(stream);
+        self.metadata.write(stream);
+        stream.put_bool(self.is_from_fishing);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<AddItemActor, ClientError> {
+        let actor_unique_id = stream.try_get_var_long()?;
+        let actor_runtime_id = stream.try_get_unsigned_var_long()?;
+        let item = ItemStack::read(stream)?;
+        let position = Vec3::read(stream)?;
+        let motion = Vec3::read(stream)?;
+        let metadata = ActorMetadata::read(stream)?;
+        let is_from_fishing = stream.try_get_bool()?;
+
+        Ok(AddItemActor{ actor_unique_id, actor_runtime_id, item, position, motion, metadata, is_from_fishing })
+    }
+}
@@ -0,0 +1,114 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::types::abilities::AbilityData;
+use crate::protocol::game::types::actor_link::ActorLink;
+use crate::protocol::game::types::actor_metadata::ActorMetadata;
+use crate::protocol::game::types::item_stack::ItemStack;
+use crate::protocol::game::types::property_sync_data::PropertySyncData;
+use crate::protocol::game::types::vector::Vec3;
+use uuid::Uuid;
+use crate::protocol::game::serializer::stream_helper::{get_string, put_string, StreamReader, get_uuid};
+
+// Spawns another player, its unique ID is in the abilities data
+pub struct AddPlayer {
+    pub uuid: Uuid,
+    pub username: String,
+    pub actor_runtime_id: u64,
+    pub platform_chat_id: String,
+    pub position: Vec3,
+    pub motion: Vec3,
+    pub pitch: f32,
+    pub yaw: f32,
+    pub head_yaw: f32,
+    pub held_item: ItemStack,
+    pub game_mode: i32,
+    pub metadata: ActorMetadata,
+    pub properties: PropertySyncData,
+    pub abilities: AbilityData,
+    pub links: Vec<ActorLink>,
+    pub device_id: String,
+    pub build_platform: i32
+}
+
+pub fn new(uuid: Uuid, username: String, actor_unique_id: i64, actor_runtime_id: u64, position: Vec3) -> AddPlayer {
+    AddPlayer{
+        uuid,
+        username,
+        actor_runtime_id,
+        platform_chat_id: String::new(),
+        position,
+        motion: Vec3::default(),
+        pitch: 0.0,
+        yaw: 0.0,
+        head_yaw: 0.0,
+        held_item: ItemStack::default(),
+        game_mode: 0,
+        metadata: ActorMetadata::new(),
+        properties: PropertySyncData::default(),
+        abilities: AbilityData{ target_actor_unique_id: actor_unique_id, ..AbilityData::default() },
+        links: vec![],
+        device_id: String::new(),
+        build_platform: -1
+    }
+}
+
+impl AddPlayer {
+    pub fn get_actor_unique_id(&self) -> i64 {
+        self.abilities.target_actor_unique_id
+    }
+}
+
+impl BedrockPacket for AddPlayer {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::AddPlayer);
+
+    fn encode(&self, stream: &mut Stream) {
+        stream.put(self.uuid.as_bytes().to_vec());
+        put_string(stream, &self.username);
+        stream.put_unsigned_var_long(self.actor_runtime_id);
+        put_string(stream, &self.platform_chat_id);
+        self.position.write(stream);
+        self.motion.write(stream);
+        stream.put_l_float(self.pitch);
+        stream.put_l_float(self.yaw);
+        stream.put_l_float(self.head_yaw);
+        self.held_item.write(stream);
+        stream.put_var_int(self.game_mode);
+        self.metadata.write(stream);
+        self.properties.write(stream);
+        self.abilities.write(stream);
+        stream.put_unsigned_var_int(self.links.len() as u32);
+        for link in &self.links {
+            link.write(stream);
+        }
+        put_string(stream, &self.device_id);
+        stream.put_l_int(self.build_platform as u32);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<AddPlayer, ClientError> {
+        let uuid = get_uuid(stream)?;
+        let username = get_string(stream)?;
+        let actor_runtime_id = stream.try_get_unsigned_var_long()?;
+        let platform_chat_id = get_string(stream)?;
+        let position = Vec3::read(stream)?;
+        let motion = Vec3::read(stream)?;
+        let pitch = stream.try_get_l_float()?;
+        let yaw = stream.try_get_l_float()?;
+        let head_yaw = stream.try_get_l_float()?;
+        let held_item = ItemStack::read(stream)?;
+        let game_mode = stream.try_get_var_int()?;
+        let metadata = ActorMetadata::read(stream)?;
+        let properties = PropertySyncData::read(stream)?;
+        let abilities = AbilityData::read(stream)?;
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut links = Vec::new();
+        for _ in 0..count {
+            links.push(ActorLink::read(stream)?);
+        }
+        let device_id = get_string(stream)?;
+        let build_platform = stream.try_get_l_int()? as i32;
+
+        Ok(AddPlayer{ uuid, username, actor_runtime_id, platform_chat_id, position, motion, pitch, yaw, head_yaw, held_item, game_mode, metadata, properties, abilities, links, device_id, build_platform })
+    }
+}
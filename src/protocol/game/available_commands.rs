@@ -0,0 +1,95 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::types::command_data::{ChainedSubcommand, CommandData, CommandEnum, CommandEnumConstraint, SoftEnum};
+use crate::protocol::game::serializer::stream_helper::{get_strings, put_strings, StreamReader};
+
+// Commands as they are on the wire, `CommandTree` resolves the indices
+pub struct AvailableCommands {
+    pub enum_values: Vec<String>,
+    pub chained_subcommand_values: Vec<String>,
+    pub postfixes: Vec<String>,
+    pub enums: Vec<CommandEnum>,
+    pub chained_subcommands: Vec<ChainedSubcommand>,
+    pub commands: Vec<CommandData>,
+    pub soft_enums: Vec<SoftEnum>,
+    pub enum_constraints: Vec<CommandEnumConstraint>
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn new(
+    enum_values: Vec<String>,
+    chained_subcommand_values: Vec<String>,
+    postfixes: Vec<String>,
+    enums: Vec<CommandEnum>,
+    chained_subcommands: Vec<ChainedSubcommand>,
+    commands: Vec<CommandData>,
+    soft_enums: Vec<SoftEnum>,
+    enum_constraints: Vec<CommandEnumConstraint>
+) -> AvailableCommands {
+    AvailableCommands{ enum_values, chained_subcommand_values, postfixes, enums, chained_subcommands, commands, soft_enums, enum_constraints }
+}
+
+impl BedrockPacket for AvailableCommands {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::AvailableCommands);
+
+    fn encode(&self, stream: &mut Stream) {
+        put_strings(stream, &self.enum_values);
+        put_strings(stream, &self.chained_subcommand_values);
+        put_strings(stream, &self.postfixes);
+        stream.put_unsigned_var_int(self.enums.len() as u32);
+        for command_enum in &self.enums {
+            command_enum.write(stream, self.enum_values.len());
+        }
+        stream.put_unsigned_var_int(self.chained_subcommands.len() as u32);
+        for chained_subcommand in &self.chained_subcommands {
+            chained_subcommand.write(stream);
+        }
+        stream.put_unsigned_var_int(self.commands.len() as u32);
+        for command in &self.commands {
+            command.write(stream);
+        }
+        stream.put_unsigned_var_int(self.soft_enums.len() as u32);
+        for soft_enum in &self.soft_enums {
+            soft_enum.write(stream);
+        }
+        stream.put_unsigned_var_int(self.enum_constraints.len() as u32);
+        for constraint in &self.enum_constraints {
+            constraint.write(stream);
+        }
+    }
+
+    fn decode(stream: &mut Stream) -> Result<AvailableCommands, ClientError> {
+        let enum_values = get_strings(stream)?;
+        let chained_subcommand_values = get_strings(stream)?;
+        let postfixes = get_strings(stream)?;
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut enums = Vec::new();
+        for _ in 0..count {
+            enums.push(CommandEnum::read(stream, enum_values.len())?);
+        }
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut chained_subcommands = Vec::new();
+        for _ in 0..count {
+            chained_subcommands.push(ChainedSubcommand::read(stream)?);
+        }
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut commands = Vec::new();
+        for _ in 0..count {
+            commands.push(CommandData::read(stream)?);
+        }
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut soft_enums = Vec::new();
+        for _ in 0..count {
+            soft_enums.push(SoftEnum::read(stream)?);
+        }
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut enum_constraints = Vec::new();
+        for _ in 0..count {
+            enum_constraints.push(CommandEnumConstraint::read(stream)?);
+        }
+
+        Ok(AvailableCommands{ enum_values, chained_subcommand_values, postfixes, enums, chained_subcommands, commands, soft_enums, enum_constraints })
+    }
+}
@@ -0,0 +1,39 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::protocol_version;
+
+pub trait BedrockPacket {
+    const ID: u16;
+
+    // Writes the packet body, the header (packet id) is written by `encode_packet`.
+    fn encode(&self, stream: &mut Stream);
+
+    // Reads the packet body, the stream offset must be right after the packet id.
+    fn decode(stream: &mut Stream) -> Result<Self, ClientError> where Self: Sized;
+
+    // Packets whose layout changed between protocol versions override these, the others use the same layout for every version
+    fn encode_versioned(&self, stream: &mut Stream, _protocol: u32) {
+        self.encode(stream);
+    }
+
+    fn decode_versioned(stream: &mut Stream, _protocol: u32) -> Result<Self, ClientError> where Self: Sized {
+        Self::decode(stream)
+    }
+}
+
+// Packet as it is stored inside a game packet batch: length + packet id + body
+pub fn encode_packet<P: BedrockPacket>(packet: &P) -> Vec<u8> {
+    encode_packet_versioned(packet, protocol_version::LATEST)
+}
+
+pub fn encode_packet_versioned<P: BedrockPacket>(packet: &P, protocol: u32) -> Vec<u8> {
+    let mut stream = Stream::new(Vec::new(), 0);
+    stream.put_unsigned_var_int(P::ID as u32);
+    packet.encode_versioned(&mut stream, protocol);
+
+    let mut batch_stream = Stream::new(Vec::new(), 0);
+    batch_stream.put_unsigned_var_int(stream.get_buffer().len() as u32);
+    batch_stream.put(stream.get_buffer());
+
+    batch_stream.get_buffer()
+}
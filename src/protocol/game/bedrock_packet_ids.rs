@@ -419,7 +419,7 @@ impl BedrockPacketType {
             _ => BedrockPacketType::Unknown,
         }
     }
-    pub(crate) fn get_byte(self) -> u16 {
+    pub(crate) const fn get_byte(self) -> u16 {
         match self {
             BedrockPacketType::Login => 0x01,
             BedrockPacketType::PlayStatus => 0x02,
@@ -1,5 +1,8 @@
+use crate::protocol::game::bedrock_packet::BedrockPacket;
 use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
 
 pub struct ClientCacheStatus {
     enabled: bool
@@ -9,17 +12,14 @@ pub fn new(enabled: bool) -> ClientCacheStatus {
     ClientCacheStatus { enabled }
 }
 
-impl ClientCacheStatus {
-    pub fn encode(&mut self) -> Vec<u8> {
-        let mut stream = Stream::new(Vec::new(), 0);
-        stream.put_unsigned_var_int(BedrockPacketType::get_byte(BedrockPacketType::ClientCacheStatus) as u32);
+impl BedrockPacket for ClientCacheStatus {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::ClientCacheStatus);
 
+    fn encode(&self, stream: &mut Stream) {
         stream.put_bool(self.enabled);
+    }
 
-        let mut compress_stream = Stream::new(Vec::new(), 0);
-        compress_stream.put_unsigned_var_int(stream.get_buffer().len() as u32);
-        compress_stream.put(stream.get_buffer());
-
-        compress_stream.get_buffer()
+    fn decode(stream: &mut Stream) -> Result<ClientCacheStatus, ClientError> {
+        Ok(ClientCacheStatus{ enabled: stream.try_get_bool()? })
     }
 }
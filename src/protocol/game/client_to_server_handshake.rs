@@ -1,5 +1,7 @@
+use crate::protocol::game::bedrock_packet::BedrockPacket;
 use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
 
 pub struct ClientToServerHandshake {
 }
@@ -8,15 +10,12 @@ pub fn new() -> ClientToServerHandshake {
     ClientToServerHandshake{}
 }
 
-impl ClientToServerHandshake {
-    pub fn encode(&mut self) -> Vec<u8> {
-        let mut stream = Stream::new(Vec::new(), 0);
-        stream.put_unsigned_var_int(BedrockPacketType::get_byte(BedrockPacketType::ClientToServerHandshake) as u32);
+impl BedrockPacket for ClientToServerHandshake {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::ClientToServerHandshake);
 
-        let mut compress_stream = Stream::new(Vec::new(), 0);
-        compress_stream.put_unsigned_var_int(stream.get_buffer().len() as u32);
-        compress_stream.put(stream.get_buffer());
+    fn encode(&self, _stream: &mut Stream) {}
 
-        compress_stream.get_buffer()
+    fn decode(_stream: &mut Stream) -> Result<ClientToServerHandshake, ClientError> {
+        Ok(ClientToServerHandshake{})
     }
 }
\ No newline at end of file
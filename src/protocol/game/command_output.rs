@@ -0,0 +1,85 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::text::TextMessage;
+use crate::protocol::game::types::command_origin_data::CommandOriginData;
+use crate::protocol::game::serializer::stream_helper::{get_string, put_string, StreamReader};
+
+pub const TYPE_NONE: u8 = 0;
+pub const TYPE_LAST_OUTPUT: u8 = 1;
+pub const TYPE_SILENT: u8 = 2;
+pub const TYPE_ALL_OUTPUT: u8 = 3;
+pub const TYPE_DATA_SET: u8 = 4;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandOutputMessage {
+    pub success: bool,
+    pub message: String, // usually a translation key, e.g. "commands.time.set"
+    pub parameters: Vec<String>
+}
+
+impl CommandOutputMessage {
+    // Same shape as a translated Text message, so it can be rendered the same way
+    pub fn to_text_message(&self) -> TextMessage {
+        TextMessage::Translation{ message: self.message.clone(), parameters: self.parameters.clone() }
+    }
+}
+
+pub struct CommandOutput {
+    pub origin_data: CommandOriginData, // copied from the CommandRequest
+    pub output_type: u8,
+    pub success_count: u32,
+    pub messages: Vec<CommandOutputMessage>,
+    pub data_set: String // only for TYPE_DATA_SET
+}
+
+pub fn new(origin_data: CommandOriginData, output_type: u8, success_count: u32, messages: Vec<CommandOutputMessage>, data_set: String) -> CommandOutput {
+    CommandOutput{ origin_data, output_type, success_count, messages, data_set }
+}
+
+impl BedrockPacket for CommandOutput {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::CommandOutput);
+
+    fn encode(&self, stream: &mut Stream) {
+        self.origin_data.write(stream);
+        stream.put_byte(self.output_type);
+        stream.put_unsigned_var_int(self.success_count);
+        stream.put_unsigned_var_int(self.messages.len() as u32);
+        for message in &self.messages {
+            stream.put_bool(message.success);
+            put_string(stream, &message.message);
+            stream.put_unsigned_var_int(message.parameters.len() as u32);
+            for parameter in &message.parameters {
+                put_string(stream, parameter);
+            }
+        }
+        if self.output_type == TYPE_DATA_SET {
+            put_string(stream, &self.data_set);
+        }
+    }
+
+    fn decode(stream: &mut Stream) -> Result<CommandOutput, ClientError> {
+        let origin_data = CommandOriginData::read(stream)?;
+        let output_type = stream.try_get_byte()?;
+        let success_count = stream.try_get_unsigned_var_int()?;
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut messages = Vec::new();
+        for _ in 0..count {
+            let success = stream.try_get_bool()?;
+            let message = get_string(stream)?;
+            let parameter_count = stream.try_get_unsigned_var_int()?;
+            let mut parameters = Vec::new();
+            for _ in 0..parameter_count {
+                parameters.push(get_string(stream)?);
+            }
+            messages.push(CommandOutputMessage{ success, message, parameters });
+        }
+        let mut data_set = String::new();
+        if output_type == TYPE_DATA_SET {
+            data_set = get_string(stream)?;
+        }
+
+        Ok(CommandOutput{ origin_data, output_type, success_count, messages, data_set })
+    }
+}
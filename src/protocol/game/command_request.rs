@@ -0,0 +1,41 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::types::command_origin_data::CommandOriginData;
+use crate::protocol::game::serializer::stream_helper::{StreamReader, get_string};
+
+// Command syntax version the client speaks
+pub const COMMAND_VERSION: i32 = 39;
+
+pub struct CommandRequest {
+    pub command: String, // with the leading "/"
+    pub origin_data: CommandOriginData,
+    pub is_internal: bool,
+    pub version: i32
+}
+
+pub fn new(command: String, origin_data: CommandOriginData, is_internal: bool, version: i32) -> CommandRequest {
+    CommandRequest{ command, origin_data, is_internal, version }
+}
+
+impl BedrockPacket for CommandRequest {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::CommandRequest);
+
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_unsigned_var_int(self.command.len() as u32);
+        stream.put(self.command.clone().into_bytes());
+        self.origin_data.write(stream);
+        stream.put_bool(self.is_internal);
+        stream.put_var_int(self.version);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<CommandRequest, ClientError> {
+        let command = get_string(stream)?;
+        let origin_data = CommandOriginData::read(stream)?;
+        let is_internal = stream.try_get_bool()?;
+        let version = stream.try_get_var_int()?;
+
+        Ok(CommandRequest{ command, origin_data, is_internal, version })
+    }
+}
@@ -1,4 +1,8 @@
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::serializer::stream_helper::{StreamReader, get_string};
 
 pub struct Disconnect {
     pub reason: i32,
@@ -7,21 +11,38 @@ pub struct Disconnect {
     pub filtered_message: Option<String>
 }
 
-pub fn decode(bytes: Vec<u8>) -> Disconnect {
-    let mut stream = Stream::new(bytes, 0);
+pub fn new(reason: i32, message: Option<String>, filtered_message: Option<String>) -> Disconnect {
+    Disconnect{ reason, skip_message: message.is_none(), message, filtered_message }
+}
 
-    let reason = stream.get_var_int();  // bunda da sıkıntı var gibi?
-    let skip_message = stream.get_bool();
-    let mut message: Option<String> = None;
-    let mut filtered_message: Option<String> = None;
+impl BedrockPacket for Disconnect {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::Disconnect);
 
-    if !skip_message {
-        let mut length = stream.get_unsigned_var_int();
-        message = Option::from(String::from_utf8(stream.get(length).unwrap()).unwrap());
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_var_int(self.reason);
+        stream.put_bool(self.skip_message);
+        if !self.skip_message {
+            let message = self.message.clone().unwrap_or_default();
+            stream.put_unsigned_var_int(message.len() as u32);
+            stream.put(message.into_bytes());
 
-        length = stream.get_unsigned_var_int();
-        filtered_message = Option::from(String::from_utf8(stream.get(length).unwrap()).unwrap());
+            let filtered_message = self.filtered_message.clone().unwrap_or_default();
+            stream.put_unsigned_var_int(filtered_message.len() as u32);
+            stream.put(filtered_message.into_bytes());
+        }
     }
 
-    Disconnect { reason, skip_message, message, filtered_message }
+    fn decode(stream: &mut Stream) -> Result<Disconnect, ClientError> {
+        let reason = stream.try_get_var_int()?;  // bunda da sıkıntı var gibi?
+        let skip_message = stream.try_get_bool()?;
+        let mut message: Option<String> = None;
+        let mut filtered_message: Option<String> = None;
+
+        if !skip_message {
+            message = Option::from(get_string(stream)?);
+            filtered_message = Option::from(get_string(stream)?);
+        }
+
+        Ok(Disconnect { reason, skip_message, message, filtered_message })
+    }
 }
\ No newline at end of file
@@ -1,4 +1,10 @@
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use log::warn;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::utils::log_target;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
 
 pub struct LevelChunk {
     pub chunk_x: i32,
@@ -23,48 +29,78 @@ const CLIENT_REQUEST_TRUNCATED_COLUMN_FAKE_COUNT: u32 = u32::MAX - 1;
 //this appears large enough for a world height of 1024 blocks - it may need to be increased in the future
 const MAX_BLOB_HASHES: u32 = 64;
 
-pub fn decode(bytes: Vec<u8>) -> LevelChunk {
-    let mut stream = Stream::new(bytes, 0);
+impl BedrockPacket for LevelChunk {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::LevelChunk);
 
-    let chunk_x = stream.get_var_int();
-    let chunk_z = stream.get_var_int();
-    let dimension_id = stream.get_var_int();
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_var_int(self.chunk_x);
+        stream.put_var_int(self.chunk_z);
+        stream.put_var_int(self.dimension_id);
 
-    let mut sub_chunk_count: isize = 0;
-    let mut client_sub_chunk_requests_enabled: bool = false;
+        if self.client_sub_chunk_requests_enabled {
+            if self.sub_chunk_count == isize::MAX {
+                stream.put_unsigned_var_int(CLIENT_REQUEST_FULL_COLUMN_FAKE_COUNT);
+            } else {
+                stream.put_unsigned_var_int(CLIENT_REQUEST_TRUNCATED_COLUMN_FAKE_COUNT);
+                stream.put_l_short(self.sub_chunk_count as u16);
+            }
+        } else {
+            stream.put_unsigned_var_int(self.sub_chunk_count as u32);
+        }
 
+        stream.put_bool(self.used_blob_hashes.is_some());
+        if let Some(used_blob_hashes) = &self.used_blob_hashes {
+            stream.put_unsigned_var_int(used_blob_hashes.len() as u32);
+            for blob_hash in used_blob_hashes {
+                stream.put_l_long(*blob_hash);
+            }
+        }
 
-    let sub_chunk_count_but_not_really = stream.get_unsigned_var_int();
-    if sub_chunk_count_but_not_really == CLIENT_REQUEST_FULL_COLUMN_FAKE_COUNT {
-        client_sub_chunk_requests_enabled = true;
-        sub_chunk_count = isize::MAX;
-    } else if sub_chunk_count_but_not_really == CLIENT_REQUEST_TRUNCATED_COLUMN_FAKE_COUNT {
-        client_sub_chunk_requests_enabled = true;
-        sub_chunk_count = stream.get_l_short() as isize;
-    } else {
-        client_sub_chunk_requests_enabled = false;
-        sub_chunk_count = sub_chunk_count_but_not_really as isize;
+        stream.put_unsigned_var_int(self.extra_payload.len() as u32);
+        stream.put(self.extra_payload.clone());
     }
 
-    let cache_enabled = stream.get_bool();
+    fn decode(stream: &mut Stream) -> Result<LevelChunk, ClientError> {
+        let chunk_x = stream.try_get_var_int()?;
+        let chunk_z = stream.try_get_var_int()?;
+        let dimension_id = stream.try_get_var_int()?;
+
+        let mut sub_chunk_count: isize = 0;
+        let mut client_sub_chunk_requests_enabled: bool = false;
+
 
-    let mut used_blob_hashes: Option<Vec<i64>> = None;
-    if cache_enabled {
-        let count = stream.get_unsigned_var_int();
-        if count > MAX_BLOB_HASHES {
-            eprintln!("Expected at most {} blob hashes, got {}", MAX_BLOB_HASHES, count);
+        let sub_chunk_count_but_not_really = stream.try_get_unsigned_var_int()?;
+        if sub_chunk_count_but_not_really == CLIENT_REQUEST_FULL_COLUMN_FAKE_COUNT {
+            client_sub_chunk_requests_enabled = true;
+            sub_chunk_count = isize::MAX;
+        } else if sub_chunk_count_but_not_really == CLIENT_REQUEST_TRUNCATED_COLUMN_FAKE_COUNT {
+            client_sub_chunk_requests_enabled = true;
+            sub_chunk_count = stream.try_get_l_short()? as isize;
         } else {
-            let mut blob_hashes = vec![];
-            for _ in 0..count {
-                let blob = stream.get_l_long();
-                blob_hashes.push(blob);
+            client_sub_chunk_requests_enabled = false;
+            sub_chunk_count = sub_chunk_count_but_not_really as isize;
+        }
+
+        let cache_enabled = stream.try_get_bool()?;
+
+        let mut used_blob_hashes: Option<Vec<i64>> = None;
+        if cache_enabled {
+            let count = stream.try_get_unsigned_var_int()?;
+            if count > MAX_BLOB_HASHES {
+                warn!(target: log_target::CHUNK, "Expected at most {} blob hashes, got {}", MAX_BLOB_HASHES, count);
+            } else {
+                let mut blob_hashes = vec![];
+                for _ in 0..count {
+                    let blob = stream.try_get_l_long()?;
+                    blob_hashes.push(blob);
+                }
+                used_blob_hashes = Option::from(blob_hashes);
             }
-            used_blob_hashes = Option::from(blob_hashes);
         }
-    }
 
-    let length = stream.get_unsigned_var_int();
-    let extra_payload = stream.get(length).unwrap();
+        let length = stream.try_get_unsigned_var_int()?;
+        let extra_payload = stream.try_get(length)?;
 
-    LevelChunk { chunk_x, chunk_z, dimension_id, sub_chunk_count, client_sub_chunk_requests_enabled, used_blob_hashes, extra_payload }
-}
\ No newline at end of file
+        Ok(LevelChunk { chunk_x, chunk_z, dimension_id, sub_chunk_count, client_sub_chunk_requests_enabled, used_blob_hashes, extra_payload })
+    }
+}
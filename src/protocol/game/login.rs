@@ -1,12 +1,21 @@
+use crate::client_data_options::ClientDataOptions;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
 use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
-use crate::protocol::game_packet::GamePacket;
-use crate::utils::encryption::Encryption;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+use crate::protocol::game::types::client_data::ClientData;
+use crate::protocol::game::types::login_identity::{IdentityClaims, IdentityData, LoginIdentity};
+use crate::utils::jwt::{self, Jwt};
 use binary_utils::binary::Stream;
 use chrono::Utc;
-use openssl::ecdsa::EcdsaSig;
+use openssl::base64::encode_block;
+use openssl::hash::{hash, MessageDigest};
 use openssl::pkey::{PKey, Private};
-use openssl::sign::Signer;
-use serde_json::{json, to_vec, Value};
+use serde_json::{json, Value};
+use uuid::{Builder, Uuid};
+
+// Signs the Xbox Live part of the chains of online players
+pub const MOJANG_ROOT_PUBLIC_KEY: &str = "MHYwEAYHKoZIzj0CAQYFK4EEACIDYgAECRXueJeTDqNRRgJi/vlRufByu/2G0i2Ebt6YMar5QX/R0DIIyrJMcUpruK4QveTfJSTp3Shlq4Gk34cD/4GUWwkv0DVuzeuB+tXija7HBxii03NHDbPAD0AKnLr2wdAp";
 
 pub struct Login {
     client_protocol: u32,
@@ -18,11 +27,43 @@ pub fn new(client_protocol: u32, chain_data_jwt: String, client_data_jwt: String
     Login{ client_protocol, chain_data_jwt, client_data_jwt }
 }
 
+// Server side: the contents of a received Login packet
 impl Login {
-    pub fn encode(&self) -> Vec<u8> {
-        let mut stream = Stream::new(Vec::new(), 0);
-        stream.put_unsigned_var_int(BedrockPacketType::get_byte(BedrockPacketType::Login) as u32);
+    pub fn get_protocol(&self) -> u32 {
+        self.client_protocol
+    }
+
+    pub fn get_chain(&self) -> Result<Vec<String>, ClientError> {
+        let chain_data: Value = serde_json::from_str(&self.chain_data_jwt).map_err(|e| ClientError::auth("Chain data is not JSON", e))?;
+        let chain = chain_data.get("chain").and_then(Value::as_array).ok_or(ClientError::Auth("Chain data has no chain".to_string(), None))?;
+        Ok(chain.iter().filter_map(Value::as_str).map(|jwt| jwt.to_string()).collect())
+    }
+
+    // Without signature checks, use `verify` for untrusted clients
+    pub fn get_identity(&self) -> Result<LoginIdentity, ClientError> {
+        identity_from_chain(&self.get_chain()?)
+    }
+
+    pub fn get_client_data(&self) -> Result<ClientData, ClientError> {
+        let client_data = Jwt::parse(&self.client_data_jwt).map_err(|e| ClientError::auth("Client data can not be parsed", e))?;
+        client_data.claims().map_err(|e| ClientError::auth("Client data can not be parsed", e))
+    }
+
+    // Verifies the chain links and the client data signature against the identity key, the identity is authenticated
+    // if the chain leads to the Mojang root key
+    pub fn verify(&self) -> Result<(LoginIdentity, ClientData), ClientError> {
+        let identity = verify_chain(&self.get_chain()?, MOJANG_ROOT_PUBLIC_KEY)?;
+        let public_key = jwt::public_key_from_base64(&identity.identity_public_key).map_err(|e| ClientError::auth("Identity public key is invalid", e))?;
+        let client_data = Jwt::parse(&self.client_data_jwt).map_err(|e| ClientError::auth("Client data can not be parsed", e))?;
+        client_data.verify(&public_key).map_err(|e| ClientError::auth("Client data verification failed", e))?;
+        Ok((identity, client_data.claims().map_err(|e| ClientError::auth("Client data can not be parsed", e))?))
+    }
+}
+
+impl BedrockPacket for Login {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::Login);
 
+    fn encode(&self, stream: &mut Stream) {
         stream.put_int(self.client_protocol);
 
         let mut jwt_stream = Stream::new(Vec::new(), 0);
@@ -33,34 +74,74 @@ impl Login {
 
         stream.put_unsigned_var_int(jwt_stream.get_buffer().len() as u32);
         stream.put(jwt_stream.get_buffer());
+    }
+
+    fn decode(stream: &mut Stream) -> Result<Login, ClientError> {
+        let client_protocol = stream.try_get_int()?;
 
-        let mut main_stream = Stream::new(vec![0xfe], 0);
+        let length = stream.try_get_unsigned_var_int()?;
+        let mut jwt_stream = Stream::new(stream.try_get(length)?, 0);
+        let mut jwt_length = jwt_stream.try_get_l_int()?;
+        let chain_data_jwt = String::from_utf8(jwt_stream.try_get(jwt_length)?).map_err(|e| ClientError::decode("Chain data is not UTF-8", e))?;
+        jwt_length = jwt_stream.try_get_l_int()?;
+        let client_data_jwt = String::from_utf8(jwt_stream.try_get(jwt_length)?).map_err(|e| ClientError::decode("Client data is not UTF-8", e))?;
 
-        let mut compress_stream = Stream::new(Vec::new(), 0);
-        compress_stream.put_unsigned_var_int(stream.get_buffer().len() as u32);
-        compress_stream.put(stream.get_buffer());
+        Ok(Login{ client_protocol, chain_data_jwt, client_data_jwt })
+    }
+}
 
-        main_stream.put(GamePacket::compress(&compress_stream.get_buffer()));
+pub struct OfflineIdentity {
+    pub display_name: String,
+    pub uuid: Uuid
+}
 
-        main_stream.get_buffer()
+impl OfflineIdentity {
+    // The UUID is derived from the name, so the same name keeps its player data on the server
+    pub fn new(display_name: String) -> Result<OfflineIdentity, ClientError> {
+        let uuid = offline_uuid(&display_name)?;
+        Ok(OfflineIdentity{ display_name, uuid })
+    }
+
+    pub fn with_uuid(display_name: String, uuid: Uuid) -> OfflineIdentity {
+        OfflineIdentity{ display_name, uuid }
     }
 }
 
-pub fn convert_login_chain(chain: &mut Vec<String>, pkey: PKey<Private>, target_address: String, target_port: u16, client_guid: i64, client_version: String) -> Vec<String> {
-    let chain_one: Vec<&str> = chain[0].split('.').collect();
-    let chain_two: Vec<&str> = chain[1].split('.').collect();
+// UUID v3 of "OfflinePlayer:<name>", the same scheme servers use for players without XUID
+pub fn offline_uuid(display_name: &str) -> Result<Uuid, ClientError> {
+    // MD5 is not available everywhere, e.g. with OpenSSL in FIPS mode
+    let digest = hash(MessageDigest::md5(), format!("OfflinePlayer:{}", display_name).as_bytes())
+        .map_err(|e| ClientError::crypto("Offline UUID can not be hashed", e))?;
+    let bytes: [u8; 16] = digest.as_ref().try_into().map_err(|e| ClientError::crypto("MD5 digest is not 16 bytes", e))?;
+    Ok(Builder::from_md5_bytes(bytes).into_uuid())
+}
 
-    let chain_encoded = Encryption::b64_url_decode(chain_one[0]).unwrap();
-    let chain_decoded: Value = serde_json::from_str(chain_encoded.as_str()).expect("Chain 1 can not decoded.");
+pub fn convert_login_chain(chain: &mut Vec<String>, pkey: PKey<Private>, target_address: String, target_port: u16, client_guid: i64, client_version: String, options: &ClientDataOptions) -> Result<Vec<String>, ClientError> {
+    // a self-signed chain (offline login) is sent as it is, only the client data has to be signed
+    if chain.len() == 1 {
+        let identity_pub_key = public_key_base64(&pkey)?;
+        let display_name = identity_from_chain(chain)?.display_name;
 
-    let chain_two_encoded = Encryption::b64_url_decode(chain_two[1]).unwrap();
-    let chain_two_decoded: Value = serde_json::from_str(chain_two_encoded.as_str()).expect("Chain 2 can not decoded.");
+        let header = json!({
+            "alg": "ES384",
+            "x5u": identity_pub_key
+        });
+        let real_chain = json!({
+            "chain": chain
+        });
+        let skin_data = jwt::encode(&header, &client_data(target_address, target_port, client_guid, client_version, display_name, options), &pkey).map_err(|e| ClientError::crypto("Client data JWT can not be signed", e))?;
 
-    let identity_pub_key = chain_two_decoded.get("identityPublicKey").and_then(Value::as_str).unwrap().to_string();
-    let extra_data = chain_two_decoded.get("extraData").unwrap();
-    let display_name = extra_data.get("displayName").and_then(Value::as_str).unwrap().to_string();
+        return Ok(vec![real_chain.to_string(), skin_data]);
+    }
+    if chain.len() < 2 {
+        return Err(ClientError::Auth(format!("Expected at least 2 chain entries, got {}", chain.len()), None));
+    }
+    let chain_one = Jwt::parse(&chain[0]).map_err(|e| ClientError::auth("Chain 1 can not be decoded", e))?;
+    let identity = identity_from_chain(chain)?;
+    let identity_pub_key = identity.identity_public_key;
+    let display_name = identity.display_name;
 
-    let x5u = chain_decoded.get("x5u").and_then(Value::as_str).unwrap().to_string();
+    let x5u = chain_one.get_x5u().ok_or(ClientError::Auth("Chain 1 has no x5u".to_string(), None))?.to_string();
 
     let header = json!({
         "alg": "ES384",
@@ -75,95 +156,151 @@ pub fn convert_login_chain(chain: &mut Vec<String>, pkey: PKey<Private>, target_
         "certificateAuthority": true
     });
 
-    let address = format!("{}:{}", target_address, target_port);
+    chain.insert(0, jwt::encode(&header, &payload, &pkey).map_err(|e| ClientError::crypto("Login JWT can not be signed", e))?);
 
-    let payload_two = json!({
-        "AnimatedImageData": [],
-        "ArmSize": "wide",
-        "CapeData": "",
-        "CapeId": "",
-        "CapeImageHeight": 0,
-        "CapeImageWidth": 0,
-        "CapeOnClassicSkin": false,
-        "ClientRandomId": client_guid,
-        "CompatibleWithClientSideChunkGen": true,
-        "CurrentInputMode": 1,
-        "DefaultInputMode": 1,
-        "DeviceId": "ebc40067-bfdb-3ad0-af9d-65248592acf1",
-        "DeviceModel": "System Product Name ASUS",
-        "DeviceOS": 1, //:D
-        "GameVersion": client_version,
-        "GuiScale": -1,
-        "IsEditorMode": false,
-        "LanguageCode": "en_US",
-        "OverrideSkin": false,
-        "MaxViewDistance": 40,
-        "MemoryTier": 5,
-        "PersonaPieces": [],
-        "PersonaSkin": false,
-        "PieceTintColors": [],
-        "PlatformOfflineId": "",
-        "PlatformOnlineId": "",
-        "PlatformType": 1,
-        "PlayFabId": "a3561c5eacf46e1d",
-        "PremiumSkin": false,
-        "SelfSignedId": "651a4f81-aa0e-3c32-b30b-3d250293a340",
-        "ServerAddress": address,
-        "SkinAnimationData": "",
-        "SkinColor": "#0",
-        "SkinData": "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWExP/GhcX/xYTE/8aFxf/GhcX/xYTE/8aFxf/FhMT/yQkJP8kJCT/JCQk/yQkJP8kJCT/JCQk/yQkJP8kJCT/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAGhcX/x4aGv8aFxf/Hhoa/x4aGv8aFxf/Hhoa/xoXF/8kJCT/JCQk/xoXF/8eGhr/Hhoa/xoXF/8kJCT/JCQk/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABYTE/8iICD/GhcX/x4aGv8iICD/GhcX/yIgIP8WExP/JCQk/yQkJP8aFxf/GhcX/xoXF/8aFxf/JCQk/yQkJP8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAaFxf/IiAg/xoXF/8eGhr/IiAg/xoXF/8iICD/GhcX/yQkJP8kJCT/Hhoa/x4aGv8eGhr/Hhoa/yQkJP8kJCT/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAGhcX/yIgIP8aFxf/Hhoa/yIgIP8aFxf/IiAg/xoXF/8aFxf/Hhoa//3s6P/97Oj//ezo//3s6P8eGhr/GhcX/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABYTE/8iICD/GhcX/x4aGv8iICD/GhcX/yIgIP8WExP/FhMT//3s6P/97Oj//ezo//3s6P/97Oj//ezo/xYTE/8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAaFxf/Hhoa/xoXF/8eGhr/Hhoa/xoXF/8eGhr/GhcX//re1//97Oj//ezo//3s6P/97Oj//ezo//3s6P/63tf/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFhMT/xoXF/8WExP/GhcX/xoXF/8WExP/GhcX/xYTE//63tf//ezo//3s6P/97Oj//ezo//3s6P/97Oj/+t7X/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABYTE/8aFxf/GhcX/xYTE/8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWExP/GhcX/xYTE/8aFxf/GhcX/xYTE/8aFxf/FhMT/xYTE/8aFxf/FhMT/xoXF/8aFxf/FhMT/xoXF/8WExP/FhMT/xoXF/8WExP/GhcX/xoXF/8WExP/GhcX/xYTE/8WExP/GhcX/xYTE/8aFxf/GhcX/xYTE/8aFxf/FhMT/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWExP/GhcX/xoXF/8WExP/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA//////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAeGhr/GhcX/x4aGv8eGhr/GhcX/x4aGv8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAaFxf/Hhoa/xoXF/8eGhr/IiAg/xoXF/8eGhr/GhcX/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD//////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAGhcX/x4aGv8aFxf/Hhoa/yIgIP8aFxf/Hhoa/xoXF/8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAGhcX/yIgIP8aFxf/IiAg/x4aGv8aFxf/IiAg/xoXF/8aFxf/CgoL/woKC///9fL//+zo/woKC/8KCgv/GhcX/xoXF/8iICD/GhcX/x4aGv8iICD/GhcX/yIgIP8aFxf/GhcX/yIgIP8aFxf/IiAg/x4aGv8aFxf/IiAg/xoXF/8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABoXF/8eGhr/AAAAAB4aGv8iICD/AAAAAB4aGv8aFxf/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABoXF/8iICD/GhcX/yIgIP8eGhr/GhcX/yIgIP8aFxf/GhcX//////8AAAD///Xy///s6P8AAAD//////xoXF/8aFxf/IiAg/xoXF/8eGhr/IiAg/xoXF/8iICD/GhcX/xoXF/8iICD/GhcX/yIgIP8eGhr/GhcX/yIgIP8aFxf/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAaFxf/AAAAAAAAAAAAAAAAIiAg/wAAAAAAAAAAGhcX/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAXFxf/JCQk/xcXF/8eGhr/Hhoa/xoXF/8eGhr/GhcX/xYTE///////GRkZ//ve1//73tf/GRkZ//////8WExP/GhcX/x4aGv8aFxf/Hhoa/x4aGv8XFxf/JCQk/xcXF/8XFxf/JCQk/xcXF/8kJCT/FxcX/xcXF/8kJCT/FxcX/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/wAAAP8XFxf/AAAA/xoXF/8WExP/GhcX//re1//63tf//ezo//3s6P/97Oj//ezo//3s6P/97Oj/+t7X//re1/8aFxf/FhMT/xoXF/8kJCT/FxcX/yQkJP8XFxf/FxcX/yQkJP8XFxf/JCQk/yQkJP8XFxf/JCQk/xcXF/8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/AAAA/wAAAP8AAAD//89u///Pbv//z27//89u/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR///n0f//59H//+fR///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/wAAAP8AAAD/AAAA///Pbv//z27//89u///Pbv8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP/n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP/n0f//59H//+fR///n0f//59H//+fR///n0f//59H/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP8AAAD/AAAA/wAAAP//z27//89u///Pbv//z27/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/59H//+fR///n0f//59H//+fR///n0f//59H//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/AAAA/wAAAP8AAAD//89u///Pbv//z27//89u/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR///n0f//59H//+fR///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/AAAA/wAAAP8AAAD/AAAA///1/v8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD//+fR///n0f//59H//+fR///n0f//uoz//7qM//+6jP//uoz//7qM//+6jP//59H//+fR///n0f//59H//+fR///n0f//uoz//7qM//+6jP//uoz//7qM//+6jP//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/wAAAP8AAAD/AAAA/wAAAP//9f7/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA///n0f//59H//+fR///n0f//59H//+fR///n0f//uoz//7qM///n0f//59H//+fR///n0f//59H//+fR///n0f//uoz//7qM///n0f//uoz//7qM///n0f//uoz//7qM///n0f//x5z//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP8AAAD/AAAA/wAAAP8AAAD///X+/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP//59H//+fR///n0f//59H//+fR///n0f//59H//7qM//+6jP//59H//+fR///n0f//59H//+fR///n0f//59H//7qM//+6jP//59H//7qM//+6jP//59H//7qM//+6jP//59H//8ec///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//8ec///n0f//59H/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/AAAA/wAAAP8AAAD///X+/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD//+fR///n0f//59H//+fR//+6jP//59H//+fR//+6jP//uoz//+fR///n0f//uoz//+fR///n0f//59H//+fR//+6jP//59H//+fR//+6jP//uoz//+fR///n0f//uoz//+fR///HnP//59H//+fR//+6jP//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///HnP//59H//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA///n0f//59H//+fR///n0f//uoz//7qM//+6jP//uoz//7qM//+6jP//uoz//7qM///n0f//59H//+fR///n0f//uoz//+fR///n0f//uoz//7qM///n0f//59H//7qM///n0f//59H//8ec///n0f//uoz//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///HnP8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP//59H//+fR///n0f//59H//+fR///n0f//uoz//+fR///n0f//uoz//+fR///n0f//59H//+fR///n0f//59H//7qM///n0f//59H//7qM//+6jP//59H//+fR//+6jP//59H//+fR///HnP//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//x5z/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//uoz//+fR//+6jP//uoz//+fR//+6jP//59H//+fR///n0f//59H//+fR//+6jP//59H//+fR//+6jP//uoz//+fR///n0f//uoz//+fR///n0f//59H//+fR///n0f//uoz//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//8ec/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//7qM//+6jP//uoz//7qM//+6jP//uoz//+fR///n0f//59H//+fR///n0f//uoz//+fR//+6jP//uoz//7qM//+6jP//59H//7qM///n0f//59H//+fR///n0f//59H//7qM///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//8ec///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP/n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR//+6jP//59H//7qM//+6jP//59H//7qM///n0f//59H//+fR///n0f//59H//+fR//+6jP//uoz//7qM//+6jP//uoz//7qM///n0f//59H//8ec///n0f//59H//+fR//+6jP//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/59H//+fR///n0f8AAAD/AAAA///n0f//59H/AAAA///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//uoz//7qM//+6jP//uoz//7qM//+6jP//59H//+fR///n0f//59H//+fR///n0f//59H//+fR//+6jP//uoz//+fR///n0f//59H//+fR///n0f//x5z//+fR///n0f//59H//7qM///n0f//59H//+fR///n0f//59H//+fR///HnP//59H//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR/wAAAP8AAAD//+fR///n0f8AAAD/AAAA///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//7qM///n0f//uoz//7qM///n0f//uoz//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//uoz//7qM///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR//+6jP//59H//+fR///n0f//59H//+fR///n0f//59H//8ec///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAQEBAQAAAQP/Pbv//z27//89u///Pbv//z27//89u///Pbv//z27//+fR///n0f//59H//+fR///Pbv//z27//89u///Pbv//59H//+fR///n0f//59H//+fR///n0f//uoz//7qM//+6jP//uoz//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//7qM//+6jP//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAQAAAQAAAAEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/AAAA/wAAAP8AAAD//89u///Pbv//z27//89u/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR//3s6P/97Oj//ezo//3s6P8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/wAAAP8AAAD/AAAA///Pbv//z27//89u///Pbv8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP/n0f//59H//+fR///n0f/97Oj//ezo//3s6P/97Oj/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP8AAAD/AAAA/wAAAP//z27//89u///Pbv//z27/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/59H//+fR///n0f//59H//ezo//3s6P/97Oj//ezo/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/AAAA/wAAAP8AAAD//89u///Pbv//z27//89u/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR//3s6P/97Oj//ezo//3s6P8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP//9f7/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD///X+/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///HnP//59H//+fR///n0f//59H//+fR///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA///1/v8AAAD/AAAA/wAAAP8AAAD/AAAA///1/v//9f7///X+///1/v//59H//+fR///n0f//59H//+fR//+6jP//59H//+fR///n0f//x5z//+fR///n0f//x5z//+fR///n0f//59H/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD///X+/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD//+fR///n0f//59H//+fR///n0f//uoz//+fR///n0f//59H//8ec///n0f//59H//8ec///n0f//59H//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD///X+///1/v//9f7///X+///n0f//59H//+fR///n0f//59H//+fR///n0f//uoz//+fR///n0f//59H//+fR///n0f//59H//8ec///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP//59H//+fR///n0f//59H//+fR///n0f//uoz//+fR///n0f//59H//+fR///n0f//59H//8ec///n0f//59H/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///1/v//9f7///X+///1/v//9f7///X+///1/v//9f7///X+///1/v//9f7///X+///1/v//9f7///X+///1/v8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP/n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAA/wAAAP8AAAD/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAD/59H//+fR///n0f//59H/AAAA///n0f//59H/AAAA/wAAAP//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR//+6jP//59H//+fR///n0f//59H//8ec///HnP//59H//+fR///n0f//x5z//+fR/wAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA/+fR///n0f//59H//+fR///n0f8AAAD/AAAA///n0f//59H/AAAA/wAAAP//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//x5z//+fR///n0f//59H//+fR///n0f8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAP/n0f//59H//+fR///n0f//z27//89u///Pbv//z27//89u///Pbv//z27//89u///Pbv//z27//89u///Pbv//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H//+fR///n0f//59H/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA==",
-        "SkinGeometryData": "ewogICAiZm9ybWF0X3ZlcnNpb24iIDogIjEuMTIuMCIsCiAgICJtaW5lY3JhZnQ6Z2VvbWV0cnkiIDogWwogICAgICB7CiAgICAgICAgICJib25lcyIgOiBbCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgIm5hbWUiIDogImJvZHkiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJ3YWlzdCIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIDAuMCwgMjQuMCwgMC4wIF0KICAgICAgICAgICAgfSwKICAgICAgICAgICAgewogICAgICAgICAgICAgICAibmFtZSIgOiAid2Fpc3QiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAwLjAsIDEyLjAsIDAuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTUuMCwgOC4wLCAzLjAgXSwKICAgICAgICAgICAgICAgICAgICAgInNpemUiIDogWyAxMCwgMTYsIDEgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMCwgMCBdCiAgICAgICAgICAgICAgICAgIH0KICAgICAgICAgICAgICAgXSwKICAgICAgICAgICAgICAgIm5hbWUiIDogImNhcGUiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJib2R5IiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgMC4wLCAyNC4wLCAzLjAgXSwKICAgICAgICAgICAgICAgInJvdGF0aW9uIiA6IFsgMC4wLCAxODAuMCwgMC4wIF0KICAgICAgICAgICAgfQogICAgICAgICBdLAogICAgICAgICAiZGVzY3JpcHRpb24iIDogewogICAgICAgICAgICAiaWRlbnRpZmllciIgOiAiZ2VvbWV0cnkuY2FwZSIsCiAgICAgICAgICAgICJ0ZXh0dXJlX2hlaWdodCIgOiAzMiwKICAgICAgICAgICAgInRleHR1cmVfd2lkdGgiIDogNjQKICAgICAgICAgfQogICAgICB9LAogICAgICB7CiAgICAgICAgICJib25lcyIgOiBbCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgIm5hbWUiIDogInJvb3QiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAwLjAsIDAuMCwgMC4wIF0KICAgICAgICAgICAgfSwKICAgICAgICAgICAgewogICAgICAgICAgICAgICAiY3ViZXMiIDogWwogICAgICAgICAgICAgICAgICB7CiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyAtNC4wLCAxMi4wLCAtMi4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgOCwgMTIsIDQgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMTYsIDE2IF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAiYm9keSIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogIndhaXN0IiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgMC4wLCAyNC4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJuYW1lIiA6ICJ3YWlzdCIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogInJvb3QiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAwLjAsIDEyLjAsIDAuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTQuMCwgMjQuMCwgLTQuMCBdLAogICAgICAgICAgICAgICAgICAgICAic2l6ZSIgOiBbIDgsIDgsIDggXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMCwgMCBdCiAgICAgICAgICAgICAgICAgIH0KICAgICAgICAgICAgICAgXSwKICAgICAgICAgICAgICAgIm5hbWUiIDogImhlYWQiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJib2R5IiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgMC4wLCAyNC4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJuYW1lIiA6ICJjYXBlIiwKICAgICAgICAgICAgICAgInBhcmVudCIgOiAiYm9keSIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIDAuMCwgMjQsIDMuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAiaW5mbGF0ZSIgOiAwLjUwLAogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTQuMCwgMjQuMCwgLTQuMCBdLAogICAgICAgICAgICAgICAgICAgICAic2l6ZSIgOiBbIDgsIDgsIDggXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMzIsIDAgXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJoYXQiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJoZWFkIiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgMC4wLCAyNC4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgIm9yaWdpbiIgOiBbIDQuMCwgMTIuMCwgLTIuMCBdLAogICAgICAgICAgICAgICAgICAgICAic2l6ZSIgOiBbIDQsIDEyLCA0IF0sCiAgICAgICAgICAgICAgICAgICAgICJ1diIgOiBbIDMyLCA0OCBdCiAgICAgICAgICAgICAgICAgIH0KICAgICAgICAgICAgICAgXSwKICAgICAgICAgICAgICAgIm5hbWUiIDogImxlZnRBcm0iLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJib2R5IiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgNS4wLCAyMi4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgImluZmxhdGUiIDogMC4yNTAsCiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyA0LjAsIDEyLjAsIC0yLjAgXSwKICAgICAgICAgICAgICAgICAgICAgInNpemUiIDogWyA0LCAxMiwgNCBdLAogICAgICAgICAgICAgICAgICAgICAidXYiIDogWyA0OCwgNDggXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJsZWZ0U2xlZXZlIiwKICAgICAgICAgICAgICAgInBhcmVudCIgOiAibGVmdEFybSIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIDUuMCwgMjIuMCwgMC4wIF0KICAgICAgICAgICAgfSwKICAgICAgICAgICAgewogICAgICAgICAgICAgICAibmFtZSIgOiAibGVmdEl0ZW0iLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJsZWZ0QXJtIiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgNi4wLCAxNS4wLCAxLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgIm9yaWdpbiIgOiBbIC04LjAsIDEyLjAsIC0yLjAgXSwKICAgICAgICAgICAgICAgICAgICAgInNpemUiIDogWyA0LCAxMiwgNCBdLAogICAgICAgICAgICAgICAgICAgICAidXYiIDogWyA0MCwgMTYgXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJyaWdodEFybSIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogImJvZHkiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAtNS4wLCAyMi4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgImluZmxhdGUiIDogMC4yNTAsCiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyAtOC4wLCAxMi4wLCAtMi4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgNCwgMTIsIDQgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgNDAsIDMyIF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAicmlnaHRTbGVldmUiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJyaWdodEFybSIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIC01LjAsIDIyLjAsIDAuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImxvY2F0b3JzIiA6IHsKICAgICAgICAgICAgICAgICAgImxlYWRfaG9sZCIgOiBbIC02LCAxNSwgMSBdCiAgICAgICAgICAgICAgIH0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJyaWdodEl0ZW0iLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJyaWdodEFybSIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIC02LCAxNSwgMSBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTAuMTAsIDAuMCwgLTIuMCBdLAogICAgICAgICAgICAgICAgICAgICAic2l6ZSIgOiBbIDQsIDEyLCA0IF0sCiAgICAgICAgICAgICAgICAgICAgICJ1diIgOiBbIDE2LCA0OCBdCiAgICAgICAgICAgICAgICAgIH0KICAgICAgICAgICAgICAgXSwKICAgICAgICAgICAgICAgIm5hbWUiIDogImxlZnRMZWciLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJyb290IiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgMS45MCwgMTIuMCwgMC4wIF0KICAgICAgICAgICAgfSwKICAgICAgICAgICAgewogICAgICAgICAgICAgICAiY3ViZXMiIDogWwogICAgICAgICAgICAgICAgICB7CiAgICAgICAgICAgICAgICAgICAgICJpbmZsYXRlIiA6IDAuMjUwLAogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTAuMTAsIDAuMCwgLTIuMCBdLAogICAgICAgICAgICAgICAgICAgICAic2l6ZSIgOiBbIDQsIDEyLCA0IF0sCiAgICAgICAgICAgICAgICAgICAgICJ1diIgOiBbIDAsIDQ4IF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAibGVmdFBhbnRzIiwKICAgICAgICAgICAgICAgInBhcmVudCIgOiAibGVmdExlZyIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIDEuOTAsIDEyLjAsIDAuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTMuOTAsIDAuMCwgLTIuMCBdLAogICAgICAgICAgICAgICAgICAgICAic2l6ZSIgOiBbIDQsIDEyLCA0IF0sCiAgICAgICAgICAgICAgICAgICAgICJ1diIgOiBbIDAsIDE2IF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAicmlnaHRMZWciLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJyb290IiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgLTEuOTAsIDEyLjAsIDAuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAiaW5mbGF0ZSIgOiAwLjI1MCwKICAgICAgICAgICAgICAgICAgICAgIm9yaWdpbiIgOiBbIC0zLjkwLCAwLjAsIC0yLjAgXSwKICAgICAgICAgICAgICAgICAgICAgInNpemUiIDogWyA0LCAxMiwgNCBdLAogICAgICAgICAgICAgICAgICAgICAidXYiIDogWyAwLCAzMiBdCiAgICAgICAgICAgICAgICAgIH0KICAgICAgICAgICAgICAgXSwKICAgICAgICAgICAgICAgIm5hbWUiIDogInJpZ2h0UGFudHMiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJyaWdodExlZyIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIC0xLjkwLCAxMi4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgImluZmxhdGUiIDogMC4yNTAsCiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyAtNC4wLCAxMi4wLCAtMi4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgOCwgMTIsIDQgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMTYsIDMyIF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAiamFja2V0IiwKICAgICAgICAgICAgICAgInBhcmVudCIgOiAiYm9keSIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIDAuMCwgMjQuMCwgMC4wIF0KICAgICAgICAgICAgfQogICAgICAgICBdLAogICAgICAgICAiZGVzY3JpcHRpb24iIDogewogICAgICAgICAgICAiaWRlbnRpZmllciIgOiAiZ2VvbWV0cnkuaHVtYW5vaWQuY3VzdG9tIiwKICAgICAgICAgICAgInRleHR1cmVfaGVpZ2h0IiA6IDY0LAogICAgICAgICAgICAidGV4dHVyZV93aWR0aCIgOiA2NCwKICAgICAgICAgICAgInZpc2libGVfYm91bmRzX2hlaWdodCIgOiAyLAogICAgICAgICAgICAidmlzaWJsZV9ib3VuZHNfb2Zmc2V0IiA6IFsgMCwgMSwgMCBdLAogICAgICAgICAgICAidmlzaWJsZV9ib3VuZHNfd2lkdGgiIDogMQogICAgICAgICB9CiAgICAgIH0sCiAgICAgIHsKICAgICAgICAgImJvbmVzIiA6IFsKICAgICAgICAgICAgewogICAgICAgICAgICAgICAibmFtZSIgOiAicm9vdCIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIDAuMCwgMC4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJuYW1lIiA6ICJ3YWlzdCIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogInJvb3QiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAwLjAsIDEyLjAsIDAuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTQuMCwgMTIuMCwgLTIuMCBdLAogICAgICAgICAgICAgICAgICAgICAic2l6ZSIgOiBbIDgsIDEyLCA0IF0sCiAgICAgICAgICAgICAgICAgICAgICJ1diIgOiBbIDE2LCAxNiBdCiAgICAgICAgICAgICAgICAgIH0KICAgICAgICAgICAgICAgXSwKICAgICAgICAgICAgICAgIm5hbWUiIDogImJvZHkiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJ3YWlzdCIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIDAuMCwgMjQuMCwgMC4wIF0KICAgICAgICAgICAgfSwKICAgICAgICAgICAgewogICAgICAgICAgICAgICAiY3ViZXMiIDogWwogICAgICAgICAgICAgICAgICB7CiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyAtNC4wLCAyNC4wLCAtNC4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgOCwgOCwgOCBdLAogICAgICAgICAgICAgICAgICAgICAidXYiIDogWyAwLCAwIF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAiaGVhZCIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogImJvZHkiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAwLjAsIDI0LjAsIDAuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAiaW5mbGF0ZSIgOiAwLjUwLAogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTQuMCwgMjQuMCwgLTQuMCBdLAogICAgICAgICAgICAgICAgICAgICAic2l6ZSIgOiBbIDgsIDgsIDggXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMzIsIDAgXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJoYXQiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJoZWFkIiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgMC4wLCAyNC4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgIm9yaWdpbiIgOiBbIC0zLjkwLCAwLjAsIC0yLjAgXSwKICAgICAgICAgICAgICAgICAgICAgInNpemUiIDogWyA0LCAxMiwgNCBdLAogICAgICAgICAgICAgICAgICAgICAidXYiIDogWyAwLCAxNiBdCiAgICAgICAgICAgICAgICAgIH0KICAgICAgICAgICAgICAgXSwKICAgICAgICAgICAgICAgIm5hbWUiIDogInJpZ2h0TGVnIiwKICAgICAgICAgICAgICAgInBhcmVudCIgOiAicm9vdCIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIC0xLjkwLCAxMi4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgImluZmxhdGUiIDogMC4yNTAsCiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyAtMy45MCwgMC4wLCAtMi4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgNCwgMTIsIDQgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMCwgMzIgXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJyaWdodFBhbnRzIiwKICAgICAgICAgICAgICAgInBhcmVudCIgOiAicmlnaHRMZWciLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAtMS45MCwgMTIuMCwgMC4wIF0KICAgICAgICAgICAgfSwKICAgICAgICAgICAgewogICAgICAgICAgICAgICAiY3ViZXMiIDogWwogICAgICAgICAgICAgICAgICB7CiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyAtMC4xMCwgMC4wLCAtMi4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgNCwgMTIsIDQgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMTYsIDQ4IF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAibGVmdExlZyIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogInJvb3QiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAxLjkwLCAxMi4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgImluZmxhdGUiIDogMC4yNTAsCiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyAtMC4xMCwgMC4wLCAtMi4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgNCwgMTIsIDQgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMCwgNDggXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJsZWZ0UGFudHMiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJsZWZ0TGVnIiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgMS45MCwgMTIuMCwgMC4wIF0KICAgICAgICAgICAgfSwKICAgICAgICAgICAgewogICAgICAgICAgICAgICAiY3ViZXMiIDogWwogICAgICAgICAgICAgICAgICB7CiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyA0LjAsIDExLjUwLCAtMi4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgMywgMTIsIDQgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgMzIsIDQ4IF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAibGVmdEFybSIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogImJvZHkiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyA1LjAsIDIxLjUwLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJjdWJlcyIgOiBbCiAgICAgICAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgICAgICAgImluZmxhdGUiIDogMC4yNTAsCiAgICAgICAgICAgICAgICAgICAgICJvcmlnaW4iIDogWyA0LjAsIDExLjUwLCAtMi4wIF0sCiAgICAgICAgICAgICAgICAgICAgICJzaXplIiA6IFsgMywgMTIsIDQgXSwKICAgICAgICAgICAgICAgICAgICAgInV2IiA6IFsgNDgsIDQ4IF0KICAgICAgICAgICAgICAgICAgfQogICAgICAgICAgICAgICBdLAogICAgICAgICAgICAgICAibmFtZSIgOiAibGVmdFNsZWV2ZSIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogImxlZnRBcm0iLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyA1LjAsIDIxLjUwLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJuYW1lIiA6ICJsZWZ0SXRlbSIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogImxlZnRBcm0iLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyA2LCAxNC41MCwgMSBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTcuMCwgMTEuNTAsIC0yLjAgXSwKICAgICAgICAgICAgICAgICAgICAgInNpemUiIDogWyAzLCAxMiwgNCBdLAogICAgICAgICAgICAgICAgICAgICAidXYiIDogWyA0MCwgMTYgXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJyaWdodEFybSIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogImJvZHkiLAogICAgICAgICAgICAgICAicGl2b3QiIDogWyAtNS4wLCAyMS41MCwgMC4wIF0KICAgICAgICAgICAgfSwKICAgICAgICAgICAgewogICAgICAgICAgICAgICAiY3ViZXMiIDogWwogICAgICAgICAgICAgICAgICB7CiAgICAgICAgICAgICAgICAgICAgICJpbmZsYXRlIiA6IDAuMjUwLAogICAgICAgICAgICAgICAgICAgICAib3JpZ2luIiA6IFsgLTcuMCwgMTEuNTAsIC0yLjAgXSwKICAgICAgICAgICAgICAgICAgICAgInNpemUiIDogWyAzLCAxMiwgNCBdLAogICAgICAgICAgICAgICAgICAgICAidXYiIDogWyA0MCwgMzIgXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJyaWdodFNsZWV2ZSIsCiAgICAgICAgICAgICAgICJwYXJlbnQiIDogInJpZ2h0QXJtIiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgLTUuMCwgMjEuNTAsIDAuMCBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImxvY2F0b3JzIiA6IHsKICAgICAgICAgICAgICAgICAgImxlYWRfaG9sZCIgOiBbIC02LCAxNC41MCwgMSBdCiAgICAgICAgICAgICAgIH0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJyaWdodEl0ZW0iLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJyaWdodEFybSIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIC02LCAxNC41MCwgMSBdCiAgICAgICAgICAgIH0sCiAgICAgICAgICAgIHsKICAgICAgICAgICAgICAgImN1YmVzIiA6IFsKICAgICAgICAgICAgICAgICAgewogICAgICAgICAgICAgICAgICAgICAiaW5mbGF0ZSIgOiAwLjI1MCwKICAgICAgICAgICAgICAgICAgICAgIm9yaWdpbiIgOiBbIC00LjAsIDEyLjAsIC0yLjAgXSwKICAgICAgICAgICAgICAgICAgICAgInNpemUiIDogWyA4LCAxMiwgNCBdLAogICAgICAgICAgICAgICAgICAgICAidXYiIDogWyAxNiwgMzIgXQogICAgICAgICAgICAgICAgICB9CiAgICAgICAgICAgICAgIF0sCiAgICAgICAgICAgICAgICJuYW1lIiA6ICJqYWNrZXQiLAogICAgICAgICAgICAgICAicGFyZW50IiA6ICJib2R5IiwKICAgICAgICAgICAgICAgInBpdm90IiA6IFsgMC4wLCAyNC4wLCAwLjAgXQogICAgICAgICAgICB9LAogICAgICAgICAgICB7CiAgICAgICAgICAgICAgICJuYW1lIiA6ICJjYXBlIiwKICAgICAgICAgICAgICAgInBhcmVudCIgOiAiYm9keSIsCiAgICAgICAgICAgICAgICJwaXZvdCIgOiBbIDAuMCwgMjQsIC0zLjAgXQogICAgICAgICAgICB9CiAgICAgICAgIF0sCiAgICAgICAgICJkZXNjcmlwdGlvbiIgOiB7CiAgICAgICAgICAgICJpZGVudGlmaWVyIiA6ICJnZW9tZXRyeS5odW1hbm9pZC5jdXN0b21TbGltIiwKICAgICAgICAgICAgInRleHR1cmVfaGVpZ2h0IiA6IDY0LAogICAgICAgICAgICAidGV4dHVyZV93aWR0aCIgOiA2NCwKICAgICAgICAgICAgInZpc2libGVfYm91bmRzX2hlaWdodCIgOiAyLAogICAgICAgICAgICAidmlzaWJsZV9ib3VuZHNfb2Zmc2V0IiA6IFsgMCwgMSwgMCBdLAogICAgICAgICAgICAidmlzaWJsZV9ib3VuZHNfd2lkdGgiIDogMQogICAgICAgICB9CiAgICAgIH0KICAgXQp9Cg==",
-        "SkinGeometryDataEngineVersion": "MC4wLjA=",
-        "SkinId": "Customebc40067-bfdb-3ad0-af9d-65248592acf1",
-        "SkinImageHeight": 64,
-        "SkinImageWidth": 64,
-        "SkinResourcePatch": "ewogICAiZ2VvbWV0cnkiIDogewogICAgICAiZGVmYXVsdCIgOiAiZ2VvbWV0cnkuaHVtYW5vaWQuY3VzdG9tIgogICB9Cn0K",
-        "ThirdPartyName": display_name,
-        "ThirdPartyNameOnly": false,
-        "TrustedSkin": false,
-        "UIProfile": 0,
+    let real_chain = json!({
+        "chain": chain
     });
 
-    let header_bytes = to_vec(&header).expect("Header don't convert the byte array");
-    let encoded_header = Encryption::b64_url_encode(&header_bytes);
-
-    let payload_bytes = to_vec(&payload).expect("Payload don't convert the byte array");
-    let encoded_payload = Encryption::b64_url_encode(&payload_bytes);
+    let skin_data = jwt::encode(&header, &client_data(target_address, target_port, client_guid, client_version, display_name, options), &pkey).map_err(|e| ClientError::crypto("Client data JWT can not be signed", e))?;
 
-    let payload_two_bytes = to_vec(&payload_two).expect("Payload Two don't convert the byte array");
-    let encoded_payload_two = Encryption::b64_url_encode(&payload_two_bytes);
+    Ok(vec![real_chain.to_string(), skin_data])
 
+}
 
-    let data_to_sign = format!("{}.{}", encoded_header, encoded_payload);
-    let mut signer = Signer::new(openssl::hash::MessageDigest::sha384(), &pkey).expect("Signer not created.");
-    signer.update(data_to_sign.as_bytes()).expect("Signer update error.");
-    let signature = signer.sign_to_vec().expect("Signature creating error.");
-    let ecdsa_sig = EcdsaSig::from_der(&signature).unwrap();
-    let r = ecdsa_sig.r().to_vec();
-    let s = ecdsa_sig.s().to_vec();
-    let concatenated_signature = [r, s].concat();
-    let encoded_signature = Encryption::b64_url_encode(&concatenated_signature);
-    let jwt = format!("{}.{}.{}", encoded_header, encoded_payload, encoded_signature);
-    chain.insert(0, jwt);
+// Chain with a single self-signed identity JWT, accepted by servers running with online-mode=false
+pub fn offline_identity_chain(identity: &OfflineIdentity, pkey: &PKey<Private>) -> Result<Vec<String>, ClientError> {
+    let identity_pub_key = public_key_base64(pkey)?;
 
-    let real_chain = json!({
-        "chain": chain
+    let header = json!({
+        "alg": "ES384",
+        "x5u": identity_pub_key
     });
 
+    let current_time = Utc::now().timestamp();
+    let payload = IdentityClaims{
+        extra_data: Option::from(IdentityData{
+            xuid: "".to_string(),
+            display_name: identity.display_name.clone(),
+            uuid: identity.uuid,
+            title_id: "896928775".to_string()
+        }),
+        identity_public_key: identity_pub_key,
+        certificate_authority: None,
+        exp: Option::from(current_time + 21600),
+        nbf: Option::from(current_time - 21600),
+        iat: Option::from(current_time)
+    };
+
+    Ok(vec![jwt::encode(&header, &payload, pkey).map_err(|e| ClientError::crypto("Identity JWT can not be signed", e))?])
+}
 
-    let data_to_sign_two = format!("{}.{}", encoded_header, encoded_payload_two);
-    let mut signer_two = Signer::new(openssl::hash::MessageDigest::sha384(), &pkey).expect("Signer not created.");
-    signer_two.update(data_to_sign_two.as_bytes()).expect("Signer update error.");
-    let signature_two = signer_two.sign_to_vec().expect("Signature creating error.");
-    let ecdsa_sig = EcdsaSig::from_der(&signature_two).unwrap();
-    let r = ecdsa_sig.r().to_vec();
-    let s = ecdsa_sig.s().to_vec();
-    let concatenated_signature_two = [r, s].concat();
-    let encoded_signature_two = Encryption::b64_url_encode(&concatenated_signature_two);
+// Identity of the chain entry that carries `extraData`, without signature checks
+pub fn identity_from_chain(chain: &[String]) -> Result<LoginIdentity, ClientError> {
+    let mut identity: Option<(IdentityData, String)> = None;
+    let mut expires_at: Option<i64> = None;
+    for (i, entry) in chain.iter().enumerate() {
+        let claims: IdentityClaims = Jwt::parse(entry).and_then(|jwt| jwt.claims()).map_err(|e| ClientError::auth(format!("Chain {} can not be decoded", i + 1), e))?;
+        if let Some(exp) = claims.exp {
+            expires_at = Option::from(expires_at.map_or(exp, |earliest: i64| earliest.min(exp)));
+        }
+        if let Some(extra_data) = claims.extra_data {
+            identity = Option::from((extra_data, claims.identity_public_key));
+        }
+    }
 
-    let skin_data = format!("{}.{}.{}", encoded_header, encoded_payload_two, encoded_signature_two);
+    let (data, identity_public_key) = identity.ok_or(ClientError::Auth("Chain has no identity (extraData)".to_string(), None))?;
+    Ok(LoginIdentity{ xuid: data.xuid, uuid: data.uuid, display_name: data.display_name, title_id: data.title_id, identity_public_key, expires_at, authenticated: false })
+}
 
+// Every entry has to be signed by the identityPublicKey of the entry before it, the first one by its own x5u.
+// Offline chains (a single self-signed entry) pass as well, the identity is only authenticated if an entry before or
+// at the identity is signed by `root_key` (base64 DER, usually MOJANG_ROOT_PUBLIC_KEY).
+pub fn verify_chain(chain: &[String], root_key: &str) -> Result<LoginIdentity, ClientError> {
+    let mut next_key: Option<String> = None;
+    let mut reached_root = false;
+    let mut authenticated = false;
+    let mut has_identity = false;
+    for (i, entry) in chain.iter().enumerate() {
+        let token = Jwt::parse(entry).map_err(|e| ClientError::auth(format!("Chain {} can not be decoded", i + 1), e))?;
+        let key = match &next_key {
+            Some(key) => key.as_str(),
+            None => token.get_x5u().ok_or(ClientError::Auth(format!("Chain {} has no x5u", i + 1), None))?
+        };
+        jwt::public_key_from_base64(key).and_then(|public_key| token.verify(&public_key))
+            .map_err(|e| ClientError::auth(format!("Chain {} verification failed", i + 1), e))?;
+        reached_root |= key == root_key;
 
-    vec![real_chain.to_string(), skin_data]
+        let claims: IdentityClaims = token.claims().map_err(|e| ClientError::auth(format!("Chain {} can not be decoded", i + 1), e))?;
+        if claims.extra_data.is_some() {
+            // a second identity could be appended with the key of the first one
+            if has_identity {
+                return Err(ClientError::Auth(format!("Chain {} has a second identity (extraData)", i + 1), None));
+            }
+            has_identity = true;
+            authenticated = reached_root;
+        }
+        next_key = Option::from(claims.identity_public_key);
+    }
+    let mut identity = identity_from_chain(chain)?;
+    identity.authenticated = authenticated;
+    Ok(identity)
+}
 
-}
\ No newline at end of file
+fn public_key_base64(pkey: &PKey<Private>) -> Result<String, ClientError> {
+    let public_key_der = pkey.public_key_to_der().map_err(|e| ClientError::crypto("Public key can not be encoded", e))?;
+    Ok(encode_block(&public_key_der))
+}
+
+fn client_data(target_address: String, target_port: u16, client_guid: i64, client_version: String, display_name: String, options: &ClientDataOptions) -> ClientData {
+    let address = format!("{}:{}", target_address, target_port);
+    let skin = &options.skin;
+    let cape = options.cape.as_ref();
+
+    ClientData{
+        animated_image_data: vec![],
+        arm_size: skin.arm_size.clone(),
+        cape_data: options.get_cape_data(),
+        cape_id: cape.map(|cape| cape.id.clone()).unwrap_or_default(),
+        cape_image_height: cape.map_or(0, |cape| cape.height),
+        cape_image_width: cape.map_or(0, |cape| cape.width),
+        cape_on_classic_skin: cape.is_some_and(|cape| cape.on_classic_skin),
+        client_random_id: client_guid,
+        compatible_with_client_side_chunk_gen: true,
+        current_input_mode: options.current_input_mode,
+        default_input_mode: options.default_input_mode,
+        device_id: options.device_id.clone(),
+        device_model: options.device_model.clone(),
+        device_os: options.device_os,
+        game_version: client_version,
+        gui_scale: options.gui_scale,
+        is_editor_mode: false,
+        language_code: options.language_code.clone(),
+        override_skin: false,
+        max_view_distance: options.max_view_distance,
+        memory_tier: options.memory_tier,
+        persona_pieces: vec![],
+        persona_skin: false,
+        piece_tint_colors: vec![],
+        platform_offline_id: "".to_string(),
+        platform_online_id: "".to_string(),
+        platform_type: 1,
+        play_fab_id: options.play_fab_id.clone(),
+        premium_skin: false,
+        self_signed_id: options.self_signed_id.clone(),
+        server_address: address,
+        skin_animation_data: "".to_string(),
+        skin_color: skin.color.clone(),
+        skin_data: options.get_skin_data(),
+        skin_geometry_data: encode_block(skin.geometry.as_bytes()),
+        skin_geometry_data_engine_version: encode_block(skin.geometry_engine_version.as_bytes()),
+        skin_id: skin.id.clone(),
+        skin_image_height: skin.height,
+        skin_image_width: skin.width,
+        skin_resource_patch: encode_block(skin.resource_patch.as_bytes()),
+        third_party_name: display_name,
+        third_party_name_only: false,
+        trusted_skin: false,
+        ui_profile: options.ui_profile,
+    }
+}
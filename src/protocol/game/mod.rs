@@ -1,4 +1,7 @@
 pub mod bedrock_packet_ids;
+pub mod bedrock_packet;
+pub mod packet_registry;
+pub mod protocol_version;
 pub mod network_settings;
 pub mod req_network_settings;
 pub mod disconnect;
@@ -9,11 +12,32 @@ pub mod play_status;
 pub mod resource_packs_info;
 pub mod resource_pack_client_response;
 pub mod resource_pack_stack;
+pub mod resource_pack_data_info;
+pub mod resource_pack_chunk_request;
+pub mod resource_pack_chunk_data;
 pub mod request_chunk_radius;
 pub mod set_local_player_as_initialized;
 pub mod text;
+pub mod command_request;
+pub mod command_output;
+pub mod available_commands;
+pub mod update_soft_enum;
+pub mod player_auth_input;
+pub mod update_attributes;
 pub mod client_cache_status;
 pub mod level_chunk;
 pub mod start_game;
+pub mod add_actor;
+pub mod add_player;
+pub mod add_item_actor;
+pub mod remove_actor;
+pub mod move_actor_absolute;
+pub mod move_actor_delta;
+pub mod move_player;
+pub mod set_actor_motion;
+pub mod set_actor_data;
+pub mod set_actor_link;
+pub mod player_list;
+pub mod player_skin;
 pub mod types;
 pub mod serializer;
\ No newline at end of file
@@ -0,0 +1,53 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::types::vector::Vec3;
+use crate::protocol::game::serializer::stream_helper::{get_rotation_byte, put_rotation_byte, StreamReader};
+
+pub const FLAG_GROUND: u8 = 0x01;
+pub const FLAG_TELEPORT: u8 = 0x02;
+pub const FLAG_FORCE_MOVE: u8 = 0x04;
+
+pub struct MoveActorAbsolute {
+    pub actor_runtime_id: u64,
+    pub flags: u8,
+    pub position: Vec3,
+    pub pitch: f32,
+    pub yaw: f32,
+    pub head_yaw: f32
+}
+
+pub fn new(actor_runtime_id: u64, flags: u8, position: Vec3, pitch: f32, yaw: f32, head_yaw: f32) -> MoveActorAbsolute {
+    MoveActorAbsolute{ actor_runtime_id, flags, position, pitch, yaw, head_yaw }
+}
+
+impl MoveActorAbsolute {
+    pub fn is_on_ground(&self) -> bool {
+        self.flags & FLAG_GROUND != 0
+    }
+}
+
+impl BedrockPacket for MoveActorAbsolute {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::MoveActorAbsolute);
+
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_unsigned_var_long(self.actor_runtime_id);
+        stream.put_byte(self.flags);
+        self.position.write(stream);
+        put_rotation_byte(stream, self.pitch);
+        put_rotation_byte(stream, self.yaw);
+        put_rotation_byte(stream, self.head_yaw);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<MoveActorAbsolute, ClientError> {
+        let actor_runtime_id = stream.try_get_unsigned_var_long()?;
+        let flags = stream.try_get_byte()?;
+        let position = Vec3::read(stream)?;
+        let pitch = get_rotation_byte(stream)?;
+        let yaw = get_rotation_byte(stream)?;
+        let head_yaw = get_rotation_byte(stream)?;
+
+        Ok(MoveActorAbsolute{ actor_runtime_id, flags, position, pitch, yaw, head_yaw })
+    }
+}
@@ -0,0 +1,72 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::serializer::stream_helper::{get_rotation_byte, put_rotation_byte, StreamReader};
+
+pub const FLAG_HAS_X: u16 = 0x01;
+pub const FLAG_HAS_Y: u16 = 0x02;
+pub const FLAG_HAS_Z: u16 = 0x04;
+pub const FLAG_HAS_PITCH: u16 = 0x08;
+pub const FLAG_HAS_YAW: u16 = 0x10;
+pub const FLAG_HAS_HEAD_YAW: u16 = 0x20;
+pub const FLAG_ON_GROUND: u16 = 0x40;
+pub const FLAG_TELEPORT: u16 = 0x80;
+pub const FLAG_FORCE_MOVE: u16 = 0x100;
+
+// Despite the name, the coordinates are absolute. Only the ones flagged as changed are sent.
+pub struct MoveActorDelta {
+    pub actor_runtime_id: u64,
+    pub flags: u16,
+    pub x: Option<f32>,
+    pub y: Option<f32>,
+    pub z: Option<f32>,
+    pub pitch: Option<f32>,
+    pub yaw: Option<f32>,
+    pub head_yaw: Option<f32>
+}
+
+pub fn new(actor_runtime_id: u64) -> MoveActorDelta {
+    MoveActorDelta{ actor_runtime_id, flags: 0, x: None, y: None, z: None, pitch: None, yaw: None, head_yaw: None }
+}
+
+impl MoveActorDelta {
+    pub fn is_on_ground(&self) -> bool {
+        self.flags & FLAG_ON_GROUND != 0
+    }
+}
+
+impl BedrockPacket for MoveActorDelta {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::MoveActorDelta);
+
+    // The HAS_* flags are derived from the set fields
+    fn encode(&self, stream: &mut Stream) {
+        let mut flags = self.flags & (FLAG_ON_GROUND | FLAG_TELEPORT | FLAG_FORCE_MOVE);
+        for (value, flag) in [(self.x, FLAG_HAS_X), (self.y, FLAG_HAS_Y), (self.z, FLAG_HAS_Z), (self.pitch, FLAG_HAS_PITCH), (self.yaw, FLAG_HAS_YAW), (self.head_yaw, FLAG_HAS_HEAD_YAW)] {
+            if value.is_some() {
+                flags |= flag;
+            }
+        }
+        stream.put_unsigned_var_long(self.actor_runtime_id);
+        stream.put_l_short(flags);
+        for value in [self.x, self.y, self.z].into_iter().flatten() {
+            stream.put_l_float(value);
+        }
+        for value in [self.pitch, self.yaw, self.head_yaw].into_iter().flatten() {
+            put_rotation_byte(stream, value);
+        }
+    }
+
+    fn decode(stream: &mut Stream) -> Result<MoveActorDelta, ClientError> {
+        let actor_runtime_id = stream.try_get_unsigned_var_long()?;
+        let flags = stream.try_get_l_short()?;
+        let x = if flags & FLAG_HAS_X != 0 { Some(stream.try_get_l_float()?) } else { None };
+        let y = if flags & FLAG_HAS_Y != 0 { Some(stream.try_get_l_float()?) } else { None };
+        let z = if flags & FLAG_HAS_Z != 0 { Some(stream.try_get_l_float()?) } else { None };
+        let pitch = if flags & FLAG_HAS_PITCH != 0 { Some(get_rotation_byte(stream)?) } else { None };
+        let yaw = if flags & FLAG_HAS_YAW != 0 { Some(get_rotation_byte(stream)?) } else { None };
+        let head_yaw = if flags & FLAG_HAS_HEAD_YAW != 0 { Some(get_rotation_byte(stream)?) } else { None };
+
+        Ok(MoveActorDelta{ actor_runtime_id, flags, x, y, z, pitch, yaw, head_yaw })
+    }
+}
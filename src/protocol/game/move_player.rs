@@ -0,0 +1,65 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::types::vector::Vec3;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+
+pub const MODE_NORMAL: u8 = 0;
+pub const MODE_RESET: u8 = 1;
+pub const MODE_TELEPORT: u8 = 2;
+pub const MODE_PITCH: u8 = 3;
+
+// The position is at eye height
+pub struct MovePlayer {
+    pub actor_runtime_id: u64,
+    pub position: Vec3,
+    pub pitch: f32,
+    pub yaw: f32,
+    pub head_yaw: f32,
+    pub mode: u8,
+    pub on_ground: bool,
+    pub riding_actor_runtime_id: u64,
+    pub teleport_cause: i32, // only with MODE_TELEPORT
+    pub teleport_source_type: i32, // only with MODE_TELEPORT
+    pub tick: u64
+}
+
+pub fn new(actor_runtime_id: u64, position: Vec3, pitch: f32, yaw: f32, mode: u8) -> MovePlayer {
+    MovePlayer{ actor_runtime_id, position, pitch, yaw, head_yaw: yaw, mode, on_ground: false, riding_actor_runtime_id: 0, teleport_cause: 0, teleport_source_type: 0, tick: 0 }
+}
+
+impl BedrockPacket for MovePlayer {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::MovePlayer);
+
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_unsigned_var_long(self.actor_runtime_id);
+        self.position.write(stream);
+        stream.put_l_float(self.pitch);
+        stream.put_l_float(self.yaw);
+        stream.put_l_float(self.head_yaw);
+        stream.put_byte(self.mode);
+        stream.put_bool(self.on_ground);
+        stream.put_unsigned_var_long(self.riding_actor_runtime_id);
+        if self.mode == MODE_TELEPORT {
+            stream.put_l_int(self.teleport_cause as u32);
+            stream.put_l_int(self.teleport_source_type as u32);
+        }
+        stream.put_unsigned_var_long(self.tick);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<MovePlayer, ClientError> {
+        let actor_runtime_id = stream.try_get_unsigned_var_long()?;
+        let position = Vec3::read(stream)?;
+        let pitch = stream.try_get_l_float()?;
+        let yaw = stream.try_get_l_float()?;
+        let head_yaw = stream.try_get_l_float()?;
+        let mode = stream.try_get_byte()?;
+        let on_ground = stream.try_get_bool()?;
+        let riding_actor_runtime_id = stream.try_get_unsigned_var_long()?;
+        let (teleport_cause, teleport_source_type) = if mode == MODE_TELEPORT { (stream.try_get_l_int()? as i32, stream.try_get_l_int()? as i32) } else { (0, 0) };
+        let tick = stream.try_get_unsigned_var_long()?;
+
+        Ok(MovePlayer{ actor_runtime_id, position, pitch, yaw, head_yaw, mode, on_ground, riding_actor_runtime_id, teleport_cause, teleport_source_type, tick })
+    }
+}
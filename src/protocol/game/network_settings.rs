@@ -1,11 +1,15 @@
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
 
-pub const COMPRESS_NOTHING: u8 = 0;
-pub const COMPRESS_EVERYTHING: u8 = 1;
+pub const COMPRESS_NOTHING: u16 = 0;
+pub const COMPRESS_EVERYTHING: u16 = 1;
 
-pub const ZLIB: u8 = 0;
-pub const SNAPPY: u8 = 1;
-pub const NONE: u8 = 255;
+pub const ZLIB: u16 = 0;
+pub const SNAPPY: u16 = 1;
+pub const NONE: u16 = 0xffff;
 
 pub struct NetworkSettings {
     pub compression_threshold: u16,
@@ -15,14 +19,24 @@ pub struct NetworkSettings {
     pub client_throttle_scalar : f32
 }
 
-pub fn decode(bytes: Vec<u8>) -> NetworkSettings {
-    let mut stream = Stream::new(bytes, 0);
+impl BedrockPacket for NetworkSettings {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::NetworkSettings);
 
-    let compression_threshold = stream.get_l_short();
-    let compression_algorithm = stream.get_l_short();
-    let enable_client_throttling = stream.get_bool();
-    let client_throttle_threshold = stream.get_byte();
-    let client_throttle_scalar = stream.get_l_float();
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_l_short(self.compression_threshold);
+        stream.put_l_short(self.compression_algorithm);
+        stream.put_bool(self.enable_client_throttling);
+        stream.put_byte(self.client_throttle_threshold);
+        stream.put_l_float(self.client_throttle_scalar);
+    }
 
-    NetworkSettings { compression_threshold, compression_algorithm, enable_client_throttling, client_throttle_threshold, client_throttle_scalar }
+    fn decode(stream: &mut Stream) -> Result<NetworkSettings, ClientError> {
+        let compression_threshold = stream.try_get_l_short()?;
+        let compression_algorithm = stream.try_get_l_short()?;
+        let enable_client_throttling = stream.try_get_bool()?;
+        let client_throttle_threshold = stream.try_get_byte()?;
+        let client_throttle_scalar = stream.try_get_l_float()?;
+
+        Ok(NetworkSettings { compression_threshold, compression_algorithm, enable_client_throttling, client_throttle_threshold, client_throttle_scalar })
+    }
 }
\ No newline at end of file
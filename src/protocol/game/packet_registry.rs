@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::add_actor::AddActor;
+use crate::protocol::game::add_item_actor::AddItemActor;
+use crate::protocol::game::add_player::AddPlayer;
+use crate::protocol::game::available_commands::AvailableCommands;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::client_cache_status::ClientCacheStatus;
+use crate::protocol::game::client_to_server_handshake::ClientToServerHandshake;
+use crate::protocol::game::command_output::CommandOutput;
+use crate::protocol::game::command_request::CommandRequest;
+use crate::protocol::game::disconnect::Disconnect;
+use crate::protocol::game::level_chunk::LevelChunk;
+use crate::protocol::game::login::Login;
+use crate::protocol::game::move_actor_absolute::MoveActorAbsolute;
+use crate::protocol::game::move_actor_delta::MoveActorDelta;
+use crate::protocol::game::move_player::MovePlayer;
+use crate::protocol::game::network_settings::NetworkSettings;
+use crate::protocol::game::play_status::PlayStatus;
+use crate::protocol::game::player_auth_input::PlayerAuthInput;
+use crate::protocol::game::player_list::PlayerList;
+use crate::protocol::game::player_skin::PlayerSkin;
+use crate::protocol::game::protocol_version;
+use crate::protocol::game::remove_actor::RemoveActor;
+use crate::protocol::game::req_network_settings::RequestNetworkSettings;
+use crate::protocol::game::request_chunk_radius::RequestChunkRadius;
+use crate::protocol::game::resource_pack_chunk_data::ResourcePackChunkData;
+use crate::protocol::game::resource_pack_chunk_request::ResourcePackChunkRequest;
+use crate::protocol::game::resource_pack_client_response::ResourcePackClientResponse;
+use crate::protocol::game::resource_pack_data_info::ResourcePackDataInfo;
+use crate::protocol::game::resource_pack_stack::ResourcePackStack;
+use crate::protocol::game::resource_packs_info::ResourcePacksInfo;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+use crate::protocol::game::server_to_client_handshake::ServerToClientHandshake;
+use crate::protocol::game::set_actor_data::SetActorData;
+use crate::protocol::game::set_actor_link::SetActorLink;
+use crate::protocol::game::set_actor_motion::SetActorMotion;
+use crate::protocol::game::set_local_player_as_initialized::SetLocalPlayerAsInitializedPacket;
+use crate::protocol::game::start_game::StartGame;
+use crate::protocol::game::text::Text;
+use crate::protocol::game::update_attributes::UpdateAttributes;
+use crate::protocol::game::update_soft_enum::UpdateSoftEnum;
+
+pub enum Packet {
+    Login(Login),
+    PlayStatus(PlayStatus),
+    ServerToClientHandshake(ServerToClientHandshake),
+    ClientToServerHandshake(ClientToServerHandshake),
+    Disconnect(Disconnect),
+    ResourcePacksInfo(ResourcePacksInfo),
+    ResourcePackStack(ResourcePackStack),
+    ResourcePackClientResponse(ResourcePackClientResponse),
+    ResourcePackDataInfo(ResourcePackDataInfo),
+    ResourcePackChunkRequest(ResourcePackChunkRequest),
+    ResourcePackChunkData(ResourcePackChunkData),
+    Text(Text),
+    CommandRequest(CommandRequest),
+    CommandOutput(CommandOutput),
+    AvailableCommands(AvailableCommands),
+    UpdateSoftEnum(UpdateSoftEnum),
+    PlayerAuthInput(PlayerAuthInput),
+    UpdateAttributes(UpdateAttributes),
+    StartGame(StartGame),
+    LevelChunk(LevelChunk),
+    AddActor(AddActor),
+    AddPlayer(AddPlayer),
+    AddItemActor(AddItemActor),
+    RemoveActor(RemoveActor),
+    MoveActorAbsolute(MoveActorAbsolute),
+    MoveActorDelta(MoveActorDelta),
+    MovePlayer(MovePlayer),
+    SetActorMotion(SetActorMotion),
+    SetActorData(SetActorData),
+    SetActorLink(SetActorLink),
+    PlayerList(PlayerList),
+    PlayerSkin(PlayerSkin),
+    SetLocalPlayerAsInitialized(SetLocalPlayerAsInitializedPacket),
+    RequestChunkRadius(RequestChunkRadius),
+    ClientCacheStatus(ClientCacheStatus),
+    NetworkSettings(NetworkSettings),
+    RequestNetworkSettings(RequestNetworkSettings),
+    // Packets without a registered decoder, the payload starts right after the packet id
+    Unknown { id: u16, payload: Vec<u8> }
+}
+
+impl Packet {
+    pub fn id(&self) -> u16 {
+        match self {
+            Packet::Login(_) => Login::ID,
+            Packet::PlayStatus(_) => PlayStatus::ID,
+            Packet::ServerToClientHandshake(_) => ServerToClientHandshake::ID,
+            Packet::ClientToServerHandshake(_) => ClientToServerHandshake::ID,
+            Packet::Disconnect(_) => Disconnect::ID,
+            Packet::ResourcePacksInfo(_) => ResourcePacksInfo::ID,
+            Packet::ResourcePackStack(_) => ResourcePackStack::ID,
+            Packet::ResourcePackClientResponse(_) => ResourcePackClientResponse::ID,
+            Packet::ResourcePackDataInfo(_) => ResourcePackDataInfo::ID,
+            Packet::ResourcePackChunkRequest(_) => ResourcePackChunkRequest::ID,
+            Packet::ResourcePackChunkData(_) => ResourcePackChunkData::ID,
+            Packet::Text(_) => Text::ID,
+            Packet::CommandRequest(_) => CommandRequest::ID,
+            Packet::CommandOutput(_) => CommandOutput::ID,
+            Packet::AvailableCommands(_) => AvailableCommands::ID,
+            Packet::UpdateSoftEnum(_) => UpdateSoftEnum::ID,
+            Packet::PlayerAuthInput(_) => PlayerAuthInput::ID,
+            Packet::UpdateAttributes(_) => UpdateAttributes::ID,
+            Packet::StartGame(_) => StartGame::ID,
+            Packet::LevelChunk(_) => LevelChunk::ID,
+            Packet::AddActor(_) => AddActor::ID,
+            Packet::AddPlayer(_) => AddPlayer::ID,
+            Packet::AddItemActor(_) => AddItemActor::ID,
+            Packet::RemoveActor(_) => RemoveActor::ID,
+            Packet::MoveActorAbsolute(_) => MoveActorAbsolute::ID,
+            Packet::MoveActorDelta(_) => MoveActorDelta::ID,
+            Packet::MovePlayer(_) => MovePlayer::ID,
+            Packet::SetActorMotion(_) => SetActorMotion::ID,
+            Packet::SetActorData(_) => SetActorData::ID,
+            Packet::SetActorLink(_) => SetActorLink::ID,
+            Packet::PlayerList(_) => PlayerList::ID,
+            Packet::PlayerSkin(_) => PlayerSkin::ID,
+            Packet::SetLocalPlayerAsInitialized(_) => SetLocalPlayerAsInitializedPacket::ID,
+            Packet::RequestChunkRadius(_) => RequestChunkRadius::ID,
+            Packet::ClientCacheStatus(_) => ClientCacheStatus::ID,
+            Packet::NetworkSettings(_) => NetworkSettings::ID,
+            Packet::RequestNetworkSettings(_) => RequestNetworkSettings::ID,
+            Packet::Unknown { id, .. } => *id
+        }
+    }
+}
+
+// Decoders get the negotiated protocol so that version dependent packets can pick their layout
+pub type PacketDecoder = fn(&mut Stream, u32) -> Result<Packet, ClientError>;
+
+pub struct PacketRegistry {
+    decoders: HashMap<u16, PacketDecoder>,
+    protocol: u32
+}
+
+impl PacketRegistry {
+    // Registry with a decoder for every packet in this crate
+    pub fn new() -> PacketRegistry {
+        let mut registry = PacketRegistry::empty();
+
+        registry.register(Login::ID, |stream, protocol| Ok(Packet::Login(Login::decode_versioned(stream, protocol)?)));
+        registry.register(PlayStatus::ID, |stream, protocol| Ok(Packet::PlayStatus(PlayStatus::decode_versioned(stream, protocol)?)));
+        registry.register(ServerToClientHandshake::ID, |stream, protocol| Ok(Packet::ServerToClientHandshake(ServerToClientHandshake::decode_versioned(stream, protocol)?)));
+        registry.register(ClientToServerHandshake::ID, |stream, protocol| Ok(Packet::ClientToServerHandshake(ClientToServerHandshake::decode_versioned(stream, protocol)?)));
+        registry.register(Disconnect::ID, |stream, protocol| Ok(Packet::Disconnect(Disconnect::decode_versioned(stream, protocol)?)));
+        registry.register(ResourcePacksInfo::ID, |stream, protocol| Ok(Packet::ResourcePacksInfo(ResourcePacksInfo::decode_versioned(stream, protocol)?)));
+        registry.register(ResourcePackStack::ID, |stream, protocol| Ok(Packet::ResourcePackStack(ResourcePackStack::decode_versioned(stream, protocol)?)));
+        registry.register(ResourcePackClientResponse::ID, |stream, protocol| Ok(Packet::ResourcePackClientResponse(ResourcePackClientResponse::decode_versioned(stream, protocol)?)));
+        registry.register(ResourcePackDataInfo::ID, |stream, protocol| Ok(Packet::ResourcePackDataInfo(ResourcePackDataInfo::decode_versioned(stream, protocol)?)));
+        registry.register(ResourcePackChunkRequest::ID, |stream, protocol| Ok(Packet::ResourcePackChunkRequest(ResourcePackChunkRequest::decode_versioned(stream, protocol)?)));
+        registry.register(ResourcePackChunkData::ID, |stream, protocol| Ok(Packet::ResourcePackChunkData(ResourcePackChunkData::decode_versioned(stream, protocol)?)));
+        registry.register(Text::ID, |stream, protocol| Ok(Packet::Text(Text::decode_versioned(stream, protocol)?)));
+        registry.register(CommandRequest::ID, |stream, protocol| Ok(Packet::CommandRequest(CommandRequest::decode_versioned(stream, protocol)?)));
+        registry.register(CommandOutput::ID, |stream, protocol| Ok(Packet::CommandOutput(CommandOutput::decode_versioned(stream, protocol)?)));
+        registry.register(AvailableCommands::ID, |stream, protocol| Ok(Packet::AvailableCommands(AvailableCommands::decode_versioned(stream, protocol)?)));
+        registry.register(UpdateSoftEnum::ID, |stream, protocol| Ok(Packet::UpdateSoftEnum(UpdateSoftEnum::decode_versioned(stream, protocol)?)));
+        registry.register(PlayerAuthInput::ID, |stream, protocol| Ok(Packet::PlayerAuthInput(PlayerAuthInput::decode_versioned(stream, protocol)?)));
+        registry.register(UpdateAttributes::ID, |stream, protocol| Ok(Packet::UpdateAttributes(UpdateAttributes::decode_versioned(stream, protocol)?)));
+        registry.register(StartGame::ID, |stream, protocol| Ok(Packet::StartGame(StartGame::decode_versioned(stream, protocol)?)));
+        registry.register(LevelChunk::ID, |stream, protocol| Ok(Packet::LevelChunk(LevelChunk::decode_versioned(stream, protocol)?)));
+        registry.register(AddActor::ID, |stream, protocol| Ok(Packet::AddActor(AddActor::decode_versioned(stream, protocol)?)));
+        registry.register(AddPlayer::ID, |stream, protocol| Ok(Packet::AddPlayer(AddPlayer::decode_versioned(stream, protocol)?)));
+        registry.register(AddItemActor::ID, |stream, protocol| Ok(Packet::AddItemActor(AddItemActor::decode_versioned(stream, protocol)?)));
+        registry.register(RemoveActor::ID, |stream, protocol| Ok(Packet::RemoveActor(RemoveActor::decode_versioned(stream, protocol)?)));
+        registry.register(MoveActorAbsolute::ID, |stream, protocol| Ok(Packet::MoveActorAbsolute(MoveActorAbsolute::decode_versioned(stream, protocol)?)));
+        registry.register(MoveActorDelta::ID, |stream, protocol| Ok(Packet::MoveActorDelta(MoveActorDelta::decode_versioned(stream, protocol)?)));
+        registry.register(MovePlayer::ID, |stream, protocol| Ok(Packet::MovePlayer(MovePlayer::decode_versioned(stream, protocol)?)));
+        registry.register(SetActorMotion::ID, |stream, protocol| Ok(Packet::SetActorMotion(SetActorMotion::decode_versioned(stream, protocol)?)));
+        registry.register(SetActorData::ID, |stream, protocol| Ok(Packet::SetActorData(SetActorData::decode_versioned(stream, protocol)?)));
+        registry.register(SetActorLink::ID, |stream, protocol| Ok(Packet::SetActorLink(SetActorLink::decode_versioned(stream, protocol)?)));
+        registry.register(PlayerList::ID, |stream, protocol| Ok(Packet::PlayerList(PlayerList::decode_versioned(stream, protocol)?)));
+        registry.register(PlayerSkin::ID, |stream, protocol| Ok(Packet::PlayerSkin(PlayerSkin::decode_versioned(stream, protocol)?)));
+        registry.register(SetLocalPlayerAsInitializedPacket::ID, |stream, protocol| Ok(Packet::SetLocalPlayerAsInitialized(SetLocalPlayerAsInitializedPacket::decode_versioned(stream, protocol)?)));
+        registry.register(RequestChunkRadius::ID, |stream, protocol| Ok(Packet::RequestChunkRadius(RequestChunkRadius::decode_versioned(stream, protocol)?)));
+        registry.register(ClientCacheStatus::ID, |stream, protocol| Ok(Packet::ClientCacheStatus(ClientCacheStatus::decode_versioned(stream, protocol)?)));
+        registry.register(NetworkSettings::ID, |stream, protocol| Ok(Packet::NetworkSettings(NetworkSettings::decode_versioned(stream, protocol)?)));
+        registry.register(RequestNetworkSettings::ID, |stream, protocol| Ok(Packet::RequestNetworkSettings(RequestNetworkSettings::decode_versioned(stream, protocol)?)));
+
+        registry
+    }
+
+    pub fn empty() -> PacketRegistry {
+        PacketRegistry{ decoders: HashMap::new(), protocol: protocol_version::LATEST }
+    }
+
+    pub fn get_protocol(&self) -> u32 {
+        self.protocol
+    }
+
+    pub fn set_protocol(&mut self, protocol: u32) {
+        self.protocol = protocol;
+    }
+
+    // Replaces the decoder if the id is already registered
+    pub fn register(&mut self, packet_id: u16, decoder: PacketDecoder) {
+        self.decoders.insert(packet_id, decoder);
+    }
+
+    pub fn is_registered(&self, packet_id: u16) -> bool {
+        self.decoders.contains_key(&packet_id)
+    }
+
+    // The stream offset must be right after the packet id
+    pub fn decode(&self, packet_id: u16, stream: &mut Stream) -> Result<Packet, ClientError> {
+        match self.decoders.get(&packet_id) {
+            Some(decoder) => decoder(stream, self.protocol).map_err(|e| ClientError::decode(format!("{} could not be decoded", BedrockPacketType::get_packet_name(packet_id)), e)),
+            None => {
+                let payload = stream.try_get_remaining().map_err(|e| ClientError::decode(format!("{} payload could not be read", BedrockPacketType::get_packet_name(packet_id)), e))?;
+                Ok(Packet::Unknown{ id: packet_id, payload })
+            }
+        }
+    }
+
+    // Decodes a single packet of a batch: packet id + body (without the length prefix)
+    pub fn decode_packet(&self, bytes: Vec<u8>) -> Result<Packet, ClientError> {
+        let mut stream = Stream::new(bytes, 0);
+        let packet_id = stream.try_get_unsigned_var_int()? as u16;
+        self.decode(packet_id, &mut stream)
+    }
+}
+
+impl Default for PacketRegistry {
+    fn default() -> PacketRegistry {
+        PacketRegistry::new()
+    }
+}
@@ -1,5 +1,9 @@
 use std::convert::TryFrom;
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
 
 #[repr(u32)]
 pub enum LoginStatus {
@@ -39,9 +43,18 @@ pub struct PlayStatus {
     pub status: u32,
 }
 
-pub fn decode(bytes: Vec<u8>) -> PlayStatus {
-    let mut stream = Stream::new(bytes, 0);
+pub fn new(status: u32) -> PlayStatus {
+    PlayStatus{ status }
+}
+
+impl BedrockPacket for PlayStatus {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::PlayStatus);
 
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_int(self.status);
+    }
 
-    PlayStatus { status: stream.get_int() }
+    fn decode(stream: &mut Stream) -> Result<PlayStatus, ClientError> {
+        Ok(PlayStatus { status: stream.try_get_int()? })
+    }
 }
\ No newline at end of file
@@ -0,0 +1,196 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::protocol_version::{LATEST, V1_21_40, V1_21_50};
+use crate::protocol::game::types::vector::{Vec2, Vec3};
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+
+// Bit indices of `input_data`
+pub const INPUT_ASCEND: u32 = 0;
+pub const INPUT_DESCEND: u32 = 1;
+pub const INPUT_JUMP_DOWN: u32 = 3;
+pub const INPUT_SPRINT_DOWN: u32 = 4;
+pub const INPUT_JUMPING: u32 = 6;
+pub const INPUT_SNEAKING: u32 = 8;
+pub const INPUT_SNEAK_DOWN: u32 = 9;
+pub const INPUT_UP: u32 = 10;
+pub const INPUT_DOWN: u32 = 11;
+pub const INPUT_LEFT: u32 = 12;
+pub const INPUT_RIGHT: u32 = 13;
+pub const INPUT_WANT_UP: u32 = 16;
+pub const INPUT_WANT_DOWN: u32 = 17;
+pub const INPUT_SPRINTING: u32 = 20;
+pub const INPUT_START_SPRINTING: u32 = 25;
+pub const INPUT_STOP_SPRINTING: u32 = 26;
+pub const INPUT_START_SNEAKING: u32 = 27;
+pub const INPUT_STOP_SNEAKING: u32 = 28;
+pub const INPUT_START_JUMPING: u32 = 31;
+pub const INPUT_PERFORM_ITEM_INTERACTION: u32 = 34;
+pub const INPUT_PERFORM_BLOCK_ACTIONS: u32 = 35;
+pub const INPUT_PERFORM_ITEM_STACK_REQUEST: u32 = 36;
+pub const INPUT_HANDLED_TELEPORT: u32 = 37;
+pub const INPUT_CLIENT_PREDICTED_VEHICLE: u32 = 45;
+pub const INPUT_HORIZONTAL_COLLISION: u32 = 49;
+pub const INPUT_VERTICAL_COLLISION: u32 = 50;
+pub const INPUT_JUMP_PRESSED_RAW: u32 = 60;
+pub const INPUT_JUMP_CURRENT_RAW: u32 = 61;
+pub const INPUT_SNEAK_PRESSED_RAW: u32 = 63;
+pub const INPUT_SNEAK_CURRENT_RAW: u32 = 64;
+
+pub const INPUT_MODE_MOUSE: u32 = 1;
+pub const INPUT_MODE_TOUCH: u32 = 2;
+pub const INPUT_MODE_GAME_PAD: u32 = 3;
+
+pub const PLAY_MODE_NORMAL: u32 = 0;
+pub const PLAY_MODE_REALITY: u32 = 4;
+
+pub const INTERACTION_MODEL_TOUCH: u32 = 0;
+pub const INTERACTION_MODEL_CROSSHAIR: u32 = 1;
+pub const INTERACTION_MODEL_CLASSIC: u32 = 2;
+
+// Flags followed by data this packet does not model (item interactions, block actions, stack requests, vehicles)
+const UNSUPPORTED_INPUTS: u128 = 1 << INPUT_PERFORM_ITEM_INTERACTION | 1 << INPUT_PERFORM_BLOCK_ACTIONS | 1 << INPUT_PERFORM_ITEM_STACK_REQUEST | 1 << INPUT_CLIENT_PREDICTED_VEHICLE;
+// The raw jump and sneak flags were added in 1.21.50
+const RAW_INPUTS: u128 = 1 << INPUT_JUMP_PRESSED_RAW | 1 << INPUT_JUMP_CURRENT_RAW | 1 << INPUT_SNEAK_PRESSED_RAW | 1 << INPUT_SNEAK_CURRENT_RAW;
+
+// Before 1.21.50 `input_data` is a var long, the flags that do not fit or do not exist yet are dropped
+fn input_mask(protocol: u32) -> u128 {
+    if protocol >= V1_21_50 {
+        u128::MAX
+    } else {
+        u64::MAX as u128 & !RAW_INPUTS
+    }
+}
+
+pub struct PlayerAuthInput {
+    pub pitch: f32,
+    pub yaw: f32,
+    pub position: Vec3, // eye position, 1.62 above the feet
+    pub move_vector: Vec2,
+    pub head_yaw: f32,
+    pub input_data: u128, // one bit per INPUT_*
+    pub input_mode: u32,
+    pub play_mode: u32,
+    pub interaction_model: u32,
+    pub gaze_direction: Vec3, // before 1.21.40, only for PLAY_MODE_REALITY
+    pub interact_rotation: Vec2, // since 1.21.40
+    pub tick: u64,
+    pub delta: Vec3,
+    pub analogue_move_vector: Vec2,
+    pub camera_orientation: Vec3, // since 1.21.40
+    pub raw_move_vector: Vec2 // since 1.21.50
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn new(pitch: f32, yaw: f32, position: Vec3, move_vector: Vec2, head_yaw: f32, input_data: u128, input_mode: u32, play_mode: u32, interaction_model: u32, tick: u64, delta: Vec3) -> PlayerAuthInput {
+    PlayerAuthInput{
+        pitch,
+        yaw,
+        position,
+        move_vector,
+        head_yaw,
+        input_data,
+        input_mode,
+        play_mode,
+        interaction_model,
+        gaze_direction: Vec3::default(),
+        interact_rotation: Vec2::new(pitch, head_yaw),
+        tick,
+        delta,
+        analogue_move_vector: move_vector,
+        camera_orientation: Vec3::default(),
+        raw_move_vector: move_vector
+    }
+}
+
+impl PlayerAuthInput {
+    pub fn has_input(&self, input: u32) -> bool {
+        self.input_data & (1 << input) != 0
+    }
+}
+
+impl BedrockPacket for PlayerAuthInput {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::PlayerAuthInput);
+
+    fn encode(&self, stream: &mut Stream) {
+        self.encode_versioned(stream, LATEST);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<PlayerAuthInput, ClientError> {
+        PlayerAuthInput::decode_versioned(stream, LATEST)
+    }
+
+    fn encode_versioned(&self, stream: &mut Stream, protocol: u32) {
+        stream.put_l_float(self.pitch);
+        stream.put_l_float(self.yaw);
+        self.position.write(stream);
+        self.move_vector.write(stream);
+        stream.put_l_float(self.head_yaw);
+        // a var int for up to 64 flags, a bit set with the same encoding once there are more.
+        // The unsupported flags are left out, the server would read data after them that is not there.
+        let mut input_data = self.input_data & !UNSUPPORTED_INPUTS & input_mask(protocol);
+        while input_data >= 0x80 {
+            stream.put_byte((input_data as u8 & 0x7f) | 0x80);
+            input_data >>= 7;
+        }
+        stream.put_byte(input_data as u8);
+        stream.put_unsigned_var_int(self.input_mode);
+        stream.put_unsigned_var_int(self.play_mode);
+        stream.put_unsigned_var_int(self.interaction_model);
+        if protocol >= V1_21_40 {
+            self.interact_rotation.write(stream);
+        } else if self.play_mode == PLAY_MODE_REALITY {
+            self.gaze_direction.write(stream);
+        }
+        stream.put_unsigned_var_long(self.tick);
+        self.delta.write(stream);
+        self.analogue_move_vector.write(stream);
+        if protocol >= V1_21_40 {
+            self.camera_orientation.write(stream);
+        }
+        if protocol >= V1_21_50 {
+            self.raw_move_vector.write(stream);
+        }
+    }
+
+    fn decode_versioned(stream: &mut Stream, protocol: u32) -> Result<PlayerAuthInput, ClientError> {
+        let pitch = stream.try_get_l_float()?;
+        let yaw = stream.try_get_l_float()?;
+        let position = Vec3::read(stream)?;
+        let move_vector = Vec2::read(stream)?;
+        let head_yaw = stream.try_get_l_float()?;
+        let mut input_data = 0u128;
+        let mut shift = 0;
+        loop {
+            let byte = stream.try_get_byte()?;
+            input_data |= ((byte & 0x7f) as u128) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 || shift >= 128 {
+                break;
+            }
+        }
+        input_data &= input_mask(protocol);
+        let input_mode = stream.try_get_unsigned_var_int()?;
+        let play_mode = stream.try_get_unsigned_var_int()?;
+        let interaction_model = stream.try_get_unsigned_var_int()?;
+        let mut gaze_direction = Vec3::default();
+        let mut interact_rotation = Vec2::default();
+        if protocol >= V1_21_40 {
+            interact_rotation = Vec2::read(stream)?;
+        } else if play_mode == PLAY_MODE_REALITY {
+            gaze_direction = Vec3::read(stream)?;
+        }
+        let tick = stream.try_get_unsigned_var_long()?;
+        let delta = Vec3::read(stream)?;
+
+        if input_data & UNSUPPORTED_INPUTS != 0 {
+            return Err(ClientError::Decode("PlayerAuthInput with item interaction, block action, item stack request or vehicle data is not supported".to_string(), None));
+        }
+        let analogue_move_vector = Vec2::read(stream)?;
+        let camera_orientation = if protocol >= V1_21_40 { Vec3::read(stream)? } else { Vec3::default() };
+        let raw_move_vector = if protocol >= V1_21_50 { Vec2::read(stream)? } else { Vec2::default() };
+
+        Ok(PlayerAuthInput{ pitch, yaw, position, move_vector, head_yaw, input_data, input_mode, play_mode, interaction_model, gaze_direction, interact_rotation, tick, delta, analogue_move_vector, camera_orientation, raw_move_vector })
+    }
+}
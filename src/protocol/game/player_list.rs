@@ -0,0 +1,101 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::serializer::stream_helper::{get_string, put_string, StreamReader, get_uuid};
+use crate::protocol::game::types::skin_data::SkinData;
+use uuid::Uuid;
+
+pub const TYPE_ADD: u8 = 0;
+pub const TYPE_REMOVE: u8 = 1;
+
+// Removals only carry the UUID, the other fields keep their defaults
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PlayerListEntry {
+    pub uuid: Uuid,
+    pub actor_unique_id: i64,
+    pub username: String,
+    pub xuid: String, // empty for players that are not signed in with Xbox Live
+    pub platform_chat_id: String,
+    pub build_platform: i32, // DEVICE_* of client_data_options
+    pub skin: SkinData,
+    pub is_teacher: bool,
+    pub is_host: bool,
+    pub is_sub_client: bool,
+    pub skin_trusted: bool
+}
+
+impl PlayerListEntry {
+    pub fn removal(uuid: Uuid) -> PlayerListEntry {
+        PlayerListEntry{ uuid, ..PlayerListEntry::default() }
+    }
+}
+
+pub struct PlayerList {
+    pub list_type: u8,
+    pub entries: Vec<PlayerListEntry>
+}
+
+pub fn new(list_type: u8, entries: Vec<PlayerListEntry>) -> PlayerList {
+    PlayerList{ list_type, entries }
+}
+
+impl BedrockPacket for PlayerList {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::PlayerList);
+
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_byte(self.list_type);
+        stream.put_unsigned_var_int(self.entries.len() as u32);
+        for entry in &self.entries {
+            stream.put(entry.uuid.as_bytes().to_vec());
+            if self.list_type == TYPE_ADD {
+                stream.put_var_long(entry.actor_unique_id);
+                put_string(stream, &entry.username);
+                put_string(stream, &entry.xuid);
+                put_string(stream, &entry.platform_chat_id);
+                stream.put_l_int(entry.build_platform as u32);
+                entry.skin.write(stream);
+                stream.put_bool(entry.is_teacher);
+                stream.put_bool(entry.is_host);
+                stream.put_bool(entry.is_sub_client);
+            }
+        }
+        // the trusted flags of the skins follow after all entries
+        if self.list_type == TYPE_ADD {
+            for entry in &self.entries {
+                stream.put_bool(entry.skin_trusted);
+            }
+        }
+    }
+
+    fn decode(stream: &mut Stream) -> Result<PlayerList, ClientError> {
+        let list_type = stream.try_get_byte()?;
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut entries = Vec::new();
+        for _ in 0..count {
+            let uuid = get_uuid(stream)?;
+            if list_type != TYPE_ADD {
+                entries.push(PlayerListEntry::removal(uuid));
+                continue;
+            }
+            let actor_unique_id = stream.try_get_var_long()?;
+            let username = get_string(stream)?;
+            let xuid = get_string(stream)?;
+            let platform_chat_id = get_string(stream)?;
+            let build_platform = stream.try_get_l_int()? as i32;
+            let skin = SkinData::read(stream)?;
+            let is_teacher = stream.try_get_bool()?;
+            let is_host = stream.try_get_bool()?;
+            let is_sub_client = stream.try_get_bool()?;
+
+            entries.push(PlayerListEntry{ uuid, actor_unique_id, username, xuid, platform_chat_id, build_platform, skin, is_teacher, is_host, is_sub_client, skin_trusted: false });
+        }
+        if list_type == TYPE_ADD {
+            for entry in &mut entries {
+                entry.skin_trusted = stream.try_get_bool()?;
+            }
+        }
+
+        Ok(PlayerList{ list_type, entries })
+    }
+}
@@ -0,0 +1,42 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::serializer::stream_helper::{get_string, put_string, StreamReader, get_uuid};
+use crate::protocol::game::types::skin_data::SkinData;
+use uuid::Uuid;
+
+// A player changed its skin
+pub struct PlayerSkin {
+    pub uuid: Uuid,
+    pub skin: SkinData,
+    pub new_skin_name: String,
+    pub old_skin_name: String,
+    pub skin_trusted: bool
+}
+
+pub fn new(uuid: Uuid, skin: SkinData) -> PlayerSkin {
+    PlayerSkin{ uuid, skin, new_skin_name: String::new(), old_skin_name: String::new(), skin_trusted: false }
+}
+
+impl BedrockPacket for PlayerSkin {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::PlayerSkin);
+
+    fn encode(&self, stream: &mut Stream) {
+        stream.put(self.uuid.as_bytes().to_vec());
+        self.skin.write(stream);
+        put_string(stream, &self.new_skin_name);
+        put_string(stream, &self.old_skin_name);
+        stream.put_bool(self.skin_trusted);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<PlayerSkin, ClientError> {
+        let uuid = get_uuid(stream)?;
+        let skin = SkinData::read(stream)?;
+        let new_skin_name = get_string(stream)?;
+        let old_skin_name = get_string(stream)?;
+        let skin_trusted = stream.try_get_bool()?;
+
+        Ok(PlayerSkin{ uuid, skin, new_skin_name, old_skin_name, skin_trusted })
+    }
+}
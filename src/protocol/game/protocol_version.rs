@@ -0,0 +1,49 @@
+// Bedrock protocol numbers of the game versions this client can speak
+pub const V1_20_80: u32 = 671;
+pub const V1_21_0: u32 = 685;
+pub const V1_21_2: u32 = 686;
+pub const V1_21_20: u32 = 712;
+pub const V1_21_30: u32 = 729;
+pub const V1_21_40: u32 = 748;
+pub const V1_21_50: u32 = 766;
+
+pub const LATEST: u32 = V1_21_50;
+
+// Passed as client version to `client::create` to take the version from the server's unconnected pong
+pub const AUTO: &str = "auto";
+
+// game version => protocol, newest first
+pub const PROTOCOL_VERSIONS: [(&str, u32); 19] = [
+    ("1.21.51", V1_21_50),
+    ("1.21.50", V1_21_50),
+    ("1.21.44", V1_21_40),
+    ("1.21.43", V1_21_40),
+    ("1.21.42", V1_21_40),
+    ("1.21.41", V1_21_40),
+    ("1.21.40", V1_21_40),
+    ("1.21.31", V1_21_30),
+    ("1.21.30", V1_21_30),
+    ("1.21.23", V1_21_20),
+    ("1.21.22", V1_21_20),
+    ("1.21.21", V1_21_20),
+    ("1.21.20", V1_21_20),
+    ("1.21.3", V1_21_2),
+    ("1.21.2", V1_21_2),
+    ("1.21.1", V1_21_0),
+    ("1.21.0", V1_21_0),
+    ("1.20.81", V1_20_80),
+    ("1.20.80", V1_20_80)
+];
+
+pub fn from_game_version(game_version: &str) -> Option<u32> {
+    PROTOCOL_VERSIONS.iter().find(|(version, _)| *version == game_version).map(|(_, protocol)| *protocol)
+}
+
+// Newest game version that uses the protocol
+pub fn game_version(protocol: u32) -> Option<&'static str> {
+    PROTOCOL_VERSIONS.iter().find(|(_, p)| *p == protocol).map(|(version, _)| *version)
+}
+
+pub fn is_supported(protocol: u32) -> bool {
+    game_version(protocol).is_some()
+}
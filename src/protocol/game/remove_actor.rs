@@ -0,0 +1,27 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+
+pub struct RemoveActor {
+    pub actor_unique_id: i64
+}
+
+pub fn new(actor_unique_id: i64) -> RemoveActor {
+    RemoveActor{ actor_unique_id }
+}
+
+impl BedrockPacket for RemoveActor {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::RemoveActor);
+
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_var_long(self.actor_unique_id);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<RemoveActor, ClientError> {
+        let actor_unique_id = stream.try_get_var_long()?;
+
+        Ok(RemoveActor{ actor_unique_id })
+    }
+}
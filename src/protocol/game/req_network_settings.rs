@@ -1,5 +1,8 @@
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
 use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
 
 pub struct RequestNetworkSettings {
     protocol_version: u32
@@ -9,16 +12,14 @@ pub fn new(protocol_version: u32) -> RequestNetworkSettings {
     RequestNetworkSettings{ protocol_version }
 }
 
-impl RequestNetworkSettings {
-    pub fn encode(&self) -> Vec<u8> {
-        let mut stream = Stream::new(Vec::new(), 0);
-        stream.put_unsigned_var_int(BedrockPacketType::get_byte(BedrockPacketType::RequestNetworkSettings) as u32);
+impl BedrockPacket for RequestNetworkSettings {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::RequestNetworkSettings);
+
+    fn encode(&self, stream: &mut Stream) {
         stream.put_int(self.protocol_version);
+    }
 
-        let mut main_stream = Stream::new(Vec::new(), 0);
-        main_stream.put_byte(0xfe);
-        main_stream.put_unsigned_var_int(stream.get_buffer().len() as u32);
-        main_stream.put(stream.get_buffer());
-        main_stream.get_buffer()
+    fn decode(stream: &mut Stream) -> Result<RequestNetworkSettings, ClientError> {
+        Ok(RequestNetworkSettings{ protocol_version: stream.try_get_int()? })
     }
 }
\ No newline at end of file
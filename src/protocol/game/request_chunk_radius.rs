@@ -1,5 +1,8 @@
+use crate::protocol::game::bedrock_packet::BedrockPacket;
 use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
 
 pub struct RequestChunkRadius {
     radius: i32,
@@ -10,18 +13,18 @@ pub fn new(radius: i32, max_radius: u8) -> RequestChunkRadius {
     RequestChunkRadius{ radius, max_radius }
 }
 
-impl RequestChunkRadius {
-    pub fn encode(&mut self) -> Vec<u8> {
-        let mut stream = Stream::new(Vec::new(), 0);
-        stream.put_unsigned_var_int(BedrockPacketType::get_byte(BedrockPacketType::RequestChunkRadius) as u32);
+impl BedrockPacket for RequestChunkRadius {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::RequestChunkRadius);
 
+    fn encode(&self, stream: &mut Stream) {
         stream.put_var_int(self.radius);
         stream.put_byte(self.max_radius);
+    }
 
-        let mut compress_stream = Stream::new(Vec::new(), 0);
-        compress_stream.put_unsigned_var_int(stream.get_buffer().len() as u32);
-        compress_stream.put(stream.get_buffer());
+    fn decode(stream: &mut Stream) -> Result<RequestChunkRadius, ClientError> {
+        let radius = stream.try_get_var_int()?;
+        let max_radius = stream.try_get_byte()?;
 
-        compress_stream.get_buffer()
+        Ok(RequestChunkRadius{ radius, max_radius })
     }
 }
@@ -0,0 +1,39 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::serializer::stream_helper::{StreamReader, get_string};
+
+pub struct ResourcePackChunkData {
+    pub pack_id: String,
+    pub chunk_index: u32,
+    pub offset: u64, // byte offset of the chunk in the pack
+    pub data: Vec<u8>
+}
+
+pub fn new(pack_id: String, chunk_index: u32, offset: u64, data: Vec<u8>) -> ResourcePackChunkData {
+    ResourcePackChunkData{ pack_id, chunk_index, offset, data }
+}
+
+impl BedrockPacket for ResourcePackChunkData {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::ResourcePackChunkData);
+
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_unsigned_var_int(self.pack_id.len() as u32);
+        stream.put(self.pack_id.clone().into_bytes());
+        stream.put_l_int(self.chunk_index);
+        stream.put_l_long(self.offset as i64);
+        stream.put_unsigned_var_int(self.data.len() as u32);
+        stream.put(self.data.clone());
+    }
+
+    fn decode(stream: &mut Stream) -> Result<ResourcePackChunkData, ClientError> {
+        let pack_id = get_string(stream)?;
+        let chunk_index = stream.try_get_l_int()?;
+        let offset = stream.try_get_l_long()? as u64;
+        let length = stream.try_get_unsigned_var_int()?;
+        let data = stream.try_get(length)?;
+
+        Ok(ResourcePackChunkData{ pack_id, chunk_index, offset, data })
+    }
+}
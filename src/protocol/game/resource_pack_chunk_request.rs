@@ -0,0 +1,31 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::serializer::stream_helper::{StreamReader, get_string};
+
+pub struct ResourcePackChunkRequest {
+    pub pack_id: String,
+    pub chunk_index: u32
+}
+
+pub fn new(pack_id: String, chunk_index: u32) -> ResourcePackChunkRequest {
+    ResourcePackChunkRequest{ pack_id, chunk_index }
+}
+
+impl BedrockPacket for ResourcePackChunkRequest {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::ResourcePackChunkRequest);
+
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_unsigned_var_int(self.pack_id.len() as u32);
+        stream.put(self.pack_id.clone().into_bytes());
+        stream.put_l_int(self.chunk_index);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<ResourcePackChunkRequest, ClientError> {
+        let pack_id = get_string(stream)?;
+        let chunk_index = stream.try_get_l_int()?;
+
+        Ok(ResourcePackChunkRequest{ pack_id, chunk_index })
+    }
+}
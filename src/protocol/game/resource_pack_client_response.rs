@@ -1,6 +1,8 @@
+use crate::protocol::game::bedrock_packet::BedrockPacket;
 use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
 use binary_utils::binary::Stream;
-use uuid::Uuid;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::{StreamReader, get_string};
 
 pub const NONE: u8 = 0;
 pub const REFUSED: u8 = 1;
@@ -9,32 +11,36 @@ pub const HAVE_ALL_PACKS: u8 = 3;
 pub const COMPLETED: u8 = 4;
 
 pub struct ResourcePackClientResponse {
-    status: u8,
-    pack_ids: Vec<Uuid>
+    pub status: u8,
+    pub pack_ids: Vec<String> // "uuid_version"
 }
 
-pub fn new(status: u8, pack_ids: Vec<Uuid>) -> ResourcePackClientResponse {
+pub fn new(status: u8, pack_ids: Vec<String>) -> ResourcePackClientResponse {
     ResourcePackClientResponse{ status, pack_ids }
 }
 
-impl ResourcePackClientResponse {
-    pub fn encode(&mut self) -> Vec<u8> {
-        let mut stream = Stream::new(Vec::new(), 0);
-        stream.put_unsigned_var_int(BedrockPacketType::get_byte(BedrockPacketType::ResourcePackClientResponse) as u32);
+impl BedrockPacket for ResourcePackClientResponse {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::ResourcePackClientResponse);
 
+    fn encode(&self, stream: &mut Stream) {
         stream.put_byte(self.status);
         stream.put_l_short(self.pack_ids.len() as u16);
 
         for pack_id in &self.pack_ids {
-            let pack_id_as_bytes = pack_id.clone().into_bytes();
-            stream.put_unsigned_var_int(pack_id_as_bytes.len() as u32);
-            stream.put(Vec::from(pack_id_as_bytes));
+            stream.put_unsigned_var_int(pack_id.len() as u32);
+            stream.put(pack_id.clone().into_bytes());
         }
+    }
+
+    fn decode(stream: &mut Stream) -> Result<ResourcePackClientResponse, ClientError> {
+        let status = stream.try_get_byte()?;
+        let count = stream.try_get_l_short()?;
 
-        let mut compress_stream = Stream::new(Vec::new(), 0);
-        compress_stream.put_unsigned_var_int(stream.get_buffer().len() as u32);
-        compress_stream.put(stream.get_buffer());
+        let mut pack_ids = Vec::new();
+        for _ in 0..count {
+            pack_ids.push(get_string(stream)?);
+        }
 
-        compress_stream.get_buffer()
+        Ok(ResourcePackClientResponse{ status, pack_ids })
     }
 }
\ No newline at end of file
@@ -0,0 +1,58 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::serializer::stream_helper::{StreamReader, get_string};
+
+pub const TYPE_INVALID: u8 = 0;
+pub const TYPE_ADDON: u8 = 1;
+pub const TYPE_CACHED: u8 = 2;
+pub const TYPE_COPY_PROTECTED: u8 = 3;
+pub const TYPE_BEHAVIOURS: u8 = 4;
+pub const TYPE_PERSONA_PIECE: u8 = 5;
+pub const TYPE_RESOURCES: u8 = 6;
+pub const TYPE_SKINS: u8 = 7;
+pub const TYPE_WORLD_TEMPLATE: u8 = 8;
+
+pub struct ResourcePackDataInfo {
+    pub pack_id: String, // "uuid" or "uuid_version"
+    pub max_chunk_size: u32,
+    pub chunk_count: u32,
+    pub compressed_pack_size: u64,
+    pub sha256: Vec<u8>,
+    pub is_premium: bool,
+    pub pack_type: u8
+}
+
+pub fn new(pack_id: String, max_chunk_size: u32, chunk_count: u32, compressed_pack_size: u64, sha256: Vec<u8>, is_premium: bool, pack_type: u8) -> ResourcePackDataInfo {
+    ResourcePackDataInfo{ pack_id, max_chunk_size, chunk_count, compressed_pack_size, sha256, is_premium, pack_type }
+}
+
+impl BedrockPacket for ResourcePackDataInfo {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::ResourcePackDataInfo);
+
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_unsigned_var_int(self.pack_id.len() as u32);
+        stream.put(self.pack_id.clone().into_bytes());
+        stream.put_l_int(self.max_chunk_size);
+        stream.put_l_int(self.chunk_count);
+        stream.put_l_long(self.compressed_pack_size as i64);
+        stream.put_unsigned_var_int(self.sha256.len() as u32);
+        stream.put(self.sha256.clone());
+        stream.put_bool(self.is_premium);
+        stream.put_byte(self.pack_type);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<ResourcePackDataInfo, ClientError> {
+        let pack_id = get_string(stream)?;
+        let max_chunk_size = stream.try_get_l_int()?;
+        let chunk_count = stream.try_get_l_int()?;
+        let compressed_pack_size = stream.try_get_l_long()? as u64;
+        let length = stream.try_get_unsigned_var_int()?;
+        let sha256 = stream.try_get(length)?;
+        let is_premium = stream.try_get_bool()?;
+        let pack_type = stream.try_get_byte()?;
+
+        Ok(ResourcePackDataInfo{ pack_id, max_chunk_size, chunk_count, compressed_pack_size, sha256, is_premium, pack_type })
+    }
+}
@@ -1,7 +1,89 @@
-pub struct ResourcePacksInfo {
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::types::experiments::Experiments;
+use crate::protocol::game::serializer::stream_helper::{StreamReader, get_string};
 
+pub struct ResourcePackStack {
+    pub must_accept: bool,
+    pub behaviour_packs: Vec<ResourcePackStackEntry>,
+    pub resource_packs: Vec<ResourcePackStackEntry>,
+    pub base_game_version: String,
+    pub experiments: Experiments,
+    pub use_vanilla_editor_packs: bool
 }
 
-pub fn decode(_bytes: Vec<u8>) -> ResourcePacksInfo {
-    ResourcePacksInfo { }
-}
\ No newline at end of file
+// Order in the stack is the order the packs are applied in
+pub struct ResourcePackStackEntry {
+    pub pack_id: String,
+    pub version: String,
+    pub sub_pack_name: String
+}
+
+pub fn new(must_accept: bool, behaviour_packs: Vec<ResourcePackStackEntry>, resource_packs: Vec<ResourcePackStackEntry>, base_game_version: String, experiments: Experiments, use_vanilla_editor_packs: bool) -> ResourcePackStack {
+    ResourcePackStack{ must_accept, behaviour_packs, resource_packs, base_game_version, experiments, use_vanilla_editor_packs }
+}
+
+impl ResourcePackStackEntry {
+    pub fn new(pack_id: String, version: String, sub_pack_name: String) -> ResourcePackStackEntry {
+        ResourcePackStackEntry{ pack_id, version, sub_pack_name }
+    }
+
+    fn write(&self, stream: &mut Stream) {
+        stream.put_unsigned_var_int(self.pack_id.len() as u32);
+        stream.put(self.pack_id.clone().into_bytes());
+        stream.put_unsigned_var_int(self.version.len() as u32);
+        stream.put(self.version.clone().into_bytes());
+        stream.put_unsigned_var_int(self.sub_pack_name.len() as u32);
+        stream.put(self.sub_pack_name.clone().into_bytes());
+    }
+
+    fn read(stream: &mut Stream) -> Result<ResourcePackStackEntry, ClientError> {
+        let pack_id = get_string(stream)?;
+        let version = get_string(stream)?;
+        let sub_pack_name = get_string(stream)?;
+
+        Ok(ResourcePackStackEntry{ pack_id, version, sub_pack_name })
+    }
+}
+
+impl BedrockPacket for ResourcePackStack {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::ResourcePackStack);
+
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_bool(self.must_accept);
+        stream.put_unsigned_var_int(self.behaviour_packs.len() as u32);
+        for entry in &self.behaviour_packs {
+            entry.write(stream);
+        }
+        stream.put_unsigned_var_int(self.resource_packs.len() as u32);
+        for entry in &self.resource_packs {
+            entry.write(stream);
+        }
+        stream.put_unsigned_var_int(self.base_game_version.len() as u32);
+        stream.put(self.base_game_version.clone().into_bytes());
+        self.experiments.write(stream);
+        stream.put_bool(self.use_vanilla_editor_packs);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<ResourcePackStack, ClientError> {
+        let must_accept = stream.try_get_bool()?;
+
+        let mut count = stream.try_get_unsigned_var_int()?;
+        let mut behaviour_packs = Vec::new();
+        for _ in 0..count {
+            behaviour_packs.push(ResourcePackStackEntry::read(stream)?);
+        }
+        count = stream.try_get_unsigned_var_int()?;
+        let mut resource_packs = Vec::new();
+        for _ in 0..count {
+            resource_packs.push(ResourcePackStackEntry::read(stream)?);
+        }
+        let base_game_version = get_string(stream)?;
+        let experiments = Experiments::read(stream)?;
+        let use_vanilla_editor_packs = stream.try_get_bool()?;
+
+        Ok(ResourcePackStack{ must_accept, behaviour_packs, resource_packs, base_game_version, experiments, use_vanilla_editor_packs })
+    }
+}
@@ -1,12 +1,19 @@
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::protocol_version::{LATEST, V1_21_20, V1_21_30, V1_21_50};
+use crate::protocol::game::serializer::stream_helper::{StreamReader, get_string, get_uuid};
 use uuid::Uuid;
 
 pub struct ResourcePacksInfo {
     pub must_accept: bool,
     pub has_addons: bool,
     pub has_scripts: bool,
-    pub world_template_id: Uuid,
+    pub force_server_packs: bool, // before 1.21.30
+    pub world_template_id: Uuid, // since 1.21.50
     pub world_template_version: String,
+    pub behaviour_packs: Vec<ResourcePack>, // before 1.21.30
     pub resource_packs: Vec<ResourcePack>
 
 }
@@ -24,38 +31,150 @@ pub struct ResourcePack {
     pub cdn_url: String
 }
 
-pub fn decode(bytes: Vec<u8>) -> ResourcePacksInfo {
-    let mut stream = Stream::new(bytes, 0);
-
-    let must_accept = stream.get_bool();
-    let has_addons = stream.get_bool();
-    let has_scripts = stream.get_bool();
-
-    let world_template_id = Uuid::from_slice(&stream.get(16).unwrap()).unwrap();
-    let length = stream.get_unsigned_var_int();
-    let world_template_version = String::from_utf8(stream.get(length).unwrap()).unwrap();
-
-    let resource_pack_count = stream.get_l_short();
-    let mut resource_packs = Vec::new();
-    for _ in 0..resource_pack_count {
-        let uuid = Uuid::from_slice(&stream.get(16).unwrap()).unwrap();
-        let mut length = stream.get_unsigned_var_int();
-        let version = String::from_utf8(stream.get(length).unwrap()).unwrap();
-        let size_bytes = stream.get_l_long();
-        length = stream.get_unsigned_var_int();
-        let encryption_key = String::from_utf8(stream.get(length).unwrap()).unwrap();
-        length = stream.get_unsigned_var_int();
-        let sub_pack_name = String::from_utf8(stream.get(length).unwrap()).unwrap();
-        length = stream.get_unsigned_var_int();
-        let content_id = String::from_utf8(stream.get(length).unwrap()).unwrap();
-        let has_scripts = stream.get_bool();
-        let is_addon_pack = stream.get_bool();
-        let is_rtx_capable = stream.get_bool();
-        length = stream.get_unsigned_var_int();
-        let cdn_url = String::from_utf8(stream.get(length).unwrap()).unwrap();
-
-        resource_packs.push(ResourcePack{ uuid, version, size_bytes, encryption_key, sub_pack_name, content_id, has_scripts, is_addon_pack, is_rtx_capable, cdn_url });
+impl ResourcePack {
+    fn write(&self, stream: &mut Stream, protocol: u32, is_behaviour_pack: bool) {
+        if protocol >= V1_21_50 {
+            stream.put(self.uuid.as_bytes().to_vec());
+        } else {
+            let uuid = self.uuid.to_string();
+            stream.put_unsigned_var_int(uuid.len() as u32);
+            stream.put(uuid.into_bytes());
+        }
+        stream.put_unsigned_var_int(self.version.len() as u32);
+        stream.put(self.version.clone().into_bytes());
+        stream.put_l_long(self.size_bytes);
+        stream.put_unsigned_var_int(self.encryption_key.len() as u32);
+        stream.put(self.encryption_key.clone().into_bytes());
+        stream.put_unsigned_var_int(self.sub_pack_name.len() as u32);
+        stream.put(self.sub_pack_name.clone().into_bytes());
+        stream.put_unsigned_var_int(self.content_id.len() as u32);
+        stream.put(self.content_id.clone().into_bytes());
+        stream.put_bool(self.has_scripts);
+        if protocol >= V1_21_20 {
+            stream.put_bool(self.is_addon_pack);
+        }
+        if !is_behaviour_pack {
+            stream.put_bool(self.is_rtx_capable);
+        }
+        if protocol >= V1_21_30 {
+            stream.put_unsigned_var_int(self.cdn_url.len() as u32);
+            stream.put(self.cdn_url.clone().into_bytes());
+        }
     }
 
-    ResourcePacksInfo { must_accept, has_addons, has_scripts, world_template_id, world_template_version, resource_packs }
-}
\ No newline at end of file
+    fn read(stream: &mut Stream, protocol: u32, is_behaviour_pack: bool) -> Result<ResourcePack, ClientError> {
+        let uuid = if protocol >= V1_21_50 {
+            get_uuid(stream)?
+        } else {
+            Uuid::parse_str(&get_string(stream)?).map_err(|e| ClientError::decode("Resource pack UUID is invalid", e))?
+        };
+        let version = get_string(stream)?;
+        let size_bytes = stream.try_get_l_long()?;
+        let encryption_key = get_string(stream)?;
+        let sub_pack_name = get_string(stream)?;
+        let content_id = get_string(stream)?;
+        let has_scripts = stream.try_get_bool()?;
+        let is_addon_pack = if protocol >= V1_21_20 { stream.try_get_bool()? } else { false };
+        let is_rtx_capable = if is_behaviour_pack { false } else { stream.try_get_bool()? };
+        let mut cdn_url = String::new();
+        if protocol >= V1_21_30 {
+            cdn_url = get_string(stream)?;
+        }
+
+        Ok(ResourcePack{ uuid, version, size_bytes, encryption_key, sub_pack_name, content_id, has_scripts, is_addon_pack, is_rtx_capable, cdn_url })
+    }
+}
+
+impl BedrockPacket for ResourcePacksInfo {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::ResourcePacksInfo);
+
+    fn encode(&self, stream: &mut Stream) {
+        self.encode_versioned(stream, LATEST);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<ResourcePacksInfo, ClientError> {
+        ResourcePacksInfo::decode_versioned(stream, LATEST)
+    }
+
+    fn encode_versioned(&self, stream: &mut Stream, protocol: u32) {
+        stream.put_bool(self.must_accept);
+        if protocol >= V1_21_20 {
+            stream.put_bool(self.has_addons);
+        }
+        stream.put_bool(self.has_scripts);
+
+        if protocol >= V1_21_50 {
+            stream.put(self.world_template_id.as_bytes().to_vec());
+            stream.put_unsigned_var_int(self.world_template_version.len() as u32);
+            stream.put(self.world_template_version.clone().into_bytes());
+        }
+
+        if protocol < V1_21_30 {
+            stream.put_bool(self.force_server_packs);
+            stream.put_l_short(self.behaviour_packs.len() as u16);
+            for behaviour_pack in &self.behaviour_packs {
+                behaviour_pack.write(stream, protocol, true);
+            }
+        }
+
+        stream.put_l_short(self.resource_packs.len() as u16);
+        for resource_pack in &self.resource_packs {
+            resource_pack.write(stream, protocol, false);
+        }
+
+        // before 1.21.30 the CDN urls were sent as a separate pack id => url list
+        if protocol < V1_21_30 {
+            let cdn_urls: Vec<&ResourcePack> = self.resource_packs.iter().filter(|resource_pack| !resource_pack.cdn_url.is_empty()).collect();
+            stream.put_unsigned_var_int(cdn_urls.len() as u32);
+            for resource_pack in cdn_urls {
+                let pack_id = format!("{}_{}", resource_pack.uuid, resource_pack.version);
+                stream.put_unsigned_var_int(pack_id.len() as u32);
+                stream.put(pack_id.into_bytes());
+                stream.put_unsigned_var_int(resource_pack.cdn_url.len() as u32);
+                stream.put(resource_pack.cdn_url.clone().into_bytes());
+            }
+        }
+    }
+
+    fn decode_versioned(stream: &mut Stream, protocol: u32) -> Result<ResourcePacksInfo, ClientError> {
+        let must_accept = stream.try_get_bool()?;
+        let has_addons = if protocol >= V1_21_20 { stream.try_get_bool()? } else { false };
+        let has_scripts = stream.try_get_bool()?;
+
+        let mut world_template_id = Uuid::nil();
+        let mut world_template_version = String::new();
+        if protocol >= V1_21_50 {
+            world_template_id = get_uuid(stream)?;
+            world_template_version = get_string(stream)?;
+        }
+
+        let mut force_server_packs = false;
+        let mut behaviour_packs = Vec::new();
+        if protocol < V1_21_30 {
+            force_server_packs = stream.try_get_bool()?;
+            let behaviour_pack_count = stream.try_get_l_short()?;
+            for _ in 0..behaviour_pack_count {
+                behaviour_packs.push(ResourcePack::read(stream, protocol, true)?);
+            }
+        }
+
+        let resource_pack_count = stream.try_get_l_short()?;
+        let mut resource_packs = Vec::new();
+        for _ in 0..resource_pack_count {
+            resource_packs.push(ResourcePack::read(stream, protocol, false)?);
+        }
+
+        if protocol < V1_21_30 {
+            let cdn_url_count = stream.try_get_unsigned_var_int()?;
+            for _ in 0..cdn_url_count {
+                let pack_id = get_string(stream)?;
+                let cdn_url = get_string(stream)?;
+                if let Some(resource_pack) = resource_packs.iter_mut().find(|resource_pack| format!("{}_{}", resource_pack.uuid, resource_pack.version) == pack_id) {
+                    resource_pack.cdn_url = cdn_url;
+                }
+            }
+        }
+
+        Ok(ResourcePacksInfo { must_accept, has_addons, has_scripts, force_server_packs, world_template_id, world_template_version, behaviour_packs, resource_packs })
+    }
+}
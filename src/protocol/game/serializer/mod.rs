@@ -1 +1,2 @@
-pub mod network_nbt_serializer;
\ No newline at end of file
+pub mod network_nbt_serializer;
+pub mod stream_helper;
\ No newline at end of file
@@ -1,3 +1,4 @@
+use crate::error::ClientError;
 use binary_utils::binary::Stream;
 use mojang_nbt::base_nbt_serializer::BaseNBTSerializer;
 
@@ -81,12 +82,122 @@ impl BaseNBTSerializer for NetworkNBTSerializer {
     }
 
     fn read_string(&mut self) -> String {
+        // the trait can not return an error, the data was already checked by `network_nbt_length`
         let len = self.binary_stream.get_unsigned_var_int();
-        String::from_utf8(self.binary_stream.get(len).expect("read_string() fn error, network_nbt_serializer (BinaryStream get() error)").to_vec()).expect("read_string() fn error, network_nbt_serializer (Vec to String error)")
+        self.binary_stream.get(len).map(|bytes| String::from_utf8_lossy(&bytes).into_owned()).unwrap_or_default()
     }
 
     fn write_string(&mut self, value: String) {
         self.binary_stream.put_unsigned_var_int(value.len() as u32);
         self.binary_stream.put(value.into_bytes());
     }
-}
\ No newline at end of file
+}
+
+const MAX_DEPTH: u32 = 512;
+
+// Walks through network NBT without building the tags, the reads of the serializer panic on broken data
+struct NBTScanner<'a> {
+    buffer: &'a [u8],
+    offset: usize
+}
+
+impl NBTScanner<'_> {
+    fn error(&self, reason: &str) -> ClientError {
+        ClientError::Decode(format!("Invalid NBT at offset {}: {}", self.offset, reason), None)
+    }
+
+    fn skip(&mut self, length: usize) -> Result<&[u8], ClientError> {
+        if length > self.buffer.len() - self.offset {
+            return Err(self.error("unexpected end of the data"));
+        }
+        self.offset += length;
+        Ok(&self.buffer[self.offset - length..self.offset])
+    }
+
+    fn byte(&mut self) -> Result<u8, ClientError> {
+        Ok(self.skip(1)?[0])
+    }
+
+    fn var_long(&mut self, max_bytes: u32) -> Result<u64, ClientError> {
+        let mut value = 0;
+        for i in 0..max_bytes {
+            let byte = self.byte()?;
+            value |= ((byte & 0x7f) as u64) << (i * 7);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(self.error("var int is too long"))
+    }
+
+    // Lengths are zigzag var ints like every other int
+    fn length(&mut self) -> Result<usize, ClientError> {
+        let value = self.var_long(5)? as u32;
+        let length = ((value >> 1) as i32) ^ -((value & 1) as i32);
+        usize::try_from(length).map_err(|_| self.error("negative length"))
+    }
+
+    fn string(&mut self) -> Result<(), ClientError> {
+        let length = self.var_long(5)? as usize;
+        if std::str::from_utf8(self.skip(length)?).is_err() {
+            return Err(self.error("string is not UTF-8"));
+        }
+        Ok(())
+    }
+
+    fn payload(&mut self, tag_type: u8, depth: u32) -> Result<(), ClientError> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("too deeply nested"));
+        }
+        match tag_type {
+            1 => { self.skip(1)?; },
+            2 => { self.skip(2)?; },
+            3 => { self.var_long(5)?; },
+            4 => { self.var_long(10)?; },
+            5 => { self.skip(4)?; },
+            6 => { self.skip(8)?; },
+            7 => {
+                let length = self.length()?;
+                self.skip(length)?;
+            },
+            8 => self.string()?,
+            9 => {
+                let item_type = self.byte()?;
+                let count = self.length()?;
+                if count > 0 && item_type == 0 {
+                    return Err(self.error("list of end tags"));
+                }
+                for _ in 0..count {
+                    self.payload(item_type, depth + 1)?;
+                }
+            },
+            10 => loop {
+                let entry_type = self.byte()?;
+                if entry_type == 0 {
+                    break;
+                }
+                self.string()?;
+                self.payload(entry_type, depth + 1)?;
+            },
+            11 => {
+                for _ in 0..self.length()? {
+                    self.var_long(5)?;
+                }
+            },
+            _ => return Err(self.error(&format!("unknown tag type {}", tag_type)))
+        }
+        Ok(())
+    }
+}
+
+// Size of the network NBT at the offset, checks the data so that reading it with the serializer can not fail
+pub fn network_nbt_length(buffer: &[u8], offset: usize) -> Result<usize, ClientError> {
+    let mut scanner = NBTScanner{ buffer, offset: offset.min(buffer.len()) };
+    let tag_type = scanner.byte()?;
+    if tag_type == 0 {
+        return Err(scanner.error("the root is an end tag"));
+    }
+    scanner.string()?;
+    scanner.payload(tag_type, 0)?;
+    Ok(scanner.offset - offset)
+}
@@ -0,0 +1,174 @@
+use crate::error::ClientError;
+use crate::protocol::game::serializer::network_nbt_serializer::{network_nbt_length, NetworkNBTSerializer};
+use binary_utils::binary::Stream;
+use mojang_nbt::base_nbt_serializer::BaseNBTSerializer;
+use mojang_nbt::tree_root::TreeRoot;
+use uuid::Uuid;
+
+// The getters of the stream panic when the data ends early, everything the server sends is read with these instead
+pub trait StreamReader {
+    fn try_get(&mut self, length: u32) -> Result<Vec<u8>, ClientError>;
+
+    fn try_get_remaining(&mut self) -> Result<Vec<u8>, ClientError>;
+
+    fn try_get_byte(&mut self) -> Result<u8, ClientError> {
+        Ok(self.try_get(1)?[0])
+    }
+
+    fn try_get_bool(&mut self) -> Result<bool, ClientError> {
+        Ok(self.try_get_byte()? != 0)
+    }
+
+    fn try_get_short(&mut self) -> Result<u16, ClientError> {
+        Ok(u16::from_be_bytes(bytes(self.try_get(2)?)))
+    }
+
+    fn try_get_l_short(&mut self) -> Result<u16, ClientError> {
+        Ok(u16::from_le_bytes(bytes(self.try_get(2)?)))
+    }
+
+    fn try_get_signed_l_short(&mut self) -> Result<i16, ClientError> {
+        Ok(i16::from_le_bytes(bytes(self.try_get(2)?)))
+    }
+
+    fn try_get_l_triad(&mut self) -> Result<i32, ClientError> {
+        let triad = self.try_get(3)?;
+        Ok(i32::from_le_bytes([triad[0], triad[1], triad[2], 0]))
+    }
+
+    fn try_get_int(&mut self) -> Result<u32, ClientError> {
+        Ok(u32::from_be_bytes(bytes(self.try_get(4)?)))
+    }
+
+    fn try_get_l_int(&mut self) -> Result<u32, ClientError> {
+        Ok(u32::from_le_bytes(bytes(self.try_get(4)?)))
+    }
+
+    fn try_get_long(&mut self) -> Result<i64, ClientError> {
+        Ok(i64::from_be_bytes(bytes(self.try_get(8)?)))
+    }
+
+    fn try_get_l_long(&mut self) -> Result<i64, ClientError> {
+        Ok(i64::from_le_bytes(bytes(self.try_get(8)?)))
+    }
+
+    fn try_get_float(&mut self) -> Result<f32, ClientError> {
+        Ok(f32::from_be_bytes(bytes(self.try_get(4)?)))
+    }
+
+    fn try_get_l_float(&mut self) -> Result<f32, ClientError> {
+        Ok(f32::from_le_bytes(bytes(self.try_get(4)?)))
+    }
+
+    fn try_get_l_double(&mut self) -> Result<f64, ClientError> {
+        Ok(f64::from_le_bytes(bytes(self.try_get(8)?)))
+    }
+
+    fn try_get_unsigned_var_long(&mut self) -> Result<u64, ClientError> {
+        let mut value = 0;
+        for i in 0..10 {
+            let byte = self.try_get_byte()?;
+            value |= ((byte & 0x7f) as u64) << (i * 7);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(ClientError::Decode("Var long is longer than 10 bytes".to_string(), None))
+    }
+
+    fn try_get_unsigned_var_int(&mut self) -> Result<u32, ClientError> {
+        let mut value = 0;
+        for i in 0..5 {
+            let byte = self.try_get_byte()?;
+            value |= ((byte & 0x7f) as u32) << (i * 7);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(ClientError::Decode("Var int is longer than 5 bytes".to_string(), None))
+    }
+
+    // Zigzag encoded
+    fn try_get_var_int(&mut self) -> Result<i32, ClientError> {
+        let value = self.try_get_unsigned_var_int()?;
+        Ok(((value >> 1) as i32) ^ -((value & 1) as i32))
+    }
+
+    fn try_get_var_long(&mut self) -> Result<i64, ClientError> {
+        let value = self.try_get_unsigned_var_long()?;
+        Ok(((value >> 1) as i64) ^ -((value & 1) as i64))
+    }
+}
+
+impl StreamReader for Stream {
+    fn try_get(&mut self, length: u32) -> Result<Vec<u8>, ClientError> {
+        let offset = self.get_offset();
+        self.get(length).map_err(|e| ClientError::decode(format!("{} bytes at offset {} could not be read", length, offset), e))
+    }
+
+    fn try_get_remaining(&mut self) -> Result<Vec<u8>, ClientError> {
+        let offset = self.get_offset();
+        self.get_remaining().map_err(|e| ClientError::decode(format!("The rest of the data at offset {} could not be read", offset), e))
+    }
+}
+
+// try_get returns exactly the requested length
+fn bytes<const N: usize>(data: Vec<u8>) -> [u8; N] {
+    let mut bytes = [0; N];
+    bytes.copy_from_slice(&data);
+    bytes
+}
+
+// Encodings that are shared by many packets
+
+// Var int length + UTF-8 bytes
+pub fn get_string(stream: &mut Stream) -> Result<String, ClientError> {
+    let length = stream.try_get_unsigned_var_int()?;
+    String::from_utf8(stream.try_get(length)?).map_err(|e| ClientError::decode("String is not UTF-8", e))
+}
+
+pub fn put_string(stream: &mut Stream, value: &str) {
+    stream.put_unsigned_var_int(value.len() as u32);
+    stream.put(value.as_bytes().to_vec());
+}
+
+pub fn get_uuid(stream: &mut Stream) -> Result<Uuid, ClientError> {
+    Uuid::from_slice(&stream.try_get(16)?).map_err(|e| ClientError::decode("UUID could not be read", e))
+}
+
+pub fn get_strings(stream: &mut Stream) -> Result<Vec<String>, ClientError> {
+    let count = stream.try_get_unsigned_var_int()?;
+    let mut values = Vec::new();
+    for _ in 0..count {
+        values.push(get_string(stream)?);
+    }
+    Ok(values)
+}
+
+pub fn put_strings(stream: &mut Stream, values: &[String]) {
+    stream.put_unsigned_var_int(values.len() as u32);
+    for value in values {
+        put_string(stream, value);
+    }
+}
+
+// Network NBT at the current offset, the stream is moved behind it
+pub fn get_nbt(stream: &mut Stream) -> Result<TreeRoot, ClientError> {
+    let bytes = get_nbt_bytes(stream)?;
+    Ok(NetworkNBTSerializer::new().read(bytes, &mut 0, 0))
+}
+
+// The same NBT without decoding it into tags, to keep it or write it back as it was
+pub fn get_nbt_bytes(stream: &mut Stream) -> Result<Vec<u8>, ClientError> {
+    let length = network_nbt_length(&stream.get_buffer(), stream.get_offset() as usize)?;
+    stream.try_get(length as u32)
+}
+
+// Rotations in degrees, sent as a byte with 256 steps per turn
+pub fn get_rotation_byte(stream: &mut Stream) -> Result<f32, ClientError> {
+    Ok(stream.try_get_byte()? as f32 * (360.0 / 256.0))
+}
+
+pub fn put_rotation_byte(stream: &mut Stream, value: f32) {
+    stream.put_byte((value / (360.0 / 256.0)).rem_euclid(256.0) as u8);
+}
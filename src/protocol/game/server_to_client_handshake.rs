@@ -1,14 +1,29 @@
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
 
 pub struct ServerToClientHandshake {
     pub jwt: Vec<u8>,
 }
 
-pub fn decode(bytes: Vec<u8>) -> ServerToClientHandshake {
-    let mut stream = Stream::new(bytes, 0);
+pub fn new(jwt: Vec<u8>) -> ServerToClientHandshake {
+    ServerToClientHandshake{ jwt }
+}
+
+impl BedrockPacket for ServerToClientHandshake {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::ServerToClientHandshake);
+
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_unsigned_var_int(self.jwt.len() as u32);
+        stream.put(self.jwt.clone());
+    }
 
-    let length = stream.get_unsigned_var_int();
-    let jwt = stream.get(length).expect("ServerToClientHandshake JWT Error");
+    fn decode(stream: &mut Stream) -> Result<ServerToClientHandshake, ClientError> {
+        let length = stream.try_get_unsigned_var_int()?;
+        let jwt = stream.try_get(length)?;
 
-    ServerToClientHandshake { jwt }
+        Ok(ServerToClientHandshake { jwt })
+    }
 }
\ No newline at end of file
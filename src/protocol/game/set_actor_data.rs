@@ -0,0 +1,39 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::types::actor_metadata::ActorMetadata;
+use crate::protocol::game::types::property_sync_data::PropertySyncData;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+
+// Only the changed metadata keys and properties are sent
+pub struct SetActorData {
+    pub actor_runtime_id: u64,
+    pub metadata: ActorMetadata,
+    pub properties: PropertySyncData,
+    pub tick: u64
+}
+
+pub fn new(actor_runtime_id: u64, metadata: ActorMetadata, tick: u64) -> SetActorData {
+    SetActorData{ actor_runtime_id, metadata, properties: PropertySyncData::default(), tick }
+}
+
+impl BedrockPacket for SetActorData {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::SetActorData);
+
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_unsigned_var_long(self.actor_runtime_id);
+        self.metadata.write(stream);
+        self.properties.write(stream);
+        stream.put_unsigned_var_long(self.tick);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<SetActorData, ClientError> {
+        let actor_runtime_id = stream.try_get_unsigned_var_long()?;
+        let metadata = ActorMetadata::read(stream)?;
+        let properties = PropertySyncData::read(stream)?;
+        let tick = stream.try_get_unsigned_var_long()?;
+
+        Ok(SetActorData{ actor_runtime_id, metadata, properties, tick })
+    }
+}
@@ -0,0 +1,27 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::types::actor_link::ActorLink;
+
+pub struct SetActorLink {
+    pub link: ActorLink
+}
+
+pub fn new(link: ActorLink) -> SetActorLink {
+    SetActorLink{ link }
+}
+
+impl BedrockPacket for SetActorLink {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::SetActorLink);
+
+    fn encode(&self, stream: &mut Stream) {
+        self.link.write(stream);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<SetActorLink, ClientError> {
+        let link = ActorLink::read(stream)?;
+
+        Ok(SetActorLink{ link })
+    }
+}
@@ -0,0 +1,34 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::types::vector::Vec3;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+
+pub struct SetActorMotion {
+    pub actor_runtime_id: u64,
+    pub motion: Vec3,
+    pub tick: u64
+}
+
+pub fn new(actor_runtime_id: u64, motion: Vec3, tick: u64) -> SetActorMotion {
+    SetActorMotion{ actor_runtime_id, motion, tick }
+}
+
+impl BedrockPacket for SetActorMotion {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::SetActorMotion);
+
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_unsigned_var_long(self.actor_runtime_id);
+        self.motion.write(stream);
+        stream.put_unsigned_var_long(self.tick);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<SetActorMotion, ClientError> {
+        let actor_runtime_id = stream.try_get_unsigned_var_long()?;
+        let motion = Vec3::read(stream)?;
+        let tick = stream.try_get_unsigned_var_long()?;
+
+        Ok(SetActorMotion{ actor_runtime_id, motion, tick })
+    }
+}
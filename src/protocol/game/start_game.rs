@@ -1,12 +1,17 @@
 use binary_utils::binary::Stream;
-use mojang_nbt::base_nbt_serializer::BaseNBTSerializer;
-use crate::protocol::game::serializer::network_nbt_serializer::NetworkNBTSerializer;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::protocol_version::LATEST;
+use crate::protocol::game::serializer::stream_helper::{get_nbt, StreamReader, get_string};
+use crate::protocol::game::types::actor_properties::ActorProperties;
 use crate::protocol::game::types::block_palette_entry::BlockPaletteEntry;
 use crate::protocol::game::types::cacheable_nbt::CacheableNBT;
 use crate::protocol::game::types::item_type_entry::ItemTypeEntry;
 use crate::protocol::game::types::level_settings::LevelSettings;
 use crate::protocol::game::types::network_permissions::NetworkPermissions;
 use crate::protocol::game::types::player_movement_settings::PlayerMovementSettings;
+use mojang_nbt::tag::compound_tag::CompoundTag;
 
 pub struct StartGame {
     pub actor_unique_id: i64,
@@ -36,114 +41,184 @@ pub struct StartGame {
     pub network_permissions: NetworkPermissions,
 }
 
-pub fn decode(bytes: Vec<u8>) -> StartGame {
-    let mut stream = Stream::new(bytes, 0);
+impl StartGame {
+    // Entity properties of the local player, SetActorData of the player refers to them by index
+    pub fn get_player_actor_properties(&self) -> Result<ActorProperties, ClientError> {
+        let root = self.player_actor_properties.get_root();
+        let compound = root.as_any().downcast_ref::<CompoundTag>().ok_or(ClientError::Decode("Player actor properties are not a compound tag".to_string(), None))?;
+        ActorProperties::from_nbt(compound)
+    }
+}
 
-    let actor_unique_id = stream.get_var_long();
-    let actor_runtime_id = stream.get_unsigned_var_long();
+impl BedrockPacket for StartGame {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::StartGame);
 
-    let player_game_mode = stream.get_var_int();
-    let player_position = vec![stream.get_l_float(), stream.get_l_float(), stream.get_l_float()];
+    fn encode(&self, stream: &mut Stream) {
+        self.encode_versioned(stream, LATEST);
+    }
 
-    let pitch = stream.get_l_float();
-    let yaw = stream.get_l_float();
+    fn decode(stream: &mut Stream) -> Result<StartGame, ClientError> {
+        StartGame::decode_versioned(stream, LATEST)
+    }
 
-    let level_settings = LevelSettings::read(&mut stream);
+    fn encode_versioned(&self, stream: &mut Stream, protocol: u32) {
+        stream.put_var_long(self.actor_unique_id);
+        stream.put_unsigned_var_long(self.actor_runtime_id);
 
-    let mut length = stream.get_unsigned_var_int();
-    let level_id = String::from_utf8(stream.get(length).unwrap()).unwrap();
+        stream.put_var_int(self.player_game_mode);
+        for coordinate in &self.player_position {
+            stream.put_l_float(*coordinate);
+        }
 
-    length = stream.get_unsigned_var_int();
-    let world_name = String::from_utf8(stream.get(length).unwrap()).unwrap();
+        stream.put_l_float(self.pitch);
+        stream.put_l_float(self.yaw);
 
-    length = stream.get_unsigned_var_int();
-    let premium_world_template_id = String::from_utf8(stream.get(length).unwrap()).unwrap();
+        self.level_settings.write(stream, protocol);
 
-    let is_trial = stream.get_bool();
+        stream.put_unsigned_var_int(self.level_id.len() as u32);
+        stream.put(self.level_id.clone().into_bytes());
 
-    let player_movement_settings = PlayerMovementSettings::read(&mut stream);
+        stream.put_unsigned_var_int(self.world_name.len() as u32);
+        stream.put(self.world_name.clone().into_bytes());
 
-    let current_tick = stream.get_l_long();
+        stream.put_unsigned_var_int(self.premium_world_template_id.len() as u32);
+        stream.put(self.premium_world_template_id.clone().into_bytes());
 
-    let enchantment_seed = stream.get_var_int();
+        stream.put_bool(self.is_trial);
 
-    let mut block_palette: Vec<BlockPaletteEntry> = vec![];
-    let palette_len = stream.get_unsigned_var_int();
-    for _ in 0..palette_len {
-        length = stream.get_unsigned_var_int();
-        let block_name = String::from_utf8(stream.get(length).unwrap()).unwrap();
+        self.player_movement_settings.write(stream);
 
-        let mut offset = stream.get_offset();
-        let mut nbt_serializer = NetworkNBTSerializer::new();
-        let nbt_root = nbt_serializer.read(stream.get_buffer(), &mut offset, 0);
-        stream.set_offset(offset);
+        stream.put_l_long(self.current_tick);
 
-        let state = Box::new(nbt_root.must_get_compound_tag().expect("StartGamePacket TreeRoot to CompoundTag conversion error"));
+        stream.put_var_int(self.enchantment_seed);
 
-        block_palette.push(BlockPaletteEntry::new(block_name, CacheableNBT::new(state)));
-    }
+        stream.put_unsigned_var_int(self.block_palette.len() as u32);
+        for block in &self.block_palette {
+            let block_name = block.get_name();
+            stream.put_unsigned_var_int(block_name.len() as u32);
+            stream.put(block_name.into_bytes());
+            block.get_states().write(stream);
+        }
+
+        stream.put_unsigned_var_int(self.item_table.len() as u32);
+        for item in &self.item_table {
+            let string_id = item.get_string_id();
+            stream.put_unsigned_var_int(string_id.len() as u32);
+            stream.put(string_id.into_bytes());
+            stream.put_l_short(item.get_numeric_id() as u16);
+            stream.put_bool(item.is_component_based());
+        }
+
+        stream.put_unsigned_var_int(self.multiplayer_correlation_id.len() as u32);
+        stream.put(self.multiplayer_correlation_id.clone().into_bytes());
+
+        stream.put_bool(self.enable_new_inventory_system);
+
+        stream.put_unsigned_var_int(self.server_software_version.len() as u32);
+        stream.put(self.server_software_version.clone().into_bytes());
 
-    let mut item_table: Vec<ItemTypeEntry> = vec![];
-    let table_len = stream.get_unsigned_var_int();
-    for _ in 0..table_len {
-        length = stream.get_unsigned_var_int();
-        let string_id = String::from_utf8(stream.get(length).unwrap()).unwrap();
-        let numeric_id = stream.get_signed_l_short();
-        let is_component_based = stream.get_bool();
+        self.player_actor_properties.write(stream);
 
-        item_table.push(ItemTypeEntry::new(string_id, numeric_id, is_component_based));
+        stream.put_l_long(self.block_palette_checksum);
 
+        stream.put(self.world_template_id.clone());
+
+        stream.put_bool(self.enable_client_side_chunk_generation);
+
+        stream.put_bool(self.block_network_ids_are_hashes);
+
+        self.network_permissions.write(stream);
     }
 
-    length = stream.get_unsigned_var_int();
-    let multiplayer_correlation_id = String::from_utf8(stream.get(length).unwrap()).unwrap();
-
-    let enable_new_inventory_system = stream.get_bool();
-
-    length = stream.get_unsigned_var_int();
-    let server_software_version = String::from_utf8(stream.get(length).unwrap()).unwrap();
-
-    let mut offset = stream.get_offset();
-    let mut nbt_serializer = NetworkNBTSerializer::new();
-    let nbt_root = nbt_serializer.read(stream.get_buffer(), &mut offset, 0);
-    stream.set_offset(offset);
-    let player_actor_properties = CacheableNBT::new(Box::new(nbt_root.must_get_compound_tag().expect("StartGamePacket TreeRoot to CompoundTag conversion error")));
-
-    let block_palette_checksum = stream.get_l_long();
-
-    let world_template_id = stream.get(16).unwrap();                // nvm for now
-
-    let enable_client_side_chunk_generation = stream.get_bool();
-
-    let block_network_ids_are_hashes = stream.get_bool();
-
-    let network_permissions = NetworkPermissions::read(&mut stream);
-
-    StartGame{
-        actor_unique_id,
-        actor_runtime_id,
-        player_game_mode,
-        player_position,
-        pitch,
-        yaw,
-        level_settings,
-        level_id,
-        world_name,
-        premium_world_template_id,
-        is_trial,
-        player_movement_settings,
-        current_tick,
-        enchantment_seed,
-        block_palette,
-        item_table,
-        multiplayer_correlation_id,
-        enable_new_inventory_system,
-        server_software_version,
-        player_actor_properties,
-        block_palette_checksum,
-        world_template_id,
-        enable_client_side_chunk_generation,
-        block_network_ids_are_hashes,
-        network_permissions,
+    fn decode_versioned(stream: &mut Stream, protocol: u32) -> Result<StartGame, ClientError> {
+        let actor_unique_id = stream.try_get_var_long()?;
+        let actor_runtime_id = stream.try_get_unsigned_var_long()?;
+
+        let player_game_mode = stream.try_get_var_int()?;
+        let player_position = vec![stream.try_get_l_float()?, stream.try_get_l_float()?, stream.try_get_l_float()?];
+
+        let pitch = stream.try_get_l_float()?;
+        let yaw = stream.try_get_l_float()?;
+
+        let level_settings = LevelSettings::read(stream, protocol)?;
+        let level_id = get_string(stream)?;
+        let world_name = get_string(stream)?;
+        let premium_world_template_id = get_string(stream)?;
+
+        let is_trial = stream.try_get_bool()?;
+
+        let player_movement_settings = PlayerMovementSettings::read(stream)?;
+
+        let current_tick = stream.try_get_l_long()?;
+
+        let enchantment_seed = stream.try_get_var_int()?;
+
+        let mut block_palette: Vec<BlockPaletteEntry> = vec![];
+        let palette_len = stream.try_get_unsigned_var_int()?;
+        for _ in 0..palette_len {
+            let block_name = get_string(stream)?;
+
+            let nbt_root = get_nbt(stream)?;
+
+            let state = Box::new(nbt_root.must_get_compound_tag().ok_or(ClientError::Decode(format!("States of block {} are not a compound tag", block_name), None))?);
+
+            block_palette.push(BlockPaletteEntry::new(block_name, CacheableNBT::new(state)));
+        }
+
+        let mut item_table: Vec<ItemTypeEntry> = vec![];
+        let table_len = stream.try_get_unsigned_var_int()?;
+        for _ in 0..table_len {
+            let string_id = get_string(stream)?;
+            let numeric_id = stream.try_get_signed_l_short()?;
+            let is_component_based = stream.try_get_bool()?;
+
+            item_table.push(ItemTypeEntry::new(string_id, numeric_id, is_component_based));
+
+        }
+        let multiplayer_correlation_id = get_string(stream)?;
+
+        let enable_new_inventory_system = stream.try_get_bool()?;
+        let server_software_version = get_string(stream)?;
+
+        let nbt_root = get_nbt(stream)?;
+        let player_actor_properties = CacheableNBT::new(Box::new(nbt_root.must_get_compound_tag().ok_or(ClientError::Decode("Player actor properties are not a compound tag".to_string(), None))?));
+
+        let block_palette_checksum = stream.try_get_l_long()?;
+
+        let world_template_id = stream.try_get(16)?;                // nvm for now
+
+        let enable_client_side_chunk_generation = stream.try_get_bool()?;
+
+        let block_network_ids_are_hashes = stream.try_get_bool()?;
+
+        let network_permissions = NetworkPermissions::read(stream)?;
+
+        Ok(StartGame{
+            actor_unique_id,
+            actor_runtime_id,
+            player_game_mode,
+            player_position,
+            pitch,
+            yaw,
+            level_settings,
+            level_id,
+            world_name,
+            premium_world_template_id,
+            is_trial,
+            player_movement_settings,
+            current_tick,
+            enchantment_seed,
+            block_palette,
+            item_table,
+            multiplayer_correlation_id,
+            enable_new_inventory_system,
+            server_software_version,
+            player_actor_properties,
+            block_palette_checksum,
+            world_template_id,
+            enable_client_side_chunk_generation,
+            block_network_ids_are_hashes,
+            network_permissions,
+        })
     }
-}
\ No newline at end of file
+}
@@ -1,5 +1,8 @@
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
 use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::serializer::stream_helper::{get_string, put_string, StreamReader};
 
 pub const TYPE_RAW: u8 = 0;
 pub const TYPE_CHAT: u8 = 1;
@@ -14,116 +17,159 @@ pub const TYPE_JSON_WHISPER: u8 = 9;
 pub const TYPE_JSON: u8 = 10;
 pub const TYPE_JSON_ANNOUNCEMENT: u8 = 11;
 
+// Every text type has its own fields on the wire
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextMessage {
+    Raw(String),
+    Chat{ source_name: String, message: String },
+    Translation{ message: String, parameters: Vec<String> },
+    Popup{ message: String, parameters: Vec<String> },
+    JukeboxPopup{ message: String, parameters: Vec<String> },
+    Tip(String),
+    System(String),
+    Whisper{ source_name: String, message: String },
+    Announcement{ source_name: String, message: String },
+    JsonWhisper(String),
+    Json(String),
+    JsonAnnouncement(String)
+}
+
+impl TextMessage {
+    pub fn get_type(&self) -> u8 {
+        match self {
+            TextMessage::Raw(_) => TYPE_RAW,
+            TextMessage::Chat{ .. } => TYPE_CHAT,
+            TextMessage::Translation{ .. } => TYPE_TRANSLATION,
+            TextMessage::Popup{ .. } => TYPE_POPUP,
+            TextMessage::JukeboxPopup{ .. } => TYPE_JUKEBOX_POPUP,
+            TextMessage::Tip(_) => TYPE_TIP,
+            TextMessage::System(_) => TYPE_SYSTEM,
+            TextMessage::Whisper{ .. } => TYPE_WHISPER,
+            TextMessage::Announcement{ .. } => TYPE_ANNOUNCEMENT,
+            TextMessage::JsonWhisper(_) => TYPE_JSON_WHISPER,
+            TextMessage::Json(_) => TYPE_JSON,
+            TextMessage::JsonAnnouncement(_) => TYPE_JSON_ANNOUNCEMENT
+        }
+    }
+
+    // Message text, a translation key for translated types
+    pub fn get_message(&self) -> &str {
+        match self {
+            TextMessage::Raw(message)
+            | TextMessage::Tip(message)
+            | TextMessage::System(message)
+            | TextMessage::JsonWhisper(message)
+            | TextMessage::Json(message)
+            | TextMessage::JsonAnnouncement(message)
+            | TextMessage::Chat{ message, .. }
+            | TextMessage::Whisper{ message, .. }
+            | TextMessage::Announcement{ message, .. }
+            | TextMessage::Translation{ message, .. }
+            | TextMessage::Popup{ message, .. }
+            | TextMessage::JukeboxPopup{ message, .. } => message
+        }
+    }
+
+    pub fn get_source_name(&self) -> Option<&str> {
+        match self {
+            TextMessage::Chat{ source_name, .. } | TextMessage::Whisper{ source_name, .. } | TextMessage::Announcement{ source_name, .. } => Some(source_name),
+            _ => None
+        }
+    }
+
+    pub fn get_parameters(&self) -> &[String] {
+        match self {
+            TextMessage::Translation{ parameters, .. } | TextMessage::Popup{ parameters, .. } | TextMessage::JukeboxPopup{ parameters, .. } => parameters,
+            _ => &[]
+        }
+    }
+}
+
 pub struct Text {
-    pub text_type: u8,
     pub needs_translation: bool,
-
-    pub source_name: Option<String>,
-    pub message: String,
-    pub parameters: Option<Vec<String>>,
+    pub message: TextMessage,
 
     pub xbox_uid: String,
     pub platform_chat_id: String,
     pub filtered_message: String,
 }
 
-pub fn new(text_type: u8, needs_translation: bool, source_name: Option<String>, message: String, parameters: Option<Vec<String>>, xbox_uid: String, platform_chat_id: String, filtered_message: String) -> Text {
-    Text{ text_type, needs_translation, source_name, message, parameters, xbox_uid, platform_chat_id, filtered_message }
+pub fn new(needs_translation: bool, message: TextMessage, xbox_uid: String, platform_chat_id: String, filtered_message: String) -> Text {
+    Text{ needs_translation, message, xbox_uid, platform_chat_id, filtered_message }
+}
+
+// Chat message as the client sends it, the server checks the source name against the player's name
+pub fn chat(source_name: String, message: String, xbox_uid: String) -> Text {
+    new(false, TextMessage::Chat{ source_name, message }, xbox_uid, "".to_string(), "".to_string())
 }
 
-impl Text {
-    pub fn encode(&mut self) -> Vec<u8> {
-        let mut stream = Stream::new(Vec::new(), 0);
-        stream.put_unsigned_var_int(BedrockPacketType::get_byte(BedrockPacketType::Text) as u32);
+// The layout is the same in every protocol of `protocol_version::PROTOCOL_VERSIONS` (1.20.80 to 1.21.50),
+// so the default `encode_versioned` / `decode_versioned` are used
+impl BedrockPacket for Text {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::Text);
 
-        stream.put_byte(self.text_type);
+    fn encode(&self, stream: &mut Stream) {
+        stream.put_byte(self.message.get_type());
         stream.put_bool(self.needs_translation);
-        match self.text_type {
-            TYPE_CHAT | TYPE_WHISPER | TYPE_ANNOUNCEMENT => {
-                if let Some(source_name) = self.source_name.clone() {
-                    stream.put_unsigned_var_int(source_name.len() as u32);
-                    stream.put(source_name.into_bytes());
+        match &self.message {
+            TextMessage::Chat{ source_name, message } | TextMessage::Whisper{ source_name, message } | TextMessage::Announcement{ source_name, message } => {
+                put_string(stream, source_name);
+                put_string(stream, message);
+            },
+            TextMessage::Translation{ message, parameters } | TextMessage::Popup{ message, parameters } | TextMessage::JukeboxPopup{ message, parameters } => {
+                put_string(stream, message);
+                stream.put_unsigned_var_int(parameters.len() as u32);
+                for parameter in parameters {
+                    put_string(stream, parameter);
                 }
-                stream.put_unsigned_var_int(self.message.len() as u32);
-                stream.put(self.message.clone().into_bytes());
-
             },
-            TYPE_RAW | TYPE_TIP | TYPE_SYSTEM | TYPE_JSON | TYPE_JSON_WHISPER | TYPE_JSON_ANNOUNCEMENT => {
-                stream.put_unsigned_var_int(self.message.len() as u32);
-                stream.put(self.message.clone().into_bytes());
+            message => put_string(stream, message.get_message())
+        }
+        put_string(stream, &self.xbox_uid);
+        put_string(stream, &self.platform_chat_id);
+        put_string(stream, &self.filtered_message);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<Text, ClientError> {
+        let text_type = stream.try_get_byte()?;
+        let needs_translation = stream.try_get_bool()?;
+
+        let message = match text_type {
+            TYPE_CHAT | TYPE_WHISPER | TYPE_ANNOUNCEMENT => {
+                let source_name = get_string(stream)?;
+                let message = get_string(stream)?;
+                match text_type {
+                    TYPE_CHAT => TextMessage::Chat{ source_name, message },
+                    TYPE_WHISPER => TextMessage::Whisper{ source_name, message },
+                    _ => TextMessage::Announcement{ source_name, message }
+                }
             },
             TYPE_TRANSLATION | TYPE_POPUP | TYPE_JUKEBOX_POPUP => {
-                stream.put_unsigned_var_int(self.message.len() as u32);
-                stream.put(self.message.clone().into_bytes());
-                if let Some(parameters) = self.parameters.clone() {
-                    stream.put_unsigned_var_int(parameters.len() as u32);
-                    for parameter in parameters {
-                        stream.put_unsigned_var_int(parameter.len() as u32);
-                        stream.put(parameter.into_bytes());
-                    }
+                let message = get_string(stream)?;
+                let count = stream.try_get_unsigned_var_int()?;
+                let mut parameters = Vec::new();
+                for _ in 0..count {
+                    parameters.push(get_string(stream)?);
                 }
-            }
-            _ => {}
-        }
-        stream.put_unsigned_var_int(self.xbox_uid.len() as u32);
-        stream.put(self.xbox_uid.clone().into_bytes());
-        stream.put_unsigned_var_int(self.platform_chat_id.len() as u32);
-        stream.put(self.platform_chat_id.clone().into_bytes());
-        stream.put_unsigned_var_int(self.filtered_message.len() as u32);
-        stream.put(self.filtered_message.clone().into_bytes());
-
-        let mut compress_stream = Stream::new(Vec::new(), 0);
-        compress_stream.put_unsigned_var_int(stream.get_buffer().len() as u32);
-        compress_stream.put(stream.get_buffer());
-
-        compress_stream.get_buffer()
+                match text_type {
+                    TYPE_TRANSLATION => TextMessage::Translation{ message, parameters },
+                    TYPE_POPUP => TextMessage::Popup{ message, parameters },
+                    _ => TextMessage::JukeboxPopup{ message, parameters }
+                }
+            },
+            TYPE_RAW => TextMessage::Raw(get_string(stream)?),
+            TYPE_TIP => TextMessage::Tip(get_string(stream)?),
+            TYPE_SYSTEM => TextMessage::System(get_string(stream)?),
+            TYPE_JSON_WHISPER => TextMessage::JsonWhisper(get_string(stream)?),
+            TYPE_JSON => TextMessage::Json(get_string(stream)?),
+            TYPE_JSON_ANNOUNCEMENT => TextMessage::JsonAnnouncement(get_string(stream)?),
+            _ => return Err(ClientError::Decode(format!("Unknown text type {}", text_type), None))
+        };
+
+        let xbox_uid = get_string(stream)?;
+        let platform_chat_id = get_string(stream)?;
+        let filtered_message = get_string(stream)?;
+
+        Ok(Text{ needs_translation, message, xbox_uid, platform_chat_id, filtered_message })
     }
 }
-
-pub fn decode(bytes: Vec<u8>) -> Text {
-    let mut stream = Stream::new(bytes, 0);
-
-    let text_type = stream.get_byte();
-    let needs_translation = stream.get_bool();
-
-    let mut source_name: Option<String> = None;
-    let mut message = String::new();
-    let mut parameters: Option<Vec<String>> = None;
-    match text_type {
-        TYPE_CHAT | TYPE_WHISPER | TYPE_ANNOUNCEMENT => {
-            let mut length = stream.get_unsigned_var_int();
-            source_name = Option::from(String::from_utf8(stream.get(length).unwrap()).unwrap());
-            length = stream.get_unsigned_var_int();
-            message = String::from_utf8(stream.get(length).unwrap()).unwrap();
-
-        },
-        TYPE_RAW | TYPE_TIP | TYPE_SYSTEM | TYPE_JSON | TYPE_JSON_WHISPER | TYPE_JSON_ANNOUNCEMENT => {
-            let length = stream.get_unsigned_var_int();
-            message = String::from_utf8(stream.get(length).unwrap()).unwrap();
-        },
-        TYPE_TRANSLATION | TYPE_POPUP | TYPE_JUKEBOX_POPUP => {
-            let mut length = stream.get_unsigned_var_int();
-            message = String::from_utf8(stream.get(length).unwrap()).unwrap();
-            length = stream.get_unsigned_var_int();
-            let mut params = Vec::new();
-            for _ in 0..length {
-                let length = stream.get_unsigned_var_int();
-                let parameter = String::from_utf8(stream.get(length).unwrap()).unwrap();
-                params.push(parameter);
-            }
-            parameters = Option::from(params);
-        }
-        _ => {}
-    }
-
-    let mut length = stream.get_unsigned_var_int();
-    let xbox_uid = String::from_utf8(stream.get(length).unwrap()).unwrap();
-
-    length = stream.get_unsigned_var_int();
-    let platform_chat_id = String::from_utf8(stream.get(length).unwrap()).unwrap();
-
-    length = stream.get_unsigned_var_int();
-    let filtered_message = String::from_utf8(stream.get(length).unwrap()).unwrap();
-
-    Text { text_type, needs_translation, source_name, message, parameters, xbox_uid, platform_chat_id, filtered_message }
-}
\ No newline at end of file
@@ -0,0 +1,71 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+
+pub const LAYER_CACHE: u16 = 0;
+pub const LAYER_BASE: u16 = 1;
+pub const LAYER_SPECTATOR: u16 = 2;
+pub const LAYER_COMMANDS: u16 = 3;
+pub const LAYER_EDITOR: u16 = 4;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AbilityLayer {
+    pub layer_type: u16,
+    pub abilities_set: u32, // which abilities this layer defines
+    pub ability_values: u32,
+    pub fly_speed: f32,
+    pub walk_speed: f32
+}
+
+impl AbilityLayer {
+    pub fn read(stream: &mut Stream) -> Result<AbilityLayer, ClientError> {
+        let layer_type = stream.try_get_l_short()?;
+        let abilities_set = stream.try_get_l_int()?;
+        let ability_values = stream.try_get_l_int()?;
+        let fly_speed = stream.try_get_l_float()?;
+        let walk_speed = stream.try_get_l_float()?;
+
+        Ok(AbilityLayer{ layer_type, abilities_set, ability_values, fly_speed, walk_speed })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_l_short(self.layer_type);
+        stream.put_l_int(self.abilities_set);
+        stream.put_l_int(self.ability_values);
+        stream.put_l_float(self.fly_speed);
+        stream.put_l_float(self.walk_speed);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AbilityData {
+    pub target_actor_unique_id: i64,
+    pub player_permission: u8,
+    pub command_permission: u8,
+    pub layers: Vec<AbilityLayer>
+}
+
+impl AbilityData {
+    pub fn read(stream: &mut Stream) -> Result<AbilityData, ClientError> {
+        let target_actor_unique_id = stream.try_get_l_long()?;
+        let player_permission = stream.try_get_byte()?;
+        let command_permission = stream.try_get_byte()?;
+        let count = stream.try_get_byte()?;
+        let mut layers = Vec::new();
+        for _ in 0..count {
+            layers.push(AbilityLayer::read(stream)?);
+        }
+
+        Ok(AbilityData{ target_actor_unique_id, player_permission, command_permission, layers })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_l_long(self.target_actor_unique_id);
+        stream.put_byte(self.player_permission);
+        stream.put_byte(self.command_permission);
+        stream.put_byte(self.layers.len() as u8);
+        for layer in &self.layers {
+            layer.write(stream);
+        }
+    }
+}
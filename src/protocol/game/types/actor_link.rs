@@ -0,0 +1,44 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+
+pub const TYPE_REMOVE: u8 = 0;
+pub const TYPE_RIDER: u8 = 1;
+pub const TYPE_PASSENGER: u8 = 2;
+
+// An actor riding another one, identified by unique IDs
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActorLink {
+    pub from_unique_id: i64,
+    pub to_unique_id: i64,
+    pub link_type: u8,
+    pub immediate: bool,
+    pub caused_by_rider: bool,
+    pub vehicle_angular_velocity: f32
+}
+
+impl ActorLink {
+    pub fn new(from_unique_id: i64, to_unique_id: i64, link_type: u8) -> ActorLink {
+        ActorLink{ from_unique_id, to_unique_id, link_type, immediate: false, caused_by_rider: false, vehicle_angular_velocity: 0.0 }
+    }
+
+    pub fn read(stream: &mut Stream) -> Result<ActorLink, ClientError> {
+        let from_unique_id = stream.try_get_var_long()?;
+        let to_unique_id = stream.try_get_var_long()?;
+        let link_type = stream.try_get_byte()?;
+        let immediate = stream.try_get_bool()?;
+        let caused_by_rider = stream.try_get_bool()?;
+        let vehicle_angular_velocity = stream.try_get_l_float()?;
+
+        Ok(ActorLink{ from_unique_id, to_unique_id, link_type, immediate, caused_by_rider, vehicle_angular_velocity })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_var_long(self.from_unique_id);
+        stream.put_var_long(self.to_unique_id);
+        stream.put_byte(self.link_type);
+        stream.put_bool(self.immediate);
+        stream.put_bool(self.caused_by_rider);
+        stream.put_l_float(self.vehicle_angular_velocity);
+    }
+}
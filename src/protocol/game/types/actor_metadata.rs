@@ -0,0 +1,338 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::{get_nbt_bytes, get_string, put_string, StreamReader};
+use crate::protocol::game::types::vector::Vec3;
+use std::collections::BTreeMap;
+
+pub const TYPE_BYTE: u32 = 0;
+pub const TYPE_SHORT: u32 = 1;
+pub const TYPE_INT: u32 = 2;
+pub const TYPE_FLOAT: u32 = 3;
+pub const TYPE_STRING: u32 = 4;
+pub const TYPE_COMPOUND: u32 = 5;
+pub const TYPE_BLOCK_POS: u32 = 6;
+pub const TYPE_LONG: u32 = 7;
+pub const TYPE_VEC3: u32 = 8;
+
+// Keys with a known meaning, the comment is the type of the value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActorDataKey {
+    Flags = 0, // long, flags 0..63
+    Health = 1, // int
+    Variant = 2, // int
+    Color = 3, // byte
+    NameTag = 4, // string
+    OwnerId = 5, // long
+    TargetId = 6, // long
+    Air = 7, // short
+    PotionColor = 8, // int
+    PotionAmbient = 9, // byte
+    HurtTime = 11, // int
+    HurtDirection = 12, // int
+    ExperienceValue = 15, // int
+    PlayerFlags = 26, // byte
+    PlayerIndex = 27, // int
+    BedPosition = 28, // block pos
+    LeadHolderId = 37, // long
+    Scale = 38, // float
+    InteractiveTag = 39, // string
+    MaxAir = 42, // short
+    MarkVariant = 43, // int
+    BoundingBoxWidth = 53, // float
+    BoundingBoxHeight = 54, // float
+    FuseLength = 55, // int
+    RiderSeatPosition = 56, // vec3
+    AlwaysShowNameTag = 81, // byte
+    Color2 = 82, // byte
+    ScoreTag = 84, // string
+    Flags2 = 92 // long, flags 64..127
+}
+
+impl ActorDataKey {
+    pub fn get_id(self) -> u32 {
+        self as u32
+    }
+}
+
+impl TryFrom<u32> for ActorDataKey {
+    type Error = &'static str;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ActorDataKey::Flags),
+            1 => Ok(ActorDataKey::Health),
+            2 => Ok(ActorDataKey::Variant),
+            3 => Ok(ActorDataKey::Color),
+            4 => Ok(ActorDataKey::NameTag),
+            5 => Ok(ActorDataKey::OwnerId),
+            6 => Ok(ActorDataKey::TargetId),
+            7 => Ok(ActorDataKey::Air),
+            8 => Ok(ActorDataKey::PotionColor),
+            9 => Ok(ActorDataKey::PotionAmbient),
+            11 => Ok(ActorDataKey::HurtTime),
+            12 => Ok(ActorDataKey::HurtDirection),
+            15 => Ok(ActorDataKey::ExperienceValue),
+            26 => Ok(ActorDataKey::PlayerFlags),
+            27 => Ok(ActorDataKey::PlayerIndex),
+            28 => Ok(ActorDataKey::BedPosition),
+            37 => Ok(ActorDataKey::LeadHolderId),
+            38 => Ok(ActorDataKey::Scale),
+            39 => Ok(ActorDataKey::InteractiveTag),
+            42 => Ok(ActorDataKey::MaxAir),
+            43 => Ok(ActorDataKey::MarkVariant),
+            53 => Ok(ActorDataKey::BoundingBoxWidth),
+            54 => Ok(ActorDataKey::BoundingBoxHeight),
+            55 => Ok(ActorDataKey::FuseLength),
+            56 => Ok(ActorDataKey::RiderSeatPosition),
+            81 => Ok(ActorDataKey::AlwaysShowNameTag),
+            82 => Ok(ActorDataKey::Color2),
+            84 => Ok(ActorDataKey::ScoreTag),
+            92 => Ok(ActorDataKey::Flags2),
+            _ => Err("Unknown actor data key")
+        }
+    }
+}
+
+// Bit index in the flags, 0..63 are in `Flags` and 64..127 in `Flags2`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActorFlag {
+    OnFire = 0,
+    Sneaking = 1,
+    Riding = 2,
+    Sprinting = 3,
+    Action = 4,
+    Invisible = 5,
+    Tempted = 6,
+    InLove = 7,
+    Saddled = 8,
+    Powered = 9,
+    Ignited = 10,
+    Baby = 11,
+    Converting = 12,
+    Critical = 13,
+    CanShowNameTag = 14,
+    AlwaysShowNameTag = 15,
+    Immobile = 16,
+    Silent = 17,
+    WallClimbing = 18,
+    CanClimb = 19,
+    Swimmer = 20,
+    CanFly = 21,
+    Walker = 22,
+    Resting = 23,
+    Sitting = 24,
+    Angry = 25,
+    Interested = 26,
+    Charged = 27,
+    Tamed = 28,
+    Orphaned = 29,
+    Leashed = 30,
+    Sheared = 31,
+    Gliding = 32,
+    Elder = 33,
+    Moving = 34,
+    Breathing = 35,
+    Chested = 36,
+    Stackable = 37,
+    ShowBase = 38,
+    Rearing = 39,
+    Vibrating = 40,
+    Idling = 41,
+    EvokerSpell = 42,
+    ChargeAttack = 43,
+    WasdControlled = 44,
+    CanPowerJump = 45
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataValue {
+    Byte(u8),
+    Short(i16),
+    Int(i32),
+    Float(f32),
+    String(String),
+    Compound(Vec<u8>), // network NBT as it was sent
+    BlockPos(i32, i32, i32),
+    Long(i64),
+    Vec3(Vec3)
+}
+
+impl MetadataValue {
+    pub fn get_type(&self) -> u32 {
+        match self {
+            MetadataValue::Byte(_) => TYPE_BYTE,
+            MetadataValue::Short(_) => TYPE_SHORT,
+            MetadataValue::Int(_) => TYPE_INT,
+            MetadataValue::Float(_) => TYPE_FLOAT,
+            MetadataValue::String(_) => TYPE_STRING,
+            MetadataValue::Compound(_) => TYPE_COMPOUND,
+            MetadataValue::BlockPos(..) => TYPE_BLOCK_POS,
+            MetadataValue::Long(_) => TYPE_LONG,
+            MetadataValue::Vec3(_) => TYPE_VEC3
+        }
+    }
+
+    pub fn read(stream: &mut Stream, value_type: u32) -> Result<MetadataValue, ClientError> {
+        Ok(match value_type {
+            TYPE_BYTE => MetadataValue::Byte(stream.try_get_byte()?),
+            TYPE_SHORT => MetadataValue::Short(stream.try_get_signed_l_short()?),
+            TYPE_INT => MetadataValue::Int(stream.try_get_var_int()?),
+            TYPE_FLOAT => MetadataValue::Float(stream.try_get_l_float()?),
+            TYPE_STRING => MetadataValue::String(get_string(stream)?),
+            TYPE_COMPOUND => MetadataValue::Compound(get_nbt_bytes(stream)?),
+            TYPE_BLOCK_POS => MetadataValue::BlockPos(stream.try_get_var_int()?, stream.try_get_var_int()?, stream.try_get_var_int()?),
+            TYPE_LONG => MetadataValue::Long(stream.try_get_var_long()?),
+            TYPE_VEC3 => MetadataValue::Vec3(Vec3::read(stream)?),
+            // the size of the value is unknown, so nothing behind it can be read either
+            _ => return Err(ClientError::Decode(format!("Unknown actor metadata type {}", value_type), None))
+        })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        match self {
+            MetadataValue::Byte(value) => stream.put_byte(*value),
+            MetadataValue::Short(value) => stream.put_l_short(*value as u16),
+            MetadataValue::Int(value) => stream.put_var_int(*value),
+            MetadataValue::Float(value) => stream.put_l_float(*value),
+            MetadataValue::String(value) => put_string(stream, value),
+            MetadataValue::Compound(value) => stream.put(value.clone()),
+            MetadataValue::BlockPos(x, y, z) => {
+                stream.put_var_int(*x);
+                stream.put_var_int(*y);
+                stream.put_var_int(*z);
+            },
+            MetadataValue::Long(value) => stream.put_var_long(*value),
+            MetadataValue::Vec3(value) => value.write(stream)
+        }
+    }
+}
+
+fn flag_position(index: u32) -> (ActorDataKey, u32) {
+    if index < 64 { (ActorDataKey::Flags, index) } else { (ActorDataKey::Flags2, index - 64) }
+}
+
+// Key => value, SetActorData only carries the changed keys
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ActorMetadata {
+    pub values: BTreeMap<u32, MetadataValue>
+}
+
+impl ActorMetadata {
+    pub fn new() -> ActorMetadata {
+        ActorMetadata::default()
+    }
+
+    pub fn get(&self, key: u32) -> Option<&MetadataValue> {
+        self.values.get(&key)
+    }
+
+    pub fn set(&mut self, key: u32, value: MetadataValue) {
+        self.values.insert(key, value);
+    }
+
+    pub fn get_long(&self, key: ActorDataKey) -> Option<i64> {
+        match self.get(key.get_id()) {
+            Some(MetadataValue::Long(value)) => Option::from(*value),
+            _ => None
+        }
+    }
+
+    pub fn get_int(&self, key: ActorDataKey) -> Option<i32> {
+        match self.get(key.get_id()) {
+            Some(MetadataValue::Int(value)) => Option::from(*value),
+            _ => None
+        }
+    }
+
+    pub fn get_float(&self, key: ActorDataKey) -> Option<f32> {
+        match self.get(key.get_id()) {
+            Some(MetadataValue::Float(value)) => Option::from(*value),
+            _ => None
+        }
+    }
+
+    pub fn get_byte(&self, key: ActorDataKey) -> Option<u8> {
+        match self.get(key.get_id()) {
+            Some(MetadataValue::Byte(value)) => Option::from(*value),
+            _ => None
+        }
+    }
+
+    pub fn get_string(&self, key: ActorDataKey) -> Option<&str> {
+        match self.get(key.get_id()) {
+            Some(MetadataValue::String(value)) => Option::from(value.as_str()),
+            _ => None
+        }
+    }
+
+    pub fn get_name_tag(&self) -> Option<&str> {
+        self.get_string(ActorDataKey::NameTag)
+    }
+
+    pub fn get_score_tag(&self) -> Option<&str> {
+        self.get_string(ActorDataKey::ScoreTag)
+    }
+
+    pub fn get_variant(&self) -> Option<i32> {
+        self.get_int(ActorDataKey::Variant)
+    }
+
+    pub fn get_scale(&self) -> Option<f32> {
+        self.get_float(ActorDataKey::Scale)
+    }
+
+    // Width and height
+    pub fn get_bounding_box(&self) -> Option<(f32, f32)> {
+        Option::from((self.get_float(ActorDataKey::BoundingBoxWidth)?, self.get_float(ActorDataKey::BoundingBoxHeight)?))
+    }
+
+    pub fn get_flag(&self, flag: ActorFlag) -> bool {
+        self.get_flag_index(flag as u32)
+    }
+
+    pub fn set_flag(&mut self, flag: ActorFlag, value: bool) {
+        self.set_flag_index(flag as u32, value);
+    }
+
+    // For flags without a name in `ActorFlag`
+    pub fn get_flag_index(&self, index: u32) -> bool {
+        let (key, bit) = flag_position(index);
+        self.get_long(key).is_some_and(|flags| flags & (1 << bit) != 0)
+    }
+
+    // Keeps the other bits of the flag long
+    pub fn set_flag_index(&mut self, index: u32, value: bool) {
+        let (key, bit) = flag_position(index);
+        let flags = self.get_long(key).unwrap_or(0);
+        let flags = if value { flags | (1 << bit) } else { flags & !(1 << bit) };
+        self.set(key.get_id(), MetadataValue::Long(flags));
+    }
+
+    // Applies the changed keys of an update
+    pub fn merge(&mut self, update: &ActorMetadata) {
+        for (key, value) in &update.values {
+            self.values.insert(*key, value.clone());
+        }
+    }
+
+    pub fn read(stream: &mut Stream) -> Result<ActorMetadata, ClientError> {
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut values = BTreeMap::new();
+        for _ in 0..count {
+            let key = stream.try_get_unsigned_var_int()?;
+            let value_type = stream.try_get_unsigned_var_int()?;
+            values.insert(key, MetadataValue::read(stream, value_type)?);
+        }
+
+        Ok(ActorMetadata{ values })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_unsigned_var_int(self.values.len() as u32);
+        for (key, value) in &self.values {
+            stream.put_unsigned_var_int(*key);
+            stream.put_unsigned_var_int(value.get_type());
+            value.write(stream);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::error::ClientError;
+use crate::protocol::game::types::property_sync_data::PropertySyncData;
+use mojang_nbt::tag::compound_tag::CompoundTag;
+use mojang_nbt::tag::tag::Tag;
+
+pub const PROPERTY_INT: u32 = 0;
+pub const PROPERTY_FLOAT: u32 = 1;
+pub const PROPERTY_BOOL: u32 = 2;
+pub const PROPERTY_ENUM: u32 = 3;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyValue {
+    Int(i32),
+    Float(f32),
+    Bool(bool),
+    Enum(String)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActorPropertyDefinition {
+    pub name: String,
+    pub property_type: u32,
+    pub enum_values: Vec<String> // only for PROPERTY_ENUM
+}
+
+// Entity properties that behavior packs declare for an actor type. PropertySyncData refers to them by their index,
+// ints, bools and enums are sent as int properties, floats as float properties.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ActorProperties {
+    pub actor_type: String,
+    pub properties: Vec<ActorPropertyDefinition>
+}
+
+impl ActorProperties {
+    pub fn new(actor_type: String, properties: Vec<ActorPropertyDefinition>) -> ActorProperties {
+        ActorProperties{ actor_type, properties }
+    }
+
+    // {type: "minecraft:player", properties: [{name, type, enum: [..]}, ..]}, an empty compound when there are none
+    pub fn from_nbt(root: &CompoundTag) -> Result<ActorProperties, ClientError> {
+        let actor_type = root.get_string("type").unwrap_or_default();
+        let mut properties = Vec::new();
+        if let Some(list) = root.get_list_tag("properties".to_string()) {
+            for value in list.get_value().downcast_ref::<Vec<Box<dyn Tag>>>().ok_or(ClientError::Decode(format!("{} properties is not a list", actor_type), None))? {
+                let property = value.as_any().downcast_ref::<CompoundTag>().ok_or(ClientError::Decode(format!("{} property is not a compound tag", actor_type), None))?;
+                let name = property.get_string("name").ok_or(ClientError::Decode(format!("{} property has no name", actor_type), None))?;
+                let property_type = property.get_int("type").ok_or(ClientError::Decode(format!("{} property {} has no type", actor_type, name), None))?;
+                let mut enum_values = Vec::new();
+                if let Some(list_enum) = property.get_list_tag("enum".to_string()) {
+                    for value in list_enum.get_value().downcast_ref::<Vec<Box<dyn Tag>>>().ok_or(ClientError::Decode(format!("{} property {} enum is not a list", actor_type, name), None))? {
+                        if let Some(value) = value.get_value().downcast_ref::<String>() {
+                            enum_values.push(value.clone());
+                        }
+                    }
+                }
+                properties.push(ActorPropertyDefinition{ name, property_type, enum_values });
+            }
+        }
+
+        Ok(ActorProperties{ actor_type, properties })
+    }
+
+    pub fn index_of(&self, name: &str) -> Option<u32> {
+        self.properties.iter().position(|property| property.name == name).map(|index| index as u32)
+    }
+
+    pub fn get(&self, data: &PropertySyncData, name: &str) -> Option<PropertyValue> {
+        let index = self.index_of(name)?;
+        let property = &self.properties[index as usize];
+        if property.property_type == PROPERTY_FLOAT {
+            let (_, value) = data.float_properties.iter().find(|(i, _)| *i == index)?;
+            return Option::from(PropertyValue::Float(*value));
+        }
+        let (_, value) = data.int_properties.iter().find(|(i, _)| *i == index)?;
+        match property.property_type {
+            PROPERTY_INT => Option::from(PropertyValue::Int(*value)),
+            PROPERTY_BOOL => Option::from(PropertyValue::Bool(*value != 0)),
+            PROPERTY_ENUM => property.enum_values.get(*value as usize).map(|value| PropertyValue::Enum(value.clone())),
+            _ => None
+        }
+    }
+
+    // Name => value of every property in `data`
+    pub fn resolve(&self, data: &PropertySyncData) -> Vec<(String, PropertyValue)> {
+        self.properties.iter().filter_map(|property| self.get(data, &property.name).map(|value| (property.name.clone(), value))).collect()
+    }
+
+    // Writes `value` into `data`, false for unknown names or a value of the wrong type
+    pub fn set(&self, data: &mut PropertySyncData, name: &str, value: PropertyValue) -> bool {
+        let index = match self.index_of(name) {
+            Some(index) => index,
+            None => return false
+        };
+        let property = &self.properties[index as usize];
+        let update = match (property.property_type, value) {
+            (PROPERTY_FLOAT, PropertyValue::Float(value)) => PropertySyncData{ int_properties: vec![], float_properties: vec![(index, value)] },
+            (PROPERTY_INT, PropertyValue::Int(value)) => PropertySyncData{ int_properties: vec![(index, value)], float_properties: vec![] },
+            (PROPERTY_BOOL, PropertyValue::Bool(value)) => PropertySyncData{ int_properties: vec![(index, value as i32)], float_properties: vec![] },
+            (PROPERTY_ENUM, PropertyValue::Enum(value)) => match property.enum_values.iter().position(|enum_value| *enum_value == value) {
+                Some(value) => PropertySyncData{ int_properties: vec![(index, value as i32)], float_properties: vec![] },
+                None => return false
+            },
+            _ => return false
+        };
+        data.merge(&update);
+        true
+    }
+}
@@ -0,0 +1,124 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::protocol_version::V1_21_30;
+use crate::protocol::game::serializer::stream_helper::{get_string, put_string, StreamReader};
+
+pub const HEALTH: &str = "minecraft:health";
+pub const MOVEMENT: &str = "minecraft:movement";
+pub const UNDERWATER_MOVEMENT: &str = "minecraft:underwater_movement";
+pub const LAVA_MOVEMENT: &str = "minecraft:lava_movement";
+
+pub const OPERATION_ADDITION: i32 = 0;
+pub const OPERATION_MULTIPLY_BASE: i32 = 1;
+pub const OPERATION_MULTIPLY_TOTAL: i32 = 2;
+pub const OPERATION_CAP: i32 = 3;
+
+// Modifiers are informative, `value` of the attribute already includes them
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeModifier {
+    pub id: String,
+    pub name: String,
+    pub amount: f32,
+    pub operation: i32,
+    pub operand: i32,
+    pub serializable: bool
+}
+
+impl AttributeModifier {
+    pub fn read(stream: &mut Stream) -> Result<AttributeModifier, ClientError> {
+        let id = get_string(stream)?;
+        let name = get_string(stream)?;
+        let amount = stream.try_get_l_float()?;
+        let operation = stream.try_get_l_int()? as i32;
+        let operand = stream.try_get_l_int()? as i32;
+        let serializable = stream.try_get_bool()?;
+
+        Ok(AttributeModifier{ id, name, amount, operation, operand, serializable })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        put_string(stream, &self.id);
+        put_string(stream, &self.name);
+        stream.put_l_float(self.amount);
+        stream.put_l_int(self.operation as u32);
+        stream.put_l_int(self.operand as u32);
+        stream.put_bool(self.serializable);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attribute {
+    pub name: String,
+    pub min: f32,
+    pub max: f32,
+    pub value: f32,
+    pub default_min: f32, // since 1.21.30
+    pub default_max: f32, // since 1.21.30
+    pub default: f32,
+    pub modifiers: Vec<AttributeModifier>
+}
+
+impl Attribute {
+    pub fn new(name: String, min: f32, max: f32, value: f32, default: f32) -> Attribute {
+        Attribute{ name, min, max, value, default_min: min, default_max: max, default, modifiers: vec![] }
+    }
+
+    pub fn read(stream: &mut Stream, protocol: u32) -> Result<Attribute, ClientError> {
+        let min = stream.try_get_l_float()?;
+        let max = stream.try_get_l_float()?;
+        let value = stream.try_get_l_float()?;
+        let (default_min, default_max) = if protocol >= V1_21_30 { (stream.try_get_l_float()?, stream.try_get_l_float()?) } else { (min, max) };
+        let default = stream.try_get_l_float()?;
+        let name = get_string(stream)?;
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut modifiers = Vec::new();
+        for _ in 0..count {
+            modifiers.push(AttributeModifier::read(stream)?);
+        }
+
+        Ok(Attribute{ name, min, max, value, default_min, default_max, default, modifiers })
+    }
+
+    pub fn write(&self, stream: &mut Stream, protocol: u32) {
+        stream.put_l_float(self.min);
+        stream.put_l_float(self.max);
+        stream.put_l_float(self.value);
+        if protocol >= V1_21_30 {
+            stream.put_l_float(self.default_min);
+            stream.put_l_float(self.default_max);
+        }
+        stream.put_l_float(self.default);
+        put_string(stream, &self.name);
+        stream.put_unsigned_var_int(self.modifiers.len() as u32);
+        for modifier in &self.modifiers {
+            modifier.write(stream);
+        }
+    }
+}
+
+// The short form of an attribute that AddActor uses
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeValue {
+    pub name: String,
+    pub min: f32,
+    pub value: f32,
+    pub max: f32
+}
+
+impl AttributeValue {
+    pub fn read(stream: &mut Stream) -> Result<AttributeValue, ClientError> {
+        let name = get_string(stream)?;
+        let min = stream.try_get_l_float()?;
+        let value = stream.try_get_l_float()?;
+        let max = stream.try_get_l_float()?;
+
+        Ok(AttributeValue{ name, min, value, max })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        put_string(stream, &self.name);
+        stream.put_l_float(self.min);
+        stream.put_l_float(self.value);
+        stream.put_l_float(self.max);
+    }
+}
@@ -1,3 +1,4 @@
+use binary_utils::binary::Stream;
 use mojang_nbt::base_nbt_serializer::BaseNBTSerializer;
 use mojang_nbt::tag::tag::Tag;
 use mojang_nbt::tree_root::TreeRoot;
@@ -27,4 +28,14 @@ impl CacheableNBT {
 
         self.encoded_nbt.clone().unwrap()
     }
+
+    pub fn write(&self, stream: &mut Stream) {
+        match self.encoded_nbt.as_ref() {
+            Some(encoded_nbt) => stream.put(encoded_nbt.clone()),
+            None => {
+                let mut serializer = NetworkNBTSerializer::new();
+                stream.put(serializer.write(TreeRoot::new(self.get_root(), "".to_string())));
+            }
+        }
+    }
 }
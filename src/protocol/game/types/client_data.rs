@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+// Claims of the client data JWT (second JWT of the Login packet): device, skin and game settings
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ClientData {
+    pub animated_image_data: Vec<AnimatedImage>,
+    pub arm_size: String,
+    pub cape_data: String,
+    pub cape_id: String,
+    pub cape_image_height: u32,
+    pub cape_image_width: u32,
+    pub cape_on_classic_skin: bool,
+    pub client_random_id: i64,
+    pub compatible_with_client_side_chunk_gen: bool,
+    pub current_input_mode: i32,
+    pub default_input_mode: i32,
+    pub device_id: String,
+    pub device_model: String,
+    #[serde(rename = "DeviceOS")]
+    pub device_os: i32,
+    pub game_version: String,
+    pub gui_scale: i32,
+    pub is_editor_mode: bool,
+    pub language_code: String,
+    pub override_skin: bool,
+    pub max_view_distance: i32,
+    pub memory_tier: i32,
+    pub persona_pieces: Vec<PersonaPiece>,
+    pub persona_skin: bool,
+    pub piece_tint_colors: Vec<PieceTintColor>,
+    pub platform_offline_id: String,
+    pub platform_online_id: String,
+    pub platform_type: i32,
+    pub play_fab_id: String,
+    pub premium_skin: bool,
+    pub self_signed_id: String,
+    pub server_address: String,
+    pub skin_animation_data: String,
+    pub skin_color: String,
+    pub skin_data: String, // base64 RGBA
+    pub skin_geometry_data: String, // base64 JSON
+    pub skin_geometry_data_engine_version: String,
+    pub skin_id: String,
+    pub skin_image_height: u32,
+    pub skin_image_width: u32,
+    pub skin_resource_patch: String, // base64 JSON
+    pub third_party_name: String,
+    pub third_party_name_only: bool,
+    pub trusted_skin: bool,
+    #[serde(rename = "UIProfile")]
+    pub ui_profile: i32
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct AnimatedImage {
+    pub frames: f32,
+    pub image: String,
+    pub image_height: u32,
+    pub image_width: u32,
+    #[serde(rename = "Type")]
+    pub animation_type: i32,
+    pub animation_expression: i32
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct PersonaPiece {
+    pub is_default: bool,
+    pub pack_id: String,
+    pub piece_id: String,
+    pub piece_type: String,
+    pub product_id: String
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct PieceTintColor {
+    pub colors: Vec<String>,
+    pub piece_type: String
+}
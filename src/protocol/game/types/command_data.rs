@@ -0,0 +1,284 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::{get_string, put_string, StreamReader};
+
+pub const FLAG_TEST_USAGE: u16 = 0x1;
+pub const FLAG_HIDDEN_FROM_COMMAND_BLOCK: u16 = 0x2;
+pub const FLAG_HIDDEN_FROM_PLAYER: u16 = 0x4;
+pub const FLAG_HIDDEN_FROM_AUTOMATION: u16 = 0x8;
+pub const FLAG_LOCAL_SYNC: u16 = 0x10;
+pub const FLAG_EXECUTE_DISALLOWED: u16 = 0x20;
+pub const FLAG_MESSAGE_TYPE: u16 = 0x40;
+pub const FLAG_NOT_CHEAT: u16 = 0x80;
+pub const FLAG_ASYNC: u16 = 0x100;
+
+pub const PERMISSION_ANY: u8 = 0;
+pub const PERMISSION_GAME_DIRECTORS: u8 = 1;
+pub const PERMISSION_ADMIN: u8 = 2;
+pub const PERMISSION_HOST: u8 = 3;
+pub const PERMISSION_OWNER: u8 = 4;
+pub const PERMISSION_INTERNAL: u8 = 5;
+
+// Parameter type flags, the low 16 bits are a type id or an index depending on the flag
+pub const ARG_FLAG_VALID: u32 = 0x100000;
+pub const ARG_FLAG_ENUM: u32 = 0x200000;
+pub const ARG_FLAG_POSTFIX: u32 = 0x1000000;
+pub const ARG_FLAG_SOFT_ENUM: u32 = 0x4000000;
+
+pub const ARG_TYPE_INT: u32 = 1;
+pub const ARG_TYPE_FLOAT: u32 = 3;
+pub const ARG_TYPE_VALUE: u32 = 4;
+pub const ARG_TYPE_WILDCARD_INT: u32 = 5;
+pub const ARG_TYPE_OPERATOR: u32 = 6;
+pub const ARG_TYPE_COMPARE_OPERATOR: u32 = 7;
+pub const ARG_TYPE_TARGET: u32 = 8;
+pub const ARG_TYPE_WILDCARD_TARGET: u32 = 10;
+pub const ARG_TYPE_FILE_PATH: u32 = 17;
+pub const ARG_TYPE_INTEGER_RANGE: u32 = 23;
+pub const ARG_TYPE_EQUIPMENT_SLOTS: u32 = 47;
+pub const ARG_TYPE_STRING: u32 = 56;
+pub const ARG_TYPE_BLOCK_POSITION: u32 = 64;
+pub const ARG_TYPE_POSITION: u32 = 65;
+pub const ARG_TYPE_MESSAGE: u32 = 68;
+pub const ARG_TYPE_RAW_TEXT: u32 = 70;
+pub const ARG_TYPE_JSON: u32 = 74;
+pub const ARG_TYPE_BLOCK_STATES: u32 = 84;
+pub const ARG_TYPE_COMMAND: u32 = 87;
+
+pub const PARAM_OPTION_COLLAPSE_ENUM: u8 = 0x1;
+pub const PARAM_OPTION_HAS_SEMANTIC_CONSTRAINT: u8 = 0x2;
+pub const PARAM_OPTION_FORCE_COLLAPSE_ENUM: u8 = 0x4;
+pub const PARAM_OPTION_AS_CHAINED_COMMAND: u8 = 0x8;
+
+pub const NO_ALIASES: u32 = 0xffffffff;
+
+// Enum values are shared between all enums, each enum keeps indices into the shared list
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandEnum {
+    pub name: String,
+    pub value_indices: Vec<u32>
+}
+
+impl CommandEnum {
+    // The index width depends on the size of the shared value list
+    pub fn read(stream: &mut Stream, value_count: usize) -> Result<CommandEnum, ClientError> {
+        let name = get_string(stream)?;
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut value_indices = Vec::new();
+        for _ in 0..count {
+            value_indices.push(match value_count {
+                0..=0xff => stream.try_get_byte()? as u32,
+                0x100..=0xffff => stream.try_get_l_short()? as u32,
+                _ => stream.try_get_l_int()?
+            });
+        }
+
+        Ok(CommandEnum{ name, value_indices })
+    }
+
+    pub fn write(&self, stream: &mut Stream, value_count: usize) {
+        put_string(stream, &self.name);
+        stream.put_unsigned_var_int(self.value_indices.len() as u32);
+        for index in &self.value_indices {
+            match value_count {
+                0..=0xff => stream.put_byte(*index as u8),
+                0x100..=0xffff => stream.put_l_short(*index as u16),
+                _ => stream.put_l_int(*index)
+            }
+        }
+    }
+}
+
+// Soft enums carry their values directly and can be changed later with UpdateSoftEnum
+#[derive(Debug, Clone, PartialEq)]
+pub struct SoftEnum {
+    pub name: String,
+    pub values: Vec<String>
+}
+
+impl SoftEnum {
+    pub fn read(stream: &mut Stream) -> Result<SoftEnum, ClientError> {
+        let name = get_string(stream)?;
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut values = Vec::new();
+        for _ in 0..count {
+            values.push(get_string(stream)?);
+        }
+
+        Ok(SoftEnum{ name, values })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        put_string(stream, &self.name);
+        stream.put_unsigned_var_int(self.values.len() as u32);
+        for value in &self.values {
+            put_string(stream, value);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainedSubcommandValue {
+    pub index: u16, // into the chained subcommand values
+    pub value: u16
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainedSubcommand {
+    pub name: String,
+    pub values: Vec<ChainedSubcommandValue>
+}
+
+impl ChainedSubcommand {
+    pub fn read(stream: &mut Stream) -> Result<ChainedSubcommand, ClientError> {
+        let name = get_string(stream)?;
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut values = Vec::new();
+        for _ in 0..count {
+            let index = stream.try_get_l_short()?;
+            let value = stream.try_get_l_short()?;
+            values.push(ChainedSubcommandValue{ index, value });
+        }
+
+        Ok(ChainedSubcommand{ name, values })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        put_string(stream, &self.name);
+        stream.put_unsigned_var_int(self.values.len() as u32);
+        for value in &self.values {
+            stream.put_l_short(value.index);
+            stream.put_l_short(value.value);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandParameter {
+    pub name: String,
+    pub parameter_type: u32, // ARG_TYPE_* or an index combined with the ARG_FLAG_* flags
+    pub optional: bool,
+    pub options: u8
+}
+
+impl CommandParameter {
+    pub fn read(stream: &mut Stream) -> Result<CommandParameter, ClientError> {
+        let name = get_string(stream)?;
+        let parameter_type = stream.try_get_l_int()?;
+        let optional = stream.try_get_bool()?;
+        let options = stream.try_get_byte()?;
+
+        Ok(CommandParameter{ name, parameter_type, optional, options })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        put_string(stream, &self.name);
+        stream.put_l_int(self.parameter_type);
+        stream.put_bool(self.optional);
+        stream.put_byte(self.options);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandOverload {
+    pub chaining: bool,
+    pub parameters: Vec<CommandParameter>
+}
+
+impl CommandOverload {
+    pub fn read(stream: &mut Stream) -> Result<CommandOverload, ClientError> {
+        let chaining = stream.try_get_bool()?;
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut parameters = Vec::new();
+        for _ in 0..count {
+            parameters.push(CommandParameter::read(stream)?);
+        }
+
+        Ok(CommandOverload{ chaining, parameters })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_bool(self.chaining);
+        stream.put_unsigned_var_int(self.parameters.len() as u32);
+        for parameter in &self.parameters {
+            parameter.write(stream);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandData {
+    pub name: String,
+    pub description: String,
+    pub flags: u16,
+    pub permission_level: u8,
+    pub aliases_offset: u32, // index of the alias enum, NO_ALIASES if there is none
+    pub chained_subcommand_offsets: Vec<u16>,
+    pub overloads: Vec<CommandOverload>
+}
+
+impl CommandData {
+    pub fn read(stream: &mut Stream) -> Result<CommandData, ClientError> {
+        let name = get_string(stream)?;
+        let description = get_string(stream)?;
+        let flags = stream.try_get_l_short()?;
+        let permission_level = stream.try_get_byte()?;
+        let aliases_offset = stream.try_get_l_int()?;
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut chained_subcommand_offsets = Vec::new();
+        for _ in 0..count {
+            chained_subcommand_offsets.push(stream.try_get_l_short()?);
+        }
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut overloads = Vec::new();
+        for _ in 0..count {
+            overloads.push(CommandOverload::read(stream)?);
+        }
+
+        Ok(CommandData{ name, description, flags, permission_level, aliases_offset, chained_subcommand_offsets, overloads })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        put_string(stream, &self.name);
+        put_string(stream, &self.description);
+        stream.put_l_short(self.flags);
+        stream.put_byte(self.permission_level);
+        stream.put_l_int(self.aliases_offset);
+        stream.put_unsigned_var_int(self.chained_subcommand_offsets.len() as u32);
+        for offset in &self.chained_subcommand_offsets {
+            stream.put_l_short(*offset);
+        }
+        stream.put_unsigned_var_int(self.overloads.len() as u32);
+        for overload in &self.overloads {
+            overload.write(stream);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandEnumConstraint {
+    pub enum_value_index: u32,
+    pub enum_index: u32,
+    pub constraints: Vec<u8>
+}
+
+impl CommandEnumConstraint {
+    pub fn read(stream: &mut Stream) -> Result<CommandEnumConstraint, ClientError> {
+        let enum_value_index = stream.try_get_l_int()?;
+        let enum_index = stream.try_get_l_int()?;
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut constraints = Vec::new();
+        for _ in 0..count {
+            constraints.push(stream.try_get_byte()?);
+        }
+
+        Ok(CommandEnumConstraint{ enum_value_index, enum_index, constraints })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_l_int(self.enum_value_index);
+        stream.put_l_int(self.enum_index);
+        stream.put_unsigned_var_int(self.constraints.len() as u32);
+        stream.put(self.constraints.clone());
+    }
+}
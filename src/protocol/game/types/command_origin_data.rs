@@ -0,0 +1,58 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::{StreamReader, get_string, get_uuid};
+use uuid::Uuid;
+
+pub const ORIGIN_PLAYER: u32 = 0;
+pub const ORIGIN_BLOCK: u32 = 1;
+pub const ORIGIN_MINECART_BLOCK: u32 = 2;
+pub const ORIGIN_DEV_CONSOLE: u32 = 3;
+pub const ORIGIN_TEST: u32 = 4;
+pub const ORIGIN_AUTOMATION_PLAYER: u32 = 5;
+pub const ORIGIN_CLIENT_AUTOMATION: u32 = 6;
+pub const ORIGIN_DEDICATED_SERVER: u32 = 7;
+pub const ORIGIN_ENTITY: u32 = 8;
+pub const ORIGIN_VIRTUAL: u32 = 9;
+pub const ORIGIN_GAME_ARGUMENT: u32 = 10;
+pub const ORIGIN_ENTITY_SERVER: u32 = 11;
+pub const ORIGIN_PRECOMPILED: u32 = 12;
+pub const ORIGIN_GAME_DIRECTOR_ENTITY_SERVER: u32 = 13;
+pub const ORIGIN_SCRIPT: u32 = 14;
+pub const ORIGIN_EXECUTE_CONTEXT: u32 = 15;
+
+// Who runs a command, the server copies it into the CommandOutput
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandOriginData {
+    pub origin_type: u32,
+    pub uuid: Uuid,
+    pub request_id: String,
+    pub player_actor_unique_id: i64 // only for the dev console and test origins
+}
+
+impl CommandOriginData {
+    pub fn new(origin_type: u32, uuid: Uuid, request_id: String, player_actor_unique_id: i64) -> CommandOriginData {
+        CommandOriginData{ origin_type, uuid, request_id, player_actor_unique_id }
+    }
+
+    pub fn read(stream: &mut Stream) -> Result<CommandOriginData, ClientError> {
+        let origin_type = stream.try_get_unsigned_var_int()?;
+        let uuid = get_uuid(stream)?;
+        let request_id = get_string(stream)?;
+        let mut player_actor_unique_id = 0;
+        if origin_type == ORIGIN_DEV_CONSOLE || origin_type == ORIGIN_TEST {
+            player_actor_unique_id = stream.try_get_var_long()?;
+        }
+
+        Ok(CommandOriginData{ origin_type, uuid, request_id, player_actor_unique_id })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_unsigned_var_int(self.origin_type);
+        stream.put(self.uuid.as_bytes().to_vec());
+        stream.put_unsigned_var_int(self.request_id.len() as u32);
+        stream.put(self.request_id.clone().into_bytes());
+        if self.origin_type == ORIGIN_DEV_CONSOLE || self.origin_type == ORIGIN_TEST {
+            stream.put_var_long(self.player_actor_unique_id);
+        }
+    }
+}
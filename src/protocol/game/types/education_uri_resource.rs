@@ -1,4 +1,6 @@
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::get_string;
 
 #[derive(Debug)]
 pub struct EducationUriResource {
@@ -7,12 +9,17 @@ pub struct EducationUriResource {
 }
 
 impl EducationUriResource {
-    pub fn read(stream: &mut Stream) -> EducationUriResource {
-        let mut length = stream.get_unsigned_var_int();
-        let button_name = String::from_utf8(stream.get(length).unwrap()).unwrap();
-        length = stream.get_unsigned_var_int();
-        let link_uri = String::from_utf8(stream.get(length).unwrap()).unwrap();
+    pub fn read(stream: &mut Stream) -> Result<EducationUriResource, ClientError> {
+        let button_name = get_string(stream)?;
+        let link_uri = get_string(stream)?;
 
-        EducationUriResource{ button_name, link_uri }
+        Ok(EducationUriResource{ button_name, link_uri })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_unsigned_var_int(self.button_name.len() as u32);
+        stream.put(self.button_name.clone().into_bytes());
+        stream.put_unsigned_var_int(self.link_uri.len() as u32);
+        stream.put(self.link_uri.clone().into_bytes());
     }
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::{StreamReader, get_string};
 
 #[derive(Debug)]
 pub struct Experiments {
@@ -8,20 +10,45 @@ pub struct Experiments {
 }
 
 impl Experiments {
-    pub fn read(stream: &mut Stream) -> Experiments {
+    pub fn new(experiments: HashMap<String, bool>, has_previously_used_experiments: bool) -> Experiments {
+        Experiments{ experiments, has_previously_used_experiments }
+    }
+
+    pub fn get_experiments(&self) -> &HashMap<String, bool> {
+        &self.experiments
+    }
+
+    pub fn is_enabled(&self, experiment_name: &str) -> bool {
+        self.experiments.get(experiment_name).copied().unwrap_or(false)
+    }
+
+    pub fn has_previously_used_experiments(&self) -> bool {
+        self.has_previously_used_experiments
+    }
+
+    pub fn read(stream: &mut Stream) -> Result<Experiments, ClientError> {
         let mut experiments = HashMap::new();
 
-        let length = stream.get_l_int();
+        let length = stream.try_get_l_int()?;
 
         for _ in 0..length {
-            let len = stream.get_unsigned_var_int();
-            let experiment_name = String::from_utf8(stream.get(len).unwrap()).unwrap();
-            let enabled = stream.get_bool();
+            let experiment_name = get_string(stream)?;
+            let enabled = stream.try_get_bool()?;
             experiments.insert(experiment_name, enabled);
         }
 
-        let has_previously_used_experiments = stream.get_bool();
+        let has_previously_used_experiments = stream.try_get_bool()?;
 
-        Experiments{ experiments, has_previously_used_experiments }
+        Ok(Experiments{ experiments, has_previously_used_experiments })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_l_int(self.experiments.len() as u32);
+        for (experiment_name, enabled) in &self.experiments {
+            stream.put_unsigned_var_int(experiment_name.len() as u32);
+            stream.put(experiment_name.clone().into_bytes());
+            stream.put_bool(*enabled);
+        }
+        stream.put_bool(self.has_previously_used_experiments);
     }
 }
\ No newline at end of file
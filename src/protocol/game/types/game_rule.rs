@@ -0,0 +1,49 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+
+#[derive(Debug, Clone, Copy)]
+pub enum GameRuleValue {
+    Bool(bool),
+    Int(u32),
+    Float(f32)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct GameRule {
+    pub is_player_modifiable: bool,
+    pub value: GameRuleValue
+}
+
+impl GameRule {
+    pub fn read(stream: &mut Stream) -> Result<GameRule, ClientError> {
+        let is_player_modifiable = stream.try_get_bool()?;
+        let game_type = stream.try_get_unsigned_var_int()?;
+        let value = match game_type {
+            1 => GameRuleValue::Bool(stream.try_get_bool()?), // Bool Game Rule
+            2 => GameRuleValue::Int(stream.try_get_unsigned_var_int()?), // Int Game Rule
+            3 => GameRuleValue::Float(stream.try_get_l_float()?), // Float Game Rule
+            _ => return Err(ClientError::Decode(format!("Unknown game rule type {}", game_type), None))
+        };
+
+        Ok(GameRule{ is_player_modifiable, value })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_bool(self.is_player_modifiable);
+        match self.value {
+            GameRuleValue::Bool(value) => {
+                stream.put_unsigned_var_int(1);
+                stream.put_bool(value);
+            },
+            GameRuleValue::Int(value) => {
+                stream.put_unsigned_var_int(2);
+                stream.put_unsigned_var_int(value);
+            },
+            GameRuleValue::Float(value) => {
+                stream.put_unsigned_var_int(3);
+                stream.put_l_float(value);
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+
+// An item as the server sends it. The extra data (NBT, can place on/destroy) is kept as raw bytes.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ItemStack {
+    pub network_id: i32, // 0 = air, nothing else is written
+    pub count: u16,
+    pub metadata: u32,
+    pub stack_id: Option<i32>,
+    pub block_runtime_id: i32,
+    pub extra_data: Vec<u8>
+}
+
+impl ItemStack {
+    pub fn new(network_id: i32, count: u16, metadata: u32) -> ItemStack {
+        ItemStack{ network_id, count, metadata, stack_id: None, block_runtime_id: 0, extra_data: vec![] }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.network_id == 0
+    }
+
+    pub fn read(stream: &mut Stream) -> Result<ItemStack, ClientError> {
+        let network_id = stream.try_get_var_int()?;
+        if network_id == 0 {
+            return Ok(ItemStack::default());
+        }
+        let count = stream.try_get_l_short()?;
+        let metadata = stream.try_get_unsigned_var_int()?;
+        let stack_id = if stream.try_get_bool()? { Some(stream.try_get_var_int()?) } else { None };
+        let block_runtime_id = stream.try_get_var_int()?;
+        let length = stream.try_get_unsigned_var_int()?;
+        let extra_data = stream.try_get(length)?;
+
+        Ok(ItemStack{ network_id, count, metadata, stack_id, block_runtime_id, extra_data })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_var_int(self.network_id);
+        if self.network_id == 0 {
+            return;
+        }
+        stream.put_l_short(self.count);
+        stream.put_unsigned_var_int(self.metadata);
+        stream.put_bool(self.stack_id.is_some());
+        if let Some(stack_id) = self.stack_id {
+            stream.put_var_int(stack_id);
+        }
+        stream.put_var_int(self.block_runtime_id);
+        stream.put_unsigned_var_int(self.extra_data.len() as u32);
+        stream.put(self.extra_data.clone());
+    }
+}
@@ -1,8 +1,12 @@
 use std::collections::HashMap;
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::protocol_version::V1_21_0;
 use crate::protocol::game::types::education_uri_resource::EducationUriResource;
 use crate::protocol::game::types::experiments::Experiments;
+use crate::protocol::game::types::game_rule::GameRule;
 use crate::protocol::game::types::spawn_settings::SpawnSettings;
+use crate::protocol::game::serializer::stream_helper::{StreamReader, get_string};
 
 #[derive(Debug)]
 pub struct LevelSettings {
@@ -30,7 +34,7 @@ pub struct LevelSettings {
     pub platform_broadcast_mode: i32,
     pub commands_enabled: bool,
     pub is_texture_packs_required: bool,
-    pub game_rules: HashMap<String, u32>,
+    pub game_rules: HashMap<String, GameRule>,
     pub experiments: Experiments,
     pub has_bonus_chest_enabled: bool,
     pub has_start_with_map_enabled: bool,
@@ -60,86 +64,70 @@ pub struct LevelSettings {
 }
 
 impl LevelSettings {
-    pub fn read(stream: &mut Stream) -> LevelSettings {
-        let seed = stream.get_l_long();
-        let spawn_settings = SpawnSettings::read(stream);
-        let generator = stream.get_var_int();
-        let world_game_mode = stream.get_var_int();
-        let hardcore = stream.get_bool();
-        let difficulty = stream.get_var_int();
-        let spawn_position = vec![stream.get_var_int(), stream.get_unsigned_var_int() as i32, stream.get_var_int()];
-        let has_achievements_disabled = stream.get_bool();
-        let editor_world_type = stream.get_var_int();
-        let created_in_editor_mode = stream.get_bool();
-        let exported_from_editor_mode = stream.get_bool();
-        let time = stream.get_var_int();
-        let edu_edition_offer = stream.get_var_int();
-        let has_edu_features_enabled = stream.get_bool();
-        let mut length = stream.get_unsigned_var_int();
-        let edu_product_uuid = String::from_utf8(stream.get(length).unwrap()).unwrap();
-        let rain_level = stream.get_l_float();
-        let lightning_level = stream.get_l_float();
-        let has_confirmed_platform_locked_content = stream.get_bool();
-        let is_multiplayer_game = stream.get_bool();
-        let has_lan_broadcast = stream.get_bool();
-        let xbox_live_broadcast_mode = stream.get_var_int();
-        let platform_broadcast_mode = stream.get_var_int();
-        let commands_enabled = stream.get_bool();
-        let is_texture_packs_required = stream.get_bool();
-        let count = stream.get_unsigned_var_int();
+    pub fn read(stream: &mut Stream, protocol: u32) -> Result<LevelSettings, ClientError> {
+        let seed = stream.try_get_l_long()?;
+        let spawn_settings = SpawnSettings::read(stream)?;
+        let generator = stream.try_get_var_int()?;
+        let world_game_mode = stream.try_get_var_int()?;
+        let hardcore = stream.try_get_bool()?;
+        let difficulty = stream.try_get_var_int()?;
+        let spawn_position = vec![stream.try_get_var_int()?, stream.try_get_unsigned_var_int()? as i32, stream.try_get_var_int()?];
+        let has_achievements_disabled = stream.try_get_bool()?;
+        let editor_world_type = stream.try_get_var_int()?;
+        let created_in_editor_mode = stream.try_get_bool()?;
+        let exported_from_editor_mode = stream.try_get_bool()?;
+        let time = stream.try_get_var_int()?;
+        let edu_edition_offer = stream.try_get_var_int()?;
+        let has_edu_features_enabled = stream.try_get_bool()?;
+        let edu_product_uuid = get_string(stream)?;
+        let rain_level = stream.try_get_l_float()?;
+        let lightning_level = stream.try_get_l_float()?;
+        let has_confirmed_platform_locked_content = stream.try_get_bool()?;
+        let is_multiplayer_game = stream.try_get_bool()?;
+        let has_lan_broadcast = stream.try_get_bool()?;
+        let xbox_live_broadcast_mode = stream.try_get_var_int()?;
+        let platform_broadcast_mode = stream.try_get_var_int()?;
+        let commands_enabled = stream.try_get_bool()?;
+        let is_texture_packs_required = stream.try_get_bool()?;
+        let count = stream.try_get_unsigned_var_int()?;
         let mut game_rules = HashMap::new();
         for _ in 0..count {
-            length = stream.get_unsigned_var_int();
-            let name = String::from_utf8(stream.get(length).unwrap()).unwrap();
-            let _is_player_modifiable = stream.get_bool();
-            let game_type = stream.get_unsigned_var_int();
-            match game_type {
-                1 => { // Bool Game Rule
-                    stream.get_bool();
-                },
-                2 => { // Int Game Rule
-                    stream.get_unsigned_var_int();
-                },
-                3 => { // Float Game Rule
-                    stream.get_l_float();
-                },
-                _ => { panic!("Unknown game type {}", game_type); }
-            }
-
-            game_rules.insert(name, game_type);
+            let name = get_string(stream)?;
+            game_rules.insert(name, GameRule::read(stream)?);
+        }
+        let experiments = Experiments::read(stream)?;
+        let has_bonus_chest_enabled = stream.try_get_bool()?;
+        let has_start_with_map_enabled = stream.try_get_bool()?;
+        let default_player_permission = stream.try_get_var_int()?;
+        let server_chunk_tick_radius = stream.try_get_l_int()?;
+        let has_locked_behavior_pack = stream.try_get_bool()?;
+        let has_locked_resource_pack = stream.try_get_bool()?;
+        let is_from_locked_world_template = stream.try_get_bool()?;
+        let use_msa_gamer_tags_only = stream.try_get_bool()?;
+        let is_from_world_template = stream.try_get_bool()?;
+        let is_world_template_option_locked = stream.try_get_bool()?;
+        let only_spawn_v1_villagers = stream.try_get_bool()?;
+        let disable_persona = stream.try_get_bool()?;
+        let disable_custom_skins = stream.try_get_bool()?;
+        let mute_emote_announcements = stream.try_get_bool()?;
+        let vanilla_version = get_string(stream)?;
+        let limited_world_width = stream.try_get_l_int()?;
+        let limited_world_length = stream.try_get_l_int()?;
+        let is_new_nether = stream.try_get_bool()?;
+        let edu_shared_uri_resource = EducationUriResource::read(stream)?;
+        let experimental_gameplay_override = stream.try_get_bool()?;
+        let chat_restriction_level = stream.try_get_byte()?;
+        let disable_player_interactions = stream.try_get_bool()?;
+        let mut server_identifier = String::new();
+        let mut world_identifier = String::new();
+        let mut scenario_identifier = String::new();
+        if protocol >= V1_21_0 {
+            server_identifier = get_string(stream)?;
+            world_identifier = get_string(stream)?;
+            scenario_identifier = get_string(stream)?;
         }
-        let experiments = Experiments::read(stream);
-        let has_bonus_chest_enabled = stream.get_bool();
-        let has_start_with_map_enabled = stream.get_bool();
-        let default_player_permission = stream.get_var_int();
-        let server_chunk_tick_radius = stream.get_l_int();
-        let has_locked_behavior_pack = stream.get_bool();
-        let has_locked_resource_pack = stream.get_bool();
-        let is_from_locked_world_template = stream.get_bool();
-        let use_msa_gamer_tags_only = stream.get_bool();
-        let is_from_world_template = stream.get_bool();
-        let is_world_template_option_locked = stream.get_bool();
-        let only_spawn_v1_villagers = stream.get_bool();
-        let disable_persona = stream.get_bool();
-        let disable_custom_skins = stream.get_bool();
-        let mute_emote_announcements = stream.get_bool();
-        length = stream.get_unsigned_var_int();
-        let vanilla_version = String::from_utf8(stream.get(length).unwrap()).unwrap();
-        let limited_world_width = stream.get_l_int();
-        let limited_world_length = stream.get_l_int();
-        let is_new_nether = stream.get_bool();
-        let edu_shared_uri_resource = EducationUriResource::read(stream);
-        let experimental_gameplay_override = stream.get_bool();
-        let chat_restriction_level = stream.get_byte();
-        let disable_player_interactions = stream.get_bool();
-        length = stream.get_unsigned_var_int();
-        let server_identifier = String::from_utf8(stream.get(length).unwrap()).unwrap();
-        length = stream.get_unsigned_var_int();
-        let world_identifier = String::from_utf8(stream.get(length).unwrap()).unwrap();
-        length = stream.get_unsigned_var_int();
-        let scenario_identifier = String::from_utf8(stream.get(length).unwrap()).unwrap();
         
-        LevelSettings{
+        Ok(LevelSettings{
             seed,
             spawn_settings,
             generator,
@@ -191,6 +179,74 @@ impl LevelSettings {
             server_identifier,
             world_identifier,
             scenario_identifier,
+        })
+    }
+
+    pub fn write(&self, stream: &mut Stream, protocol: u32) {
+        stream.put_l_long(self.seed);
+        self.spawn_settings.write(stream);
+        stream.put_var_int(self.generator);
+        stream.put_var_int(self.world_game_mode);
+        stream.put_bool(self.hardcore);
+        stream.put_var_int(self.difficulty);
+        stream.put_var_int(self.spawn_position[0]);
+        stream.put_unsigned_var_int(self.spawn_position[1] as u32);
+        stream.put_var_int(self.spawn_position[2]);
+        stream.put_bool(self.has_achievements_disabled);
+        stream.put_var_int(self.editor_world_type);
+        stream.put_bool(self.created_in_editor_mode);
+        stream.put_bool(self.exported_from_editor_mode);
+        stream.put_var_int(self.time);
+        stream.put_var_int(self.edu_edition_offer);
+        stream.put_bool(self.has_edu_features_enabled);
+        stream.put_unsigned_var_int(self.edu_product_uuid.len() as u32);
+        stream.put(self.edu_product_uuid.clone().into_bytes());
+        stream.put_l_float(self.rain_level);
+        stream.put_l_float(self.lightning_level);
+        stream.put_bool(self.has_confirmed_platform_locked_content);
+        stream.put_bool(self.is_multiplayer_game);
+        stream.put_bool(self.has_lan_broadcast);
+        stream.put_var_int(self.xbox_live_broadcast_mode);
+        stream.put_var_int(self.platform_broadcast_mode);
+        stream.put_bool(self.commands_enabled);
+        stream.put_bool(self.is_texture_packs_required);
+        stream.put_unsigned_var_int(self.game_rules.len() as u32);
+        for (name, game_rule) in &self.game_rules {
+            stream.put_unsigned_var_int(name.len() as u32);
+            stream.put(name.clone().into_bytes());
+            game_rule.write(stream);
+        }
+        self.experiments.write(stream);
+        stream.put_bool(self.has_bonus_chest_enabled);
+        stream.put_bool(self.has_start_with_map_enabled);
+        stream.put_var_int(self.default_player_permission);
+        stream.put_l_int(self.server_chunk_tick_radius);
+        stream.put_bool(self.has_locked_behavior_pack);
+        stream.put_bool(self.has_locked_resource_pack);
+        stream.put_bool(self.is_from_locked_world_template);
+        stream.put_bool(self.use_msa_gamer_tags_only);
+        stream.put_bool(self.is_from_world_template);
+        stream.put_bool(self.is_world_template_option_locked);
+        stream.put_bool(self.only_spawn_v1_villagers);
+        stream.put_bool(self.disable_persona);
+        stream.put_bool(self.disable_custom_skins);
+        stream.put_bool(self.mute_emote_announcements);
+        stream.put_unsigned_var_int(self.vanilla_version.len() as u32);
+        stream.put(self.vanilla_version.clone().into_bytes());
+        stream.put_l_int(self.limited_world_width);
+        stream.put_l_int(self.limited_world_length);
+        stream.put_bool(self.is_new_nether);
+        self.edu_shared_uri_resource.write(stream);
+        stream.put_bool(self.experimental_gameplay_override);
+        stream.put_byte(self.chat_restriction_level);
+        stream.put_bool(self.disable_player_interactions);
+        if protocol >= V1_21_0 {
+            stream.put_unsigned_var_int(self.server_identifier.len() as u32);
+            stream.put(self.server_identifier.clone().into_bytes());
+            stream.put_unsigned_var_int(self.world_identifier.len() as u32);
+            stream.put(self.world_identifier.clone().into_bytes());
+            stream.put_unsigned_var_int(self.scenario_identifier.len() as u32);
+            stream.put(self.scenario_identifier.clone().into_bytes());
         }
     }
-}
\ No newline at end of file
+}
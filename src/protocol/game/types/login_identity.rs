@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// Claims of one identity chain JWT, only the last entry of an authenticated chain has `extraData`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityClaims {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra_data: Option<IdentityData>,
+    pub identity_public_key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificate_authority: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nbf: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iat: Option<i64>
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IdentityData {
+    #[serde(rename = "XUID", default)]
+    pub xuid: String, // empty for offline identities
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    #[serde(rename = "identity")]
+    pub uuid: Uuid,
+    #[serde(rename = "titleId", default, skip_serializing_if = "String::is_empty")]
+    pub title_id: String
+}
+
+// The identity a chain was issued for
+#[derive(Debug, Clone)]
+pub struct LoginIdentity {
+    pub xuid: String,
+    pub uuid: Uuid,
+    pub display_name: String,
+    pub title_id: String,
+    pub identity_public_key: String,
+    pub expires_at: Option<i64>, // earliest `exp` of the chain
+    pub authenticated: bool // set by `login::verify_chain` if the chain leads to the trusted root key
+}
+
+impl LoginIdentity {
+    // False for identities read without verification, even if they have a XUID
+    pub fn is_authenticated(&self) -> bool {
+        self.authenticated
+    }
+}
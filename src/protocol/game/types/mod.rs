@@ -6,4 +6,18 @@ pub mod player_movement_settings;
 pub mod network_permissions;
 pub mod cacheable_nbt;
 pub mod block_palette_entry;
-pub mod item_type_entry;
\ No newline at end of file
+pub mod item_type_entry;
+pub mod game_rule;
+pub mod client_data;
+pub mod login_identity;
+pub mod command_origin_data;
+pub mod command_data;
+pub mod vector;
+pub mod attribute;
+pub mod actor_metadata;
+pub mod property_sync_data;
+pub mod actor_link;
+pub mod item_stack;
+pub mod abilities;
+pub mod actor_properties;
+pub mod skin_data;
\ No newline at end of file
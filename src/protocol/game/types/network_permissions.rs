@@ -1,4 +1,6 @@
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
 
 #[derive(Debug)]
 pub struct NetworkPermissions {
@@ -6,9 +8,13 @@ pub struct NetworkPermissions {
 }
 
 impl NetworkPermissions {
-    pub fn read(stream: &mut Stream) -> NetworkPermissions {
-        let disable_client_sounds = stream.get_bool();
+    pub fn read(stream: &mut Stream) -> Result<NetworkPermissions, ClientError> {
+        let disable_client_sounds = stream.try_get_bool()?;
 
-        NetworkPermissions{ disable_client_sounds }
+        Ok(NetworkPermissions{ disable_client_sounds })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_bool(self.disable_client_sounds);
     }
 }
\ No newline at end of file
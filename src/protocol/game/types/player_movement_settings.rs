@@ -1,4 +1,6 @@
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
 
 #[derive(Debug)]
 pub struct PlayerMovementSettings {
@@ -8,11 +10,17 @@ pub struct PlayerMovementSettings {
 }
 
 impl PlayerMovementSettings {
-    pub fn read(stream: &mut Stream) -> PlayerMovementSettings {
-        let movement_type = stream.get_var_int();
-        let rewind_history_size = stream.get_var_int();
-        let server_auth_block_breaking = stream.get_bool();
+    pub fn read(stream: &mut Stream) -> Result<PlayerMovementSettings, ClientError> {
+        let movement_type = stream.try_get_var_int()?;
+        let rewind_history_size = stream.try_get_var_int()?;
+        let server_auth_block_breaking = stream.try_get_bool()?;
 
-        PlayerMovementSettings{ movement_type, rewind_history_size, server_auth_block_breaking }
+        Ok(PlayerMovementSettings{ movement_type, rewind_history_size, server_auth_block_breaking })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_var_int(self.movement_type);
+        stream.put_var_int(self.rewind_history_size);
+        stream.put_bool(self.server_auth_block_breaking);
     }
 }
\ No newline at end of file
@@ -0,0 +1,56 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+
+// Values of the entity properties declared by the behavior packs, keyed by property index
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PropertySyncData {
+    pub int_properties: Vec<(u32, i32)>,
+    pub float_properties: Vec<(u32, f32)>
+}
+
+impl PropertySyncData {
+    // Changed values replace the old ones, others are kept
+    pub fn merge(&mut self, update: &PropertySyncData) {
+        for (index, value) in &update.int_properties {
+            match self.int_properties.iter_mut().find(|(i, _)| i == index) {
+                Some(property) => property.1 = *value,
+                None => self.int_properties.push((*index, *value))
+            }
+        }
+        for (index, value) in &update.float_properties {
+            match self.float_properties.iter_mut().find(|(i, _)| i == index) {
+                Some(property) => property.1 = *value,
+                None => self.float_properties.push((*index, *value))
+            }
+        }
+    }
+
+    pub fn read(stream: &mut Stream) -> Result<PropertySyncData, ClientError> {
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut int_properties = Vec::new();
+        for _ in 0..count {
+            int_properties.push((stream.try_get_unsigned_var_int()?, stream.try_get_var_int()?));
+        }
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut float_properties = Vec::new();
+        for _ in 0..count {
+            float_properties.push((stream.try_get_unsigned_var_int()?, stream.try_get_l_float()?));
+        }
+
+        Ok(PropertySyncData{ int_properties, float_properties })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_unsigned_var_int(self.int_properties.len() as u32);
+        for (index, value) in &self.int_properties {
+            stream.put_unsigned_var_int(*index);
+            stream.put_var_int(*value);
+        }
+        stream.put_unsigned_var_int(self.float_properties.len() as u32);
+        for (index, value) in &self.float_properties {
+            stream.put_unsigned_var_int(*index);
+            stream.put_l_float(*value);
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::{get_string, put_string, StreamReader};
+use crate::protocol::game::types::client_data::{PersonaPiece, PieceTintColor};
+use std::fs;
+use std::path::Path;
+
+pub const ANIMATION_TYPE_NONE: u32 = 0;
+pub const ANIMATION_TYPE_FACE: u32 = 1;
+pub const ANIMATION_TYPE_BODY_32X32: u32 = 2;
+pub const ANIMATION_TYPE_BODY_128X128: u32 = 3;
+
+// RGBA pixels
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SkinImage {
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>
+}
+
+impl SkinImage {
+    pub fn new(width: u32, height: u32, data: Vec<u8>) -> SkinImage {
+        SkinImage{ width, height, data }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn to_png(&self) -> Result<Vec<u8>, ClientError> {
+        // the sizes come from the network
+        let expected = (self.width as u64).checked_mul(self.height as u64).and_then(|pixels| pixels.checked_mul(4));
+        if expected != Option::from(self.data.len() as u64) {
+            return Err(ClientError::Decode(format!("Skin image has {} bytes, that is not {}x{} RGBA", self.data.len(), self.width, self.height), None));
+        }
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(|e| ClientError::decode("PNG can not be encoded", e))?;
+        writer.write_image_data(&self.data).map_err(|e| ClientError::decode("PNG can not be encoded", e))?;
+        writer.finish().map_err(|e| ClientError::decode("PNG can not be encoded", e))?;
+        Ok(png)
+    }
+
+    pub fn save_png(&self, path: impl AsRef<Path>) -> Result<(), ClientError> {
+        fs::write(path, self.to_png()?)?;
+        Ok(())
+    }
+
+    pub fn read(stream: &mut Stream) -> Result<SkinImage, ClientError> {
+        let width = stream.try_get_l_int()?;
+        let height = stream.try_get_l_int()?;
+        let length = stream.try_get_unsigned_var_int()?;
+        let data = stream.try_get(length)?;
+
+        Ok(SkinImage{ width, height, data })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_l_int(self.width);
+        stream.put_l_int(self.height);
+        stream.put_unsigned_var_int(self.data.len() as u32);
+        stream.put(self.data.clone());
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SkinAnimation {
+    pub image: SkinImage, // the frames below each other
+    pub animation_type: u32,
+    pub frames: f32,
+    pub expression_type: u32
+}
+
+impl SkinAnimation {
+    pub fn read(stream: &mut Stream) -> Result<SkinAnimation, ClientError> {
+        let image = SkinImage::read(stream)?;
+        let animation_type = stream.try_get_l_int()?;
+        let frames = stream.try_get_l_float()?;
+        let expression_type = stream.try_get_l_int()?;
+
+        Ok(SkinAnimation{ image, animation_type, frames, expression_type })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        self.image.write(stream);
+        stream.put_l_int(self.animation_type);
+        stream.put_l_float(self.frames);
+        stream.put_l_int(self.expression_type);
+    }
+}
+
+fn read_persona_piece(stream: &mut Stream) -> Result<PersonaPiece, ClientError> {
+    let piece_id = get_string(stream)?;
+    let piece_type = get_string(stream)?;
+    let pack_id = get_string(stream)?;
+    let is_default = stream.try_get_bool()?;
+    let product_id = get_string(stream)?;
+
+    Ok(PersonaPiece{ is_default, pack_id, piece_id, piece_type, product_id })
+}
+
+fn write_persona_piece(stream: &mut Stream, piece: &PersonaPiece) {
+    put_string(stream, &piece.piece_id);
+    put_string(stream, &piece.piece_type);
+    put_string(stream, &piece.pack_id);
+    stream.put_bool(piece.is_default);
+    put_string(stream, &piece.product_id);
+}
+
+fn read_piece_tint_color(stream: &mut Stream) -> Result<PieceTintColor, ClientError> {
+    let piece_type = get_string(stream)?;
+    let count = stream.try_get_l_int()?;
+    let mut colors = Vec::new();
+    for _ in 0..count {
+        colors.push(get_string(stream)?);
+    }
+
+    Ok(PieceTintColor{ colors, piece_type })
+}
+
+fn write_piece_tint_color(stream: &mut Stream, tint: &PieceTintColor) {
+    put_string(stream, &tint.piece_type);
+    stream.put_l_int(tint.colors.len() as u32);
+    for color in &tint.colors {
+        put_string(stream, color);
+    }
+}
+
+// The skin of a player as PlayerList and PlayerSkin send it, the same data as the skin claims of the client data
+// but with raw images
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SkinData {
+    pub skin_id: String,
+    pub play_fab_id: String,
+    pub resource_patch: String, // JSON, names the geometry to use
+    pub skin_image: SkinImage,
+    pub animations: Vec<SkinAnimation>,
+    pub cape_image: SkinImage,
+    pub geometry_data: String, // JSON
+    pub geometry_data_engine_version: String,
+    pub animation_data: String,
+    pub cape_id: String,
+    pub full_skin_id: String,
+    pub arm_size: String,
+    pub skin_color: String,
+    pub persona_pieces: Vec<PersonaPiece>,
+    pub piece_tint_colors: Vec<PieceTintColor>,
+    pub premium: bool,
+    pub persona: bool,
+    pub persona_cape_on_classic: bool,
+    pub primary_user: bool,
+    pub override_appearance: bool
+}
+
+impl SkinData {
+    pub fn has_cape(&self) -> bool {
+        !self.cape_image.is_empty()
+    }
+
+    pub fn read(stream: &mut Stream) -> Result<SkinData, ClientError> {
+        let skin_id = get_string(stream)?;
+        let play_fab_id = get_string(stream)?;
+        let resource_patch = get_string(stream)?;
+        let skin_image = SkinImage::read(stream)?;
+        let count = stream.try_get_l_int()?;
+        let mut animations = Vec::new();
+        for _ in 0..count {
+            animations.push(SkinAnimation::read(stream)?);
+        }
+        let cape_image = SkinImage::read(stream)?;
+        let geometry_data = get_string(stream)?;
+        let geometry_data_engine_version = get_string(stream)?;
+        let animation_data = get_string(stream)?;
+        let cape_id = get_string(stream)?;
+        let full_skin_id = get_string(stream)?;
+        let arm_size = get_string(stream)?;
+        let skin_color = get_string(stream)?;
+        let count = stream.try_get_l_int()?;
+        let mut persona_pieces = Vec::new();
+        for _ in 0..count {
+            persona_pieces.push(read_persona_piece(stream)?);
+        }
+        let count = stream.try_get_l_int()?;
+        let mut piece_tint_colors = Vec::new();
+        for _ in 0..count {
+            piece_tint_colors.push(read_piece_tint_color(stream)?);
+        }
+        let premium = stream.try_get_bool()?;
+        let persona = stream.try_get_bool()?;
+        let persona_cape_on_classic = stream.try_get_bool()?;
+        let primary_user = stream.try_get_bool()?;
+        let override_appearance = stream.try_get_bool()?;
+
+        Ok(SkinData{
+            skin_id,
+            play_fab_id,
+            resource_patch,
+            skin_image,
+            animations,
+            cape_image,
+            geometry_data,
+            geometry_data_engine_version,
+            animation_data,
+            cape_id,
+            full_skin_id,
+            arm_size,
+            skin_color,
+            persona_pieces,
+            piece_tint_colors,
+            premium,
+            persona,
+            persona_cape_on_classic,
+            primary_user,
+            override_appearance
+        })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        put_string(stream, &self.skin_id);
+        put_string(stream, &self.play_fab_id);
+        put_string(stream, &self.resource_patch);
+        self.skin_image.write(stream);
+        stream.put_l_int(self.animations.len() as u32);
+        for animation in &self.animations {
+            animation.write(stream);
+        }
+        self.cape_image.write(stream);
+        put_string(stream, &self.geometry_data);
+        put_string(stream, &self.geometry_data_engine_version);
+        put_string(stream, &self.animation_data);
+        put_string(stream, &self.cape_id);
+        put_string(stream, &self.full_skin_id);
+        put_string(stream, &self.arm_size);
+        put_string(stream, &self.skin_color);
+        stream.put_l_int(self.persona_pieces.len() as u32);
+        for piece in &self.persona_pieces {
+            write_persona_piece(stream, piece);
+        }
+        stream.put_l_int(self.piece_tint_colors.len() as u32);
+        for tint in &self.piece_tint_colors {
+            write_piece_tint_color(stream, tint);
+        }
+        stream.put_bool(self.premium);
+        stream.put_bool(self.persona);
+        stream.put_bool(self.persona_cape_on_classic);
+        stream.put_bool(self.primary_user);
+        stream.put_bool(self.override_appearance);
+    }
+}
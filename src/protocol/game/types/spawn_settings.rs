@@ -1,4 +1,6 @@
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::{StreamReader, get_string};
 
 #[derive(Debug)]
 pub struct SpawnSettings {
@@ -8,14 +10,23 @@ pub struct SpawnSettings {
 }
 
 impl SpawnSettings {
-    pub fn read(stream: &mut Stream) -> SpawnSettings {
-        let biome_type = stream.get_l_short();
+    pub fn get_dimension_id(&self) -> i32 {
+        self.dimension_id
+    }
+
+    pub fn read(stream: &mut Stream) -> Result<SpawnSettings, ClientError> {
+        let biome_type = stream.try_get_l_short()?;
+        let biome_name = get_string(stream)?;
 
-        let length = stream.get_unsigned_var_int();
-        let biome_name = String::from_utf8(stream.get(length).unwrap()).unwrap();
+        let dimension_id = stream.try_get_var_int()?;
 
-        let dimension_id = stream.get_var_int();
+        Ok(SpawnSettings{ biome_type, biome_name, dimension_id })
+    }
 
-        SpawnSettings{ biome_type, biome_name, dimension_id }
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_l_short(self.biome_type);
+        stream.put_unsigned_var_int(self.biome_name.len() as u32);
+        stream.put(self.biome_name.clone().into_bytes());
+        stream.put_var_int(self.dimension_id);
     }
 }
\ No newline at end of file
@@ -0,0 +1,87 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+use std::ops::{Add, Mul, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vec2 {
+    pub x: f32,
+    pub y: f32
+}
+
+impl Vec2 {
+    pub fn new(x: f32, y: f32) -> Vec2 {
+        Vec2{ x, y }
+    }
+
+    pub fn read(stream: &mut Stream) -> Result<Vec2, ClientError> {
+        let x = stream.try_get_l_float()?;
+        let y = stream.try_get_l_float()?;
+
+        Ok(Vec2{ x, y })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_l_float(self.x);
+        stream.put_l_float(self.y);
+    }
+
+    pub fn length(&self) -> f32 {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vec3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32
+}
+
+impl Vec3 {
+    pub fn new(x: f32, y: f32, z: f32) -> Vec3 {
+        Vec3{ x, y, z }
+    }
+
+    pub fn read(stream: &mut Stream) -> Result<Vec3, ClientError> {
+        let x = stream.try_get_l_float()?;
+        let y = stream.try_get_l_float()?;
+        let z = stream.try_get_l_float()?;
+
+        Ok(Vec3{ x, y, z })
+    }
+
+    pub fn write(&self, stream: &mut Stream) {
+        stream.put_l_float(self.x);
+        stream.put_l_float(self.y);
+        stream.put_l_float(self.z);
+    }
+
+    pub fn length(&self) -> f32 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Vec3;
+
+    fn add(self, other: Vec3) -> Vec3 {
+        Vec3{ x: self.x + other.x, y: self.y + other.y, z: self.z + other.z }
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Vec3;
+
+    fn sub(self, other: Vec3) -> Vec3 {
+        Vec3{ x: self.x - other.x, y: self.y - other.y, z: self.z - other.z }
+    }
+}
+
+impl Mul<f32> for Vec3 {
+    type Output = Vec3;
+
+    fn mul(self, factor: f32) -> Vec3 {
+        Vec3{ x: self.x * factor, y: self.y * factor, z: self.z * factor }
+    }
+}
@@ -0,0 +1,57 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::protocol_version::LATEST;
+use crate::protocol::game::types::attribute::Attribute;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+
+// Only the changed attributes of the actor are sent
+pub struct UpdateAttributes {
+    pub actor_runtime_id: u64,
+    pub attributes: Vec<Attribute>,
+    pub tick: u64
+}
+
+pub fn new(actor_runtime_id: u64, attributes: Vec<Attribute>, tick: u64) -> UpdateAttributes {
+    UpdateAttributes{ actor_runtime_id, attributes, tick }
+}
+
+impl UpdateAttributes {
+    pub fn get_attribute(&self, name: &str) -> Option<&Attribute> {
+        self.attributes.iter().find(|attribute| attribute.name == name)
+    }
+}
+
+impl BedrockPacket for UpdateAttributes {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::UpdateAttributes);
+
+    fn encode(&self, stream: &mut Stream) {
+        self.encode_versioned(stream, LATEST);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<UpdateAttributes, ClientError> {
+        UpdateAttributes::decode_versioned(stream, LATEST)
+    }
+
+    fn encode_versioned(&self, stream: &mut Stream, protocol: u32) {
+        stream.put_unsigned_var_long(self.actor_runtime_id);
+        stream.put_unsigned_var_int(self.attributes.len() as u32);
+        for attribute in &self.attributes {
+            attribute.write(stream, protocol);
+        }
+        stream.put_unsigned_var_long(self.tick);
+    }
+
+    fn decode_versioned(stream: &mut Stream, protocol: u32) -> Result<UpdateAttributes, ClientError> {
+        let actor_runtime_id = stream.try_get_unsigned_var_long()?;
+        let count = stream.try_get_unsigned_var_int()?;
+        let mut attributes = Vec::new();
+        for _ in 0..count {
+            attributes.push(Attribute::read(stream, protocol)?);
+        }
+        let tick = stream.try_get_unsigned_var_long()?;
+
+        Ok(UpdateAttributes{ actor_runtime_id, attributes, tick })
+    }
+}
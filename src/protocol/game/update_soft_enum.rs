@@ -0,0 +1,37 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::bedrock_packet::BedrockPacket;
+use crate::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use crate::protocol::game::serializer::stream_helper::{get_string, get_strings, put_string, put_strings, StreamReader};
+
+pub const TYPE_ADD: u8 = 0;
+pub const TYPE_REMOVE: u8 = 1;
+pub const TYPE_SET: u8 = 2;
+
+pub struct UpdateSoftEnum {
+    pub enum_name: String,
+    pub values: Vec<String>,
+    pub action_type: u8
+}
+
+pub fn new(enum_name: String, values: Vec<String>, action_type: u8) -> UpdateSoftEnum {
+    UpdateSoftEnum{ enum_name, values, action_type }
+}
+
+impl BedrockPacket for UpdateSoftEnum {
+    const ID: u16 = BedrockPacketType::get_byte(BedrockPacketType::UpdateSoftEnum);
+
+    fn encode(&self, stream: &mut Stream) {
+        put_string(stream, &self.enum_name);
+        put_strings(stream, &self.values);
+        stream.put_byte(self.action_type);
+    }
+
+    fn decode(stream: &mut Stream) -> Result<UpdateSoftEnum, ClientError> {
+        let enum_name = get_string(stream)?;
+        let values = get_strings(stream)?;
+        let action_type = stream.try_get_byte()?;
+
+        Ok(UpdateSoftEnum{ enum_name, values, action_type })
+    }
+}
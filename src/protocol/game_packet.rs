@@ -1,74 +1,145 @@
+use crate::error::ClientError;
+use crate::protocol::game::network_settings;
 use crate::utils::encryption::Encryption;
+use crate::utils::log_target;
 use binary_utils::binary::Stream;
 use libdeflater::{CompressionLvl, Compressor};
+use log::trace;
 use miniz_oxide::inflate::decompress_to_vec;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompressionAlgorithm {
+    Zlib,
+    Snappy,
+    None
+}
+
+// Prefix byte of every compressed batch
+const PREFIX_ZLIB: u8 = 0;
+const PREFIX_SNAPPY: u8 = 1;
+const PREFIX_NONE: u8 = 0xff;
+
+const ZLIB_LEVEL: CompressionLvl = match CompressionLvl::new(7) {
+    Ok(level) => level,
+    Err(_) => panic!("Invalid compression level")
+};
+
+impl CompressionAlgorithm {
+    // The id is the prefix byte of a compressed batch
+    pub fn from_id(id: u8) -> Option<CompressionAlgorithm> {
+        match id {
+            PREFIX_ZLIB => Option::from(CompressionAlgorithm::Zlib),
+            PREFIX_SNAPPY => Option::from(CompressionAlgorithm::Snappy),
+            PREFIX_NONE => Option::from(CompressionAlgorithm::None),
+            _ => None
+        }
+    }
+
+    pub fn get_id(self) -> u8 {
+        match self {
+            CompressionAlgorithm::Zlib => PREFIX_ZLIB,
+            CompressionAlgorithm::Snappy => PREFIX_SNAPPY,
+            CompressionAlgorithm::None => PREFIX_NONE
+        }
+    }
+
+    // The NetworkSettings value is a short, "none" is 0xffff there and not 0xff
+    pub fn from_network_id(id: u16) -> Option<CompressionAlgorithm> {
+        match id {
+            network_settings::ZLIB => Option::from(CompressionAlgorithm::Zlib),
+            network_settings::SNAPPY => Option::from(CompressionAlgorithm::Snappy),
+            network_settings::NONE => Option::from(CompressionAlgorithm::None),
+            _ => None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Compression {
+    pub algorithm: CompressionAlgorithm,
+    // batches smaller than this are sent with the "none" prefix, 0 disables compression
+    pub threshold: u16
+}
+
+impl Compression {
+    pub fn new(algorithm: CompressionAlgorithm, threshold: u16) -> Compression {
+        Compression{ algorithm, threshold }
+    }
+}
+
+// `compression` is None until NetworkSettings is received, batches have no compression prefix before that
 pub struct GamePacket {
     pub encryption: Option<Encryption>,
-    pub compress_enabled: bool
+    pub compression: Option<Compression>
 }
 
 impl GamePacket {
 
-    pub fn new(encryption: Option<Encryption>, compress_enabled: bool) -> GamePacket {
-        GamePacket{ encryption, compress_enabled }
+    pub fn new(encryption: Option<Encryption>, compression: Option<Compression>) -> GamePacket {
+        GamePacket{ encryption, compression }
     }
 
-    pub fn encode(&mut self, payload: &Vec<u8>) -> Vec<u8> {
+    pub fn encode(&mut self, payload: &Vec<u8>) -> Result<Vec<u8>, ClientError> {
         let mut main_stream = Stream::new(vec![0xfe], 0);
 
         let mut compressed = payload.clone();
-        if self.compress_enabled {
-            compressed = GamePacket::compress(payload);
+        if let Some(compression) = self.compression {
+            compressed = GamePacket::compress(payload, compression)?;
         }
 
         let mut encrypted = compressed.clone();
         if let Some(ref mut encryption) = self.encryption {
-            encrypted = encryption.encrypt(&compressed).expect("Game Packet Encrypt Error");
+            encrypted = encryption.encrypt(&compressed).map_err(|e| ClientError::Crypto("Game packet could not be encrypted".to_string(), Option::from(e)))?;
         }
 
         main_stream.put(encrypted);
-        main_stream.get_buffer()
+        Ok(main_stream.get_buffer())
     }
 
-    /*pub fn encrypt(&mut self, payload: &Vec<u8>) -> Vec<u8> {
-        let mut main_stream = Stream::new(vec![0xfe], 0);
-        let compressed = GamePacket::compress(payload);
-        let encrypted = self.encryption.encrypt(&compressed).expect("GamePacket Encrypt Error");
-        main_stream.put(encrypted);
-        main_stream.get_buffer()
-    }*/
-
-    pub fn decrypt(&mut self, payload: &Vec<u8>) -> Vec<u8> {
+    pub fn decrypt(&mut self, payload: &Vec<u8>) -> Result<Vec<u8>, ClientError> {
         if let Some(ref mut encryption) = self.encryption {
-            return encryption.decrypt(payload).expect("Decrypt Error GamePacket");
+            return encryption.decrypt(payload).map_err(|e| ClientError::Crypto("Game packet could not be decrypted".to_string(), Option::from(e)));
         }
-        payload.clone()
+        Ok(payload.clone())
     }
 
-    pub fn compress(payload: &Vec<u8>) -> Vec<u8> {
-        let compression_level = 7;
-        let min_compression_size = 256;
-        let compressible = payload.len() >= min_compression_size;
-        let level = if compressible { compression_level } else { 0 };
+    pub fn compress(payload: &Vec<u8>, compression: Compression) -> Result<Vec<u8>, ClientError> {
+        let compressible = compression.threshold != 0 && payload.len() >= compression.threshold as usize;
+        let algorithm = if compressible { compression.algorithm } else { CompressionAlgorithm::None };
 
-        let compression_level = CompressionLvl::new(level).expect("Invalid compression level");
-        let mut compressor = Compressor::new(compression_level);
+        let mut result = vec![algorithm.get_id()];
+        match algorithm {
+            CompressionAlgorithm::Zlib => {
+                let mut compressor = Compressor::new(ZLIB_LEVEL);
 
-        let mut compressed_data = vec![0u8; compressor.deflate_compress_bound(payload.len())];
+                let mut compressed_data = vec![0u8; compressor.deflate_compress_bound(payload.len())];
 
-        let _compressed_size = compressor
-            .deflate_compress(payload.as_slice(), &mut compressed_data)
-            .expect("Compression failed");
+                let compressed_size = compressor
+                    .deflate_compress(payload.as_slice(), &mut compressed_data)
+                    .map_err(|e| ClientError::compression("Deflate failed", e))?;
+                compressed_data.truncate(compressed_size);
 
-        let mut result = vec![0x00];
-        result.extend(compressed_data);
+                result.extend(compressed_data);
+            },
+            CompressionAlgorithm::Snappy => {
+                result.extend(snap::raw::Encoder::new().compress_vec(payload.as_slice()).map_err(|e| ClientError::compression("Snappy compression failed", e))?);
+            },
+            CompressionAlgorithm::None => result.extend(payload)
+        }
 
-        result
+        Ok(result)
     }
 
-    pub fn decompress(payload: &Vec<u8>) -> Vec<u8> {
-        let decompressed_data = decompress_to_vec(payload.as_slice()).expect("DecompressToVec Error");
-        decompressed_data
+    // Payload starts with the compression prefix, the algorithm is chosen by the sender per batch
+    pub fn decompress(payload: &Vec<u8>) -> Result<Vec<u8>, ClientError> {
+        let (&id, data) = payload.split_first().ok_or(ClientError::Decompression("Game packet has no compression prefix".to_string(), None))?;
+        let algorithm = CompressionAlgorithm::from_id(id).ok_or(ClientError::Decompression(format!("Unknown compression algorithm 0x{:02x}", id), None))?;
+        trace!(target: log_target::GAME, "Compression Type: {:?}", algorithm);
+
+        match algorithm {
+            CompressionAlgorithm::Zlib => decompress_to_vec(data).map_err(|e| ClientError::Decompression(format!("Inflate failed: {:?}", e.status), None)),
+            CompressionAlgorithm::Snappy => snap::raw::Decoder::new().decompress_vec(data).map_err(|e| ClientError::decompression("Snappy decompression failed", e)),
+            CompressionAlgorithm::None => Ok(data.to_vec())
+        }
     }
 }
@@ -1,4 +1,6 @@
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
 
 pub struct IncompatibleProtocol {
     pub server_protocol: u8,
@@ -6,13 +8,14 @@ pub struct IncompatibleProtocol {
     pub server_guid: i64
 }
 
-pub fn decode(bytes: Vec<u8>) -> IncompatibleProtocol {
+pub fn decode(bytes: Vec<u8>) -> Result<IncompatibleProtocol, ClientError> {
     let mut stream = Stream::new(bytes, 0);
 
-    let _ = stream.get_byte();
-    let server_protocol = stream.get_byte();
-    let magic: [u8; 16] = stream.get(16).expect("Failed to get magic").try_into().expect("Invalid length for magic");
-    let server_guid = stream.get_long();
+    let _ = stream.try_get_byte()?;
+    let server_protocol = stream.try_get_byte()?;
+    let mut magic = [0; 16];
+    magic.copy_from_slice(&stream.try_get(16)?);
+    let server_guid = stream.try_get_long()?;
 
-    IncompatibleProtocol { server_protocol, magic, server_guid }
+    Ok(IncompatibleProtocol { server_protocol, magic, server_guid })
 }
@@ -1,3 +1,5 @@
+pub mod unconnected_ping;
+pub mod unconnected_pong;
 pub mod open_conn_req1;
 pub mod open_conn_reply1;
 pub mod open_conn_req2;
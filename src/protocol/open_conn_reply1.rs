@@ -1,6 +1,8 @@
 use binary_utils::binary::Stream;
-use crate::utils::color_format;
-use crate::utils::color_format::COLOR_WHITE;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+use crate::utils::log_target;
+use log::trace;
 
 pub struct OpenConnReply1 {
     pub magic: [u8; 16],
@@ -11,29 +13,30 @@ pub struct OpenConnReply1 {
 }
 
 impl OpenConnReply1 {
-    pub fn decode(bytes: Vec<u8>) -> OpenConnReply1 {
+    pub fn decode(bytes: Vec<u8>) -> Result<OpenConnReply1, ClientError> {
         let mut stream = Stream::new(bytes, 0);
 
-        let _ = stream.get_byte();
-        let magic: [u8; 16] = stream.get(16).expect("Failed to get magic").try_into().expect("Invalid length for magic");
-        let server_guid = stream.get_long();
-        let server_security = stream.get_bool();
+        let _ = stream.try_get_byte()?;
+        let mut magic = [0; 16];
+        magic.copy_from_slice(&stream.try_get(16)?);
+        let server_guid = stream.try_get_long()?;
+        let server_security = stream.try_get_bool()?;
         let mut cookie = None;
         if server_security {
-            cookie = Option::from(stream.get_int());
+            cookie = Option::from(stream.try_get_int()?);
         }
-        let mtu = stream.get_short();
+        let mtu = stream.try_get_short()?;
 
-        OpenConnReply1 { magic, server_guid, server_security, cookie, mtu }
+        Ok(OpenConnReply1 { magic, server_guid, server_security, cookie, mtu })
     }
 
     pub fn debug(&self) {
-        println!("--- {}OpenConnReply1{} ---", color_format::COLOR_GOLD, COLOR_WHITE);
-        println!("Magic: {:?}", self.magic);
+        trace!(target: log_target::RAKNET, "--- OpenConnReply1 ---");
+        trace!(target: log_target::RAKNET, "Magic: {:?}", self.magic);
         let guid_format = format!("{:x}", self.server_guid);
-        println!("Server GUID (Format DecToHex): {}", guid_format);
-        println!("Server Security: {}", self.server_security);
-        println!("Cookie: {:?}", self.cookie);
-        println!("MTU: {}", self.mtu);
+        trace!(target: log_target::RAKNET, "Server GUID (Format DecToHex): {}", guid_format);
+        trace!(target: log_target::RAKNET, "Server Security: {}", self.server_security);
+        trace!(target: log_target::RAKNET, "Cookie: {:?}", self.cookie);
+        trace!(target: log_target::RAKNET, "MTU: {}", self.mtu);
     }
 }
@@ -1,7 +1,9 @@
 use binary_utils::binary::Stream;
-use crate::utils::{address, color_format};
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+use crate::utils::{address, log_target};
 use crate::utils::address::InternetAddress;
-use crate::utils::color_format::COLOR_WHITE;
+use log::trace;
 
 pub struct OpenConnReply2 {
     pub magic: [u8; 16],
@@ -12,28 +14,28 @@ pub struct OpenConnReply2 {
 }
 
 impl OpenConnReply2 {
-    pub fn decode(bytes: Vec<u8>) -> OpenConnReply2 {
+    pub fn decode(bytes: Vec<u8>) -> Result<OpenConnReply2, ClientError> {
         let mut stream = Stream::new(bytes, 0);
 
-        let _ = stream.get_byte();
-        let magic: [u8; 16] = stream.get(16).expect("Failed to get magic").try_into().expect("Invalid length for magic");
+        let _ = stream.try_get_byte()?;
+        let mut magic = [0; 16];
+        magic.copy_from_slice(&stream.try_get(16)?);
 
-        let server_guid = stream.get_long();
-        let (client_address, offset) = address::get_address(stream.get_remaining().unwrap()).unwrap();
-        stream.set_offset(stream.get_offset() + offset);
-        let mtu = stream.get_short();
-        let encryption_enabled = stream.get_bool();
+        let server_guid = stream.try_get_long()?;
+        let client_address = address::read(&mut stream)?;
+        let mtu = stream.try_get_short()?;
+        let encryption_enabled = stream.try_get_bool()?;
 
-        OpenConnReply2 { magic, server_guid, client_address, mtu, encryption_enabled }
+        Ok(OpenConnReply2 { magic, server_guid, client_address, mtu, encryption_enabled })
     }
 
     pub fn debug(&self) {
-        println!("--- {}OpenConnReply2{} ---", color_format::COLOR_GOLD, COLOR_WHITE);
-        println!("Magic: {:?}", self.magic);
+        trace!(target: log_target::RAKNET, "--- OpenConnReply2 ---");
+        trace!(target: log_target::RAKNET, "Magic: {:?}", self.magic);
         let guid_format = format!("{:x}", self.server_guid);
-        println!("Server GUID (Format DecToHex): {}", guid_format);
-        println!("Client Address: {}:{}", self.client_address.address, self.client_address.port);
-        println!("MTU: {}", self.mtu);
-        println!("Encryption Enabled: {}", self.encryption_enabled);
+        trace!(target: log_target::RAKNET, "Server GUID (Format DecToHex): {}", guid_format);
+        trace!(target: log_target::RAKNET, "Client Address: {}:{}", self.client_address.address, self.client_address.port);
+        trace!(target: log_target::RAKNET, "MTU: {}", self.mtu);
+        trace!(target: log_target::RAKNET, "Encryption Enabled: {}", self.encryption_enabled);
     }
 }
@@ -3,6 +3,8 @@
 pub enum PacketType {
     ConnectedPing,
     ConnectedPong,
+    UnconnectedPing,
+    UnconnectedPong,
     OpenConnReq1,
     OpenConnReply1,
     OpenConnReq2,
@@ -25,6 +27,8 @@ impl PacketType {
         match byte {
             0x00 => PacketType::ConnectedPing,
             0x03 => PacketType::ConnectedPong,
+            0x01 => PacketType::UnconnectedPing,
+            0x1c => PacketType::UnconnectedPong,
             0x05 => PacketType::OpenConnReq1,
             0x06 => PacketType::OpenConnReply1,
             0x07 => PacketType::OpenConnReq2,
@@ -46,6 +50,8 @@ impl PacketType {
         match self {
             PacketType::ConnectedPing => 0x00,
             PacketType::ConnectedPong => 0x03,
+            PacketType::UnconnectedPing => 0x01,
+            PacketType::UnconnectedPong => 0x1c,
             PacketType::OpenConnReq1 => 0x05,
             PacketType::OpenConnReply1 => 0x06,
             PacketType::OpenConnReq2 => 0x07,
@@ -0,0 +1,23 @@
+use binary_utils::binary::Stream;
+use crate::protocol::packet_ids::{PacketType, MAGIC};
+
+pub struct UnconnectedPing {
+    pub ping_time: i64,
+    pub client_guid: i64
+}
+
+impl UnconnectedPing {
+
+    pub fn create(ping_time: i64, client_guid: i64) -> UnconnectedPing {
+        UnconnectedPing { ping_time, client_guid }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut stream = Stream::new(Vec::new(), 0);
+        stream.put_byte(PacketType::get_byte(PacketType::UnconnectedPing));
+        stream.put_long(self.ping_time);
+        stream.put(Vec::from(MAGIC));
+        stream.put_long(self.client_guid);
+        stream.get_buffer()
+    }
+}
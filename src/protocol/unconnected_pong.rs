@@ -0,0 +1,45 @@
+use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
+use crate::utils::log_target;
+use log::trace;
+
+pub struct UnconnectedPong {
+    pub ping_time: i64,
+    pub server_guid: i64,
+    pub magic: [u8; 16],
+    // MCPE;motd;protocol;game version;players;max players;server guid;sub motd;game mode;...
+    pub server_id: String
+}
+
+impl UnconnectedPong {
+    pub fn decode(bytes: Vec<u8>) -> Result<UnconnectedPong, ClientError> {
+        let mut stream = Stream::new(bytes, 0);
+
+        let _ = stream.try_get_byte()?;
+        let ping_time = stream.try_get_long()?;
+        let server_guid = stream.try_get_long()?;
+        let mut magic = [0; 16];
+        magic.copy_from_slice(&stream.try_get(16)?);
+        let length = stream.try_get_short()?;
+        let server_id = String::from_utf8_lossy(&stream.try_get(length as u32)?).to_string();
+
+        Ok(UnconnectedPong { ping_time, server_guid, magic, server_id })
+    }
+
+    pub fn get_protocol(&self) -> Option<u32> {
+        self.server_id.split(';').nth(2).and_then(|protocol| protocol.parse().ok())
+    }
+
+    pub fn get_game_version(&self) -> Option<String> {
+        self.server_id.split(';').nth(3).map(|version| version.to_string())
+    }
+
+    pub fn debug(&self) {
+        trace!(target: log_target::RAKNET, "--- UnconnectedPong ---");
+        trace!(target: log_target::RAKNET, "Ping Time: {:?}", self.ping_time);
+        let guid_format = format!("{:x}", self.server_guid);
+        trace!(target: log_target::RAKNET, "Server GUID (Format DecToHex): {}", guid_format);
+        trace!(target: log_target::RAKNET, "Server ID: {}", self.server_id);
+    }
+}
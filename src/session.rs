@@ -0,0 +1,301 @@
+use crate::connection_state::ConnectionState;
+use crate::entity_manager::EntityManager;
+use crate::movement::MovementController;
+use crate::online_players::OnlinePlayers;
+use crate::error::ClientError;
+use crate::protocol::frame_set::{Datagram, FrameNumberCache, MAX_ORDER_CHANNELS, RELIABLE_ORDERED};
+use crate::protocol::game::bedrock_packet::{encode_packet_versioned, BedrockPacket};
+use crate::protocol::game::command_output::{CommandOutput, CommandOutputMessage};
+use crate::protocol::game::command_request::COMMAND_VERSION;
+use crate::protocol::game::player_auth_input::PlayerAuthInput;
+use crate::protocol::game::types::command_origin_data::{CommandOriginData, ORIGIN_PLAYER};
+use crate::protocol::game::types::login_identity::LoginIdentity;
+use crate::protocol::game::types::vector::Vec3;
+use crate::protocol::game::{command_request, protocol_version, text};
+use crate::protocol::game_packet::GamePacket;
+use crate::utils::chunk::world::World;
+use crate::utils::command_tree::{CommandError, CommandTree};
+use binary_utils::binary::Stream;
+use std::collections::HashMap;
+use std::net::UdpSocket;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::oneshot;
+use uuid::{Builder, Uuid};
+
+pub const COMMAND_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy)]
+pub struct SendOptions {
+    pub reliability: u8,
+    pub order_channel: u8
+}
+
+impl SendOptions {
+    pub fn new(reliability: u8, order_channel: u8) -> SendOptions {
+        SendOptions{ reliability, order_channel }
+    }
+}
+
+impl Default for SendOptions {
+    fn default() -> SendOptions {
+        SendOptions{ reliability: RELIABLE_ORDERED, order_channel: 0 }
+    }
+}
+
+// Several packets sent in a single game packet
+pub struct PacketBatch {
+    stream: Stream,
+    protocol: u32
+}
+
+impl PacketBatch {
+    pub fn new() -> PacketBatch {
+        PacketBatch::with_protocol(protocol_version::LATEST)
+    }
+
+    // Packets are encoded when they are added, so the batch has to know the protocol of the session it is sent to
+    pub fn with_protocol(protocol: u32) -> PacketBatch {
+        PacketBatch{ stream: Stream::new(Vec::new(), 0), protocol }
+    }
+
+    pub fn add<P: BedrockPacket>(&mut self, packet: &P) -> &mut PacketBatch {
+        self.stream.put(encode_packet_versioned(packet, self.protocol));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stream.get_buffer().is_empty()
+    }
+}
+
+impl Default for PacketBatch {
+    fn default() -> PacketBatch {
+        PacketBatch::new()
+    }
+}
+
+// Output of a command sent with `run_command`
+#[derive(Debug, Clone)]
+pub struct CommandResult {
+    pub request_uuid: Uuid,
+    pub output_type: u8,
+    pub success_count: u32,
+    pub messages: Vec<CommandOutputMessage>,
+    pub data_set: String
+}
+
+impl CommandResult {
+    pub fn new(output: &CommandOutput) -> CommandResult {
+        CommandResult{
+            request_uuid: output.origin_data.uuid,
+            output_type: output.output_type,
+            success_count: output.success_count,
+            messages: output.messages.clone(),
+            data_set: output.data_set.clone()
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.success_count > 0
+    }
+}
+
+// Everything that is needed to write to the server. Shared between the receive loop and every ClientHandle.
+pub struct Session {
+    socket: Arc<UdpSocket>,
+    pub game: GamePacket,
+    pub frame_number_cache: FrameNumberCache,
+    pub state: ConnectionState,
+    pub protocol: u32,
+    pub identity: LoginIdentity,
+    pub commands: Option<CommandTree>, // set once AvailableCommands was received
+    pub movement: MovementController, // starts at the StartGame position
+    pub world: World, // chunks around the player for the physics of `movement`
+    pub actor_runtime_id: u64, // of the local player, set by StartGame
+    pub entities: EntityManager, // actors spawned around the local player, without the local player itself
+    pub players: OnlinePlayers, // everyone in the player list of the server
+    pending_commands: HashMap<Uuid, oneshot::Sender<CommandResult>>
+}
+
+impl Session {
+    pub fn new(socket: Arc<UdpSocket>, game: GamePacket, frame_number_cache: FrameNumberCache, protocol: u32, identity: LoginIdentity) -> Session {
+        Session{ socket, game, frame_number_cache, state: ConnectionState::OfflineHandshake, protocol, identity, commands: None, movement: MovementController::new(Vec3::default(), 0.0, 0.0, 0), world: World::new(0), actor_runtime_id: 0, entities: EntityManager::new(), players: OnlinePlayers::new(), pending_commands: HashMap::new() }
+    }
+
+    pub fn chat(&mut self, message: &str) -> Result<(), ClientError> {
+        let text = text::chat(self.identity.display_name.clone(), message.to_string(), self.identity.xuid.clone());
+        self.send_packet(&text, SendOptions::default())
+    }
+
+    // Servers drop whisper Text packets from clients, so whispers go through /tell like in the vanilla chat screen
+    pub fn whisper(&mut self, target: &str, message: &str) -> Result<(), ClientError> {
+        self.send_command(&format!("tell \"{}\" {}", target.replace('"', "\\\""), message)).map(|_| ())
+    }
+
+    // Returns the request UUID, the server copies it into the CommandOutput of this command.
+    // Commands that do not fit the command tree of the server are not sent.
+    pub fn send_command(&mut self, command: &str) -> Result<Uuid, ClientError> {
+        if let Some(commands) = &self.commands {
+            commands.validate(command)?;
+        }
+        let request_uuid = Builder::from_random_bytes(rand::random()).into_uuid();
+        let command = if command.starts_with('/') { command.to_string() } else { format!("/{}", command) };
+        let origin_data = CommandOriginData::new(ORIGIN_PLAYER, request_uuid, request_uuid.to_string(), 0);
+        self.send_packet(&command_request::new(command, origin_data, false, COMMAND_VERSION), SendOptions::default())?;
+        Ok(request_uuid)
+    }
+
+    // Sends the command and registers for its output in one step, so the output can not arrive before anyone waits for it
+    pub fn run_command(&mut self, command: &str) -> Result<(Uuid, oneshot::Receiver<CommandResult>), ClientError> {
+        let request_uuid = self.send_command(command)?;
+        let (sender, receiver) = oneshot::channel();
+        self.pending_commands.insert(request_uuid, sender);
+        Ok((request_uuid, receiver))
+    }
+
+    // Hands the output to the waiting `run_command`, false if nobody waits for it
+    pub fn complete_command(&mut self, output: &CommandOutput) -> bool {
+        match self.pending_commands.remove(&output.origin_data.uuid) {
+            Some(sender) => sender.send(CommandResult::new(output)).is_ok(),
+            None => false
+        }
+    }
+
+    pub fn cancel_command(&mut self, request_uuid: &Uuid) {
+        self.pending_commands.remove(request_uuid);
+    }
+
+    // Moves the local player by one tick and sends the input to the server
+    pub fn tick(&mut self) -> Result<PlayerAuthInput, ClientError> {
+        let input = self.movement.tick_in(&self.world);
+        self.send_packet(&input, SendOptions::default())?;
+        Ok(input)
+    }
+
+    pub fn send_packet<P: BedrockPacket>(&mut self, packet: &P, options: SendOptions) -> Result<(), ClientError> {
+        self.send_game_packet(encode_packet_versioned(packet, self.protocol), options)
+    }
+
+    pub fn send_batch(&mut self, batch: &PacketBatch, options: SendOptions) -> Result<(), ClientError> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+        if batch.protocol != self.protocol {
+            return Err(ClientError::Protocol(format!("Batch was encoded for protocol {}, session uses {}", batch.protocol, self.protocol), None));
+        }
+        self.send_game_packet(batch.stream.get_buffer(), options)
+    }
+
+    fn send_game_packet(&mut self, batch: Vec<u8>, options: SendOptions) -> Result<(), ClientError> {
+        match self.state {
+            ConnectionState::OfflineHandshake | ConnectionState::Connecting | ConnectionState::Disconnected => {
+                return Err(ClientError::Protocol(format!("Game packets can not be sent in state {}", self.state), None));
+            },
+            _ => {}
+        }
+        let game_packet = self.game.encode(&batch)?;
+        self.send_raw(game_packet, options)
+    }
+
+    // Sends an already encoded RakNet packet inside a frame set
+    pub fn send_raw(&mut self, body: Vec<u8>, options: SendOptions) -> Result<(), ClientError> {
+        if options.order_channel as usize >= MAX_ORDER_CHANNELS {
+            return Err(ClientError::Protocol(format!("Order channel must be less than {}", MAX_ORDER_CHANNELS), None));
+        }
+        let datagrams = Datagram::split_packet(body, options.reliability, options.order_channel, &mut self.frame_number_cache);
+
+        for datagram in datagrams {
+            self.socket.send(&datagram.to_binary())?;
+        }
+        Ok(())
+    }
+
+    // Packets outside of frame sets (offline messages, ACK/NACK)
+    pub fn send_unconnected(&self, buffer: &[u8]) -> Result<(), ClientError> {
+        self.socket.send(buffer)?;
+        Ok(())
+    }
+}
+
+// Cloneable handle that can send packets from other threads or tasks while `Client::connect` is running.
+#[derive(Clone)]
+pub struct ClientHandle {
+    session: Arc<Mutex<Session>>
+}
+
+impl ClientHandle {
+    pub fn new(session: Arc<Mutex<Session>>) -> ClientHandle {
+        ClientHandle{ session }
+    }
+
+    pub fn send_packet<P: BedrockPacket>(&self, packet: &P, options: SendOptions) -> Result<(), ClientError> {
+        self.session.lock().unwrap().send_packet(packet, options)
+    }
+
+    pub fn send_batch(&self, batch: &PacketBatch, options: SendOptions) -> Result<(), ClientError> {
+        self.session.lock().unwrap().send_batch(batch, options)
+    }
+
+    pub fn chat(&self, message: &str) -> Result<(), ClientError> {
+        self.session.lock().unwrap().chat(message)
+    }
+
+    pub fn whisper(&self, target: &str, message: &str) -> Result<(), ClientError> {
+        self.session.lock().unwrap().whisper(target, message)
+    }
+
+    pub async fn run_command(&self, command: &str) -> Result<CommandResult, ClientError> {
+        self.run_command_with_timeout(command, COMMAND_TIMEOUT).await
+    }
+
+    // Waits for the CommandOutput, servers that answer with Text messages instead run into the timeout
+    pub async fn run_command_with_timeout(&self, command: &str, timeout: Duration) -> Result<CommandResult, ClientError> {
+        let (request_uuid, receiver) = self.session.lock().unwrap().run_command(command)?;
+        match tokio::time::timeout(timeout, receiver).await {
+            Ok(Ok(result)) => Ok(result),
+            Ok(Err(_)) => Err(ClientError::Protocol(format!("Session closed before the output of {} arrived", command), None)),
+            Err(_) => {
+                self.session.lock().unwrap().cancel_command(&request_uuid);
+                Err(ClientError::Command(CommandError::Timeout(command.to_string(), timeout)))
+            }
+        }
+    }
+
+    pub fn get_commands(&self) -> Option<CommandTree> {
+        self.session.lock().unwrap().commands.clone()
+    }
+
+    // e.g. `handle.movement(|movement| movement.walk(1.0, 0.0))`, the input is sent with the next tick
+    pub fn movement<R>(&self, f: impl FnOnce(&mut MovementController) -> R) -> R {
+        f(&mut self.session.lock().unwrap().movement)
+    }
+
+    // e.g. `handle.world(|world| world.get_block(0, 64, 0).map(String::from))`
+    pub fn world<R>(&self, f: impl FnOnce(&World) -> R) -> R {
+        f(&self.session.lock().unwrap().world)
+    }
+
+    // e.g. `handle.entities(|entities| entities.get_player("Steve").map(|player| player.position))`
+    pub fn entities<R>(&self, f: impl FnOnce(&EntityManager) -> R) -> R {
+        f(&self.session.lock().unwrap().entities)
+    }
+
+    // e.g. `handle.players(|players| players.to_json())`
+    pub fn players<R>(&self, f: impl FnOnce(&OnlinePlayers) -> R) -> R {
+        f(&self.session.lock().unwrap().players)
+    }
+
+    pub fn get_state(&self) -> ConnectionState {
+        self.session.lock().unwrap().state
+    }
+
+    pub fn get_protocol(&self) -> u32 {
+        self.session.lock().unwrap().protocol
+    }
+
+    // Empty batch encoded with the protocol of this session
+    pub fn new_batch(&self) -> PacketBatch {
+        PacketBatch::with_protocol(self.get_protocol())
+    }
+}
@@ -1,5 +1,6 @@
-use std::error::Error;
 use binary_utils::binary::Stream;
+use crate::error::ClientError;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
 use std::net::{IpAddr, Ipv6Addr};
 
 pub struct InternetAddress {
@@ -43,39 +44,23 @@ pub fn new(version: u8, address: String, port: u16) -> InternetAddress {
     InternetAddress{ version, address, port }
 }
 
-pub fn get_address(address: Vec<u8>) -> Result<(InternetAddress, u32), Box<dyn Error>> {
-    let mut stream = Stream::new(address, 0);
-    let version = stream.get_byte();
+pub fn read(stream: &mut Stream) -> Result<InternetAddress, ClientError> {
+    let version = stream.try_get_byte()?;
     if version == 4 {
-        let address = format!(
-            "{}.{}.{}.{}",
-            !stream.get_byte() & 0xff,
-            !stream.get_byte() & 0xff,
-            !stream.get_byte() & 0xff,
-            !stream.get_byte() & 0xff
-        );
-        let port = stream.get_short();
-        Ok((InternetAddress{ version, address, port }, stream.get_offset()))
+        let bytes = stream.try_get(4)?;
+        let address = format!("{}.{}.{}.{}", !bytes[0], !bytes[1], !bytes[2], !bytes[3]);
+        let port = stream.try_get_short()?;
+        Ok(InternetAddress{ version, address, port })
     } else if version == 6 {
-        stream.get_l_short(); //Family, AF_INET6
-        let port = stream.get_short();
-        stream.get_int(); //flow info
-        let bytes = stream.get(16);
-
-        let address = match bytes {
-            Ok(byte) => {
-                stream.get_int(); //scope ID
-                let byte_array: [u8; 16] = byte.try_into().expect("Incorrect length for IPv6 address");
-                let ipv6 = Ipv6Addr::from(byte_array);
-                IpAddr::V6(ipv6).to_string()
-            }
-            _ => { "error".to_string() }
-        };
-        Ok((InternetAddress{
-            version,
-            address,
-            port,
-        }, stream.get_offset()))
-
-    } else { Ok((InternetAddress{ version, address: "error".to_string(), port: 0 }, stream.get_offset())) }
+        stream.try_get_l_short()?; //Family, AF_INET6
+        let port = stream.try_get_short()?;
+        stream.try_get_int()?; //flow info
+        let mut octets = [0; 16];
+        octets.copy_from_slice(&stream.try_get(16)?);
+        stream.try_get_int()?; //scope ID
+        let address = IpAddr::V6(Ipv6Addr::from(octets)).to_string();
+        Ok(InternetAddress{ version, address, port })
+    } else {
+        Err(ClientError::Decode(format!("Unknown address version {}", version), None))
+    }
 }
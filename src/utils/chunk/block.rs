@@ -123,7 +123,8 @@ impl PropertyValues {
     }
 }
 
-fn hash_identifier(id: &str) -> u64 {
+// FNV-1 64, the game orders the block types by it
+pub fn hash_identifier(id: &str) -> u64 {
     let mut hash = 14695981039346656037_u64;
     for byte in id.as_bytes() {
         hash = hash.wrapping_mul(1099511628211_u64);
@@ -0,0 +1,76 @@
+use crate::error::ClientError;
+use crate::protocol::game::serializer::network_nbt_serializer::{network_nbt_length, NetworkNBTSerializer};
+use crate::utils::chunk::block::hash_identifier;
+use mojang_nbt::base_nbt_serializer::BaseNBTSerializer;
+use std::collections::HashMap;
+
+// Block names by runtime ID. The runtime ID is the index of the block state in the canonical block states of the
+// game version, the server does not send them (only its custom blocks in StartGame).
+#[derive(Debug, Clone, Default)]
+pub struct BlockPalette {
+    names: Vec<String>,
+    first_ids: HashMap<String, u32> // name => runtime ID of its first state
+}
+
+impl BlockPalette {
+    // One name per block state, in runtime ID order
+    pub fn new(names: Vec<String>) -> BlockPalette {
+        let mut first_ids = HashMap::new();
+        for (runtime_id, name) in names.iter().enumerate() {
+            first_ids.entry(name.clone()).or_insert(runtime_id as u32);
+        }
+        BlockPalette{ names, first_ids }
+    }
+
+    // canonical_block_states.nbt of the game data: network NBT compounds with a name and states, one after another
+    pub fn from_canonical_states(bytes: Vec<u8>) -> Result<BlockPalette, ClientError> {
+        let mut names = Vec::new();
+        let mut offset = 0;
+        while offset < bytes.len() {
+            // only the state itself is handed to the serializer, it takes the buffer by value
+            let length = network_nbt_length(&bytes, offset)?;
+            let state = NetworkNBTSerializer::new().read(bytes[offset..offset + length].to_vec(), &mut 0, 0).must_get_compound_tag()
+                .ok_or(ClientError::Decode(format!("Block state {} is not a compound tag", names.len()), None))?;
+            names.push(state.get_string("name").ok_or(ClientError::Decode(format!("Block state {} has no name", names.len()), None))?);
+            offset += length;
+        }
+        Ok(BlockPalette::new(names))
+    }
+
+    // Custom blocks (name, number of states) are sorted in between the vanilla blocks by the hash of their name,
+    // which shifts the runtime IDs of every block behind them
+    pub fn with_custom_blocks(&self, custom_blocks: &[(String, usize)]) -> BlockPalette {
+        let mut blocks: Vec<(&str, usize)> = Vec::new();
+        for name in &self.names {
+            match blocks.last_mut() {
+                Some((last, count)) if *last == name.as_str() => *count += 1,
+                _ => blocks.push((name, 1))
+            }
+        }
+        for (name, count) in custom_blocks {
+            if !self.first_ids.contains_key(name) {
+                blocks.push((name, *count));
+            }
+        }
+        blocks.sort_by_key(|(name, _)| hash_identifier(name));
+
+        BlockPalette::new(blocks.into_iter().flat_map(|(name, count)| std::iter::repeat_n(name.to_string(), count)).collect())
+    }
+
+    pub fn get_name(&self, runtime_id: u32) -> Option<&str> {
+        self.names.get(runtime_id as usize).map(|name| name.as_str())
+    }
+
+    // Runtime ID of the first state of the block, e.g. air which has only one
+    pub fn get_runtime_id(&self, name: &str) -> Option<u32> {
+        self.first_ids.get(name).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
@@ -4,7 +4,8 @@ use crate::utils::chunk::sub_chunk::SubChunk;
 use crate::utils::chunk_resolve;
 
 pub struct Chunk {
-    // r holds the (vertical) range of the Chunk. It includes both the minimum and maximum coordinates.
+    // min_sub_chunk is the Y index of the lowest sub chunk, sub chunk i covers the blocks from (min_sub_chunk + i) * 16.
+    pub min_sub_chunk: isize,
     // air is the runtime ID of air.
     pub air: u32,
     // recalculateHeightMap is true if the chunk's height map should be recalculated on the next call to the HeightMap
@@ -12,31 +13,28 @@ pub struct Chunk {
     pub recalculate_height_map: bool,
     // heightMap is the height map of the chunk.
     pub height_map: HeightMap,
-    // sub holds all sub chunks part of the chunk. The pointers held by the array are nil if no sub chunk is
-    // allocated at the indices.
+    // sub holds all sub chunks part of the chunk, sub chunks that were not sent only contain air.
     pub sub_chunks: Vec<SubChunk>,
-    // biomes is an array of biome IDs. There is one biome ID for every column in the chunk.
+    // biomes holds one biome storage for every sub chunk.
     pub biomes: Vec<PalettedStorage>
 }
 
 impl Chunk {
-    pub fn new(air: u32) -> Chunk {
-        let (max, min) = chunk_resolve::get_dimension_chunk_bounds(0);
-        let n =  (((max - min) >> 4) + 1) as usize;
+    pub fn new(air: u32, dimension_id: i32) -> Chunk {
+        // the bounds are sub chunk indices, both inclusive
+        let (min, max) = chunk_resolve::get_dimension_chunk_bounds(dimension_id);
+        let n = (max - min + 1) as usize;
 
         let mut sub_chunks: Vec<SubChunk> = Vec::with_capacity(n);
         let mut biomes: Vec<PalettedStorage> = Vec::with_capacity(n);
 
         for _ in 0..n {
-            //sub_chunks.push(NewSubChunk(air));
-            //biomes.push(emptyStorage(0));
+            sub_chunks.push(SubChunk::new(air));
+            biomes.push(PalettedStorage::empty(0));
         }
 
-        /*for i := 0; i < n; i++ {
-            sub[i] = NewSubChunk(air)
-            biomes[i] = emptyStorage(0)
-        }*/
         Chunk{
+            min_sub_chunk: min,
             air,
             recalculate_height_map: true,
             height_map: HeightMap {}, // edit again
@@ -45,4 +43,20 @@ impl Chunk {
         }
     }
 
+    // x and z are relative to the chunk (0..16), y is the world height. Air outside of the height range.
+    pub fn get_block(&self, x: u8, y: i32, z: u8) -> u32 {
+        self.get_block_layer(x, y, z, 0)
+    }
+
+    pub fn get_block_layer(&self, x: u8, y: i32, z: u8, layer: usize) -> u32 {
+        let index = (y >> 4) as isize - self.min_sub_chunk;
+        if index < 0 {
+            return self.air;
+        }
+        match self.sub_chunks.get(index as usize) {
+            Some(sub_chunk) => sub_chunk.get_block(x, (y & 15) as u8, z, layer),
+            None => self.air
+        }
+    }
+
 }
@@ -3,4 +3,6 @@ pub mod sub_chunk;
 pub mod paletted_storage;
 pub mod height_map;
 pub mod palette;
-pub mod block;
\ No newline at end of file
+pub mod block;
+pub mod block_palette;
+pub mod world;
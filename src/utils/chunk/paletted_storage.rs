@@ -1,4 +1,32 @@
-#[derive(Clone, Copy)]
+// Values of the 4096 blocks (or biomes) of a sub chunk, each value is an index into the palette
+#[derive(Debug, Clone)]
 pub struct PalettedStorage {
+    pub bits_per_index: u8,
+    pub words: Vec<u32>,
+    pub palette: Vec<u32>
+}
 
-}
\ No newline at end of file
+impl PalettedStorage {
+    pub fn new(bits_per_index: u8, words: Vec<u32>, palette: Vec<u32>) -> PalettedStorage {
+        PalettedStorage{ bits_per_index, words, palette }
+    }
+
+    // The same value everywhere, needs no words at all
+    pub fn empty(value: u32) -> PalettedStorage {
+        PalettedStorage::new(0, vec![], vec![value])
+    }
+
+    // Coordinates are relative to the sub chunk (0..16)
+    pub fn get(&self, x: u8, y: u8, z: u8) -> u32 {
+        if self.bits_per_index == 0 {
+            return self.palette.first().copied().unwrap_or(0);
+        }
+        let bits = self.bits_per_index as usize;
+        let index = ((x as usize & 15) << 8) | ((z as usize & 15) << 4) | (y as usize & 15);
+        // indices never span two words, the leftover bits of a word are padding
+        let indices_per_word = 32 / bits;
+        let word = self.words.get(index / indices_per_word).copied().unwrap_or(0);
+        let palette_index = (word >> ((index % indices_per_word) * bits)) & ((1u32 << bits) - 1);
+        self.palette.get(palette_index as usize).copied().unwrap_or(0)
+    }
+}
@@ -2,7 +2,7 @@ use crate::utils::chunk::paletted_storage::PalettedStorage;
 
 pub struct SubChunk {
     pub air: u32,
-    pub storages: Vec<PalettedStorage>,
+    pub storages: Vec<PalettedStorage>, // layer 0 holds the blocks, layer 1 mostly water of waterlogged blocks
     pub block_light: Vec<u8>,
     pub sky_light: Vec<u8>
 }
@@ -16,4 +16,12 @@ impl SubChunk {
             sky_light: vec![],
         }
     }
-}
\ No newline at end of file
+
+    // Runtime ID of the block, air if the layer does not exist
+    pub fn get_block(&self, x: u8, y: u8, z: u8, layer: usize) -> u32 {
+        match self.storages.get(layer) {
+            Some(storage) => storage.get(x, y, z),
+            None => self.air
+        }
+    }
+}
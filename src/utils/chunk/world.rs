@@ -0,0 +1,92 @@
+use crate::error::ClientError;
+use crate::protocol::game::level_chunk::LevelChunk;
+use crate::utils::chunk::block_palette::BlockPalette;
+use crate::utils::chunk::chunk::Chunk;
+use crate::utils::chunk_resolve;
+use std::collections::HashMap;
+
+// Chunks the server sent for the current dimension, blocks can only be named once a palette is set
+pub struct World {
+    dimension_id: i32,
+    palette: Option<BlockPalette>,
+    chunks: HashMap<(i32, i32), Chunk>
+}
+
+impl World {
+    pub fn new(dimension_id: i32) -> World {
+        World{ dimension_id, palette: None, chunks: HashMap::new() }
+    }
+
+    pub fn get_dimension_id(&self) -> i32 {
+        self.dimension_id
+    }
+
+    // The chunks of the old dimension are dropped
+    pub fn set_dimension_id(&mut self, dimension_id: i32) {
+        self.dimension_id = dimension_id;
+        self.chunks.clear();
+    }
+
+    pub fn set_palette(&mut self, palette: Option<BlockPalette>) {
+        self.palette = palette;
+    }
+
+    pub fn get_palette(&self) -> Option<&BlockPalette> {
+        self.palette.as_ref()
+    }
+
+    // Runtime ID of air, sub chunks that were not sent are filled with it
+    pub fn get_air(&self) -> u32 {
+        self.palette.as_ref().and_then(|palette| palette.get_runtime_id("minecraft:air")).unwrap_or(0)
+    }
+
+    pub fn add_chunk(&mut self, chunk_x: i32, chunk_z: i32, chunk: Chunk) {
+        self.chunks.insert((chunk_x, chunk_z), chunk);
+    }
+
+    // False if the chunk was not stored: other dimension, or its sub chunks have to be requested separately
+    pub fn add_level_chunk(&mut self, level_chunk: &LevelChunk) -> Result<bool, ClientError> {
+        if level_chunk.dimension_id != self.dimension_id || level_chunk.client_sub_chunk_requests_enabled {
+            return Ok(false);
+        }
+        let chunk = chunk_resolve::decode_level_chunk(level_chunk, self.get_air())?;
+        self.add_chunk(level_chunk.chunk_x, level_chunk.chunk_z, chunk);
+        Ok(true)
+    }
+
+    pub fn remove_chunk(&mut self, chunk_x: i32, chunk_z: i32) -> Option<Chunk> {
+        self.chunks.remove(&(chunk_x, chunk_z))
+    }
+
+    pub fn get_chunk(&self, chunk_x: i32, chunk_z: i32) -> Option<&Chunk> {
+        self.chunks.get(&(chunk_x, chunk_z))
+    }
+
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    // Block coordinates
+    pub fn is_loaded(&self, x: i32, z: i32) -> bool {
+        self.chunks.contains_key(&(x >> 4, z >> 4))
+    }
+
+    // None if the chunk is not loaded
+    pub fn get_runtime_id(&self, x: i32, y: i32, z: i32) -> Option<u32> {
+        self.get_runtime_id_layer(x, y, z, 0)
+    }
+
+    pub fn get_runtime_id_layer(&self, x: i32, y: i32, z: i32, layer: usize) -> Option<u32> {
+        self.get_chunk(x >> 4, z >> 4).map(|chunk| chunk.get_block_layer((x & 15) as u8, y, (z & 15) as u8, layer))
+    }
+
+    // None if the chunk is not loaded, there is no palette or the runtime ID is not in it
+    pub fn get_block(&self, x: i32, y: i32, z: i32) -> Option<&str> {
+        self.get_block_layer(x, y, z, 0)
+    }
+
+    pub fn get_block_layer(&self, x: i32, y: i32, z: i32, layer: usize) -> Option<&str> {
+        let runtime_id = self.get_runtime_id_layer(x, y, z, layer)?;
+        self.palette.as_ref()?.get_name(runtime_id)
+    }
+}
@@ -1,8 +1,13 @@
+use crate::error::ClientError;
+use crate::protocol::game::level_chunk::LevelChunk;
+use crate::protocol::game::serializer::stream_helper::StreamReader;
 use crate::utils::chunk::chunk::Chunk;
-use crate::utils::chunk::palette::PaletteSize;
+use crate::utils::chunk::palette::{PaletteSize, SIZES};
 use crate::utils::chunk::paletted_storage::PalettedStorage;
 use crate::utils::chunk::sub_chunk::SubChunk;
+use crate::utils::log_target;
 use binary_utils::binary::Stream;
+use log::warn;
 
 pub struct ChunkResolve {
 
@@ -16,111 +21,120 @@ pub fn get_dimension_chunk_bounds(dimension_id: i32) -> (isize, isize) {
     }
 }
 
-pub fn network_decode(sub_chunk_count: isize, extra_payload: Vec<u8>, air: u32) -> Chunk {
+// Only for chunks without sub chunk requests, the others carry no blocks
+pub fn decode_level_chunk(level_chunk: &LevelChunk, air: u32) -> Result<Chunk, ClientError> {
+    network_decode(level_chunk.sub_chunk_count, level_chunk.extra_payload.clone(), air, level_chunk.dimension_id)
+        .map_err(|e| ClientError::decode(format!("Chunk {} {} could not be decoded", level_chunk.chunk_x, level_chunk.chunk_z), e))
+}
+
+pub fn network_decode(sub_chunk_count: isize, extra_payload: Vec<u8>, air: u32, dimension_id: i32) -> Result<Chunk, ClientError> {
     let mut chunk_stream = Stream::new(extra_payload, 0);
 
-    let mut chunk = Chunk::new(air);
+    let mut chunk = Chunk::new(air, dimension_id);
 
     for i in 0..sub_chunk_count {
         let mut index = i as u8;
-        chunk.sub_chunks.insert(index as usize, decode_sub_chunk(&mut chunk_stream, &mut index, &chunk));
+        let sub_chunk = decode_sub_chunk(&mut chunk_stream, &mut index, &chunk)?;
+        match chunk.sub_chunks.get_mut(index as usize) {
+            Some(slot) => *slot = sub_chunk,
+            None => warn!(target: log_target::CHUNK, "sub chunk index {} is out of the dimension bounds", index)
+        }
     }
 
-    let mut last: PalettedStorage = PalettedStorage {}; /////////////////////////////////////////
+    let mut last: Option<PalettedStorage> = None;
 
-    for i in 0..chunk.sub_chunks.len() {
-        let mut b = decode_paletted_storage(&mut chunk_stream);
+    for i in 0..chunk.biomes.len() {
+        let mut b = decode_paletted_storage(&mut chunk_stream)?;
 
-        if let Some(storage) = b {
-            last = storage;
+        if let Some(storage) = &b {
+            last = Some(storage.clone());
         } else {
             // b == nil means this paletted storage had the flag pointing to the previous one. It basically means we should
             // inherit whatever palette we decoded last.
             if i == 0 {
                 // This should never happen and there is no way to handle this.
-                eprintln!("first biome storage pointed to previous one");
+                warn!(target: log_target::CHUNK, "first biome storage pointed to previous one");
             }
-            b = Some(last);
+            b = last.clone();
         }
-        chunk.biomes.insert(i, b.clone().expect("biomes clone error, fn name: network_decode"));
+        chunk.biomes[i] = b.unwrap_or_else(|| PalettedStorage::empty(0));
     }
 
-    chunk
+    Ok(chunk)
 }
 
-pub fn decode_sub_chunk(chunk_stream: &mut Stream, index: &mut u8, chunk: &Chunk) -> SubChunk {
-    let version = chunk_stream.get_byte();
+pub fn decode_sub_chunk(chunk_stream: &mut Stream, index: &mut u8, chunk: &Chunk) -> Result<SubChunk, ClientError> {
+    let version = chunk_stream.try_get_byte()?;
 
     let mut sub_chunk = SubChunk::new(chunk.air);
 
     match version {
         1 => {
             // Version 1 only has one layer for each sub chunk, but uses the format with palettes.
-            let storage = decode_paletted_storage(chunk_stream).unwrap();
+            let storage = decode_paletted_storage(chunk_stream)?.ok_or_else(previous_storage_error)?;
             sub_chunk.storages.push(storage);
         },
         8 | 9 => {
             // Version 8 allows up to 256 layers for one sub chunk.
-            let storage_count = chunk_stream.get_byte();
+            let storage_count = chunk_stream.try_get_byte()?;
 
             if version == 9 {
-                let u_index = chunk_stream.get_byte();
-                let (min, _) = get_dimension_chunk_bounds(0);
+                let u_index = chunk_stream.try_get_byte()?;
                 // The index as written here isn't the actual index of the sub-chunk within the chunk. Rather, it is the Y
                 // value of the sub-chunk. This means that we need to translate it to an index.
-                *index = ( (u_index as i8) - ((min >> 4) as i8) ) as u8;
+                *index = ((u_index as i8) as isize - chunk.min_sub_chunk) as u8;
             }
 
-            for i in 0..storage_count {
-                sub_chunk.storages.insert(i as usize, decode_paletted_storage(chunk_stream).unwrap());
+            for _ in 0..storage_count {
+                sub_chunk.storages.push(decode_paletted_storage(chunk_stream)?.ok_or_else(previous_storage_error)?);
             }
 
         },
-        _ => {}
+        _ => return Err(ClientError::Decode(format!("unknown sub chunk version {}", version), None))
     }
 
-    sub_chunk
+    Ok(sub_chunk)
+}
+
+fn previous_storage_error() -> ClientError {
+    ClientError::Decode("sub chunk storage points to a previous one".to_string(), None)
 }
 
-pub fn decode_paletted_storage(chunk_stream: &mut Stream) -> Option<PalettedStorage> {
-    let mut block_size = chunk_stream.get_byte();
+pub fn decode_paletted_storage(chunk_stream: &mut Stream) -> Result<Option<PalettedStorage>, ClientError> {
+    let mut block_size = chunk_stream.try_get_byte()?;
 
+    // the lowest bit tells runtime IDs from persistent NBT palettes, the network always sends runtime IDs
     block_size >>= 1;
 
     if block_size == 0x7f {
-        return None;
+        return Ok(None);
     }
 
-
-    let size = PaletteSize(block_size);
-    if block_size > 32 {
-        eprintln!("cannot read paletted storage (size={}): size too large", block_size)
+    if !SIZES.iter().any(|size| size.0 == block_size) {
+        return Err(ClientError::Decode(format!("cannot read paletted storage (size={}): invalid size", block_size), None));
     }
+    let size = PaletteSize(block_size);
 
     let u32_count = size.uint32s();
     let mut u32s: Vec<u32> = Vec::with_capacity(u32_count);
     let byte_count = u32_count * 4;
 
-    let data = chunk_stream.get(byte_count as u32).expect("cannot read chunk stream data, fn name: decode_paletted_storage");
-
-    if data.len() != byte_count {
-        eprintln!("cannot read paletted storage (size={}): not enough block data present: expected {} bytes, got {}", block_size, byte_count, data.len());
-    }
+    let data = chunk_stream.try_get(byte_count as u32)?;
 
     for i in 0..u32_count {
         // Explicitly don't use the binary package to greatly improve performance of reading the uint32s.
         u32s.push((data[i*4] as u32) | (data[i*4+1] as u32) << 8 | (data[i*4+2] as u32) << 16 | (data[i*4+3] as u32) << 24);
     }
-    /*p, err := e.decodePalette(buf, paletteSize(blockSize), pe)
-    return newPalettedStorage(uint32s, p), err*/
-
-
-
-
-
-
-
 
+    // A storage without indices still has one palette entry, but no palette length
+    let palette_count = if block_size == 0 { 1 } else { chunk_stream.try_get_var_int()? };
+    if palette_count <= 0 || palette_count > 4096 {
+        return Err(ClientError::Decode(format!("cannot read paletted storage (size={}): invalid palette length {}", block_size, palette_count), None));
+    }
+    let mut palette = Vec::with_capacity(palette_count as usize);
+    for _ in 0..palette_count {
+        palette.push(chunk_stream.try_get_var_int()? as u32);
+    }
 
-    Option::from(PalettedStorage{})
-}
\ No newline at end of file
+    Ok(Option::from(PalettedStorage::new(block_size, u32s, palette)))
+}
@@ -0,0 +1,426 @@
+use crate::error::ClientError;
+use crate::protocol::game::available_commands::AvailableCommands;
+use crate::protocol::game::types::command_data::*;
+use crate::protocol::game::update_soft_enum::{self, UpdateSoftEnum};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::time::Duration;
+
+const OPERATORS: [&str; 9] = ["=", "+=", "-=", "*=", "/=", "%=", "<", ">", "><"];
+const COMPARE_OPERATORS: [&str; 5] = ["<", "<=", "=", ">=", ">"];
+const SELECTORS: [&str; 6] = ["@a", "@e", "@p", "@r", "@s", "@initiator"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParameterKind {
+    Int,
+    Float,
+    Value,
+    WildcardInt,
+    Operator,
+    CompareOperator,
+    Target,
+    WildcardTarget,
+    FilePath,
+    IntegerRange,
+    EquipmentSlots,
+    String,
+    BlockPosition,
+    Position,
+    Message,
+    RawText,
+    Json,
+    BlockStates,
+    Command,
+    Enum{ name: String, values: Vec<String> },
+    SoftEnum(String), // values can change, they are looked up in the tree
+    Postfix(String),  // an int followed by this suffix, e.g. "10L"
+    Unknown(u32)
+}
+
+impl ParameterKind {
+    fn from_type(parameter_type: u32) -> ParameterKind {
+        match parameter_type {
+            ARG_TYPE_INT => ParameterKind::Int,
+            ARG_TYPE_FLOAT => ParameterKind::Float,
+            ARG_TYPE_VALUE => ParameterKind::Value,
+            ARG_TYPE_WILDCARD_INT => ParameterKind::WildcardInt,
+            ARG_TYPE_OPERATOR => ParameterKind::Operator,
+            ARG_TYPE_COMPARE_OPERATOR => ParameterKind::CompareOperator,
+            ARG_TYPE_TARGET => ParameterKind::Target,
+            ARG_TYPE_WILDCARD_TARGET => ParameterKind::WildcardTarget,
+            ARG_TYPE_FILE_PATH => ParameterKind::FilePath,
+            ARG_TYPE_INTEGER_RANGE => ParameterKind::IntegerRange,
+            ARG_TYPE_EQUIPMENT_SLOTS => ParameterKind::EquipmentSlots,
+            ARG_TYPE_STRING => ParameterKind::String,
+            ARG_TYPE_BLOCK_POSITION => ParameterKind::BlockPosition,
+            ARG_TYPE_POSITION => ParameterKind::Position,
+            ARG_TYPE_MESSAGE => ParameterKind::Message,
+            ARG_TYPE_RAW_TEXT => ParameterKind::RawText,
+            ARG_TYPE_JSON => ParameterKind::Json,
+            ARG_TYPE_BLOCK_STATES => ParameterKind::BlockStates,
+            ARG_TYPE_COMMAND => ParameterKind::Command,
+            other => ParameterKind::Unknown(other)
+        }
+    }
+
+    // Type name as the game shows it in the usage hint
+    pub fn get_name(&self) -> String {
+        match self {
+            ParameterKind::Int => "int".to_string(),
+            ParameterKind::Float | ParameterKind::Value => "float".to_string(),
+            ParameterKind::WildcardInt => "wildcard int".to_string(),
+            ParameterKind::Operator => "operator".to_string(),
+            ParameterKind::CompareOperator => "compare operator".to_string(),
+            ParameterKind::Target | ParameterKind::WildcardTarget => "target".to_string(),
+            ParameterKind::FilePath => "filepath".to_string(),
+            ParameterKind::IntegerRange => "integer range".to_string(),
+            ParameterKind::EquipmentSlots => "equipment slots".to_string(),
+            ParameterKind::String => "string".to_string(),
+            ParameterKind::BlockPosition | ParameterKind::Position => "x y z".to_string(),
+            ParameterKind::Message => "message".to_string(),
+            ParameterKind::RawText => "text".to_string(),
+            ParameterKind::Json => "json".to_string(),
+            ParameterKind::BlockStates => "block states".to_string(),
+            ParameterKind::Command => "command".to_string(),
+            ParameterKind::Enum{ name, .. } | ParameterKind::SoftEnum(name) => name.clone(),
+            ParameterKind::Postfix(postfix) => format!("int{}", postfix),
+            ParameterKind::Unknown(parameter_type) => format!("unknown {}", parameter_type)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Parameter {
+    pub name: String,
+    pub kind: ParameterKind,
+    pub optional: bool,
+    pub options: u8
+}
+
+#[derive(Debug, Clone)]
+pub struct Overload {
+    pub chaining: bool,
+    pub parameters: Vec<Parameter>
+}
+
+#[derive(Debug, Clone)]
+pub struct ChainedSubcommandEntry {
+    pub name: String,
+    pub values: Vec<String>
+}
+
+#[derive(Debug, Clone)]
+pub struct CommandEntry {
+    pub name: String,
+    pub description: String,
+    pub flags: u16,
+    pub permission_level: u8,
+    pub aliases: Vec<String>,
+    pub chained_subcommands: Vec<ChainedSubcommandEntry>,
+    pub overloads: Vec<Overload>
+}
+
+impl CommandEntry {
+    pub fn has_flag(&self, flag: u16) -> bool {
+        self.flags & flag != 0
+    }
+}
+
+#[derive(Debug)]
+pub enum CommandError {
+    UnknownCommand(String),
+    NoMatchingOverload{ command: String, usage: Vec<String> },
+    Timeout(String, Duration) // no CommandOutput in time
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::UnknownCommand(name) => write!(f, "Unknown command: {}", name),
+            CommandError::NoMatchingOverload{ command, usage } => write!(f, "Syntax error in /{}, usage: {}", command, usage.join(" | ")),
+            CommandError::Timeout(command, timeout) => write!(f, "No output for {} within {:?}", command, timeout)
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+// Commands the server announced with AvailableCommands, kept up to date with UpdateSoftEnum
+#[derive(Debug, Clone, Default)]
+pub struct CommandTree {
+    commands: BTreeMap<String, CommandEntry>,
+    aliases: HashMap<String, String>,
+    soft_enums: HashMap<String, Vec<String>>
+}
+
+impl CommandTree {
+    pub fn from_packet(packet: &AvailableCommands) -> Result<CommandTree, ClientError> {
+        let mut enums = Vec::new();
+        for command_enum in &packet.enums {
+            let values = command_enum.value_indices.iter()
+                .map(|index| packet.enum_values.get(*index as usize).cloned().ok_or(ClientError::Decode(format!("Enum {} has value index {} out of range", command_enum.name, index), None)))
+                .collect::<Result<Vec<String>, ClientError>>()?;
+            enums.push((command_enum.name.clone(), values));
+        }
+
+        let mut tree = CommandTree::default();
+        for soft_enum in &packet.soft_enums {
+            tree.soft_enums.insert(soft_enum.name.clone(), soft_enum.values.clone());
+        }
+
+        for command in &packet.commands {
+            let mut aliases = Vec::new();
+            if command.aliases_offset != NO_ALIASES {
+                let (_, values) = enums.get(command.aliases_offset as usize).ok_or(ClientError::Decode(format!("Aliases of /{} point to missing enum {}", command.name, command.aliases_offset), None))?;
+                aliases = values.iter().filter(|alias| **alias != command.name).cloned().collect();
+            }
+
+            let mut chained_subcommands = Vec::new();
+            for offset in &command.chained_subcommand_offsets {
+                let chained_subcommand = packet.chained_subcommands.get(*offset as usize).ok_or(ClientError::Decode(format!("/{} has chained subcommand {} out of range", command.name, offset), None))?;
+                let values = chained_subcommand.values.iter()
+                    .map(|value| packet.chained_subcommand_values.get(value.index as usize).cloned().ok_or(ClientError::Decode(format!("Chained subcommand {} has value index {} out of range", chained_subcommand.name, value.index), None)))
+                    .collect::<Result<Vec<String>, ClientError>>()?;
+                chained_subcommands.push(ChainedSubcommandEntry{ name: chained_subcommand.name.clone(), values });
+            }
+
+            let mut overloads = Vec::new();
+            for overload in &command.overloads {
+                let mut parameters = Vec::new();
+                for parameter in &overload.parameters {
+                    let kind = resolve_kind(packet, &enums, parameter.parameter_type).ok_or(ClientError::Decode(format!("Parameter {} of /{} has an unknown type 0x{:x}", parameter.name, command.name, parameter.parameter_type), None))?;
+                    parameters.push(Parameter{ name: parameter.name.clone(), kind, optional: parameter.optional, options: parameter.options });
+                }
+                overloads.push(Overload{ chaining: overload.chaining, parameters });
+            }
+
+            for alias in &aliases {
+                tree.aliases.insert(alias.to_lowercase(), command.name.to_lowercase());
+            }
+            tree.commands.insert(command.name.to_lowercase(), CommandEntry{
+                name: command.name.clone(),
+                description: command.description.clone(),
+                flags: command.flags,
+                permission_level: command.permission_level,
+                aliases,
+                chained_subcommands,
+                overloads
+            });
+        }
+
+        Ok(tree)
+    }
+
+    pub fn update_soft_enum(&mut self, update: &UpdateSoftEnum) {
+        let values = self.soft_enums.entry(update.enum_name.clone()).or_default();
+        match update.action_type {
+            update_soft_enum::TYPE_ADD => {
+                for value in &update.values {
+                    if !values.contains(value) {
+                        values.push(value.clone());
+                    }
+                }
+            },
+            update_soft_enum::TYPE_REMOVE => values.retain(|value| !update.values.contains(value)),
+            _ => *values = update.values.clone()
+        }
+    }
+
+    // Looks up a command by name or alias, with or without the leading "/"
+    pub fn get_command(&self, name: &str) -> Option<&CommandEntry> {
+        let name = name.strip_prefix('/').unwrap_or(name).to_lowercase();
+        let name = self.aliases.get(&name).unwrap_or(&name);
+        self.commands.get(name)
+    }
+
+    pub fn get_commands(&self) -> impl Iterator<Item = &CommandEntry> {
+        self.commands.values()
+    }
+
+    pub fn get_soft_enum(&self, name: &str) -> Option<&[String]> {
+        self.soft_enums.get(name).map(Vec::as_slice)
+    }
+
+    // One line per overload, e.g. "/gamemode <gameMode: GameMode> [player: target]"
+    pub fn usage(&self, command: &CommandEntry) -> Vec<String> {
+        command.overloads.iter().map(|overload| {
+            let mut line = format!("/{}", command.name);
+            for parameter in &overload.parameters {
+                let text = match &parameter.kind {
+                    ParameterKind::Enum{ values, .. } if values.len() == 1 => values[0].clone(),
+                    kind => format!("{}: {}", parameter.name, kind.get_name())
+                };
+                if parameter.optional {
+                    line += &format!(" [{}]", text);
+                } else {
+                    line += &format!(" <{}>", text);
+                }
+            }
+            line
+        }).collect()
+    }
+
+    // Returns the first overload the command line fits. Chaining overloads (/execute ... run ...) are followed by
+    // subcommands the overload does not describe, those are not checked and left to the server.
+    pub fn validate(&self, line: &str) -> Result<&Overload, CommandError> {
+        let tokens = tokenize(line.strip_prefix('/').unwrap_or(line));
+        let (name, arguments) = tokens.split_first().ok_or(CommandError::UnknownCommand("".to_string()))?;
+        let command = self.get_command(name).ok_or(CommandError::UnknownCommand(name.clone()))?;
+
+        command.overloads.iter().find(|overload| {
+            if overload.chaining {
+                return true;
+            }
+            let (matched, used) = self.match_overload(overload, arguments);
+            used == arguments.len() && overload.parameters[matched..].iter().all(|parameter| parameter.optional)
+        }).ok_or(CommandError::NoMatchingOverload{ command: command.name.clone(), usage: self.usage(command) })
+    }
+
+    // Candidates for the word that is being typed at the end of the line
+    pub fn complete(&self, line: &str) -> Vec<String> {
+        let line = line.strip_prefix('/').unwrap_or(line);
+        let mut tokens = tokenize(line);
+        let partial = if line.is_empty() || line.ends_with(char::is_whitespace) { "".to_string() } else { tokens.pop().unwrap_or_default() };
+        let partial = partial.to_lowercase();
+
+        let mut candidates = BTreeSet::new();
+        if tokens.is_empty() {
+            candidates.extend(self.commands.values().map(|command| command.name.clone()).chain(self.aliases.keys().cloned()).filter(|name| name.to_lowercase().starts_with(&partial)));
+            return candidates.into_iter().collect();
+        }
+
+        let Some(command) = self.get_command(&tokens[0]) else {
+            return vec![];
+        };
+        let arguments = &tokens[1..];
+        for overload in &command.overloads {
+            let (matched, used) = self.match_overload(overload, arguments);
+            if used != arguments.len() || matched >= overload.parameters.len() {
+                continue;
+            }
+            let values: Vec<String> = match &overload.parameters[matched].kind {
+                ParameterKind::Enum{ values, .. } => values.clone(),
+                ParameterKind::SoftEnum(name) => self.soft_enums.get(name).cloned().unwrap_or_default(),
+                ParameterKind::Target | ParameterKind::WildcardTarget => SELECTORS.iter().map(|selector| selector.to_string()).collect(),
+                ParameterKind::Operator => OPERATORS.iter().map(|operator| operator.to_string()).collect(),
+                ParameterKind::CompareOperator => COMPARE_OPERATORS.iter().map(|operator| operator.to_string()).collect(),
+                _ => vec![]
+            };
+            candidates.extend(values.into_iter().filter(|value| value.to_lowercase().starts_with(&partial)));
+        }
+        candidates.into_iter().collect()
+    }
+
+    // Number of parameters matched and tokens used, stops at the first parameter that does not fit
+    fn match_overload(&self, overload: &Overload, tokens: &[String]) -> (usize, usize) {
+        let mut used = 0;
+        for (matched, parameter) in overload.parameters.iter().enumerate() {
+            if used == tokens.len() {
+                return (matched, used);
+            }
+            match self.match_parameter(&parameter.kind, &tokens[used..]) {
+                Some(count) => used += count,
+                None => return (matched, used)
+            }
+        }
+        (overload.parameters.len(), used)
+    }
+
+    // Number of tokens the parameter takes from the start of `tokens`
+    fn match_parameter(&self, kind: &ParameterKind, tokens: &[String]) -> Option<usize> {
+        let token = unquote(&tokens[0]);
+        let matches = match kind {
+            ParameterKind::Int => token.parse::<i32>().is_ok(),
+            ParameterKind::Float | ParameterKind::Value => token.parse::<f32>().is_ok(),
+            ParameterKind::WildcardInt => token == "*" || token.parse::<i32>().is_ok(),
+            ParameterKind::Operator => OPERATORS.contains(&token),
+            ParameterKind::CompareOperator => COMPARE_OPERATORS.contains(&token),
+            ParameterKind::Target => is_target(token),
+            ParameterKind::WildcardTarget => token == "*" || is_target(token),
+            ParameterKind::IntegerRange => is_integer_range(token),
+            ParameterKind::BlockStates => token.starts_with('[') && token.ends_with(']'),
+            ParameterKind::BlockPosition | ParameterKind::Position => {
+                return (tokens.len() >= 3 && tokens[..3].iter().all(|token| is_coordinate(token))).then_some(3);
+            },
+            ParameterKind::Message | ParameterKind::RawText | ParameterKind::Json | ParameterKind::Command => return Some(tokens.len()),
+            ParameterKind::Enum{ values, .. } => values.iter().any(|value| value.eq_ignore_ascii_case(token)),
+            ParameterKind::SoftEnum(name) => self.soft_enums.get(name).is_some_and(|values| values.iter().any(|value| value.eq_ignore_ascii_case(token))),
+            ParameterKind::Postfix(postfix) => token.len() > postfix.len()
+                && token[token.len() - postfix.len()..].eq_ignore_ascii_case(postfix)
+                && token[..token.len() - postfix.len()].parse::<i32>().is_ok(),
+            ParameterKind::FilePath | ParameterKind::EquipmentSlots | ParameterKind::String | ParameterKind::Unknown(_) => true
+        };
+        matches.then_some(1)
+    }
+}
+
+fn resolve_kind(packet: &AvailableCommands, enums: &[(String, Vec<String>)], parameter_type: u32) -> Option<ParameterKind> {
+    let index = (parameter_type & 0xffff) as usize;
+    if parameter_type & ARG_FLAG_POSTFIX != 0 {
+        packet.postfixes.get(index).map(|postfix| ParameterKind::Postfix(postfix.clone()))
+    } else if parameter_type & ARG_FLAG_SOFT_ENUM != 0 {
+        packet.soft_enums.get(index).map(|soft_enum| ParameterKind::SoftEnum(soft_enum.name.clone()))
+    } else if parameter_type & ARG_FLAG_ENUM != 0 {
+        enums.get(index).map(|(name, values)| ParameterKind::Enum{ name: name.clone(), values: values.clone() })
+    } else {
+        Some(ParameterKind::from_type(index as u32))
+    }
+}
+
+// Splits like the game does, quoted strings and selector arguments stay in one token
+pub fn tokenize(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut quoted = false;
+    let mut depth = 0;
+    for c in line.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            '[' | '{' if !quoted => depth += 1,
+            ']' | '}' if !quoted && depth > 0 => depth -= 1,
+            c if c.is_whitespace() && !quoted && depth == 0 => {
+                if !token.is_empty() {
+                    tokens.push(std::mem::take(&mut token));
+                }
+                continue;
+            },
+            _ => {}
+        }
+        token.push(c);
+    }
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+    tokens
+}
+
+fn unquote(token: &str) -> &str {
+    token.strip_prefix('"').and_then(|token| token.strip_suffix('"')).unwrap_or(token)
+}
+
+fn is_target(token: &str) -> bool {
+    match token.strip_prefix('@') {
+        Some(selector) => {
+            let name = selector.split('[').next().unwrap_or_default();
+            SELECTORS.contains(&format!("@{}", name).as_str()) && (name.len() == selector.len() || selector.ends_with(']'))
+        },
+        None => !token.is_empty()
+    }
+}
+
+// "5", "1..5", "..5", "3..", optionally negated with "!"
+fn is_integer_range(token: &str) -> bool {
+    let token = token.strip_prefix('!').unwrap_or(token);
+    match token.split_once("..") {
+        Some((min, max)) => (!min.is_empty() || !max.is_empty())
+            && (min.is_empty() || min.parse::<i32>().is_ok())
+            && (max.is_empty() || max.parse::<i32>().is_ok()),
+        None => token.parse::<i32>().is_ok()
+    }
+}
+
+// Absolute, relative (~) or local (^) coordinate
+fn is_coordinate(token: &str) -> bool {
+    let number = token.strip_prefix('~').or(token.strip_prefix('^')).unwrap_or(token);
+    (number.is_empty() && number.len() != token.len()) || number.parse::<f32>().is_ok()
+}
@@ -5,8 +5,10 @@ use ctr::cipher::{KeyIvInit, StreamCipher};
 use ctr::Ctr128BE;
 use openssl::bn::BigNum;
 use openssl::derive::Deriver;
+use openssl::ec::{EcGroup, EcKey};
 use openssl::error::ErrorStack;
 use openssl::hash::{hash, MessageDigest};
+use openssl::nid::Nid;
 use openssl::pkey::{PKey, Private, Public};
 use std::error::Error;
 
@@ -23,9 +25,9 @@ pub struct Encryption {
 
 impl Encryption {
 
-    pub fn new(encryption_key: Vec<u8>, iv: Vec<u8>) -> Result<Self, Box<dyn Error>> {
-        let decrypt_cipher = Aes256Ctr::new_from_slices(&encryption_key, &iv).expect("Decrypt Cipher Creating Error");
-        let encrypt_cipher = Aes256Ctr::new_from_slices(&encryption_key, &iv).expect("Encrypt Cipher Creating Error");
+    pub fn new(encryption_key: Vec<u8>, iv: Vec<u8>) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let decrypt_cipher = Aes256Ctr::new_from_slices(&encryption_key, &iv).map_err(|_| "Invalid key or iv length for the decrypt cipher")?;
+        let encrypt_cipher = Aes256Ctr::new_from_slices(&encryption_key, &iv).map_err(|_| "Invalid key or iv length for the encrypt cipher")?;
 
         Ok(Encryption {
             key: encryption_key,
@@ -37,19 +39,19 @@ impl Encryption {
     }
 
     // Fake GCM mode (MCBE specific)
-    pub fn fake_gcm(encryption_key: Vec<u8>) -> Result<Self, Box<dyn Error>> {
+    pub fn fake_gcm(encryption_key: Vec<u8>) -> Result<Self, Box<dyn Error + Send + Sync>> {
         let mut iv = encryption_key[..12].to_vec();
         iv.extend_from_slice(&[0x00, 0x00, 0x00, 0x02]);
 
         Self::new(encryption_key, iv)
     }
 
-    pub fn cfb8(encryption_key: Vec<u8>) -> Result<Self, Box<dyn Error>> {
+    pub fn cfb8(encryption_key: Vec<u8>) -> Result<Self, Box<dyn Error + Send + Sync>> {
         let iv = encryption_key[..16].to_vec();
         Self::new(encryption_key, iv)
     }
 
-    pub fn decrypt(&mut self, encrypted: &Vec<u8>) -> Result<Vec<u8>, Box<dyn Error>> {
+    pub fn decrypt(&mut self, encrypted: &Vec<u8>) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
         if encrypted.len() < 9 {
             return Err("Payload is too short".into());
         }
@@ -78,7 +80,7 @@ impl Encryption {
         Ok(payload)
     }
 
-    pub fn encrypt(&mut self, payload: &Vec<u8>) -> Result<Vec<u8>, Box<dyn Error>> {
+    pub fn encrypt(&mut self, payload: &Vec<u8>) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
         let packet_counter = self.encrypt_counter;
         self.encrypt_counter += 1;
 
@@ -101,11 +103,14 @@ impl Encryption {
         Ok(hash[..8].to_vec())
     }
 
-    pub fn b64_url_decode(base64_url: &str) -> Result<String, Box<dyn Error>> {
+    pub fn b64_url_decode(base64_url: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+        Ok(String::from_utf8(Encryption::b64_url_decode_bytes(base64_url)?)?)
+    }
+
+    pub fn b64_url_decode_bytes(base64_url: &str) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
         const BASE64_URL: engine::GeneralPurpose = engine::GeneralPurpose::new(&alphabet::URL_SAFE, general_purpose::NO_PAD);
 
-        let b64_url = BASE64_URL.decode(base64_url).unwrap();
-        Ok(String::from_utf8(b64_url)?)
+        Ok(BASE64_URL.decode(base64_url)?)
     }
 
     pub fn b64_url_encode(input: &Vec<u8>) -> String {
@@ -116,33 +121,38 @@ impl Encryption {
     }
 }
 
-pub fn generate_key(secret: &BigNum, salt: Vec<u8>) -> Vec<u8> {
-    let mut hex_secret = secret.to_hex_str().unwrap().to_string();
+pub fn generate_key(secret: &BigNum, salt: Vec<u8>) -> Result<Vec<u8>, ErrorStack> {
+    let mut hex_secret = secret.to_hex_str()?.to_string();
 
     if hex_secret.len() < 96 {
         hex_secret = format!("{:0>96}", hex_secret);
     }
 
-    let secret_bytes = hex::decode(hex_secret).unwrap();
+    let secret_bytes = hex::decode(hex_secret).expect("BigNum hex string is always valid hex");
 
     let combined = [salt, secret_bytes].concat();
 
-    hash(MessageDigest::sha256(), &combined).unwrap().to_vec()
+    Ok(hash(MessageDigest::sha256(), &combined)?.to_vec())
 }
 
-pub fn generate_shared_secret(local_private: PKey<Private>, remote_public: PKey<Public>) -> BigNum{
-    let mut deriver = Deriver::new(&local_private).unwrap();
-    deriver.set_peer(&remote_public).unwrap();
-    let secret = deriver.derive_to_vec().unwrap();
+pub fn generate_shared_secret(local_private: PKey<Private>, remote_public: PKey<Public>) -> Result<BigNum, ErrorStack> {
+    let mut deriver = Deriver::new(&local_private)?;
+    deriver.set_peer(&remote_public)?;
+    let secret = deriver.derive_to_vec()?;
     /*
 	$hexSecret = openssl_pkey_derive($remotePub, $localPriv, 48);
 	return gmp_init(bin2hex($hexSecret), 16);
     */
 
-    BigNum::from_hex_str(&hex::encode(secret)).unwrap()
+    BigNum::from_hex_str(&hex::encode(secret))
 }
 
-pub fn parse_der_public_key(der_key: &[u8]) -> PKey<Public> {
-    let pkey = PKey::public_key_from_der(der_key).expect("Pem To Public Key Convert Error");
-    pkey
-}
\ No newline at end of file
+pub fn parse_der_public_key(der_key: &[u8]) -> Result<PKey<Public>, ErrorStack> {
+    PKey::public_key_from_der(der_key)
+}
+
+// P-384 key pair for a self-signed identity (offline login)
+pub fn generate_ec_key() -> Result<EcKey<Private>, ErrorStack> {
+    let group = EcGroup::from_curve_name(Nid::SECP384R1)?;
+    EcKey::generate(&group)
+}
@@ -0,0 +1,167 @@
+use crate::utils::encryption::Encryption;
+use chrono::Utc;
+use openssl::bn::BigNum;
+use openssl::ecdsa::EcdsaSig;
+use openssl::hash::MessageDigest;
+use openssl::pkey::{HasPublic, PKey, Private, Public};
+use openssl::sign::{Signer, Verifier};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{to_vec, Value};
+use std::fmt;
+
+pub const ALGORITHM: &str = "ES384";
+
+// P-384 coordinates are 48 bytes, a JWT signature is r || s
+const COMPONENT_SIZE: usize = 48;
+
+// Allowed clock difference for exp and nbf
+const LEEWAY: i64 = 60;
+
+#[derive(Debug)]
+pub enum JwtError {
+    Malformed(String),
+    UnsupportedAlgorithm(String),
+    InvalidSignature,
+    Expired(i64),
+    NotYetValid(i64),
+    Crypto(String)
+}
+
+impl fmt::Display for JwtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JwtError::Malformed(reason) => write!(f, "Malformed JWT: {}", reason),
+            JwtError::UnsupportedAlgorithm(alg) => write!(f, "Unsupported JWT algorithm {}, expected {}", alg, ALGORITHM),
+            JwtError::InvalidSignature => write!(f, "JWT signature does not match the key"),
+            JwtError::Expired(exp) => write!(f, "JWT expired at {}", exp),
+            JwtError::NotYetValid(nbf) => write!(f, "JWT is not valid before {}", nbf),
+            JwtError::Crypto(reason) => write!(f, "JWT crypto error: {}", reason)
+        }
+    }
+}
+
+impl std::error::Error for JwtError {}
+
+pub struct Jwt {
+    pub header: Value,
+    pub payload: Value,
+    signed_data: String, // header.payload as it was received
+    signature: Vec<u8>
+}
+
+impl Jwt {
+    // Only checks the shape, use `verify` before trusting the contents
+    pub fn parse(jwt: &str) -> Result<Jwt, JwtError> {
+        let parts: Vec<&str> = jwt.split('.').collect();
+        if parts.len() != 3 {
+            return Err(JwtError::Malformed(format!("{} parts, expected 3", parts.len())));
+        }
+
+        let header = decode_part(parts[0], "header")?;
+        let payload = decode_part(parts[1], "payload")?;
+        let signature = Encryption::b64_url_decode_bytes(parts[2]).map_err(|e| JwtError::Malformed(format!("signature is not base64: {}", e)))?;
+
+        Ok(Jwt{ header, payload, signed_data: format!("{}.{}", parts[0], parts[1]), signature })
+    }
+
+    pub fn get_x5u(&self) -> Option<&str> {
+        self.header.get("x5u").and_then(Value::as_str)
+    }
+
+    pub fn get_claim(&self, name: &str) -> Option<&Value> {
+        self.payload.get(name)
+    }
+
+    pub fn get_str_claim(&self, name: &str) -> Option<&str> {
+        self.payload.get(name).and_then(Value::as_str)
+    }
+
+    // Payload as a typed claims struct
+    pub fn claims<T: DeserializeOwned>(&self) -> Result<T, JwtError> {
+        serde_json::from_value(self.payload.clone()).map_err(|e| JwtError::Malformed(format!("unexpected claims: {}", e)))
+    }
+
+    // Algorithm, signature and the exp/nbf claims (if present)
+    pub fn verify<T: HasPublic>(&self, public_key: &PKey<T>) -> Result<(), JwtError> {
+        let alg = self.header.get("alg").and_then(Value::as_str).unwrap_or_default();
+        if alg != ALGORITHM {
+            return Err(JwtError::UnsupportedAlgorithm(alg.to_string()));
+        }
+        if self.signature.len() != COMPONENT_SIZE * 2 {
+            return Err(JwtError::InvalidSignature);
+        }
+
+        let r = BigNum::from_slice(&self.signature[..COMPONENT_SIZE]).map_err(|e| JwtError::Crypto(e.to_string()))?;
+        let s = BigNum::from_slice(&self.signature[COMPONENT_SIZE..]).map_err(|e| JwtError::Crypto(e.to_string()))?;
+        let der = EcdsaSig::from_private_components(r, s).and_then(|sig| sig.to_der()).map_err(|e| JwtError::Crypto(e.to_string()))?;
+
+        let mut verifier = Verifier::new(MessageDigest::sha384(), public_key).map_err(|e| JwtError::Crypto(e.to_string()))?;
+        verifier.update(self.signed_data.as_bytes()).map_err(|e| JwtError::Crypto(e.to_string()))?;
+        if !verifier.verify(&der).map_err(|e| JwtError::Crypto(e.to_string()))? {
+            return Err(JwtError::InvalidSignature);
+        }
+
+        self.verify_time(Utc::now().timestamp())
+    }
+
+    // Verifies against the key in its own x5u header and returns that key
+    pub fn verify_x5u(&self) -> Result<PKey<Public>, JwtError> {
+        let x5u = self.get_x5u().ok_or(JwtError::Malformed("header has no x5u".to_string()))?;
+        let public_key = public_key_from_base64(x5u)?;
+        self.verify(&public_key)?;
+        Ok(public_key)
+    }
+
+    pub fn verify_time(&self, now: i64) -> Result<(), JwtError> {
+        if let Some(exp) = self.get_claim("exp").and_then(Value::as_i64) {
+            if now > exp + LEEWAY {
+                return Err(JwtError::Expired(exp));
+            }
+        }
+        if let Some(nbf) = self.get_claim("nbf").and_then(Value::as_i64) {
+            if now < nbf - LEEWAY {
+                return Err(JwtError::NotYetValid(nbf));
+            }
+        }
+        Ok(())
+    }
+}
+
+// x5u and identityPublicKey values: base64 (not url safe) DER encoded public keys
+pub fn public_key_from_base64(key: &str) -> Result<PKey<Public>, JwtError> {
+    let der = openssl::base64::decode_block(key).map_err(|e| JwtError::Malformed(format!("public key is not base64: {}", e)))?;
+    PKey::public_key_from_der(&der).map_err(|e| JwtError::Malformed(format!("public key is not DER: {}", e)))
+}
+
+pub fn encode<H: Serialize, P: Serialize>(header: &H, payload: &P, pkey: &PKey<Private>) -> Result<String, JwtError> {
+    let header_bytes = to_vec(header).map_err(|e| JwtError::Malformed(e.to_string()))?;
+    let encoded_header = Encryption::b64_url_encode(&header_bytes);
+
+    let payload_bytes = to_vec(payload).map_err(|e| JwtError::Malformed(e.to_string()))?;
+    let encoded_payload = Encryption::b64_url_encode(&payload_bytes);
+
+    let data_to_sign = format!("{}.{}", encoded_header, encoded_payload);
+    let signature = sign(pkey, &data_to_sign)?;
+    let encoded_signature = Encryption::b64_url_encode(&signature);
+
+    Ok(format!("{}.{}.{}", encoded_header, encoded_payload, encoded_signature))
+}
+
+// ES384 signature in the JWT (r || s) format
+fn sign(pkey: &PKey<Private>, data: &str) -> Result<Vec<u8>, JwtError> {
+    let mut signer = Signer::new(MessageDigest::sha384(), pkey).map_err(|e| JwtError::Crypto(e.to_string()))?;
+    signer.update(data.as_bytes()).map_err(|e| JwtError::Crypto(e.to_string()))?;
+    let signature = signer.sign_to_vec().map_err(|e| JwtError::Crypto(e.to_string()))?;
+    let ecdsa_sig = EcdsaSig::from_der(&signature).map_err(|e| JwtError::Crypto(e.to_string()))?;
+    // both halves are fixed size, leading zero bytes must be kept
+    let r = ecdsa_sig.r().to_vec_padded(COMPONENT_SIZE as i32).map_err(|e| JwtError::Crypto(e.to_string()))?;
+    let s = ecdsa_sig.s().to_vec_padded(COMPONENT_SIZE as i32).map_err(|e| JwtError::Crypto(e.to_string()))?;
+
+    Ok([r, s].concat())
+}
+
+fn decode_part(part: &str, name: &str) -> Result<Value, JwtError> {
+    let decoded = Encryption::b64_url_decode(part).map_err(|e| JwtError::Malformed(format!("{} is not base64: {}", name, e)))?;
+    serde_json::from_str(decoded.as_str()).map_err(|e| JwtError::Malformed(format!("{} is not JSON: {}", name, e)))
+}
@@ -0,0 +1,5 @@
+// Targets of the `log` records, so applications can filter each layer separately (e.g. RUST_LOG=raknet=warn,game=debug)
+pub const RAKNET: &str = "raknet";
+pub const GAME: &str = "game";
+pub const AUTH: &str = "auth";
+pub const CHUNK: &str = "chunk";
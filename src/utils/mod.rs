@@ -1,5 +1,11 @@
 pub mod address;
 pub mod encryption;
+pub mod jwt;
+pub mod pack_cache;
+pub mod pack_decryption;
+pub mod pack_reader;
+pub mod command_tree;
 pub mod color_format;
+pub mod log_target;
 pub mod chunk_resolve;
 pub mod chunk;
\ No newline at end of file
@@ -0,0 +1,157 @@
+use crate::error::ClientError;
+use crate::protocol::game::resource_pack_chunk_data::ResourcePackChunkData;
+use crate::protocol::game::resource_pack_data_info::ResourcePackDataInfo;
+use crate::utils::pack_decryption::{self, PackFiles};
+use crate::utils::pack_reader::Pack;
+use openssl::sha::sha256;
+use std::fs;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+// Pack ids are sent as "uuid_version", some servers leave out the version
+pub fn pack_id(uuid: &Uuid, version: &str) -> String {
+    format!("{}_{}", uuid, version)
+}
+
+pub fn split_pack_id(pack_id: &str) -> (&str, Option<&str>) {
+    match pack_id.split_once('_') {
+        Some((uuid, version)) => (uuid, Option::from(version)),
+        None => (pack_id, None)
+    }
+}
+
+// Content addressed pack store: objects/<sha256> holds the pack, packs/<uuid>/<version> the hash of it.
+// Packs shared by several servers are only stored once.
+pub struct PackCache {
+    root: PathBuf
+}
+
+impl PackCache {
+    pub fn new(root: impl Into<PathBuf>) -> PackCache {
+        PackCache{ root: root.into() }
+    }
+
+    pub fn contains(&self, uuid: &Uuid, version: &str) -> bool {
+        self.get(uuid, version).is_some()
+    }
+
+    // None if the pack is missing or its object does not match the recorded hash anymore
+    pub fn get(&self, uuid: &Uuid, version: &str) -> Option<Vec<u8>> {
+        let hash = fs::read_to_string(self.index_path(uuid, version)).ok()?;
+        let data = fs::read(self.object_path(hash.trim())).ok()?;
+        if hex::encode(sha256(&data)) != hash.trim() {
+            return None;
+        }
+        Some(data)
+    }
+
+    pub fn store(&self, uuid: &Uuid, version: &str, data: &[u8]) -> Result<PathBuf, ClientError> {
+        let hash = hex::encode(sha256(data));
+        let object_path = self.object_path(&hash);
+        if !object_path.exists() {
+            fs::create_dir_all(self.root.join("objects"))?;
+            // written under a temporary name first, a crash must not leave a truncated object behind
+            let temporary_path = object_path.with_extension("tmp");
+            fs::write(&temporary_path, data)?;
+            fs::rename(&temporary_path, &object_path)?;
+        }
+
+        let index_path = self.index_path(uuid, version);
+        if let Some(parent) = index_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(index_path, &hash)?;
+        Ok(object_path)
+    }
+
+    fn index_path(&self, uuid: &Uuid, version: &str) -> PathBuf {
+        // the version comes from the server, it must not be able to leave the cache directory
+        let version: String = version.chars().map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' }).collect();
+        self.root.join("packs").join(uuid.to_string()).join(version)
+    }
+
+    fn object_path(&self, hash: &str) -> PathBuf {
+        self.root.join("objects").join(format!("{}.zip", hash))
+    }
+}
+
+// A verified pack as the server sent it, still encrypted if it has a content key
+pub struct DownloadedPack {
+    pub uuid: Uuid,
+    pub version: String,
+    pub content_key: String,
+    pub data: Vec<u8>
+}
+
+impl DownloadedPack {
+    // Unpacks (and decrypts) the pack zip
+    pub fn files(&self) -> Result<PackFiles, ClientError> {
+        pack_decryption::open_pack(&self.data, &self.content_key)
+    }
+
+    // Unpacks the pack and parses its manifest and assets
+    pub fn pack(&self) -> Result<Pack, ClientError> {
+        Pack::from_bytes(&self.data, &self.content_key)
+    }
+}
+
+// Collects the chunks of one pack announced by a ResourcePackDataInfo
+pub struct PackDownload {
+    pub pack_id: String,
+    chunk_count: u32,
+    size: u64,
+    sha256: Vec<u8>,
+    next_chunk: u32,
+    data: Vec<u8>
+}
+
+impl PackDownload {
+    // Chunk sizes come from the server, the announced size is not trusted for the allocation
+    const MAX_PACK_SIZE: u64 = 512 * 1024 * 1024;
+
+    pub fn new(data_info: &ResourcePackDataInfo) -> Result<PackDownload, ClientError> {
+        if data_info.compressed_pack_size > PackDownload::MAX_PACK_SIZE {
+            return Err(ClientError::Protocol(format!("Resource pack {} is too big ({} bytes)", data_info.pack_id, data_info.compressed_pack_size), None));
+        }
+        Ok(PackDownload{
+            pack_id: data_info.pack_id.clone(),
+            chunk_count: data_info.chunk_count,
+            size: data_info.compressed_pack_size,
+            sha256: data_info.sha256.clone(),
+            next_chunk: 0,
+            data: Vec::new()
+        })
+    }
+
+    // Chunks are requested one after another, so they have to arrive in order
+    pub fn add_chunk(&mut self, chunk_data: &ResourcePackChunkData) -> Result<(), ClientError> {
+        if chunk_data.chunk_index != self.next_chunk || chunk_data.offset != self.data.len() as u64 {
+            return Err(ClientError::Protocol(format!("Resource pack {} chunk {} at offset {} is out of order, expected chunk {} at {}", self.pack_id, chunk_data.chunk_index, chunk_data.offset, self.next_chunk, self.data.len()), None));
+        }
+        if self.data.len() as u64 + chunk_data.data.len() as u64 > self.size {
+            return Err(ClientError::Protocol(format!("Resource pack {} is bigger than the announced {} bytes", self.pack_id, self.size), None));
+        }
+        self.data.extend_from_slice(&chunk_data.data);
+        self.next_chunk += 1;
+        Ok(())
+    }
+
+    pub fn next_chunk(&self) -> Option<u32> {
+        if self.is_complete() { None } else { Option::from(self.next_chunk) }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.next_chunk >= self.chunk_count
+    }
+
+    // The pack, if its size and SHA-256 match the announced ones
+    pub fn finish(self) -> Result<Vec<u8>, ClientError> {
+        if self.data.len() as u64 != self.size {
+            return Err(ClientError::Protocol(format!("Resource pack {} has {} bytes, {} were announced", self.pack_id, self.data.len(), self.size), None));
+        }
+        if sha256(&self.data).as_slice() != self.sha256.as_slice() {
+            return Err(ClientError::Protocol(format!("Resource pack {} does not match its SHA-256", self.pack_id), None));
+        }
+        Ok(self.data)
+    }
+}
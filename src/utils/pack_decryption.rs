@@ -0,0 +1,161 @@
+use crate::error::ClientError;
+use openssl::symm::{decrypt, Cipher};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Cursor, Read};
+use std::path::Path;
+use zip::ZipArchive;
+
+// contents.json of an encrypted pack: version, magic, content id, then the encrypted JSON from this offset
+const CONTENTS_MAGIC: u32 = 0x9bcfb9fc;
+const CONTENTS_HEADER_SIZE: usize = 0x100;
+const CONTENTS_FILE: &str = "contents.json";
+
+const KEY_SIZE: usize = 32;
+
+// Zip entries can claim any size, a small pack must not unpack into gigabytes
+const MAX_UNPACKED_SIZE: u64 = 1024 * 1024 * 1024;
+
+#[derive(Deserialize)]
+struct Contents {
+    content: Vec<ContentEntry>
+}
+
+#[derive(Deserialize)]
+struct ContentEntry {
+    path: String,
+    #[serde(default)]
+    key: Option<String>
+}
+
+// Files of a pack by their path relative to the pack root ("manifest.json", "textures/blocks/stone.png", ...)
+pub struct PackFiles {
+    files: BTreeMap<String, Vec<u8>>
+}
+
+impl PackFiles {
+    // Unpacked pack on disk, paths are relative to `directory`
+    pub fn from_directory(directory: impl AsRef<Path>) -> Result<PackFiles, ClientError> {
+        let mut files = BTreeMap::new();
+        let mut directories = vec![directory.as_ref().to_path_buf()];
+        while let Some(current) = directories.pop() {
+            for entry in fs::read_dir(&current)? {
+                let path = entry?.path();
+                if path.is_dir() {
+                    directories.push(path);
+                } else if let Ok(relative) = path.strip_prefix(directory.as_ref()) {
+                    let relative = relative.to_string_lossy().replace('\\', "/");
+                    files.insert(relative, fs::read(&path)?);
+                }
+            }
+        }
+        Ok(PackFiles{ files })
+    }
+
+    pub fn get(&self, path: &str) -> Option<&[u8]> {
+        self.files.get(path).map(Vec::as_slice)
+    }
+
+    pub fn contains(&self, path: &str) -> bool {
+        self.files.contains_key(path)
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.files.keys().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    pub fn extract_to(&self, directory: impl AsRef<Path>) -> Result<(), ClientError> {
+        let directory = directory.as_ref();
+        for (path, data) in &self.files {
+            let target = directory.join(path);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(target, data)?;
+        }
+        Ok(())
+    }
+}
+
+// Unpacks a pack zip, with a content key (`ResourcePack::encryption_key`) the encrypted files are decrypted
+pub fn open_pack(pack: &[u8], content_key: &str) -> Result<PackFiles, ClientError> {
+    let mut files = read_zip(pack)?;
+    if content_key.is_empty() {
+        return Ok(PackFiles{ files });
+    }
+
+    let contents = files.get(CONTENTS_FILE).ok_or(ClientError::Decode(format!("Encrypted pack has no {}", CONTENTS_FILE), None))?;
+    let contents = decrypt_contents(contents, content_key)?;
+    for entry in contents.content {
+        let Some(key) = entry.key.filter(|key| !key.is_empty()) else { continue };
+        // directories are listed too, only files have data
+        if let Some(data) = files.get_mut(&entry.path) {
+            *data = decrypt_cfb8(data, &key)?;
+        }
+    }
+    files.remove(CONTENTS_FILE);
+    Ok(PackFiles{ files })
+}
+
+fn decrypt_contents(contents: &[u8], content_key: &str) -> Result<Contents, ClientError> {
+    if contents.len() < CONTENTS_HEADER_SIZE {
+        return Err(ClientError::Decode(format!("{} is too short for an encrypted pack", CONTENTS_FILE), None));
+    }
+    let magic = u32::from_le_bytes([contents[4], contents[5], contents[6], contents[7]]);
+    if magic != CONTENTS_MAGIC {
+        return Err(ClientError::Decode(format!("{} has no encryption header (magic {:#x})", CONTENTS_FILE, magic), None));
+    }
+
+    let json = decrypt_cfb8(&contents[CONTENTS_HEADER_SIZE..], content_key)?;
+    serde_json::from_slice(&json).map_err(|e| ClientError::decode(format!("Decrypted {} is not valid, wrong content key?", CONTENTS_FILE), e))
+}
+
+// AES-256-CFB8 with the first 16 bytes of the key as IV, as the game does it
+pub fn decrypt_cfb8(data: &[u8], key: &str) -> Result<Vec<u8>, ClientError> {
+    let key = key.as_bytes();
+    if key.len() != KEY_SIZE {
+        return Err(ClientError::Crypto(format!("Pack key must be {} bytes, got {}", KEY_SIZE, key.len()), None));
+    }
+    decrypt(Cipher::aes_256_cfb8(), key, Some(&key[..16]), data).map_err(|e| ClientError::crypto("Pack file can not be decrypted", e))
+}
+
+// Paths are made relative to the directory of manifest.json, packs are often zipped with their folder
+fn read_zip(pack: &[u8]) -> Result<BTreeMap<String, Vec<u8>>, ClientError> {
+    let mut archive = ZipArchive::new(Cursor::new(pack)).map_err(|e| ClientError::decode("Pack is not a zip file", e))?;
+    let root = archive.file_names()
+        .filter(|name| *name == "manifest.json" || name.ends_with("/manifest.json"))
+        .min_by_key(|name| name.len())
+        .map(|name| name.trim_end_matches("manifest.json").to_string())
+        .unwrap_or_default();
+
+    let mut files = BTreeMap::new();
+    let mut unpacked_size = 0;
+    for i in 0..archive.len() {
+        let file = archive.by_index(i).map_err(|e| ClientError::decode("Pack entry can not be read", e))?;
+        if file.is_dir() {
+            continue;
+        }
+        // enclosed_name rejects "../" and absolute paths
+        let Some(path) = file.enclosed_name().and_then(|path| path.to_str().map(|path| path.replace('\\', "/"))) else { continue };
+        let Some(path) = path.strip_prefix(&root).map(|path| path.to_string()) else { continue };
+
+        // the sizes in the zip headers are not trusted, at most one byte more than allowed is read
+        let mut data = Vec::new();
+        file.take(MAX_UNPACKED_SIZE - unpacked_size + 1).read_to_end(&mut data)?;
+        unpacked_size += data.len() as u64;
+        if unpacked_size > MAX_UNPACKED_SIZE {
+            return Err(ClientError::Decode(format!("Pack is bigger than {} bytes unpacked", MAX_UNPACKED_SIZE), None));
+        }
+        files.insert(path, data);
+    }
+    Ok(files)
+}
@@ -0,0 +1,363 @@
+use crate::error::ClientError;
+use crate::protocol::game::resource_packs_info::ResourcePack;
+use crate::utils::log_target;
+use crate::utils::pack_decryption::{self, PackFiles};
+use log::warn;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use uuid::Uuid;
+
+// Module types of manifest.json
+pub const MODULE_RESOURCES: &str = "resources";
+pub const MODULE_DATA: &str = "data"; // behaviour pack
+pub const MODULE_SCRIPT: &str = "script";
+pub const MODULE_SKIN_PACK: &str = "skin_pack";
+pub const MODULE_WORLD_TEMPLATE: &str = "world_template";
+
+const TEXTURE_EXTENSIONS: [&str; 2] = ["png", "tga"];
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Manifest {
+    #[serde(default)]
+    pub format_version: u32,
+    pub header: ManifestHeader,
+    #[serde(default)]
+    pub modules: Vec<ManifestModule>,
+    #[serde(default)]
+    pub dependencies: Vec<ManifestDependency>,
+    #[serde(default)]
+    pub capabilities: Vec<String>,
+    #[serde(default)]
+    pub metadata: Option<Value>
+}
+
+// Versions are [1, 0, 0] arrays up to format version 2 and "1.0.0" strings since 3, both are kept as "1.0.0"
+#[derive(Deserialize, Debug, Clone)]
+pub struct ManifestHeader {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub uuid: Uuid,
+    #[serde(deserialize_with = "version")]
+    pub version: String,
+    #[serde(default, deserialize_with = "optional_version")]
+    pub min_engine_version: Option<String>
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ManifestModule {
+    #[serde(rename = "type")]
+    pub module_type: String,
+    pub uuid: Uuid,
+    #[serde(deserialize_with = "version")]
+    pub version: String,
+    #[serde(default)]
+    pub description: String
+}
+
+// Either another pack (uuid) or a script module ("@minecraft/server")
+#[derive(Deserialize, Debug, Clone)]
+pub struct ManifestDependency {
+    #[serde(default)]
+    pub uuid: Option<Uuid>,
+    #[serde(default)]
+    pub module_name: Option<String>,
+    #[serde(default, deserialize_with = "optional_version")]
+    pub version: Option<String>
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawVersion {
+    Parts(Vec<u32>),
+    Text(String)
+}
+
+impl RawVersion {
+    fn into_string(self) -> String {
+        match self {
+            RawVersion::Parts(parts) => parts.iter().map(u32::to_string).collect::<Vec<String>>().join("."),
+            RawVersion::Text(text) => text
+        }
+    }
+}
+
+fn version<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    Ok(RawVersion::deserialize(deserializer)?.into_string())
+}
+
+fn optional_version<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    Ok(Option::<RawVersion>::deserialize(deserializer)?.map(RawVersion::into_string))
+}
+
+// A parsed pack with an index of the assets other tools usually look up
+pub struct Pack {
+    pub manifest: Manifest,
+    pub files: PackFiles,
+    pub textures: Vec<String>, // texture files, with extension
+    pub blocks: HashMap<String, Value>, // blocks.json: block => definition
+    pub terrain_textures: HashMap<String, Vec<String>>, // terrain_texture.json: texture name => paths without extension
+    pub item_textures: HashMap<String, Vec<String>>, // item_texture.json
+    pub languages: HashMap<String, HashMap<String, String>>, // texts/<language>.lang
+    pub entities: HashMap<String, Value>, // identifier => client entity (resource pack) or entity (behaviour pack) definition
+    pub items: HashMap<String, Value> // identifier => item definition
+}
+
+impl Pack {
+    // A .mcpack/.zip file or an unpacked pack directory
+    pub fn open(path: impl AsRef<Path>) -> Result<Pack, ClientError> {
+        let path = path.as_ref();
+        if path.is_dir() {
+            Pack::from_files(PackFiles::from_directory(path)?)
+        } else {
+            Pack::from_bytes(&fs::read(path)?, "")
+        }
+    }
+
+    // Pack zip as downloaded, `content_key` for encrypted packs (empty otherwise)
+    pub fn from_bytes(pack: &[u8], content_key: &str) -> Result<Pack, ClientError> {
+        Pack::from_files(pack_decryption::open_pack(pack, content_key)?)
+    }
+
+    pub fn from_files(files: PackFiles) -> Result<Pack, ClientError> {
+        let manifest_data = files.get("manifest.json").ok_or(ClientError::Decode("Pack has no manifest.json".to_string(), None))?;
+        let manifest: Manifest = serde_json::from_value(parse_json(manifest_data)?).map_err(|e| ClientError::decode("manifest.json is not a valid manifest", e))?;
+
+        let mut pack = Pack{
+            manifest,
+            textures: Vec::new(),
+            blocks: HashMap::new(),
+            terrain_textures: HashMap::new(),
+            item_textures: HashMap::new(),
+            languages: HashMap::new(),
+            entities: HashMap::new(),
+            items: HashMap::new(),
+            files
+        };
+        pack.index();
+        Ok(pack)
+    }
+
+    // Files that are not valid JSON are skipped, one broken asset does not make the whole pack unusable
+    fn index(&mut self) {
+        let paths: Vec<String> = self.files.paths().map(|path| path.to_string()).collect();
+        for path in paths {
+            let data = self.files.get(&path).unwrap_or_default();
+            let extension = path.rsplit_once('.').map(|(_, extension)| extension).unwrap_or_default();
+
+            if path.starts_with("textures/") && TEXTURE_EXTENSIONS.contains(&extension) {
+                self.textures.push(path.clone());
+            } else if path == "blocks.json" {
+                if let Some(Value::Object(blocks)) = parse_asset(&path, data) {
+                    self.blocks = blocks.into_iter().filter(|(name, _)| name != "format_version").collect();
+                }
+            } else if path == "textures/terrain_texture.json" {
+                if let Some(atlas) = parse_asset(&path, data) {
+                    self.terrain_textures = texture_atlas(&atlas);
+                }
+            } else if path == "textures/item_texture.json" {
+                if let Some(atlas) = parse_asset(&path, data) {
+                    self.item_textures = texture_atlas(&atlas);
+                }
+            } else if let Some(language) = path.strip_prefix("texts/").and_then(|name| name.strip_suffix(".lang")) {
+                self.languages.insert(language.to_string(), parse_lang(data));
+            } else if extension == "json" && (path.starts_with("entity/") || path.starts_with("entities/")) {
+                let Some(definition) = parse_asset(&path, data) else { continue };
+                if let Some(identifier) = identifier(&definition, &["minecraft:client_entity", "minecraft:entity"]) {
+                    self.entities.insert(identifier, definition);
+                }
+            } else if extension == "json" && path.starts_with("items/") {
+                let Some(definition) = parse_asset(&path, data) else { continue };
+                if let Some(identifier) = identifier(&definition, &["minecraft:item"]) {
+                    self.items.insert(identifier, definition);
+                }
+            }
+        }
+        self.textures.sort();
+    }
+
+    pub fn get_uuid(&self) -> Uuid {
+        self.manifest.header.uuid
+    }
+
+    pub fn get_version(&self) -> &str {
+        &self.manifest.header.version
+    }
+
+    pub fn is_behaviour_pack(&self) -> bool {
+        self.manifest.modules.iter().any(|module| module.module_type == MODULE_DATA || module.module_type == MODULE_SCRIPT)
+    }
+
+    // Whether this is the pack a server announced
+    pub fn matches(&self, resource_pack: &ResourcePack) -> bool {
+        self.get_uuid() == resource_pack.uuid && self.get_version() == resource_pack.version
+    }
+
+    // Texture file for a path without extension ("textures/blocks/stone"), as terrain_texture.json refers to them
+    pub fn texture(&self, path: &str) -> Option<(&str, &[u8])> {
+        TEXTURE_EXTENSIONS.iter()
+            .map(|extension| format!("{}.{}", path, extension))
+            .find_map(|file| self.textures.iter().find(|texture| **texture == file))
+            .and_then(|texture| self.files.get(texture).map(|data| (texture.as_str(), data)))
+    }
+
+    pub fn translate(&self, language: &str, key: &str) -> Option<&str> {
+        self.languages.get(language).and_then(|texts| texts.get(key)).map(String::as_str)
+    }
+}
+
+// Packs in stack order: when several packs provide an asset, the first one wins
+pub struct PackSet {
+    packs: Vec<Pack>
+}
+
+impl PackSet {
+    pub fn new() -> PackSet {
+        PackSet{ packs: Vec::new() }
+    }
+
+    pub fn push(&mut self, pack: Pack) {
+        self.packs.push(pack);
+    }
+
+    pub fn get_packs(&self) -> &[Pack] {
+        &self.packs
+    }
+
+    pub fn get(&self, uuid: &Uuid) -> Option<&Pack> {
+        self.packs.iter().find(|pack| pack.get_uuid() == *uuid)
+    }
+
+    pub fn find(&self, resource_pack: &ResourcePack) -> Option<&Pack> {
+        self.packs.iter().find(|pack| pack.matches(resource_pack))
+    }
+
+    // The pack that provides a file
+    pub fn provider(&self, path: &str) -> Option<&Pack> {
+        self.packs.iter().find(|pack| pack.files.contains(path))
+    }
+
+    // Texture file of a terrain texture name ("stone"), the first variant if there are several
+    pub fn terrain_texture(&self, name: &str) -> Option<(&Pack, &str, &[u8])> {
+        let path = self.packs.iter().find_map(|pack| pack.terrain_textures.get(name).and_then(|paths| paths.first()))?;
+        self.packs.iter().find_map(|pack| pack.texture(path).map(|(file, data)| (pack, file, data)))
+    }
+
+    pub fn block(&self, name: &str) -> Option<(&Pack, &Value)> {
+        self.packs.iter().find_map(|pack| pack.blocks.get(name).map(|block| (pack, block)))
+    }
+
+    pub fn entity(&self, identifier: &str) -> Option<(&Pack, &Value)> {
+        self.packs.iter().find_map(|pack| pack.entities.get(identifier).map(|entity| (pack, entity)))
+    }
+
+    pub fn item(&self, identifier: &str) -> Option<(&Pack, &Value)> {
+        self.packs.iter().find_map(|pack| pack.items.get(identifier).map(|item| (pack, item)))
+    }
+
+    pub fn translate(&self, language: &str, key: &str) -> Option<&str> {
+        self.packs.iter().find_map(|pack| pack.translate(language, key))
+    }
+}
+
+impl Default for PackSet {
+    fn default() -> PackSet {
+        PackSet::new()
+    }
+}
+
+// Pack JSON may have a BOM and comments, both are not valid JSON
+pub fn parse_json(data: &[u8]) -> Result<Value, ClientError> {
+    let text = String::from_utf8_lossy(data);
+    let text = text.trim_start_matches('\u{feff}');
+
+    let mut json = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    let mut in_string = false;
+    while let Some(c) = chars.next() {
+        if in_string {
+            json.push(c);
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    json.push(escaped);
+                }
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                json.push(c);
+            },
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        json.push(c);
+                        break;
+                    }
+                }
+            },
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            },
+            _ => json.push(c)
+        }
+    }
+    serde_json::from_str(&json).map_err(|e| ClientError::decode("Pack file is not valid JSON", e))
+}
+
+fn parse_asset(path: &str, data: &[u8]) -> Option<Value> {
+    match parse_json(data) {
+        Ok(value) => Option::from(value),
+        Err(e) => {
+            warn!(target: log_target::GAME, "Skipping pack file {}: {}", path, e);
+            None
+        }
+    }
+}
+
+// key=value lines, "##" starts a comment
+fn parse_lang(data: &[u8]) -> HashMap<String, String> {
+    String::from_utf8_lossy(data).lines()
+        .filter_map(|line| line.split_once('='))
+        .filter(|(key, _)| !key.trim_start().starts_with("##"))
+        .map(|(key, value)| (key.trim().to_string(), value.split("\t##").next().unwrap_or_default().trim_end().to_string()))
+        .collect()
+}
+
+// "textures" of an atlas entry is a path, an object with "path" or a list of either
+fn texture_atlas(atlas: &Value) -> HashMap<String, Vec<String>> {
+    fn paths(textures: &Value) -> Vec<String> {
+        match textures {
+            Value::String(path) => vec![path.clone()],
+            Value::Object(object) => object.get("path").and_then(Value::as_str).map(|path| vec![path.to_string()]).unwrap_or_default(),
+            Value::Array(list) => list.iter().flat_map(paths).collect(),
+            _ => Vec::new()
+        }
+    }
+
+    atlas.get("texture_data").and_then(Value::as_object)
+        .map(|data| data.iter().map(|(name, entry)| (name.clone(), entry.get("textures").map(paths).unwrap_or_default())).collect())
+        .unwrap_or_default()
+}
+
+fn identifier(definition: &Value, keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .find_map(|key| definition.get(key))
+        .and_then(|object| object.get("description"))
+        .and_then(|description| description.get("identifier"))
+        .and_then(Value::as_str)
+        .map(|identifier| identifier.to_string())
+}
@@ -0,0 +1,60 @@
+mod common;
+
+use bedrock_client::protocol::game::{command_output, update_soft_enum};
+use bedrock_client::protocol::game::text::TextMessage;
+use bedrock_client::session::CommandResult;
+use bedrock_client::utils::command_tree::{CommandError, CommandTree};
+use common::{sample_commands, sample_output};
+use uuid::Uuid;
+
+#[test]
+fn command_tree() {
+    let mut commands = CommandTree::from_packet(&sample_commands()).unwrap();
+
+    assert_eq!(commands.get_command("/gm").unwrap().name, "gamemode");
+    assert_eq!(commands.get_command("tp").unwrap().aliases, vec!["tp"]);
+    assert_eq!(commands.usage(commands.get_command("gamemode").unwrap()), vec!["/gamemode <gameMode: GameMode> [player: target]"]);
+
+    assert!(commands.validate("/gamemode creative").is_ok());
+    assert!(commands.validate("gm SURVIVAL @a[r=5, tag=\"a b\"]").is_ok());
+    assert!(commands.validate("/tp ~ ~10 ^-2.5").is_ok());
+    assert!(commands.validate("/tp \"Steve Two\"").is_ok());
+    assert!(commands.validate("/xp 10L Steve").is_ok());
+    assert!(commands.validate("/say hello there").is_ok());
+    assert!(matches!(commands.validate("/gamemode adventure"), Err(CommandError::NoMatchingOverload{ .. })));
+    assert!(matches!(commands.validate("/gamemode"), Err(CommandError::NoMatchingOverload{ .. })));
+    assert!(matches!(commands.validate("/gamemode creative Steve extra"), Err(CommandError::NoMatchingOverload{ .. })));
+    assert!(matches!(commands.validate("/xp 10 Steve"), Err(CommandError::NoMatchingOverload{ .. })));
+    assert!(matches!(commands.validate("/fly"), Err(CommandError::UnknownCommand(_))));
+    assert!(commands.validate("/execute as @a at @s run say hi").is_ok());
+
+    assert_eq!(commands.complete("/g"), vec!["gamemode", "gm"]);
+    assert_eq!(commands.complete("/gamemode c"), vec!["creative"]);
+    assert_eq!(commands.complete("/gamemode creative "), vec!["@a", "@e", "@initiator", "@p", "@r", "@s"]);
+    assert!(commands.complete("/say ").is_empty());
+
+    assert!(commands.validate("/warp arena").is_err());
+    commands.update_soft_enum(&update_soft_enum::new("Warps".to_string(), vec!["arena".to_string(), "spawn".to_string()], update_soft_enum::TYPE_ADD));
+    assert!(commands.validate("/warp arena").is_ok());
+    assert_eq!(commands.complete("/warp "), vec!["arena", "spawn"]);
+    commands.update_soft_enum(&update_soft_enum::new("Warps".to_string(), vec!["spawn".to_string()], update_soft_enum::TYPE_REMOVE));
+    assert_eq!(commands.get_soft_enum("Warps").unwrap(), ["arena".to_string()]);
+    commands.update_soft_enum(&update_soft_enum::new("Warps".to_string(), vec!["lobby".to_string()], update_soft_enum::TYPE_SET));
+    assert!(commands.validate("/warp lobby").is_ok() && commands.validate("/warp arena").is_err());
+
+    let mut broken = sample_commands();
+    broken.enums[0].value_indices.push(42);
+    assert!(CommandTree::from_packet(&broken).is_err());
+}
+
+#[test]
+fn command_result() {
+    let result = CommandResult::new(&sample_output(command_output::TYPE_ALL_OUTPUT));
+    assert_eq!(result.request_uuid, Uuid::from_u128(9));
+    assert!(result.is_success());
+    assert_eq!(result.messages[0].to_text_message(), TextMessage::Translation{ message: "commands.time.set".to_string(), parameters: vec!["1000".to_string()] });
+
+    let mut failed = sample_output(command_output::TYPE_LAST_OUTPUT);
+    failed.success_count = 0;
+    assert!(!CommandResult::new(&failed).is_success());
+}
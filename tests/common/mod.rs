@@ -0,0 +1,107 @@
+// Helpers shared by the test binaries, not every binary uses all of them
+#![allow(dead_code)]
+
+use bedrock_client::client_data_options;
+use bedrock_client::protocol::game::{available_commands, command_output};
+use bedrock_client::protocol::game::available_commands::AvailableCommands;
+use bedrock_client::protocol::game::bedrock_packet::{encode_packet, BedrockPacket};
+use bedrock_client::protocol::game::command_output::CommandOutputMessage;
+use bedrock_client::protocol::game::packet_registry::PacketRegistry;
+use bedrock_client::protocol::game::player_list::PlayerListEntry;
+use bedrock_client::protocol::game::types::attribute::{self, Attribute, AttributeModifier};
+use bedrock_client::protocol::game::types::command_data::{self, CommandData, CommandEnum, CommandOverload, CommandParameter, SoftEnum};
+use bedrock_client::protocol::game::types::command_origin_data::{self, CommandOriginData};
+use bedrock_client::protocol::game::types::skin_data::{SkinData, SkinImage};
+use binary_utils::binary::Stream;
+use uuid::Uuid;
+
+pub fn round_trip<P: BedrockPacket>(packet: &P) {
+    let encoded = encode_packet(packet);
+
+    let mut stream = Stream::new(encoded.clone(), 0);
+    let length = stream.get_unsigned_var_int();
+    let body = stream.get(length).unwrap();
+    assert_eq!(PacketRegistry::new().decode_packet(body.clone()).unwrap().id(), P::ID);
+
+    let mut body_stream = Stream::new(body.clone(), 0);
+    body_stream.get_unsigned_var_int();
+    let offset = body_stream.get_offset();
+    assert_eq!(encode_packet(&P::decode(&mut body_stream).unwrap()), encoded);
+
+    // cut off packets must not panic
+    for end in offset as usize..body.len() {
+        let _ = P::decode(&mut Stream::new(body[..end].to_vec(), offset));
+    }
+}
+
+pub fn movement_attribute(value: f32) -> Attribute {
+    let mut movement = Attribute::new(attribute::MOVEMENT.to_string(), 0.0, f32::MAX, value, 0.1);
+    movement.modifiers.push(AttributeModifier{ id: "D208FC00-42AA-4AAD-9276-D5446530DE43".to_string(), name: "Sprinting speed boost".to_string(), amount: 0.3, operation: attribute::OPERATION_MULTIPLY_TOTAL, operand: 0, serializable: false });
+    movement
+}
+
+pub fn list_entry(uuid: u128, username: &str, xuid: &str) -> PlayerListEntry {
+    let skin = SkinData{ skin_id: format!("{}-skin", username), skin_image: SkinImage::new(64, 64, vec![uuid as u8; 64 * 64 * 4]), ..SkinData::default() };
+    PlayerListEntry{ uuid: Uuid::from_u128(uuid), actor_unique_id: uuid as i64, username: username.to_string(), xuid: xuid.to_string(), build_platform: client_data_options::DEVICE_ANDROID, skin, skin_trusted: true, ..PlayerListEntry::default() }
+}
+
+pub fn png(width: u32, height: u32, color_type: png::ColorType, pixels: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut bytes, width, height);
+    encoder.set_color(color_type);
+    encoder.write_header().unwrap().write_image_data(pixels).unwrap();
+    bytes
+}
+
+pub fn parameter(name: &str, parameter_type: u32, optional: bool) -> CommandParameter {
+    CommandParameter{ name: name.to_string(), parameter_type, optional, options: 0 }
+}
+
+// /gamemode <mode> [player], /tp <player> | <x y z>, /xp <amount>L [player], /warp <Warps>, /say <message>, /execute ...
+pub fn sample_commands() -> AvailableCommands {
+    let strings = |values: &[&str]| values.iter().map(|value| value.to_string()).collect::<Vec<String>>();
+    let enum_flags = command_data::ARG_FLAG_VALID | command_data::ARG_FLAG_ENUM;
+    let command = |name: &str, aliases_offset: u32, overloads: Vec<Vec<CommandParameter>>| CommandData{
+        name: name.to_string(),
+        description: format!("commands.{}.description", name),
+        flags: 0,
+        permission_level: command_data::PERMISSION_ADMIN,
+        aliases_offset,
+        chained_subcommand_offsets: vec![],
+        overloads: overloads.into_iter().map(|parameters| CommandOverload{ chaining: false, parameters }).collect()
+    };
+
+    available_commands::new(
+        strings(&["survival", "creative", "gamemode", "gm", "teleport", "tp"]),
+        vec![],
+        strings(&["L"]),
+        vec![
+            CommandEnum{ name: "GameMode".to_string(), value_indices: vec![0, 1] },
+            CommandEnum{ name: "GamemodeAliases".to_string(), value_indices: vec![2, 3] },
+            CommandEnum{ name: "TeleportAliases".to_string(), value_indices: vec![4, 5] }
+        ],
+        vec![],
+        vec![
+            command("gamemode", 1, vec![vec![parameter("gameMode", enum_flags, false), parameter("player", command_data::ARG_FLAG_VALID | command_data::ARG_TYPE_TARGET, true)]]),
+            command("teleport", 2, vec![
+                vec![parameter("destination", command_data::ARG_FLAG_VALID | command_data::ARG_TYPE_TARGET, false)],
+                vec![parameter("destination", command_data::ARG_FLAG_VALID | command_data::ARG_TYPE_POSITION, false)]
+            ]),
+            command("xp", command_data::NO_ALIASES, vec![vec![parameter("amount", command_data::ARG_FLAG_POSTFIX, false), parameter("player", command_data::ARG_FLAG_VALID | command_data::ARG_TYPE_TARGET, true)]]),
+            command("warp", command_data::NO_ALIASES, vec![vec![parameter("name", command_data::ARG_FLAG_VALID | command_data::ARG_FLAG_SOFT_ENUM, false)]]),
+            command("say", command_data::NO_ALIASES, vec![vec![parameter("message", command_data::ARG_FLAG_VALID | command_data::ARG_TYPE_MESSAGE, false)]]),
+            CommandData{
+                overloads: vec![CommandOverload{ chaining: true, parameters: vec![parameter("subcommand", command_data::ARG_FLAG_VALID | command_data::ARG_TYPE_STRING, false)] }],
+                ..command("execute", command_data::NO_ALIASES, vec![])
+            }
+        ],
+        vec![SoftEnum{ name: "Warps".to_string(), values: strings(&["spawn"]) }],
+        vec![]
+    )
+}
+
+pub fn sample_output(output_type: u8) -> command_output::CommandOutput {
+    let origin_data = CommandOriginData::new(command_origin_data::ORIGIN_PLAYER, Uuid::from_u128(9), Uuid::from_u128(9).to_string(), 0);
+    let messages = vec![CommandOutputMessage{ success: true, message: "commands.time.set".to_string(), parameters: vec!["1000".to_string()] }];
+    command_output::new(origin_data, output_type, 1, messages, if output_type == command_output::TYPE_DATA_SET { "{\"time\":1000}".to_string() } else { "".to_string() })
+}
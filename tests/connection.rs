@@ -0,0 +1,360 @@
+mod common;
+
+use bedrock_client::auth::OfflineAuth;
+use bedrock_client::client;
+use bedrock_client::connection_state::{ConnectionState, StateChange, StateMachine};
+use bedrock_client::entity_manager::Entity;
+use bedrock_client::error::ClientError;
+use bedrock_client::handler::{ClientHandler, DebugHandler};
+use bedrock_client::protocol::connected_ping::ConnectedPing;
+use bedrock_client::protocol::connected_pong::ConnectedPong;
+use bedrock_client::protocol::frame_set::{self, Datagram, RELIABLE_ORDERED};
+use bedrock_client::protocol::game::{add_player, disconnect, network_settings, play_status, player_list, protocol_version, remove_actor, request_chunk_radius, text};
+use bedrock_client::protocol::game::bedrock_packet::encode_packet;
+use bedrock_client::protocol::game::bedrock_packet_ids::BedrockPacketType;
+use bedrock_client::protocol::game::disconnect::Disconnect;
+use bedrock_client::protocol::game::login::{self, OfflineIdentity};
+use bedrock_client::protocol::game::packet_registry::Packet;
+use bedrock_client::protocol::game::player_list::PlayerListEntry;
+use bedrock_client::protocol::game::text::Text;
+use bedrock_client::protocol::game::types::vector::Vec3;
+use bedrock_client::protocol::game_packet::{Compression, CompressionAlgorithm, GamePacket};
+use bedrock_client::protocol::open_conn_reply2::OpenConnReply2;
+use bedrock_client::protocol::packet_ids::PacketType;
+use bedrock_client::protocol::unconnected_pong::UnconnectedPong;
+use bedrock_client::session::{ClientHandle, PacketBatch, SendOptions, Session};
+use bedrock_client::utils::{address, log_target};
+use binary_utils::binary::Stream;
+use common::list_entry;
+use std::error::Error;
+use std::net::UdpSocket;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use uuid::Uuid;
+
+#[test]
+fn connection_states() {
+    let handshake = [
+        ConnectionState::OfflineHandshake,
+        ConnectionState::Connecting,
+        ConnectionState::Connected,
+        ConnectionState::NetworkSettings,
+        ConnectionState::Login,
+        ConnectionState::Encrypted,
+        ConnectionState::ResourcePacks,
+        ConnectionState::Spawning,
+        ConnectionState::InGame
+    ];
+    for (i, from) in handshake.iter().enumerate() {
+        for (j, to) in handshake.iter().enumerate() {
+            // only the next step, and Login => ResourcePacks for servers without encryption
+            let allowed = j == i + 1 || (*from == ConnectionState::Login && *to == ConnectionState::ResourcePacks);
+            assert_eq!(from.can_transition_to(*to), allowed, "{} -> {}", from, to);
+        }
+        assert!(from.can_transition_to(ConnectionState::Disconnected));
+        assert!(!ConnectionState::Disconnected.can_transition_to(*from));
+    }
+    assert!(!ConnectionState::Disconnected.can_transition_to(ConnectionState::Disconnected));
+
+    assert_eq!(ConnectionState::OfflineHandshake.timeout(), Option::from(Duration::from_secs(5)));
+    assert_eq!(ConnectionState::ResourcePacks.timeout(), Option::from(Duration::from_secs(60)));
+    assert_eq!(ConnectionState::InGame.timeout(), None);
+    assert_eq!(ConnectionState::Disconnected.timeout(), None);
+
+    assert!(ConnectionState::OfflineHandshake.accepts_raknet(PacketType::OpenConnReply1));
+    assert!(!ConnectionState::Connecting.accepts_raknet(PacketType::OpenConnReply1));
+    assert!(ConnectionState::Connecting.accepts_raknet(PacketType::ConnReqAccepted));
+    assert!(!ConnectionState::InGame.accepts_raknet(PacketType::ConnReqAccepted));
+    assert!(!ConnectionState::Connecting.accepts_raknet(PacketType::Game));
+    assert!(ConnectionState::Connected.accepts_raknet(PacketType::Game));
+    assert!(!ConnectionState::Disconnected.accepts_raknet(PacketType::Game));
+
+    assert!(ConnectionState::NetworkSettings.accepts(&BedrockPacketType::NetworkSettings));
+    assert!(!ConnectionState::NetworkSettings.accepts(&BedrockPacketType::StartGame));
+    assert!(ConnectionState::Login.accepts(&BedrockPacketType::ServerToClientHandshake));
+    assert!(ConnectionState::Encrypted.accepts(&BedrockPacketType::ResourcePacksInfo));
+    assert!(!ConnectionState::Connected.accepts(&BedrockPacketType::Text));
+    assert!(ConnectionState::ResourcePacks.accepts(&BedrockPacketType::StartGame));
+    assert!(ConnectionState::ResourcePacks.accepts(&BedrockPacketType::ResourcePackChunkData));
+    assert!(!ConnectionState::ResourcePacks.accepts(&BedrockPacketType::LevelChunk));
+    assert!(!ConnectionState::ResourcePacks.accepts(&BedrockPacketType::MovePlayer));
+    assert!(!ConnectionState::InGame.accepts(&BedrockPacketType::StartGame));
+    assert!(!ConnectionState::InGame.accepts(&BedrockPacketType::ResourcePackStack));
+    assert!(ConnectionState::InGame.accepts(&BedrockPacketType::Text));
+    for state in handshake {
+        assert!(state.accepts(&BedrockPacketType::Disconnect));
+    }
+    assert!(!ConnectionState::Disconnected.accepts(&BedrockPacketType::Disconnect));
+
+    let mut state_machine = StateMachine::new();
+    let changes = state_machine.subscribe();
+    assert!(state_machine.is(ConnectionState::OfflineHandshake) && !state_machine.is_timed_out());
+    assert!(state_machine.transition(ConnectionState::InGame).is_err());
+    assert!(state_machine.is(ConnectionState::OfflineHandshake));
+    state_machine.transition(ConnectionState::Connecting).unwrap();
+    let change = changes.try_recv().unwrap();
+    assert_eq!((change.from, change.to), (ConnectionState::OfflineHandshake, ConnectionState::Connecting));
+    assert!(changes.try_recv().is_err()); // the rejected transition was not announced
+    state_machine.refresh();
+    assert!(state_machine.elapsed() < Duration::from_secs(5));
+}
+
+// Records the handler calls in the order they were made
+struct RecordingHandler {
+    events: Arc<Mutex<Vec<String>>>
+}
+
+impl ClientHandler for RecordingHandler {
+    fn on_text(&mut self, text: &Text) {
+        self.events.lock().unwrap().push(format!("text {}", text.message.get_message()));
+    }
+
+    fn on_entity_added(&mut self, entity: &Entity) {
+        self.events.lock().unwrap().push(format!("added {}", entity.runtime_id));
+    }
+
+    fn on_entity_removed(&mut self, entity: &Entity) {
+        self.events.lock().unwrap().push(format!("removed {}", entity.runtime_id));
+    }
+
+    fn on_player_join(&mut self, player: &PlayerListEntry) {
+        self.events.lock().unwrap().push(format!("join {}", player.username));
+    }
+
+    fn on_player_leave(&mut self, player: &PlayerListEntry) {
+        self.events.lock().unwrap().push(format!("leave {}", player.username));
+    }
+
+    fn on_disconnect(&mut self, disconnect: &Disconnect) {
+        self.events.lock().unwrap().push(format!("disconnect {}", disconnect.reason));
+    }
+
+    fn on_packet(&mut self, packet: &Packet) {
+        self.events.lock().unwrap().push(format!("packet {}", packet.id()));
+    }
+
+    fn on_raw_packet(&mut self, packet_id: u16, payload: &[u8]) {
+        self.events.lock().unwrap().push(format!("raw {} {}", packet_id, payload.len()));
+    }
+}
+
+#[test]
+fn handler_dispatch() {
+    let mut client = client::create_offline("127.0.0.1".to_string(), 19132, "1.21.50".to_string(), OfflineIdentity::new("Bot".to_string()).unwrap()).unwrap();
+    let events = Arc::new(Mutex::new(Vec::new()));
+    client.set_handler(RecordingHandler{ events: events.clone() });
+
+    // spawning before the handshake is an illegal transition
+    let error = client.handle_game_packet(Packet::PlayStatus(play_status::new(3))).unwrap_err();
+    assert!(matches!(error, ClientError::Protocol(..)), "{}", error);
+    assert_eq!(client.get_state(), ConnectionState::OfflineHandshake);
+    events.lock().unwrap().clear();
+
+    let packets = vec![
+        Packet::Text(text::chat("Steve".to_string(), "hi".to_string(), "".to_string())),
+        Packet::PlayerList(player_list::new(player_list::TYPE_ADD, vec![list_entry(1, "Steve", ""), list_entry(2, "Alex", "")])),
+        Packet::AddPlayer(add_player::new(Uuid::from_u128(1), "Steve".to_string(), 7, 3, Vec3::new(0.0, 64.0, 0.0))),
+        Packet::RemoveActor(remove_actor::new(99)), // not tracked, no event
+        Packet::RemoveActor(remove_actor::new(7)),
+        Packet::PlayerList(player_list::new(player_list::TYPE_REMOVE, vec![PlayerListEntry::removal(Uuid::from_u128(2))])),
+        Packet::Unknown{ id: 0x1ff, payload: vec![1, 2, 3] }
+    ];
+    for packet in packets {
+        client.handle_game_packet(packet).unwrap();
+    }
+    // the connection ends with the disconnect, after the handler saw it
+    assert!(client.handle_game_packet(Packet::Disconnect(disconnect::new(2, Option::from("bye".to_string()), None))).is_err());
+
+    assert_eq!(*events.lock().unwrap(), vec![
+        "packet 9", "text hi", "packet 63", "join Steve", "join Alex", "packet 12", "added 3", "packet 14", "packet 14", "removed 3",
+        "packet 63", "leave Alex", "raw 511 3", "packet 5", "disconnect 2"
+    ]);
+    // the state was updated before the handler was called
+    assert_eq!(client.handle().players(|players| players.len()), 1);
+    assert!(client.handle().entities(|entities| entities.is_empty()));
+}
+
+// Records of every test in this binary, each test only looks for its own messages
+struct RecordingLogger {
+    records: Mutex<Vec<(String, String)>>
+}
+
+impl log::Log for RecordingLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        self.records.lock().unwrap().push((record.target().to_string(), record.args().to_string()));
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: RecordingLogger = RecordingLogger{ records: Mutex::new(Vec::new()) };
+
+#[test]
+fn log_targets() {
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(log::LevelFilter::Trace);
+
+    ConnectedPing::create(1234).debug();
+    DebugHandler{}.on_state_change(&StateChange{ from: ConnectionState::Login, to: ConnectionState::Encrypted });
+
+    let records = LOGGER.records.lock().unwrap();
+    assert!(records.contains(&(log_target::RAKNET.to_string(), "Ping Time: 1234".to_string())));
+    assert!(records.contains(&(log_target::GAME.to_string(), "State: Login -> Encrypted".to_string())));
+    // nothing falls back to the module path as target, RUST_LOG=raknet=off has to silence the whole layer
+    assert!(records.iter().all(|(target, _)| !target.starts_with("bedrock_client")), "{:?}", records);
+}
+
+fn connected_session(protocol: u32) -> (Session, UdpSocket) {
+    let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+    server.set_read_timeout(Option::from(Duration::from_secs(5))).unwrap();
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    socket.connect(server.local_addr().unwrap()).unwrap();
+    let auth_data = OfflineAuth::new(OfflineIdentity::new("Bot".to_string()).unwrap()).auth_data().unwrap();
+    let identity = login::identity_from_chain(&auth_data.chain).unwrap();
+    let session = Session::new(Arc::new(socket), GamePacket::new(None, None), frame_set::start_number_cache(), protocol, identity);
+    (session, server)
+}
+
+// Batch of the game packet in the next datagram, the session has no compression and encryption
+fn receive_batch(server: &UdpSocket) -> (Datagram, Vec<u8>) {
+    let mut buffer = vec![0; 2048];
+    let (length, _) = server.recv_from(&mut buffer).unwrap();
+    let datagram = Datagram::from_binary(buffer[..length].to_vec()).unwrap();
+    let body = datagram.frames[0].body.clone();
+    assert_eq!(body[0], 0xfe);
+    (datagram, body[1..].to_vec())
+}
+
+#[test]
+fn session_sending() {
+    let (mut session, server) = connected_session(protocol_version::LATEST);
+    let text = text::chat("Bot".to_string(), "hello".to_string(), "".to_string());
+
+    // nothing can be sent before the RakNet connection is up
+    assert!(session.send_packet(&text, SendOptions::default()).is_err());
+    session.state = ConnectionState::Connected;
+
+    session.send_packet(&text, SendOptions::default()).unwrap();
+    let (datagram, batch) = receive_batch(&server);
+    assert_eq!(batch, encode_packet(&text));
+    assert_eq!(datagram.frames[0].order.as_ref().map(|order| order.order_channel), Option::from(0));
+
+    let request = request_chunk_radius::new(8, 8);
+    let mut packets = PacketBatch::new();
+    packets.add(&text).add(&request);
+    session.send_batch(&packets, SendOptions::new(RELIABLE_ORDERED, 3)).unwrap();
+    let (datagram, batch) = receive_batch(&server);
+    assert_eq!(batch, [encode_packet(&text), encode_packet(&request)].concat());
+    assert_eq!(datagram.frames[0].order.as_ref().map(|order| order.order_channel), Option::from(3));
+    session.send_batch(&PacketBatch::new(), SendOptions::default()).unwrap(); // empty batches are not sent
+
+    // order channels 0..31 exist, nothing is sent for the others
+    assert!(session.send_packet(&text, SendOptions::new(RELIABLE_ORDERED, frame_set::MAX_ORDER_CHANNELS as u8)).is_err());
+    session.send_packet(&text, SendOptions::new(RELIABLE_ORDERED, frame_set::MAX_ORDER_CHANNELS as u8 - 1)).unwrap();
+    let (datagram, _) = receive_batch(&server);
+    assert_eq!(datagram.frames[0].order.as_ref().map(|order| order.order_channel), Option::from(frame_set::MAX_ORDER_CHANNELS as u8 - 1));
+
+    // a batch encoded for another protocol is refused
+    let mut old_batch = PacketBatch::with_protocol(protocol_version::V1_21_40);
+    old_batch.add(&text);
+    assert!(session.send_batch(&old_batch, SendOptions::default()).is_err());
+}
+
+#[test]
+fn client_handle_sending() {
+    let (mut session, server) = connected_session(protocol_version::LATEST);
+    session.state = ConnectionState::InGame;
+    let handle = ClientHandle::new(Arc::new(Mutex::new(session)));
+
+    // clones send from other threads through the shared session
+    let sender = handle.clone();
+    std::thread::spawn(move || {
+        let mut batch = sender.new_batch();
+        batch.add(&request_chunk_radius::new(8, 8));
+        sender.send_batch(&batch, SendOptions::default())
+    }).join().unwrap().unwrap();
+    let (_, batch) = receive_batch(&server);
+    assert_eq!(batch, encode_packet(&request_chunk_radius::new(8, 8)));
+
+    handle.send_packet(&request_chunk_radius::new(4, 4), SendOptions::default()).unwrap();
+    let (_, batch) = receive_batch(&server);
+    assert_eq!(batch, encode_packet(&request_chunk_radius::new(4, 4)));
+    assert!(handle.send_packet(&request_chunk_radius::new(4, 4), SendOptions::new(RELIABLE_ORDERED, 32)).is_err());
+    assert_eq!(handle.get_protocol(), protocol_version::LATEST);
+}
+
+#[test]
+fn protocol_versions() {
+    assert_eq!(protocol_version::from_game_version("1.21.50"), Option::from(protocol_version::V1_21_50));
+    assert_eq!(protocol_version::from_game_version("1.21.44"), Option::from(protocol_version::V1_21_40));
+    assert_eq!(protocol_version::from_game_version("1.2.0"), None);
+    assert!(protocol_version::is_supported(protocol_version::LATEST));
+
+    // reconnecting can not fix an unsupported version
+    let error = client::create_offline("127.0.0.1".to_string(), 19132, "1.2.0".to_string(), OfflineIdentity::new("Bot".to_string()).unwrap()).err().unwrap();
+    assert!(matches!(error, ClientError::UnsupportedVersion(_)) && !error.is_retryable());
+    assert!(ClientError::Protocol("Timed out in state Login".to_string(), None).is_retryable());
+    assert!(ClientError::auth("Chain data is not JSON", "expected value").source().is_some());
+
+    // the protocol is read from the server id of the pong
+    let server_id = "MCPE;Bot server;766;1.21.50;0;10;1;Bedrock level;Survival;1;19132;19133;";
+    let mut stream = Stream::new(vec![0x1c], 0);
+    stream.put_long(1);
+    stream.put_long(2);
+    stream.put(vec![0; 16]);
+    stream.put_short(server_id.len() as u16);
+    stream.put(server_id.as_bytes().to_vec());
+    let pong = UnconnectedPong::decode(stream.get_buffer()).unwrap();
+    assert_eq!((pong.get_protocol(), pong.get_game_version()), (Option::from(766), Option::from("1.21.50".to_string())));
+    assert!(UnconnectedPong::decode(stream.get_buffer()[..40].to_vec()).is_err());
+}
+
+#[test]
+fn raknet_decode_errors() {
+    let mut stream = Stream::new(vec![0x08], 0);
+    stream.put(vec![0; 16]);
+    stream.put_long(42);
+    stream.put(address::new(4, "127.0.0.1".to_string(), 19132).put_address());
+    stream.put_short(1400);
+    stream.put_bool(false);
+    let reply = OpenConnReply2::decode(stream.get_buffer()).unwrap();
+    assert_eq!((reply.client_address.address, reply.client_address.port, reply.mtu), ("127.0.0.1".to_string(), 19132, 1400));
+    for end in 0..stream.get_buffer().len() {
+        assert!(OpenConnReply2::decode(stream.get_buffer()[..end].to_vec()).is_err());
+    }
+
+    let pong = ConnectedPong::create(1, 2).encode();
+    assert_eq!(ConnectedPong::decode(pong.clone()).unwrap().pong_time, 2);
+    assert!(ConnectedPong::decode(pong[..9].to_vec()).is_err());
+
+    // an unreliable frame announcing two bytes of body with only one present
+    let datagram = vec![0x84, 0, 0, 0, 0, 0, 16, 0xfe];
+    assert!(Datagram::from_binary(datagram.clone()).is_err());
+    assert_eq!(Datagram::from_binary([datagram, vec![0]].concat()).unwrap().frames[0].body, vec![0xfe, 0]);
+}
+
+#[test]
+fn compression() {
+    let payload = vec![7u8; 512];
+    for algorithm in [CompressionAlgorithm::Zlib, CompressionAlgorithm::Snappy, CompressionAlgorithm::None] {
+        let compressed = GamePacket::compress(&payload, Compression::new(algorithm, 256)).unwrap();
+        assert_eq!(compressed[0], algorithm.get_id());
+        assert_eq!(GamePacket::decompress(&compressed).unwrap(), payload);
+    }
+
+    // below the threshold (or with compression disabled) the batch is only prefixed
+    let small = GamePacket::compress(&payload[..16].to_vec(), Compression::new(CompressionAlgorithm::Zlib, 256)).unwrap();
+    assert_eq!(small[0], CompressionAlgorithm::None.get_id());
+    let disabled = GamePacket::compress(&payload, Compression::new(CompressionAlgorithm::Snappy, 0)).unwrap();
+    assert_eq!(disabled[0], CompressionAlgorithm::None.get_id());
+
+    // NetworkSettings sends "none" as a short
+    assert_eq!(CompressionAlgorithm::from_network_id(0xffff), Some(CompressionAlgorithm::None));
+    assert_eq!(CompressionAlgorithm::from_network_id(network_settings::SNAPPY), Some(CompressionAlgorithm::Snappy));
+    assert_eq!(CompressionAlgorithm::from_network_id(0xff), None);
+    assert_eq!(GamePacket::decompress(&disabled).unwrap(), payload);
+}
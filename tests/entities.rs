@@ -0,0 +1,228 @@
+mod common;
+
+use bedrock_client::client_data_options::{self, Cape, Skin};
+use bedrock_client::entity_manager::EntityManager;
+use bedrock_client::online_players::OnlinePlayers;
+use bedrock_client::protocol::game::{add_actor, add_item_actor, add_player, move_actor_absolute, move_actor_delta, move_player, player_list, player_skin, remove_actor, set_actor_data, set_actor_link, set_actor_motion};
+use bedrock_client::protocol::game::player_list::PlayerListEntry;
+use bedrock_client::protocol::game::types::actor_link::{self, ActorLink};
+use bedrock_client::protocol::game::types::actor_metadata::{ActorDataKey, ActorFlag, ActorMetadata, MetadataValue};
+use bedrock_client::protocol::game::types::actor_properties::{self, ActorProperties, ActorPropertyDefinition, PropertyValue};
+use bedrock_client::protocol::game::types::client_data::PersonaPiece;
+use bedrock_client::protocol::game::types::item_stack::ItemStack;
+use bedrock_client::protocol::game::types::property_sync_data::PropertySyncData;
+use bedrock_client::protocol::game::types::skin_data::{SkinAnimation, SkinImage};
+use bedrock_client::protocol::game::types::vector::Vec3;
+use binary_utils::binary::Stream;
+use common::{list_entry, round_trip};
+use uuid::Uuid;
+
+fn zombie(runtime_id: u64, unique_id: i64, position: Vec3) -> add_actor::AddActor {
+    let mut packet = add_actor::new(unique_id, runtime_id, "minecraft:zombie".to_string(), position);
+    packet.metadata.set(4, MetadataValue::String("Bob".to_string()));
+    packet
+}
+
+#[test]
+fn entity_packets() {
+    let mut actor = zombie(2, -5, Vec3::new(1.0, 64.0, 1.0));
+    actor.metadata.set(0, MetadataValue::Long(1 << 14));
+    actor.metadata.set(38, MetadataValue::Float(0.5));
+    actor.metadata.set(9, MetadataValue::BlockPos(1, -2, 3));
+    actor.properties.int_properties.push((0, 3));
+    actor.links.push(ActorLink::new(-5, 7, actor_link::TYPE_RIDER));
+    round_trip(&actor);
+    let mut player = add_player::new(Uuid::from_u128(9), "Steve".to_string(), 7, 3, Vec3::new(0.0, 70.0, 0.0));
+    player.held_item = ItemStack{ network_id: 5, count: 1, metadata: 0, stack_id: Option::from(1), block_runtime_id: 0, extra_data: vec![0, 0] };
+    round_trip(&player);
+    round_trip(&add_item_actor::new(8, 4, ItemStack::new(5, 64, 0), Vec3::new(0.5, 65.0, 0.5)));
+    round_trip(&remove_actor::new(-5));
+    round_trip(&move_actor_absolute::new(2, move_actor_absolute::FLAG_GROUND, Vec3::new(2.0, 64.0, 1.0), 0.0, 90.0, 90.0));
+    let mut delta = move_actor_delta::new(2);
+    delta.y = Option::from(65.5);
+    delta.yaw = Option::from(180.0);
+    round_trip(&delta);
+    let mut teleport = move_player::new(3, Vec3::new(10.0, 71.62, 10.0), 0.0, 45.0, move_player::MODE_TELEPORT);
+    teleport.teleport_cause = 2;
+    round_trip(&teleport);
+    round_trip(&set_actor_motion::new(2, Vec3::new(0.0, 0.42, 0.0), 100));
+    round_trip(&set_actor_data::new(2, ActorMetadata::new(), 100));
+    round_trip(&set_actor_link::new(ActorLink::new(-5, 7, actor_link::TYPE_REMOVE)));
+}
+
+#[test]
+fn entity_tracking() {
+    let mut entities = EntityManager::new();
+    entities.add_actor(&zombie(2, -5, Vec3::new(1.0, 64.0, 1.0)));
+    entities.add_actor(&zombie(4, -6, Vec3::new(20.0, 64.0, 0.0)));
+    entities.add_player(&add_player::new(Uuid::from_u128(9), "Steve".to_string(), 7, 3, Vec3::new(5.0, 64.0, 0.0)));
+    entities.add_item_actor(&add_item_actor::new(8, 5, ItemStack::new(5, 64, 0), Vec3::new(0.0, 64.0, 6.0)));
+    assert_eq!(entities.len(), 4);
+    assert_eq!(entities.get_player("steve").map(|player| player.runtime_id), Option::from(3));
+    assert_eq!(entities.get_players().count(), 1);
+    assert!(entities.get(5).unwrap().item.is_some());
+
+    // players move at eye height, entities are tracked at their feet
+    let mut move_steve = move_player::new(3, Vec3::new(5.0, 65.62, 1.0), 0.0, 90.0, move_player::MODE_NORMAL);
+    move_steve.on_ground = true;
+    assert!(entities.move_player(&move_steve));
+    let steve = entities.get(3).unwrap();
+    assert!((steve.position.y - 64.0).abs() < 1e-4 && steve.on_ground);
+
+    let mut delta = move_actor_delta::new(2);
+    delta.x = Option::from(2.0);
+    assert!(entities.move_delta(&delta));
+    assert_eq!(entities.get(2).unwrap().position, Vec3::new(2.0, 64.0, 1.0));
+    assert!(!entities.move_absolute(&move_actor_absolute::new(99, 0, Vec3::default(), 0.0, 0.0, 0.0)));
+
+    // SetActorData only carries the changed keys
+    let mut data = ActorMetadata::new();
+    data.set(38, MetadataValue::Float(2.0));
+    assert!(entities.set_data(&set_actor_data::new(2, data, 1)));
+    let metadata = &entities.get(2).unwrap().metadata;
+    assert_eq!(metadata.get(4), Option::from(&MetadataValue::String("Bob".to_string())));
+    assert_eq!(metadata.get(38), Option::from(&MetadataValue::Float(2.0)));
+
+    assert!(entities.set_link(&set_actor_link::new(ActorLink::new(-5, 7, actor_link::TYPE_RIDER))));
+    assert_eq!(entities.get_by_unique_id(-5).unwrap().links.len(), 1);
+    assert!(entities.set_link(&set_actor_link::new(ActorLink::new(-5, 7, actor_link::TYPE_REMOVE))));
+    assert!(entities.get_by_unique_id(-5).unwrap().links.is_empty());
+
+    let origin = Vec3::new(0.0, 64.0, 0.0);
+    assert_eq!(entities.nearest(origin).map(|entity| entity.runtime_id), Option::from(2));
+    assert_eq!(entities.in_radius(origin, 7.0).iter().map(|entity| entity.runtime_id).collect::<Vec<u64>>(), vec![2, 3, 5]);
+
+    // removal is by unique ID, a respawn with the same runtime ID replaces the old entity
+    assert_eq!(entities.remove(-6).map(|entity| entity.runtime_id), Option::from(4));
+    assert!(entities.remove(-6).is_none());
+    entities.add_actor(&zombie(2, -9, Vec3::default()));
+    assert!(entities.get_by_unique_id(-5).is_none());
+    assert_eq!(entities.len(), 3);
+    entities.clear();
+    assert!(entities.is_empty());
+}
+
+#[test]
+fn actor_metadata() {
+    let mut metadata = ActorMetadata::new();
+    metadata.set(ActorDataKey::NameTag.get_id(), MetadataValue::String("Bob".to_string()));
+    metadata.set(ActorDataKey::Scale.get_id(), MetadataValue::Float(0.5));
+    metadata.set(ActorDataKey::BoundingBoxWidth.get_id(), MetadataValue::Float(0.6));
+    metadata.set(ActorDataKey::BoundingBoxHeight.get_id(), MetadataValue::Float(1.9));
+    metadata.set_flag(ActorFlag::Sneaking, true);
+    metadata.set_flag(ActorFlag::Baby, true);
+    metadata.set_flag_index(70, true);
+    metadata.set_flag(ActorFlag::Baby, false);
+    assert_eq!(metadata.get_name_tag(), Option::from("Bob"));
+    assert_eq!(metadata.get_scale(), Option::from(0.5));
+    assert_eq!(metadata.get_bounding_box(), Option::from((0.6, 1.9)));
+    assert_eq!(metadata.get_variant(), None);
+    assert!(metadata.get_flag(ActorFlag::Sneaking) && !metadata.get_flag(ActorFlag::Baby) && !metadata.get_flag(ActorFlag::OnFire));
+    // flags 64.. are packed into the second long
+    assert_eq!(metadata.get_long(ActorDataKey::Flags), Option::from(1 << ActorFlag::Sneaking as i64));
+    assert_eq!(metadata.get_long(ActorDataKey::Flags2), Option::from(1 << 6));
+    assert_eq!(ActorDataKey::try_from(92), Ok(ActorDataKey::Flags2));
+    assert!(ActorDataKey::try_from(10).is_err());
+
+    let definitions = ActorProperties::new("minecraft:player".to_string(), vec![
+        ActorPropertyDefinition{ name: "test:level".to_string(), property_type: actor_properties::PROPERTY_INT, enum_values: vec![] },
+        ActorPropertyDefinition{ name: "test:speed".to_string(), property_type: actor_properties::PROPERTY_FLOAT, enum_values: vec![] },
+        ActorPropertyDefinition{ name: "test:mode".to_string(), property_type: actor_properties::PROPERTY_ENUM, enum_values: vec!["idle".to_string(), "angry".to_string()] },
+        ActorPropertyDefinition{ name: "test:glowing".to_string(), property_type: actor_properties::PROPERTY_BOOL, enum_values: vec![] }
+    ]);
+    let mut data = PropertySyncData{ int_properties: vec![(0, 3), (2, 1)], float_properties: vec![(1, 0.25)] };
+    assert_eq!(definitions.get(&data, "test:mode"), Option::from(PropertyValue::Enum("angry".to_string())));
+    assert_eq!(definitions.get(&data, "test:speed"), Option::from(PropertyValue::Float(0.25)));
+    assert_eq!(definitions.get(&data, "test:glowing"), None);
+    assert!(definitions.set(&mut data, "test:glowing", PropertyValue::Bool(true)));
+    assert!(definitions.set(&mut data, "test:mode", PropertyValue::Enum("idle".to_string())));
+    assert!(!definitions.set(&mut data, "test:level", PropertyValue::Float(1.0)));
+    assert!(!definitions.set(&mut data, "test:mode", PropertyValue::Enum("sleeping".to_string())));
+    assert_eq!(definitions.resolve(&data), vec![
+        ("test:level".to_string(), PropertyValue::Int(3)),
+        ("test:speed".to_string(), PropertyValue::Float(0.25)),
+        ("test:mode".to_string(), PropertyValue::Enum("idle".to_string())),
+        ("test:glowing".to_string(), PropertyValue::Bool(true))
+    ]);
+
+    // the local player is not an entity, its SetActorData is kept separately
+    let mut entities = EntityManager::new();
+    entities.set_property_definitions(definitions);
+    let mut update = set_actor_data::new(1, metadata.clone(), 0);
+    update.properties = data;
+    entities.set_player_data(&update);
+    assert!(entities.get_player_metadata().get_flag(ActorFlag::Sneaking));
+    assert_eq!(entities.get_player_property("test:level"), Option::from(PropertyValue::Int(3)));
+}
+
+#[test]
+fn actor_metadata_encoding() {
+    let mut metadata = ActorMetadata::new();
+    metadata.set(ActorDataKey::NameTag.get_id(), MetadataValue::String("Bob".to_string()));
+    metadata.set(ActorDataKey::Air.get_id(), MetadataValue::Short(-3));
+    metadata.set(ActorDataKey::BedPosition.get_id(), MetadataValue::BlockPos(1, -2, 3));
+    metadata.set(ActorDataKey::RiderSeatPosition.get_id(), MetadataValue::Vec3(Vec3::new(0.0, 1.5, 0.0)));
+    metadata.set(200, MetadataValue::Byte(1));
+    metadata.set_flag(ActorFlag::Sprinting, true);
+
+    let mut stream = Stream::new(Vec::new(), 0);
+    metadata.write(&mut stream);
+    let mut stream = Stream::new(stream.get_buffer(), 0);
+    assert_eq!(ActorMetadata::read(&mut stream).unwrap(), metadata);
+    assert!(stream.feof());
+
+    // one value of an unknown type
+    assert!(ActorMetadata::read(&mut Stream::new(vec![1, 0, 99, 0], 0)).is_err());
+}
+
+#[test]
+fn player_list_packets() {
+    let mut steve = list_entry(1, "Steve", "2535400000000001");
+    steve.skin.animations.push(SkinAnimation{ image: SkinImage::new(32, 64, vec![1; 32 * 64 * 4]), animation_type: 1, frames: 2.0, expression_type: 0 });
+    steve.skin.cape_image = SkinImage::new(64, 32, vec![2; 64 * 32 * 4]);
+    steve.skin.persona_pieces.push(PersonaPiece{ is_default: true, pack_id: "pack".to_string(), piece_id: "piece".to_string(), piece_type: "persona_skeleton".to_string(), product_id: "".to_string() });
+    round_trip(&player_list::new(player_list::TYPE_ADD, vec![steve.clone(), list_entry(2, "Alex", "")]));
+    round_trip(&player_list::new(player_list::TYPE_REMOVE, vec![PlayerListEntry::removal(Uuid::from_u128(1))]));
+    round_trip(&player_skin::new(Uuid::from_u128(1), steve.skin));
+}
+
+#[test]
+fn online_players() {
+    let mut players = OnlinePlayers::new();
+    let joined = players.apply(&player_list::new(player_list::TYPE_ADD, vec![list_entry(1, "Steve", "2535400000000001"), list_entry(2, "alex", ""), list_entry(3, "Bob", "2535400000000003")]));
+    assert_eq!(joined.len(), 3);
+    assert_eq!(players.get_by_name("STEVE").map(|player| player.uuid), Option::from(Uuid::from_u128(1)));
+    assert_eq!(players.get_by_xuid("2535400000000003").map(|player| player.username.as_str()), Option::from("Bob"));
+    assert!(players.get_by_xuid("").is_none());
+    assert_eq!(players.get_players().iter().map(|player| player.username.as_str()).collect::<Vec<&str>>(), vec!["alex", "Bob", "Steve"]);
+
+    let left = players.apply(&player_list::new(player_list::TYPE_REMOVE, vec![PlayerListEntry::removal(Uuid::from_u128(3)), PlayerListEntry::removal(Uuid::from_u128(9))]));
+    assert_eq!(left.iter().map(|player| player.username.as_str()).collect::<Vec<&str>>(), vec!["Bob"]);
+    assert_eq!(players.len(), 2);
+
+    let mut skin = list_entry(1, "Steve", "").skin;
+    skin.skin_id = "new".to_string();
+    skin.cape_image = SkinImage::new(64, 32, vec![7; 64 * 32 * 4]);
+    assert!(players.update_skin(&player_skin::new(Uuid::from_u128(1), skin.clone())));
+    assert!(!players.update_skin(&player_skin::new(Uuid::from_u128(3), skin)));
+    assert_eq!(players.get(&Uuid::from_u128(1)).unwrap().skin.skin_id, "new");
+
+    let json = players.to_json();
+    assert_eq!(json[1]["username"], "Steve");
+    assert_eq!(json[1]["xuid"], "2535400000000001");
+    assert_eq!(json[1]["build_platform"], client_data_options::DEVICE_ANDROID);
+
+    let root = std::env::temp_dir().join(format!("bedrock_client_skins_{}", std::process::id()));
+    assert_eq!(players.export_skins(&root).unwrap(), 2);
+    let geometry = r#"{"minecraft:geometry":[{"description":{"identifier":"geometry.test"}}]}"#.to_string();
+    let exported = Skin::from_png_bytes(&std::fs::read(root.join("Steve.png")).unwrap(), geometry).unwrap();
+    assert_eq!((exported.width, exported.height, exported.data[0]), (64, 64, 1));
+    let cape = Cape::from_png_bytes("cape".to_string(), &std::fs::read(root.join("Steve_cape.png")).unwrap()).unwrap();
+    assert_eq!(cape.data, vec![7; 64 * 32 * 4]);
+    assert!(!root.join("alex_cape.png").exists());
+    std::fs::remove_dir_all(root).unwrap();
+
+    // an image that does not match its size is not exported
+    assert!(SkinImage::new(64, 64, vec![0; 16]).to_png().is_err());
+    assert!(SkinImage::new(u32::MAX, u32::MAX, vec![0; 16]).to_png().is_err());
+}
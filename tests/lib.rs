@@ -5,9 +5,11 @@ mod tests {
 
     use bedrock_client::client;
 
+    // needs a server on 127.0.0.1:19132 and a Microsoft account, run with `cargo test -- --ignored`
     #[tokio::test]
+    #[ignore]
     async fn test() {
-        let client = client::create("127.0.0.1".to_string(), 19132, "1.21.50".to_string(), true);
+        let client = client::create("127.0.0.1".to_string(), 19132, "1.21.50".to_string());
         client.await.unwrap().connect().expect("Target IP Connection Error");
     }
 }
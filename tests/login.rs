@@ -0,0 +1,157 @@
+mod common;
+
+use bedrock_client::auth::OfflineAuth;
+use bedrock_client::client_data_options::{self, Cape, ClientDataOptions, Skin};
+use bedrock_client::error::ClientError;
+use bedrock_client::protocol::game::bedrock_packet::{encode_packet, BedrockPacket};
+use bedrock_client::protocol::game::login::{self, OfflineIdentity};
+use bedrock_client::protocol::game::protocol_version;
+use bedrock_client::utils::encryption::{self, Encryption};
+use bedrock_client::utils::jwt::{self, Jwt, JwtError};
+use binary_utils::binary::Stream;
+use common::png;
+use openssl::pkey::PKey;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+#[test]
+fn offline_login_chain() {
+    let identity = OfflineIdentity::new("Steve".to_string()).unwrap();
+    let uuid = identity.uuid;
+    assert_eq!(uuid, login::offline_uuid("Steve").unwrap());
+    assert_eq!(uuid.get_version_num(), 3);
+
+    let auth_data = OfflineAuth::new(identity).auth_data().unwrap();
+    assert!(auth_data.expires_at().unwrap() > 0);
+    let pkey = PKey::from_ec_key(auth_data.ec_key).unwrap();
+    let mut chain = auth_data.chain;
+    let login_data = login::convert_login_chain(&mut chain, pkey, "127.0.0.1".to_string(), 19132, 12345, "1.21.50".to_string(), &ClientDataOptions::default()).unwrap();
+
+    let chain: Value = serde_json::from_str(&login_data[0]).unwrap();
+    let chain = chain["chain"].as_array().unwrap();
+    assert_eq!(chain.len(), 1);
+
+    let parts: Vec<&str> = chain[0].as_str().unwrap().split('.').collect();
+    let header: Value = serde_json::from_str(&Encryption::b64_url_decode(parts[0]).unwrap()).unwrap();
+    let payload: Value = serde_json::from_str(&Encryption::b64_url_decode(parts[1]).unwrap()).unwrap();
+    assert_eq!(header["x5u"], payload["identityPublicKey"]);
+    assert_eq!(payload["extraData"]["displayName"], "Steve");
+    assert_eq!(payload["extraData"]["identity"], uuid.to_string());
+    assert_eq!(payload["extraData"]["XUID"], "");
+    Jwt::parse(chain[0].as_str().unwrap()).unwrap().verify_x5u().unwrap();
+    Jwt::parse(&login_data[1]).unwrap().verify_x5u().unwrap();
+}
+
+#[test]
+fn jwt_verification() {
+    let pkey = PKey::from_ec_key(encryption::generate_ec_key().unwrap()).unwrap();
+    let x5u = openssl::base64::encode_block(&pkey.public_key_to_der().unwrap());
+    let header = json!({ "alg": "ES384", "x5u": x5u });
+
+    let token = jwt::encode(&header, &json!({ "salt": "c2FsdA==" }), &pkey).unwrap();
+    let parsed = Jwt::parse(&token).unwrap();
+    parsed.verify_x5u().unwrap();
+    assert_eq!(parsed.get_str_claim("salt"), Option::from("c2FsdA=="));
+
+    // payload swapped for one the key never signed
+    let other = jwt::encode(&header, &json!({ "salt": "b3RoZXI=" }), &pkey).unwrap();
+    let parts: Vec<&str> = token.split('.').collect();
+    let forged = format!("{}.{}.{}", parts[0], other.split('.').nth(1).unwrap(), parts[2]);
+    assert!(matches!(Jwt::parse(&forged).unwrap().verify_x5u(), Err(JwtError::InvalidSignature)));
+
+    let expired = jwt::encode(&header, &json!({ "exp": 1000 }), &pkey).unwrap();
+    assert!(matches!(Jwt::parse(&expired).unwrap().verify_x5u(), Err(JwtError::Expired(1000))));
+
+    let wrong_alg = jwt::encode(&json!({ "alg": "none", "x5u": x5u }), &json!({}), &pkey).unwrap();
+    assert!(matches!(Jwt::parse(&wrong_alg).unwrap().verify_x5u(), Err(JwtError::UnsupportedAlgorithm(_))));
+
+    assert!(matches!(Jwt::parse("a.b"), Err(JwtError::Malformed(_))));
+}
+
+#[test]
+fn login_identity() {
+    let identity = OfflineIdentity::new("Alex".to_string()).unwrap();
+    let uuid = identity.uuid;
+    let auth_data = OfflineAuth::new(identity).auth_data().unwrap();
+    let pkey = PKey::from_ec_key(auth_data.ec_key).unwrap();
+    let mut chain = auth_data.chain;
+    let login_data = login::convert_login_chain(&mut chain, pkey.clone(), "127.0.0.1".to_string(), 19132, 12345, "1.21.50".to_string(), &ClientDataOptions::default()).unwrap();
+
+    let mut stream = Stream::new(encode_packet(&login::new(protocol_version::LATEST, login_data[0].clone(), login_data[1].clone())), 0);
+    stream.get_unsigned_var_int();
+    stream.get_unsigned_var_int();
+    let packet = login::Login::decode(&mut stream).unwrap();
+    assert_eq!(packet.get_protocol(), protocol_version::LATEST);
+
+    let (identity, client_data) = packet.verify().unwrap();
+    assert_eq!(identity.display_name, "Alex");
+    assert_eq!(identity.uuid, uuid);
+    assert!(!identity.is_authenticated()); // offline, not signed by the Mojang root key
+    assert!(identity.expires_at.unwrap() > 0);
+    assert_eq!(client_data.third_party_name, "Alex");
+    assert_eq!(client_data.server_address, "127.0.0.1:19132");
+    assert_eq!(client_data.game_version, "1.21.50");
+    assert_eq!(client_data.client_random_id, 12345);
+
+    // client data signed by a key that is not the identity key
+    let other_key = PKey::from_ec_key(encryption::generate_ec_key().unwrap()).unwrap();
+    let x5u = openssl::base64::encode_block(&other_key.public_key_to_der().unwrap());
+    let forged = jwt::encode(&json!({ "alg": "ES384", "x5u": x5u }), &client_data, &other_key).unwrap();
+    assert!(login::new(protocol_version::LATEST, login_data[0].clone(), forged).verify().is_err());
+
+    // the same chain with its own key as the trusted root
+    let chain = packet.get_chain().unwrap();
+    let root_key = Jwt::parse(&chain[0]).unwrap().get_x5u().unwrap().to_string();
+    assert!(login::verify_chain(&chain, &root_key).unwrap().is_authenticated());
+    assert!(!login::verify_chain(&chain, login::MOJANG_ROOT_PUBLIC_KEY).unwrap().is_authenticated());
+    assert!(!login::identity_from_chain(&chain).unwrap().is_authenticated());
+
+    // an identity appended with the identity key of the chain
+    let claims = json!({ "extraData": { "XUID": "2535400000000000", "displayName": "Steve", "identity": Uuid::from_u128(1) }, "identityPublicKey": root_key });
+    let appended = jwt::encode(&json!({ "alg": "ES384", "x5u": root_key }), &claims, &pkey).unwrap();
+    assert!(matches!(login::verify_chain(&[chain, vec![appended]].concat(), &root_key), Err(ClientError::Auth(reason, None)) if reason.contains("second identity")));
+}
+
+#[test]
+fn client_data_options() {
+    let default_skin = Skin::default();
+    assert_eq!((default_skin.data.len(), default_skin.width, default_skin.height), (64 * 64 * 4, 64, 64));
+    assert!(serde_json::from_str::<serde_json::Value>(&default_skin.geometry).is_ok());
+    assert!(default_skin.resource_patch.contains("geometry.humanoid.custom"));
+
+    let geometry = json!({
+        "format_version": "1.12.0",
+        "minecraft:geometry": [{ "description": { "identifier": "geometry.bot" }, "bones": [] }]
+    }).to_string();
+    let skin = Skin::from_png_bytes(&png(128, 128, png::ColorType::Rgb, &[7; 128 * 128 * 3]), geometry.clone()).unwrap();
+    assert_eq!(skin.data.len(), 128 * 128 * 4);
+    assert_eq!(&skin.data[..4], &[7, 7, 7, 255]);
+    assert!(Skin::from_png_bytes(&png(32, 32, png::ColorType::Rgba, &[0; 32 * 32 * 4]), geometry).is_err());
+    let cape = Cape::from_png_bytes("bot_cape".to_string(), &png(64, 32, png::ColorType::Rgba, &[1; 64 * 32 * 4])).unwrap();
+
+    let options = ClientDataOptions::new()
+        .skin(skin.with_arm_size(client_data_options::ARM_SIZE_SLIM))
+        .cape(cape)
+        .device(client_data_options::DEVICE_IOS, "iPhone15,2".to_string())
+        .input_mode(client_data_options::INPUT_TOUCH, client_data_options::INPUT_TOUCH)
+        .language("de_DE".to_string())
+        .memory_tier(2);
+    assert_ne!(options.device_id, ClientDataOptions::new().device_id);
+
+    let auth_data = OfflineAuth::new(OfflineIdentity::new("Bot".to_string()).unwrap()).auth_data().unwrap();
+    let mut chain = auth_data.chain;
+    let login_data = login::convert_login_chain(&mut chain, PKey::from_ec_key(auth_data.ec_key).unwrap(), "127.0.0.1".to_string(), 19132, 1, "1.21.50".to_string(), &options).unwrap();
+    let client_data = login::new(protocol_version::LATEST, login_data[0].clone(), login_data[1].clone()).get_client_data().unwrap();
+
+    assert_eq!(client_data.device_os, client_data_options::DEVICE_IOS);
+    assert_eq!(client_data.device_model, "iPhone15,2");
+    assert_eq!(client_data.device_id, options.device_id);
+    assert_eq!(client_data.current_input_mode, client_data_options::INPUT_TOUCH);
+    assert_eq!(client_data.language_code, "de_DE");
+    assert_eq!((client_data.skin_image_width, client_data.skin_image_height), (128, 128));
+    assert_eq!(client_data.arm_size, "slim");
+    assert_eq!(client_data.skin_id, "Customgeometry.bot");
+    assert_eq!(openssl::base64::decode_block(&client_data.skin_resource_patch).unwrap(), br#"{"geometry":{"default":"geometry.bot"}}"#);
+    assert_eq!(client_data.cape_id, "bot_cape");
+    assert_eq!(client_data.cape_image_width, 64);
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use bedrock_client::movement::{self, MovementController};
+use bedrock_client::protocol::game::bedrock_packet::{encode_packet_versioned, BedrockPacket};
+use bedrock_client::protocol::game::player_auth_input::{self, PlayerAuthInput};
+use bedrock_client::protocol::game::protocol_version;
+use bedrock_client::protocol::game::types::attribute::{self, Attribute};
+use bedrock_client::protocol::game::types::vector::Vec3;
+use bedrock_client::protocol::game::update_attributes::{self, UpdateAttributes};
+use binary_utils::binary::Stream;
+use common::movement_attribute;
+
+#[test]
+fn versioned_player_auth_input() {
+    let mut movement = MovementController::new(Vec3::new(0.5, 65.62, 0.5), 0.0, 0.0, 100);
+    movement.walk(1.0, 0.0);
+    movement.sneak(true);
+    movement.jump();
+    let packet = movement.tick();
+
+    for (_, protocol) in protocol_version::PROTOCOL_VERSIONS {
+        let encoded = encode_packet_versioned(&packet, protocol);
+
+        let mut stream = Stream::new(encoded.clone(), 0);
+        stream.get_unsigned_var_int();
+        stream.get_unsigned_var_int();
+        let decoded = PlayerAuthInput::decode_versioned(&mut stream, protocol).unwrap();
+        assert!(stream.feof());
+        assert_eq!(decoded.has_input(player_auth_input::INPUT_SNEAK_CURRENT_RAW), protocol >= protocol_version::V1_21_50);
+        assert_eq!(decoded.input_data | 1 << player_auth_input::INPUT_JUMP_PRESSED_RAW | 1 << player_auth_input::INPUT_JUMP_CURRENT_RAW | 1 << player_auth_input::INPUT_SNEAK_PRESSED_RAW | 1 << player_auth_input::INPUT_SNEAK_CURRENT_RAW, packet.input_data);
+        assert_eq!(encode_packet_versioned(&decoded, protocol), encoded);
+    }
+
+    // before 1.21.50 the flags are a var long without the raw jump and sneak bits
+    let mut movement = MovementController::new(Vec3::new(0.5, 65.62, 0.5), 0.0, 0.0, 100);
+    movement.sneak(true);
+    let packet = movement.tick();
+    assert!(packet.has_input(player_auth_input::INPUT_SNEAK_CURRENT_RAW));
+    let mut stream = Stream::new(encode_packet_versioned(&packet, protocol_version::V1_21_40), 0);
+    stream.get_unsigned_var_int();
+    stream.get_unsigned_var_int();
+    stream.get(32).unwrap(); // rotation, position, move vector and head yaw
+    let expected = 1u64 << player_auth_input::INPUT_SNEAKING | 1 << player_auth_input::INPUT_SNEAK_DOWN | 1 << player_auth_input::INPUT_START_SNEAKING;
+    assert_eq!(stream.get_unsigned_var_long(), expected);
+
+    // an item interaction is followed by data this client does not read
+    let mut stream = Stream::new(vec![0; 32], 0);
+    stream.put_unsigned_var_long(1 << player_auth_input::INPUT_PERFORM_ITEM_INTERACTION);
+    stream.put(vec![0; 24]);
+    assert!(PlayerAuthInput::decode(&mut Stream::new(stream.get_buffer(), 0)).is_err());
+}
+
+#[test]
+fn movement_controller() {
+    let mut movement = MovementController::new(Vec3::new(0.5, 65.62, 0.5), 0.0, 0.0, 100);
+    let input = movement.tick();
+    assert_eq!((input.tick, input.position, input.delta), (101, Vec3::new(0.5, 65.62, 0.5), Vec3::default()));
+
+    // yaw 0 faces +z, strafing left goes to +x
+    movement.walk(1.0, 0.0);
+    let input = movement.tick();
+    assert!((input.delta.z - movement::WALK_SPEED).abs() < 1e-6 && input.delta.x.abs() < 1e-6);
+    assert!(input.has_input(player_auth_input::INPUT_UP) && !input.has_input(player_auth_input::INPUT_SPRINTING));
+    movement.walk(0.0, 1.0);
+    assert!((movement.tick().delta.x - movement::WALK_SPEED).abs() < 1e-6);
+
+    movement.look(450.0, -120.0);
+    assert_eq!((movement.get_yaw(), movement.get_pitch()), (90.0, -90.0));
+    movement.walk(1.0, 0.0);
+    movement.sprint(true);
+    let input = movement.tick();
+    assert!((input.delta.x + movement::SPRINT_SPEED).abs() < 1e-6);
+    assert!(input.has_input(player_auth_input::INPUT_START_SPRINTING) && input.has_input(player_auth_input::INPUT_SPRINTING));
+    assert!(!movement.tick().has_input(player_auth_input::INPUT_START_SPRINTING));
+
+    // diagonal input is not faster than straight input
+    movement.walk(1.0, 1.0);
+    movement.sprint(false);
+    let input = movement.tick();
+    assert!(input.has_input(player_auth_input::INPUT_STOP_SPRINTING));
+    assert!((input.delta.length() - movement::WALK_SPEED).abs() < 1e-6);
+
+    movement.jump();
+    assert!(movement.tick().has_input(player_auth_input::INPUT_START_JUMPING));
+    assert!(!movement.tick().has_input(player_auth_input::INPUT_START_JUMPING));
+    movement.stop();
+    assert_eq!(movement.tick().delta, Vec3::default());
+    assert_eq!(movement.get_tick(), 109);
+}
+
+#[test]
+fn versioned_update_attributes() {
+    let packet = update_attributes::new(1, vec![movement_attribute(0.13), Attribute::new(attribute::HEALTH.to_string(), 0.0, 20.0, 17.0, 20.0)], 500);
+    for (_, protocol) in protocol_version::PROTOCOL_VERSIONS {
+        let encoded = encode_packet_versioned(&packet, protocol);
+
+        let mut stream = Stream::new(encoded.clone(), 0);
+        stream.get_unsigned_var_int();
+        stream.get_unsigned_var_int();
+        let decoded = UpdateAttributes::decode_versioned(&mut stream, protocol).unwrap();
+        assert!(stream.feof());
+        assert_eq!(decoded.get_attribute(attribute::MOVEMENT), packet.get_attribute(attribute::MOVEMENT));
+        assert_eq!(encode_packet_versioned(&decoded, protocol), encoded);
+    }
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use bedrock_client::protocol::game::bedrock_packet::{encode_packet_versioned, BedrockPacket};
+use bedrock_client::protocol::game::{command_output, command_request, disconnect, play_status, protocol_version, request_chunk_radius, resource_pack_chunk_data, resource_pack_chunk_request, resource_pack_client_response, resource_pack_data_info, resource_pack_stack, text, update_soft_enum};
+use bedrock_client::protocol::game::resource_pack_stack::ResourcePackStackEntry;
+use bedrock_client::protocol::game::resource_packs_info::{ResourcePack, ResourcePacksInfo};
+use bedrock_client::protocol::game::text::TextMessage;
+use bedrock_client::protocol::game::types::command_origin_data::{self, CommandOriginData};
+use bedrock_client::protocol::game::types::experiments::Experiments;
+use binary_utils::binary::Stream;
+use common::{round_trip, sample_commands, sample_output};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+#[test]
+fn packet_round_trip() {
+    round_trip(&text::chat("Steve".to_string(), "hello".to_string(), "".to_string()));
+    round_trip(&text::new(true, TextMessage::Translation{ message: "chat.type.text".to_string(), parameters: vec!["Steve".to_string(), "hello".to_string()] }, "".to_string(), "".to_string(), "".to_string()));
+    round_trip(&text::new(false, TextMessage::Whisper{ source_name: "Alex".to_string(), message: "hi".to_string() }, "2535400000000000".to_string(), "".to_string(), "hi".to_string()));
+    round_trip(&text::new(false, TextMessage::Tip("tip".to_string()), "".to_string(), "".to_string(), "".to_string()));
+    round_trip(&command_request::new("/say hi".to_string(), CommandOriginData::new(command_origin_data::ORIGIN_PLAYER, Uuid::from_u128(7), "request-1".to_string(), 0), false, command_request::COMMAND_VERSION));
+    round_trip(&sample_commands());
+    round_trip(&sample_output(command_output::TYPE_ALL_OUTPUT));
+    round_trip(&sample_output(command_output::TYPE_DATA_SET));
+    round_trip(&update_soft_enum::new("Warps".to_string(), vec!["spawn".to_string()], update_soft_enum::TYPE_ADD));
+    round_trip(&command_request::new("/help".to_string(), CommandOriginData::new(command_origin_data::ORIGIN_DEV_CONSOLE, Uuid::from_u128(7), "".to_string(), -5), true, command_request::COMMAND_VERSION));
+    round_trip(&play_status::new(3));
+    round_trip(&disconnect::new(0, Option::from("Kicked".to_string()), Option::from("".to_string())));
+    round_trip(&request_chunk_radius::new(8, 8));
+    round_trip(&resource_pack_client_response::new(resource_pack_client_response::SEND_PACKS, vec!["00000000-0000-0000-0000-000000001234_1.0.0".to_string()]));
+    round_trip(&resource_pack_data_info::new("00000000-0000-0000-0000-000000001234_1.0.0".to_string(), 1024, 3, 2500, vec![7; 32], false, resource_pack_data_info::TYPE_RESOURCES));
+    round_trip(&resource_pack_chunk_request::new("00000000-0000-0000-0000-000000001234_1.0.0".to_string(), 2));
+    round_trip(&resource_pack_chunk_data::new("00000000-0000-0000-0000-000000001234_1.0.0".to_string(), 2, 2048, vec![1, 2, 3]));
+    round_trip(&resource_pack_stack::new(true, vec![], vec![ResourcePackStackEntry::new("00000000-0000-0000-0000-000000001234".to_string(), "1.0.0".to_string(), "".to_string())], "1.21.50".to_string(), Experiments::new(HashMap::from([("data_driven_items".to_string(), true)]), true), false));
+
+    // unknown text type
+    assert!(text::Text::decode(&mut Stream::new(vec![99, 0, 0, 0, 0], 0)).is_err());
+}
+
+#[test]
+fn versioned_text() {
+    let packets = [
+        text::chat("Steve".to_string(), "hi".to_string(), "1234".to_string()),
+        text::new(true, TextMessage::Translation{ message: "%death.attack.generic".to_string(), parameters: vec!["Steve".to_string()] }, "".to_string(), "".to_string(), "".to_string())
+    ];
+    for (_, protocol) in protocol_version::PROTOCOL_VERSIONS {
+        for packet in &packets {
+            let encoded = encode_packet_versioned(packet, protocol);
+
+            let mut stream = Stream::new(encoded.clone(), 0);
+            stream.get_unsigned_var_int();
+            stream.get_unsigned_var_int();
+            let decoded = text::Text::decode_versioned(&mut stream, protocol).unwrap();
+            assert!(stream.feof());
+            assert_eq!((decoded.message.get_message(), decoded.message.get_parameters()), (packet.message.get_message(), packet.message.get_parameters()));
+            assert_eq!(encode_packet_versioned(&decoded, protocol), encoded);
+        }
+    }
+}
+
+#[test]
+fn versioned_resource_packs_info() {
+    let resource_pack = || ResourcePack{ uuid: Uuid::from_u128(0x1234_5678_9abc_def0_1234_5678_9abc_def0), version: "1.0.0".to_string(), size_bytes: 1024, encryption_key: "".to_string(), sub_pack_name: "".to_string(), content_id: "".to_string(), has_scripts: false, is_addon_pack: false, is_rtx_capable: false, cdn_url: "https://cdn.example.com/pack.zip".to_string() };
+
+    for (_, protocol) in protocol_version::PROTOCOL_VERSIONS {
+        let packet = ResourcePacksInfo{ must_accept: true, has_addons: false, has_scripts: false, force_server_packs: false, world_template_id: Uuid::nil(), world_template_version: "".to_string(), behaviour_packs: vec![], resource_packs: vec![resource_pack()] };
+        let encoded = encode_packet_versioned(&packet, protocol);
+
+        let mut stream = Stream::new(encoded.clone(), 0);
+        stream.get_unsigned_var_int();
+        stream.get_unsigned_var_int();
+        let decoded = ResourcePacksInfo::decode_versioned(&mut stream, protocol).unwrap();
+        assert!(stream.feof());
+        assert_eq!(decoded.resource_packs[0].cdn_url, packet.resource_packs[0].cdn_url);
+        assert_eq!(encode_packet_versioned(&decoded, protocol), encoded);
+    }
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use bedrock_client::protocol::game::{resource_pack_chunk_data, resource_pack_data_info};
+use bedrock_client::utils::pack_cache::{self, PackCache, PackDownload};
+use bedrock_client::utils::pack_decryption;
+use bedrock_client::utils::pack_reader::{self, Pack, PackSet};
+use openssl::symm::{encrypt, Cipher};
+use serde_json::json;
+use std::io::Write;
+use uuid::Uuid;
+
+#[test]
+fn resource_pack_download() {
+    let pack = (0..2500u32).map(|i| (i % 251) as u8).collect::<Vec<u8>>();
+    let uuid = Uuid::from_u128(0x1234);
+    let pack_id = pack_cache::pack_id(&uuid, "1.0.0");
+    assert_eq!(pack_cache::split_pack_id(&pack_id), (uuid.to_string().as_str(), Option::from("1.0.0")));
+
+    let data_info = resource_pack_data_info::new(pack_id.clone(), 1024, 3, pack.len() as u64, openssl::sha::sha256(&pack).to_vec(), false, resource_pack_data_info::TYPE_RESOURCES);
+    let mut download = PackDownload::new(&data_info).unwrap();
+    for (i, chunk) in pack.chunks(1024).enumerate() {
+        assert_eq!(download.next_chunk(), Option::from(i as u32));
+        download.add_chunk(&resource_pack_chunk_data::new(pack_id.clone(), i as u32, i as u64 * 1024, chunk.to_vec())).unwrap();
+    }
+    assert!(download.is_complete());
+    assert_eq!(download.finish().unwrap(), pack);
+
+    // out of order chunk
+    let mut download = PackDownload::new(&data_info).unwrap();
+    assert!(download.add_chunk(&resource_pack_chunk_data::new(pack_id.clone(), 1, 1024, pack[1024..2048].to_vec())).is_err());
+
+    // same size, different content
+    let mut download = PackDownload::new(&data_info).unwrap();
+    for (i, chunk) in pack.chunks(1024).enumerate() {
+        download.add_chunk(&resource_pack_chunk_data::new(pack_id.clone(), i as u32, i as u64 * 1024, vec![0; chunk.len()])).unwrap();
+    }
+    assert!(download.finish().is_err());
+
+    let root = std::env::temp_dir().join(format!("bedrock_client_pack_cache_{}", std::process::id()));
+    let cache = PackCache::new(&root);
+    assert!(!cache.contains(&uuid, "1.0.0"));
+    let object = cache.store(&uuid, "1.0.0", &pack).unwrap();
+    cache.store(&Uuid::from_u128(0x5678), "2.0.0", &pack).unwrap();
+    assert_eq!(cache.get(&uuid, "1.0.0").unwrap(), pack);
+    assert_eq!(std::fs::read_dir(root.join("objects")).unwrap().count(), 1);
+    assert!(!cache.contains(&uuid, "1.0.1"));
+
+    // a modified object is not handed out
+    std::fs::write(&object, b"changed").unwrap();
+    assert!(cache.get(&uuid, "1.0.0").is_none());
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+fn encrypt_cfb8(data: &[u8], key: &str) -> Vec<u8> {
+    encrypt(Cipher::aes_256_cfb8(), key.as_bytes(), Some(&key.as_bytes()[..16]), data).unwrap()
+}
+
+#[test]
+fn encrypted_pack() {
+    let content_key = "0123456789abcdef0123456789abcdef";
+    let file_key = "fedcba9876543210fedcba9876543210";
+    let contents = json!({ "content": [
+        { "path": "manifest.json" },
+        { "path": "textures/" },
+        { "path": "textures/stone.json", "key": file_key }
+    ] }).to_string();
+
+    let mut header = vec![0; 4];
+    header.extend_from_slice(&0x9bcfb9fcu32.to_le_bytes());
+    header.resize(0x10, 0);
+    header.push(4);
+    header.extend_from_slice(b"test");
+    header.resize(0x100, 0);
+    header.extend(encrypt_cfb8(contents.as_bytes(), content_key));
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+    for (path, data) in [("pack/manifest.json", b"{}".to_vec()), ("pack/contents.json", header), ("pack/textures/stone.json", encrypt_cfb8(b"{\"stone\":1}", file_key))] {
+        zip.start_file(path, options).unwrap();
+        zip.write_all(&data).unwrap();
+    }
+    let pack = zip.finish().unwrap().into_inner();
+
+    let files = pack_decryption::open_pack(&pack, content_key).unwrap();
+    assert_eq!(files.paths().collect::<Vec<&str>>(), vec!["manifest.json", "textures/stone.json"]);
+    assert_eq!(files.get("textures/stone.json").unwrap(), b"{\"stone\":1}");
+    assert_eq!(files.get("manifest.json").unwrap(), b"{}");
+
+    assert!(pack_decryption::open_pack(&pack, "ffffffffffffffffffffffffffffffff").is_err());
+    assert!(pack_decryption::open_pack(&pack, "short").is_err());
+    // without the key the files stay as they are
+    assert_ne!(pack_decryption::open_pack(&pack, "").unwrap().get("textures/stone.json").unwrap(), b"{\"stone\":1}");
+}
+
+#[test]
+fn pack_reader() {
+    let root = std::env::temp_dir().join(format!("bedrock_client_pack_reader_{}", std::process::id()));
+    let files = [
+        ("base/manifest.json", r#"{
+            // format version 2 uses version arrays
+            "format_version": 2,
+            "header": { "name": "Base", "uuid": "00000000-0000-0000-0000-000000000001", "version": [1, 2, 0], "min_engine_version": [1, 21, 0] },
+            "modules": [{ "type": "resources", "uuid": "00000000-0000-0000-0000-000000000002", "version": [1, 2, 0] }],
+            "dependencies": [{ "module_name": "@minecraft/server", "version": "1.8.0" }]
+        }"#),
+        ("base/textures/terrain_texture.json", r#"{ "texture_data": { "stone": { "textures": ["textures/blocks/stone", { "path": "textures/blocks/stone_2" }] } } }"#),
+        ("base/textures/blocks/stone.png", "png"),
+        ("base/texts/en_US.lang", "## comment\nblock.stone.name=Stone\t## trailing\n"),
+        ("base/entity/bot.json", r#"{ "minecraft:client_entity": { "description": { "identifier": "custom:bot" } } }"#),
+        ("base/items/wand.json", r#"{ "minecraft:item": { "description": { "identifier": "custom:wand" } } }"#),
+        ("base/items/broken.json", r#"{ "minecraft:item": "#),
+        ("override/manifest.json", r#"{ "format_version": 3, "header": { "name": "Override", "uuid": "00000000-0000-0000-0000-000000000003", "version": "2.0.0" } }"#),
+        ("override/texts/en_US.lang", "block.stone.name=Rock"),
+        ("override/textures/blocks/stone.tga", "tga")
+    ];
+    for (path, contents) in files {
+        std::fs::create_dir_all(root.join(path).parent().unwrap()).unwrap();
+        std::fs::write(root.join(path), contents).unwrap();
+    }
+
+    let base = Pack::open(root.join("base")).unwrap();
+    assert_eq!(base.get_version(), "1.2.0");
+    assert_eq!(base.manifest.header.min_engine_version.as_deref(), Option::from("1.21.0"));
+    assert_eq!(base.manifest.modules[0].module_type, pack_reader::MODULE_RESOURCES);
+    assert_eq!(base.manifest.dependencies[0].module_name.as_deref(), Option::from("@minecraft/server"));
+    assert!(!base.is_behaviour_pack());
+    assert_eq!(base.terrain_textures["stone"], vec!["textures/blocks/stone", "textures/blocks/stone_2"]);
+    assert_eq!(base.textures, vec!["textures/blocks/stone.png"]);
+    assert_eq!(base.translate("en_US", "block.stone.name"), Option::from("Stone"));
+    assert!(base.entities.contains_key("custom:bot"));
+    assert!(base.items.contains_key("custom:wand"));
+    assert_eq!(base.items.len(), 1); // broken.json is skipped
+
+    let override_pack = Pack::open(root.join("override")).unwrap();
+    assert_eq!(override_pack.get_version(), "2.0.0");
+
+    let mut packs = PackSet::new();
+    packs.push(override_pack);
+    packs.push(base);
+    assert_eq!(packs.translate("en_US", "block.stone.name"), Option::from("Rock"));
+    let (pack, file, data) = packs.terrain_texture("stone").unwrap();
+    assert_eq!((pack.manifest.header.name.as_str(), file, data), ("Override", "textures/blocks/stone.tga", b"tga".as_slice()));
+    assert_eq!(packs.entity("custom:bot").unwrap().0.get_uuid(), Uuid::from_u128(1));
+    assert_eq!(packs.provider("entity/bot.json").unwrap().get_uuid(), Uuid::from_u128(1));
+
+    assert!(Pack::open(root.join("base/textures")).is_err());
+    std::fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,152 @@
+mod common;
+
+use bedrock_client::movement::{self, MovementController};
+use bedrock_client::physics::{self, Physics};
+use bedrock_client::protocol::game::serializer::network_nbt_serializer::network_nbt_length;
+use bedrock_client::protocol::game::types::attribute::{self, Attribute};
+use bedrock_client::protocol::game::types::vector::Vec3;
+use bedrock_client::utils::chunk::block_palette::BlockPalette;
+use bedrock_client::utils::chunk::chunk::Chunk;
+use bedrock_client::utils::chunk::paletted_storage::PalettedStorage;
+use bedrock_client::utils::chunk::world::World;
+use common::movement_attribute;
+
+// Sub chunk storage with 4 bits per block, `block` returns the palette index
+fn storage(palette: Vec<u32>, block: impl Fn(u8, u8, u8) -> u32) -> PalettedStorage {
+    let mut words = vec![0u32; 512];
+    for x in 0..16u8 {
+        for z in 0..16u8 {
+            for y in 0..16u8 {
+                let index = (x as usize) << 8 | (z as usize) << 4 | y as usize;
+                words[index / 8] |= block(x, y, z) << ((index % 8) * 4);
+            }
+        }
+    }
+    PalettedStorage::new(4, words, palette)
+}
+
+const AIR: u32 = 0;
+const STONE: u32 = 1;
+const SLAB: u32 = 2;
+const WATER: u32 = 3;
+
+// Stone floor with its top at y 64, `blocks` returns the runtime ID for y 64..80
+fn test_world(blocks: impl Fn(u8, u8, u8) -> u32) -> World {
+    let mut chunk = Chunk::new(AIR, 0);
+    // sub chunk 3 (y 48..64) is the 7th from the bottom of the overworld
+    chunk.sub_chunks[7].storages.push(storage(vec![AIR, STONE], |_, y, _| (y == 15) as u32));
+    chunk.sub_chunks[8].storages.push(storage(vec![AIR, STONE, SLAB, WATER], blocks));
+
+    let mut world = World::new(0);
+    world.set_palette(Option::from(BlockPalette::new(vec!["minecraft:air", "minecraft:stone", "minecraft:smooth_stone_slab", "minecraft:water"].into_iter().map(String::from).collect())));
+    world.add_chunk(0, 0, chunk);
+    world
+}
+
+#[test]
+fn chunk_storage() {
+    let storage = storage(vec![7, 9], |x, y, z| (x == 1 && y == 2 && z == 3) as u32);
+    assert_eq!((storage.get(1, 2, 3), storage.get(3, 2, 1), storage.get(0, 0, 0)), (9, 7, 7));
+    assert_eq!(PalettedStorage::empty(5).get(15, 15, 15), 5);
+
+    let world = test_world(|x, y, z| if (x, y, z) == (8, 0, 6) { SLAB } else { AIR });
+    assert_eq!(world.get_block(8, 63, 8), Option::from("minecraft:stone"));
+    assert_eq!(world.get_block(8, 64, 6), Option::from("minecraft:smooth_stone_slab"));
+    assert_eq!(world.get_block(8, -64, 8), Option::from("minecraft:air"));
+    assert_eq!(world.get_block(8, 400, 8), Option::from("minecraft:air"));
+    assert_eq!(world.get_block(20, 63, 8), None);
+    assert!(world.is_loaded(15, 0) && !world.is_loaded(-1, 0));
+
+    // custom blocks are sorted in by the hash of their name
+    let palette = BlockPalette::new(vec!["minecraft:air".to_string(), "minecraft:stone".to_string(), "minecraft:stone".to_string()]);
+    let custom = palette.with_custom_blocks(&[("custom:block".to_string(), 2)]);
+    assert_eq!(custom.len(), 5);
+    assert_eq!(custom.get_runtime_id("custom:block").map(|id| custom.get_name(id + 1)), Option::from(Option::from("custom:block")));
+    assert_eq!(custom.get_name(custom.get_runtime_id("minecraft:stone").unwrap() + 1), Option::from("minecraft:stone"));
+
+    // a compound with the string "name" => "a" and an int list, then a cut off state
+    let state = vec![10, 0, 8, 4, b'n', b'a', b'm', b'e', 1, b'a', 9, 1, b'l', 3, 4, 2, 1, 0];
+    assert_eq!(network_nbt_length(&state, 0).unwrap(), state.len());
+    assert!(network_nbt_length(&state[..state.len() - 1], 0).is_err());
+    assert!(network_nbt_length(&[10, 0, 99, 0], 0).is_err());
+    assert!(BlockPalette::from_canonical_states(state[..5].to_vec()).is_err());
+}
+
+#[test]
+fn physics_collisions() {
+    assert!(physics::jump_height() > 1.2 && physics::jump_height() < 1.3);
+    assert!(physics::collision_boxes("minecraft:short_grass").is_empty());
+    assert_eq!(physics::collision_boxes("minecraft:oak_fence")[0].max.y, 1.5);
+    // blocks whose shape depends on the state are solid in their closed shape
+    assert_eq!(physics::collision_boxes("minecraft:wooden_door")[0].max.y, 1.0);
+    assert_eq!(physics::collision_boxes("minecraft:spruce_trapdoor")[0].max.y, 3.0 / 16.0);
+    assert_eq!(physics::collision_boxes("minecraft:birch_fence_gate")[0].max.y, 1.5);
+
+    // bottom slab at z 6, a wall two blocks high at z 10
+    let world = test_world(|x, y, z| match (x, y, z) {
+        (8, 0, 6) => SLAB,
+        (8, 0..=1, 10) => STONE,
+        _ => AIR
+    });
+
+    // falls from y 70 onto the floor
+    let mut movement = MovementController::new(Vec3::new(8.5, 70.0 + physics::EYE_HEIGHT, 2.5), 0.0, 0.0, 0);
+    let mut damage = 0.0;
+    for _ in 0..40 {
+        movement.tick_in(&world);
+        damage += movement.get_physics().fall_damage;
+    }
+    assert!(movement.get_physics().on_ground);
+    assert_eq!(movement.get_position().y, 64.0 + physics::EYE_HEIGHT);
+    assert_eq!(damage, 3.0);
+
+    // walks at walking speed, up the slab, down again and stops in front of the wall
+    movement.walk(1.0, 0.0);
+    let mut highest = 0.0f32;
+    let mut speeds = Vec::new();
+    for _ in 0..60 {
+        let input = movement.tick_in(&world);
+        highest = highest.max(input.position.y);
+        speeds.push(input.delta.z);
+    }
+    // still in front of the slab
+    assert!((speeds[12] - movement::WALK_SPEED).abs() < 1e-3);
+    assert_eq!(highest, 64.5 + physics::EYE_HEIGHT);
+    assert!(movement.get_physics().collided_horizontally);
+    assert!((movement.get_position().z - 9.7).abs() < 1e-4);
+    assert_eq!(movement.get_position().y, 64.0 + physics::EYE_HEIGHT);
+
+    // the sprint modifier of the attribute is not applied twice
+    let mut physics = Physics::new();
+    assert!(physics.set_attribute(&movement_attribute(0.13)));
+    assert!((physics.movement_speed - 0.1).abs() < 1e-6);
+    assert!(!physics.set_attribute(&Attribute::new(attribute::HEALTH.to_string(), 0.0, 20.0, 20.0, 20.0)));
+}
+
+#[test]
+fn physics_fluids() {
+    let world = test_world(|_, y, _| if y < 8 { WATER } else { AIR });
+    let mut movement = MovementController::new(Vec3::new(8.5, 70.0 + physics::EYE_HEIGHT, 8.5), 0.0, 0.0, 0);
+    let mut damage = 0.0;
+    for _ in 0..80 {
+        movement.tick_in(&world);
+        assert!(movement.get_physics().velocity.y > -0.2);
+        damage += movement.get_physics().fall_damage;
+    }
+    assert!(movement.get_physics().in_water && movement.get_physics().on_ground);
+    assert_eq!(damage, 0.0);
+
+    // swims up while jumping
+    let y = movement.get_position().y;
+    for _ in 0..10 {
+        movement.jump();
+        movement.tick_in(&world);
+    }
+    assert!(movement.get_position().y > y);
+
+    // without the chunk the controller moves like without physics
+    let mut movement = MovementController::new(Vec3::new(100.5, 70.0, 100.5), 0.0, 0.0, 0);
+    movement.walk(1.0, 0.0);
+    assert!((movement.tick_in(&world).delta.z - movement::WALK_SPEED).abs() < 1e-6);
+    assert_eq!(movement.get_position().y, 70.0);
+}